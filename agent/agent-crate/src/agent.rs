@@ -0,0 +1,125 @@
+use crate::{data::*, event::AgentEvent};
+use alloc::string::String;
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, Bytes},
+    ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use common::{errors::Error, events, utils::call_versioned_contract_ignoring_result};
+
+/// @notice Single owner of the admin roles of the DAO contracts, forwarding calls of its agents
+/// @dev The ownership agent can call any entry point and decides which entry points of which
+/// targets the parameter and emergency agents may call. Agents can be accounts as well as
+/// contracts such as the voting contract.
+pub trait AGENT<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        ownership_agent: Key,
+        parameter_agent: Key,
+        emergency_agent: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        Permissions::init();
+        events::init::<AgentEvent>();
+        set_ownership_agent(ownership_agent);
+        set_parameter_agent(parameter_agent);
+        set_emergency_agent(emergency_agent);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    /// @notice Set the future agents, applied once the future ownership agent accepts them
+    #[inline(always)]
+    fn commit_set_agents(&self, o_agent: Key, p_agent: Key, e_agent: Key) {
+        if self.get_caller() != get_ownership_agent() {
+            runtime::revert(ApiError::from(Error::AgentAccessDenied1));
+        };
+        set_future_ownership_agent(o_agent);
+        set_future_parameter_agent(p_agent);
+        set_future_emergency_agent(e_agent);
+        self.emit(&AgentEvent::CommitAgents {
+            ownership_agent: o_agent,
+            parameter_agent: p_agent,
+            emergency_agent: e_agent,
+        });
+    }
+
+    #[inline(always)]
+    fn accept_set_agents(&self) {
+        if self.get_caller() != get_future_ownership_agent() {
+            runtime::revert(ApiError::from(Error::AgentAccessDenied2));
+        };
+        let p_agent: Key = get_future_parameter_agent();
+        let e_agent: Key = get_future_emergency_agent();
+        set_ownership_agent(self.get_caller());
+        set_parameter_agent(p_agent);
+        set_emergency_agent(e_agent);
+        self.emit(&AgentEvent::ApplyAgents {
+            ownership_agent: self.get_caller(),
+            parameter_agent: p_agent,
+            emergency_agent: e_agent,
+        });
+    }
+
+    /// @notice Allow or disallow agent `role` to call `entry_point` of `target`
+    /// @param role `PARAMETER` or `EMERGENCY`, the ownership agent can call everything
+    #[inline(always)]
+    fn set_permission(&self, role: u8, target: Key, entry_point: String, allowed: bool) {
+        if self.get_caller() != get_ownership_agent() {
+            runtime::revert(ApiError::from(Error::AgentAccessDenied3));
+        };
+        if role != PARAMETER && role != EMERGENCY {
+            runtime::revert(ApiError::from(Error::AgentInvalidRole));
+        }
+        Permissions::instance().set(role, &target, &entry_point, allowed);
+        self.emit(&AgentEvent::SetPermission {
+            role,
+            target,
+            entry_point,
+            allowed,
+        });
+    }
+
+    /// @notice Check whether `addr` may call `entry_point` of `target` through this contract
+    #[inline(always)]
+    fn can_execute(&self, addr: Key, target: Key, entry_point: String) -> bool {
+        addr == get_ownership_agent()
+            || (addr == get_parameter_agent()
+                && Permissions::instance().get(PARAMETER, &target, &entry_point))
+            || (addr == get_emergency_agent()
+                && Permissions::instance().get(EMERGENCY, &target, &entry_point))
+    }
+
+    /// @notice Call `entry_point` of contract package `target` as this contract
+    /// @param args `RuntimeArgs` of the call, serialized with `ToBytes`
+    #[inline(always)]
+    fn execute(&self, target: Key, entry_point: String, args: Bytes) {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::AgentIsLocked));
+        }
+        set_lock(true);
+        let sender: Key = self.get_caller();
+        if !self.can_execute(sender, target, entry_point.clone()) {
+            runtime::revert(ApiError::from(Error::AgentAccessDenied4));
+        }
+        let args: RuntimeArgs =
+            bytesrepr::deserialize(args.to_vec()).unwrap_or_revert_with(Error::AgentInvalidArgs);
+        call_versioned_contract_ignoring_result(
+            target.into_hash().unwrap_or_revert().into(),
+            &entry_point,
+            args,
+        );
+        self.emit(&AgentEvent::Execute {
+            sender,
+            target,
+            entry_point,
+        });
+        set_lock(false);
+    }
+
+    fn emit(&self, agent_event: &AgentEvent) {
+        events::emit(agent_event);
+    }
+}
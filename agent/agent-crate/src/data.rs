@@ -0,0 +1,117 @@
+use alloc::{format, string::String};
+use casper_types::{ContractHash, ContractPackageHash, Key};
+use casperlabs_contract_utils::*;
+use common::{keys::*, utils::*};
+
+pub const OWNERSHIP: u8 = 0;
+pub const PARAMETER: u8 = 1;
+pub const EMERGENCY: u8 = 2;
+
+pub struct Permissions {
+    dict: Dict,
+}
+
+impl Permissions {
+    #[inline(always)]
+    pub fn instance() -> Permissions {
+        Permissions {
+            dict: Dict::instance(PERMISSIONS_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(PERMISSIONS_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, role: u8, target: &Key, entry_point: &str) -> bool {
+        let key_: String = permission_key(role, target, entry_point);
+        self.dict.get(key_.as_str()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, role: u8, target: &Key, entry_point: &str, value: bool) {
+        let key_: String = permission_key(role, target, entry_point);
+        self.dict.set(key_.as_str(), value);
+    }
+}
+
+pub fn permission_key(role: u8, target: &Key, entry_point: &str) -> String {
+    hash(format!(
+        "{}{}{}{}",
+        PERMISSIONS_DICT,
+        role,
+        key_to_str(target),
+        entry_point
+    ))
+}
+
+pub fn get_ownership_agent() -> Key {
+    get_key(OWNERSHIP_AGENT).unwrap_or_else(zero_address)
+}
+
+pub fn set_ownership_agent(ownership_agent: Key) {
+    set_key(OWNERSHIP_AGENT, ownership_agent);
+}
+
+pub fn get_parameter_agent() -> Key {
+    get_key(PARAMETER_AGENT).unwrap_or_else(zero_address)
+}
+
+pub fn set_parameter_agent(parameter_agent: Key) {
+    set_key(PARAMETER_AGENT, parameter_agent);
+}
+
+pub fn get_emergency_agent() -> Key {
+    get_key(EMERGENCY_AGENT).unwrap_or_else(zero_address)
+}
+
+pub fn set_emergency_agent(emergency_agent: Key) {
+    set_key(EMERGENCY_AGENT, emergency_agent);
+}
+
+pub fn get_future_ownership_agent() -> Key {
+    get_key(FUTURE_OWNERSHIP_AGENT).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_ownership_agent(future_ownership_agent: Key) {
+    set_key(FUTURE_OWNERSHIP_AGENT, future_ownership_agent);
+}
+
+pub fn get_future_parameter_agent() -> Key {
+    get_key(FUTURE_PARAMETER_AGENT).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_parameter_agent(future_parameter_agent: Key) {
+    set_key(FUTURE_PARAMETER_AGENT, future_parameter_agent);
+}
+
+pub fn get_future_emergency_agent() -> Key {
+    get_key(FUTURE_EMERGENCY_AGENT).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_emergency_agent(future_emergency_agent: Key) {
+    set_key(FUTURE_EMERGENCY_AGENT, future_emergency_agent);
+}
+
+pub fn get_lock() -> bool {
+    get_key(LOCK).unwrap_or_default()
+}
+
+pub fn set_lock(lock: bool) {
+    set_key(LOCK, lock);
+}
+
+pub fn get_contract_hash() -> ContractHash {
+    get_key(SELF_CONTRACT_HASH).unwrap_or_default()
+}
+
+pub fn set_contract_hash(contract_hash: ContractHash) {
+    set_key(SELF_CONTRACT_HASH, contract_hash);
+}
+
+pub fn get_package_hash() -> ContractPackageHash {
+    get_key(SELF_CONTRACT_PACKAGE_HASH).unwrap_or_default()
+}
+
+pub fn set_package_hash(package_hash: ContractPackageHash) {
+    set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
+}
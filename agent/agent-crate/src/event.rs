@@ -0,0 +1,148 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key,
+};
+use common::events::{CasperEvent, Schema, Schemas};
+
+pub enum AgentEvent {
+    CommitAgents {
+        ownership_agent: Key,
+        parameter_agent: Key,
+        emergency_agent: Key,
+    },
+    ApplyAgents {
+        ownership_agent: Key,
+        parameter_agent: Key,
+        emergency_agent: Key,
+    },
+    SetPermission {
+        role: u8,
+        target: Key,
+        entry_point: String,
+        allowed: bool,
+    },
+    Execute {
+        sender: Key,
+        target: Key,
+        entry_point: String,
+    },
+}
+
+impl AgentEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            AgentEvent::CommitAgents {
+                ownership_agent: _,
+                parameter_agent: _,
+                emergency_agent: _,
+            } => "commitAgents",
+            AgentEvent::ApplyAgents {
+                ownership_agent: _,
+                parameter_agent: _,
+                emergency_agent: _,
+            } => "applyAgents",
+            AgentEvent::SetPermission {
+                role: _,
+                target: _,
+                entry_point: _,
+                allowed: _,
+            } => "setPermission",
+            AgentEvent::Execute {
+                sender: _,
+                target: _,
+                entry_point: _,
+            } => "execute",
+        }
+        .to_string()
+    }
+}
+
+impl CasperEvent for AgentEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            AgentEvent::CommitAgents {
+                ownership_agent,
+                parameter_agent,
+                emergency_agent,
+            } => Ok([
+                ownership_agent.to_bytes()?,
+                parameter_agent.to_bytes()?,
+                emergency_agent.to_bytes()?,
+            ]
+            .concat()),
+            AgentEvent::ApplyAgents {
+                ownership_agent,
+                parameter_agent,
+                emergency_agent,
+            } => Ok([
+                ownership_agent.to_bytes()?,
+                parameter_agent.to_bytes()?,
+                emergency_agent.to_bytes()?,
+            ]
+            .concat()),
+            AgentEvent::SetPermission {
+                role,
+                target,
+                entry_point,
+                allowed,
+            } => Ok([
+                role.to_bytes()?,
+                target.to_bytes()?,
+                entry_point.to_bytes()?,
+                allowed.to_bytes()?,
+            ]
+            .concat()),
+            AgentEvent::Execute {
+                sender,
+                target,
+                entry_point,
+            } => Ok([
+                sender.to_bytes()?,
+                target.to_bytes()?,
+                entry_point.to_bytes()?,
+            ]
+            .concat()),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "commitAgents",
+                Schema::new()
+                    .with_elem::<Key>("ownership_agent")
+                    .with_elem::<Key>("parameter_agent")
+                    .with_elem::<Key>("emergency_agent"),
+            )
+            .with(
+                "applyAgents",
+                Schema::new()
+                    .with_elem::<Key>("ownership_agent")
+                    .with_elem::<Key>("parameter_agent")
+                    .with_elem::<Key>("emergency_agent"),
+            )
+            .with(
+                "setPermission",
+                Schema::new()
+                    .with_elem::<u8>("role")
+                    .with_elem::<Key>("target")
+                    .with_elem::<String>("entry_point")
+                    .with_elem::<bool>("allowed"),
+            )
+            .with(
+                "execute",
+                Schema::new()
+                    .with_elem::<Key>("sender")
+                    .with_elem::<Key>("target")
+                    .with_elem::<String>("entry_point"),
+            )
+    }
+}
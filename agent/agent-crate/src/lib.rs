@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+mod agent;
+pub mod data;
+pub mod event;
+
+pub use agent::AGENT;
@@ -0,0 +1,116 @@
+use agent_crate::data::permission_key;
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    runtime_args, CLTyped, Key, RuntimeArgs,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+
+pub struct AGENTInstance(TestContract);
+impl AGENTInstance {
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        ownership_agent: Key,
+        parameter_agent: Key,
+        emergency_agent: Key,
+        time: u64,
+    ) -> AGENTInstance {
+        AGENTInstance(TestContract::new(
+            env,
+            "agent.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "ownership_agent" => ownership_agent,
+                "parameter_agent" => parameter_agent,
+                "emergency_agent" => emergency_agent,
+            },
+            time,
+        ))
+    }
+
+    pub fn commit_set_agents(
+        &self,
+        owner: AccountHash,
+        o_agent: Key,
+        p_agent: Key,
+        e_agent: Key,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            owner,
+            "commit_set_agents",
+            runtime_args! {
+                "o_agent" => o_agent,
+                "p_agent" => p_agent,
+                "e_agent" => e_agent
+            },
+            time,
+        );
+    }
+
+    pub fn accept_set_agents(&self, owner: AccountHash, time: u64) {
+        self.0
+            .call_contract(owner, "accept_set_agents", runtime_args! {}, time);
+    }
+
+    pub fn set_permission(
+        &self,
+        owner: AccountHash,
+        role: u8,
+        target: Key,
+        entry_point: &str,
+        allowed: bool,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            owner,
+            "set_permission",
+            runtime_args! {
+                "role" => role,
+                "target" => target,
+                "entry_point" => entry_point.to_string(),
+                "allowed" => allowed
+            },
+            time,
+        );
+    }
+
+    pub fn execute(
+        &self,
+        owner: AccountHash,
+        target: Key,
+        entry_point: &str,
+        args: RuntimeArgs,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            owner,
+            "execute",
+            runtime_args! {
+                "target" => target,
+                "entry_point" => entry_point.to_string(),
+                "args" => Bytes::from(args.to_bytes().unwrap())
+            },
+            time,
+        );
+    }
+
+    pub fn has_permission(&self, role: u8, target: &Key, entry_point: &str) -> bool {
+        self.0
+            .query_dictionary(PERMISSIONS_DICT, permission_key(role, target, entry_point))
+            .unwrap_or_default()
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+
+    // Get stored key values
+    pub fn key_value<T: CLTyped + FromBytes>(&self, key: String) -> T {
+        self.0.query_named_key(key)
+    }
+}
@@ -0,0 +1,203 @@
+use agent_crate::data::{EMERGENCY, OWNERSHIP, PARAMETER};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+
+use crate::agent_instance::AGENTInstance;
+
+const NAME: &str = "AGENT";
+
+fn deploy_smart_wallet_checker(env: &TestEnv, sender: AccountHash, admin: Key) -> TestContract {
+    TestContract::new(
+        env,
+        "smart-wallet-checker.wasm",
+        "smart-wallet-checker",
+        sender,
+        runtime_args! {
+            "admin" => admin,
+        },
+        0,
+    )
+}
+
+// Deploys the agent with three different agents and a smart wallet checker administrated by it
+fn deploy() -> (
+    TestEnv,
+    AGENTInstance,
+    TestContract,
+    AccountHash,
+    AccountHash,
+    AccountHash,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let parameter = env.next_user();
+    let emergency = env.next_user();
+    let agent = AGENTInstance::new_deploy(
+        &env,
+        NAME,
+        owner,
+        Key::from(owner),
+        Key::from(parameter),
+        Key::from(emergency),
+        0,
+    );
+    let checker = deploy_smart_wallet_checker(&env, owner, Key::Hash(agent.package_hash()));
+    (env, agent, checker, owner, parameter, emergency)
+}
+
+fn check(env: &TestEnv, owner: AccountHash, checker: &TestContract, addr: Key) -> bool {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(CHECK),
+            "package_hash" => Key::Hash(checker.package_hash()),
+            "addr" => addr
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[CHECK.into()])
+}
+
+#[test]
+fn test_deploy() {
+    let (_env, agent, _, owner, parameter, emergency) = deploy();
+    let ret: Key = agent.key_value(OWNERSHIP_AGENT.to_string());
+    assert_eq!(ret, Key::from(owner));
+    let ret: Key = agent.key_value(PARAMETER_AGENT.to_string());
+    assert_eq!(ret, Key::from(parameter));
+    let ret: Key = agent.key_value(EMERGENCY_AGENT.to_string());
+    assert_eq!(ret, Key::from(emergency));
+}
+
+#[test]
+fn test_execute_as_ownership_agent() {
+    let (env, agent, checker, owner, _, _) = deploy();
+    let wallet: Key = Key::Hash([7u8; 32]);
+    agent.execute(
+        owner,
+        Key::Hash(checker.package_hash()),
+        "approve_wallet",
+        runtime_args! {
+            "wallet" => wallet
+        },
+        0,
+    );
+    assert!(check(&env, owner, &checker, wallet), "Call not forwarded");
+}
+
+#[test]
+fn test_set_permission() {
+    let (_env, agent, checker, owner, _, _) = deploy();
+    let target: Key = Key::Hash(checker.package_hash());
+    agent.set_permission(owner, PARAMETER, target, "approve_wallet", true, 0);
+    assert!(agent.has_permission(PARAMETER, &target, "approve_wallet"));
+    assert!(!agent.has_permission(EMERGENCY, &target, "approve_wallet"));
+    agent.set_permission(owner, PARAMETER, target, "approve_wallet", false, 0);
+    assert!(!agent.has_permission(PARAMETER, &target, "approve_wallet"));
+}
+
+#[test]
+fn test_execute_with_permission() {
+    let (env, agent, checker, owner, parameter, _) = deploy();
+    let target: Key = Key::Hash(checker.package_hash());
+    let wallet: Key = Key::Hash([7u8; 32]);
+    agent.set_permission(owner, PARAMETER, target, "approve_wallet", true, 0);
+    agent.execute(
+        parameter,
+        target,
+        "approve_wallet",
+        runtime_args! {
+            "wallet" => wallet
+        },
+        0,
+    );
+    assert!(check(&env, owner, &checker, wallet), "Call not forwarded");
+}
+
+#[test]
+#[should_panic]
+fn test_execute_without_permission() {
+    let (_env, agent, checker, owner, _, emergency) = deploy();
+    let target: Key = Key::Hash(checker.package_hash());
+    // Permissions are per agent, the emergency agent is not allowed
+    agent.set_permission(owner, PARAMETER, target, "approve_wallet", true, 0);
+    agent.execute(
+        emergency,
+        target,
+        "approve_wallet",
+        runtime_args! {
+            "wallet" => Key::Hash([7u8; 32])
+        },
+        0,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_execute_by_non_agent() {
+    let (env, agent, checker, _, _, _) = deploy();
+    let user = env.next_user();
+    agent.execute(
+        user,
+        Key::Hash(checker.package_hash()),
+        "approve_wallet",
+        runtime_args! {
+            "wallet" => Key::Hash([7u8; 32])
+        },
+        0,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_set_permission_by_parameter_agent() {
+    let (_env, agent, checker, _, parameter, _) = deploy();
+    agent.set_permission(
+        parameter,
+        PARAMETER,
+        Key::Hash(checker.package_hash()),
+        "approve_wallet",
+        true,
+        0,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_set_permission_for_ownership_agent() {
+    let (_env, agent, checker, owner, _, _) = deploy();
+    agent.set_permission(
+        owner,
+        OWNERSHIP,
+        Key::Hash(checker.package_hash()),
+        "approve_wallet",
+        true,
+        0,
+    );
+}
+
+#[test]
+fn test_set_agents() {
+    let (env, agent, _, owner, parameter, emergency) = deploy();
+    let new_owner = env.next_user();
+    agent.commit_set_agents(
+        owner,
+        Key::from(new_owner),
+        Key::from(emergency),
+        Key::from(parameter),
+        0,
+    );
+    let ret: Key = agent.key_value(OWNERSHIP_AGENT.to_string());
+    assert_eq!(ret, Key::from(owner), "Agents applied before acceptance");
+    agent.accept_set_agents(new_owner, 0);
+    let ret: Key = agent.key_value(OWNERSHIP_AGENT.to_string());
+    assert_eq!(ret, Key::from(new_owner));
+    let ret: Key = agent.key_value(PARAMETER_AGENT.to_string());
+    assert_eq!(ret, Key::from(emergency));
+    let ret: Key = agent.key_value(EMERGENCY_AGENT.to_string());
+    assert_eq!(ret, Key::from(parameter));
+}
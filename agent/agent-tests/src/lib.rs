@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod agent_tests;
+
+#[cfg(test)]
+pub mod agent_instance;
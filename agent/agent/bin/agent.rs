@@ -0,0 +1,366 @@
+#![no_main]
+#![no_std]
+extern crate alloc;
+use agent_crate::{self, data, AGENT};
+use alloc::{collections::BTreeSet, format, string::String, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs,
+    URef,
+};
+use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
+
+#[derive(Default)]
+struct Agent(OnChainContractStorage);
+impl ContractContext<OnChainContractStorage> for Agent {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl AGENT<OnChainContractStorage> for Agent {}
+impl Agent {
+    fn constructor(
+        &mut self,
+        ownership_agent: Key,
+        parameter_agent: Key,
+        emergency_agent: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        AGENT::init(
+            self,
+            ownership_agent,
+            parameter_agent,
+            emergency_agent,
+            contract_hash,
+            package_hash,
+        );
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let ownership_agent: Key = runtime::get_named_arg("ownership_agent");
+    let parameter_agent: Key = runtime::get_named_arg("parameter_agent");
+    let emergency_agent: Key = runtime::get_named_arg("emergency_agent");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Agent::default().constructor(
+        ownership_agent,
+        parameter_agent,
+        emergency_agent,
+        contract_hash,
+        package_hash,
+    );
+}
+
+/// Set the future agents, applied once the future ownership agent accepts them
+/// # Parameters
+/// * `o_agent` - Ownership agent
+/// * `p_agent` - Parameter agent
+/// * `e_agent` - Emergency agent
+#[no_mangle]
+fn commit_set_agents() {
+    let o_agent: Key = runtime::get_named_arg("o_agent");
+    let p_agent: Key = runtime::get_named_arg("p_agent");
+    let e_agent: Key = runtime::get_named_arg("e_agent");
+    Agent::default().commit_set_agents(o_agent, p_agent, e_agent);
+}
+
+/// Apply the future agents, only callable by the future ownership agent
+#[no_mangle]
+fn accept_set_agents() {
+    Agent::default().accept_set_agents();
+}
+
+/// Allow or disallow an agent to call an entry point of a target
+/// # Parameters
+/// * `role` - 1 for the parameter agent, 2 for the emergency agent
+/// * `target` - Contract package hash of the target
+/// * `entry_point` - Entry point of the target
+/// * `allowed` - Whether the call is allowed
+#[no_mangle]
+fn set_permission() {
+    let role: u8 = runtime::get_named_arg("role");
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let allowed: bool = runtime::get_named_arg("allowed");
+    Agent::default().set_permission(role, target, entry_point, allowed);
+}
+
+#[no_mangle]
+fn has_permission() {
+    let role: u8 = runtime::get_named_arg("role");
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let ret: bool = data::Permissions::instance().get(role, &target, &entry_point);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Check whether `addr` may call `entry_point` of `target` through this contract
+#[no_mangle]
+fn can_execute() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let ret: bool = Agent::default().can_execute(addr, target, entry_point);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Call an entry point of a contract as this contract
+/// # Parameters
+/// * `target` - Contract package hash of the target
+/// * `entry_point` - Entry point of the target
+/// * `args` - `RuntimeArgs` of the call, serialized with `ToBytes`
+#[no_mangle]
+fn execute() {
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let args: Bytes = runtime::get_named_arg("args");
+    Agent::default().execute(target, entry_point, args);
+}
+
+#[no_mangle]
+fn ownership_agent() {
+    runtime::ret(CLValue::from_t(data::get_ownership_agent()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn parameter_agent() {
+    runtime::ret(CLValue::from_t(data::get_parameter_agent()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn emergency_agent() {
+    runtime::ret(CLValue::from_t(data::get_emergency_agent()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn future_ownership_agent() {
+    runtime::ret(CLValue::from_t(data::get_future_ownership_agent()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn future_parameter_agent() {
+    runtime::ret(CLValue::from_t(data::get_future_parameter_agent()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn future_emergency_agent() {
+    runtime::ret(CLValue::from_t(data::get_future_emergency_agent()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("ownership_agent", Key::cl_type()),
+            Parameter::new("parameter_agent", Key::cl_type()),
+            Parameter::new("emergency_agent", Key::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "commit_set_agents",
+        vec![
+            Parameter::new("o_agent", Key::cl_type()),
+            Parameter::new("p_agent", Key::cl_type()),
+            Parameter::new("e_agent", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "accept_set_agents",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_permission",
+        vec![
+            Parameter::new("role", u8::cl_type()),
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("allowed", bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "has_permission",
+        vec![
+            Parameter::new("role", u8::cl_type()),
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "can_execute",
+        vec![
+            Parameter::new("addr", Key::cl_type()),
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute",
+        vec![
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("args", Bytes::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "ownership_agent",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "parameter_agent",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "emergency_agent",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_ownership_agent",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_parameter_agent",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_emergency_agent",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        // add a first version to this package
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let ownership_agent: Key = runtime::get_named_arg("ownership_agent");
+        let parameter_agent: Key = runtime::get_named_arg("parameter_agent");
+        let emergency_agent: Key = runtime::get_named_arg("emergency_agent");
+        let constructor_args = runtime_args! {
+            "ownership_agent" => ownership_agent,
+            "parameter_agent" => parameter_agent,
+            "emergency_agent" => emergency_agent,
+            "package_hash" => package_hash,
+            "contract_hash" => contract_hash,
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    }
+    // If contract package did already exist
+    else {
+        // get the package
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+        // create new version and install it
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
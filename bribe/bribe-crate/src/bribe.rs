@@ -0,0 +1,402 @@
+use crate::{data::*, event::BribeEvent};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::FromBytes, runtime_args, ApiError, CLTyped, ContractHash, ContractPackageHash, Key,
+    RuntimeArgs, U256,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use common::{errors::*, events, int128::I128};
+use crv20::Address;
+use gauge_controller_crate::data::{Point, VotedSlope};
+
+/// @notice Vote incentives for the voters of the gauge controller
+/// @dev Anyone can deposit reward tokens for a gauge and a week. The voters of the gauge claim
+/// them pro rata to the bias of their vote at the start of the week, out of the weight of the
+/// gauge that week, both read from the gauge controller. A vote cast after the start of the week
+/// earns nothing for it, and weight the admin gave the gauge leaves its share unclaimed. The
+/// controller only keeps the last vote of a user, so the biases of a vote are recorded per week
+/// by `checkpoint_vote` before the user votes again, weeks that were not are lost. Rewards can
+/// be claimed for `CLAIM_WINDOW`, after which their depositors sweep what was left unclaimed.
+pub trait BRIBE<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        controller: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        RewardsPerWeek::init();
+        ClaimedPerWeek::init();
+        UserClaimed::init();
+        VoteBiases::init();
+        SnapshotWeeks::init();
+        DepositorRewards::init();
+        events::init::<BribeEvent>();
+        set_controller(controller);
+        set_lock(false);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    #[inline(always)]
+    fn _now(&self) -> U256 {
+        U256::from(u64::from(runtime::get_blocktime()))
+    }
+
+    #[inline(always)]
+    fn _week_start(&self, time: U256) -> U256 {
+        time.checked_div(WEEK)
+            .unwrap_or_revert_with(Error::BribeDivisionError1)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::BribeMultiplicationError1)
+    }
+
+    /// @notice End of the claim window of the rewards of `week`, when depositors can sweep them
+    #[inline(always)]
+    fn _claim_window_end(&self, week: U256) -> U256 {
+        week.checked_add(CLAIM_WINDOW)
+            .unwrap_or_revert_with(Error::BribeAdditionError7)
+    }
+
+    #[inline(always)]
+    fn _controller_call<T: CLTyped + FromBytes>(&self, entry_point: &str, args: RuntimeArgs) -> T {
+        runtime::call_versioned_contract(
+            get_controller().into_hash().unwrap_or_revert().into(),
+            None,
+            entry_point,
+            args,
+        )
+    }
+
+    /// @notice Deposit `amount` of `reward_token` for the voters of `gauge` in the week starting
+    ///         at `week`
+    /// @dev `week` is rounded down to the start of its week, which can be the current week or a
+    ///      later one. The caller has to approve `amount` to this contract first
+    #[inline(always)]
+    fn deposit_reward(&self, gauge: Key, week: U256, reward_token: Key, amount: U256) {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::BribeIsLocked1));
+        }
+        set_lock(true);
+        if amount == 0.into() {
+            runtime::revert(ApiError::from(Error::BribeZeroAmount));
+        }
+        let week: U256 = self._week_start(week);
+        if week < self._week_start(self._now()) {
+            runtime::revert(ApiError::from(Error::BribePeriodEnded));
+        }
+        // Reverts for gauges that were not added to the controller
+        let _: I128 = self._controller_call("gauge_types", runtime_args! {"addr" => gauge});
+        let () = runtime::call_versioned_contract(
+            reward_token.into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer_from",
+            runtime_args! {
+                "owner" => Address::from(self.get_caller()),
+                "recipient" => Address::from(Key::from(get_package_hash())),
+                "amount" => amount
+            },
+        );
+        let rewards: U256 = RewardsPerWeek::instance().get(&gauge, &week, &reward_token);
+        RewardsPerWeek::instance().set(
+            &gauge,
+            &week,
+            &reward_token,
+            rewards
+                .checked_add(amount)
+                .unwrap_or_revert_with(Error::BribeAdditionError1),
+        );
+        let deposited: U256 =
+            DepositorRewards::instance().get(&self.get_caller(), &gauge, &week, &reward_token);
+        DepositorRewards::instance().set(
+            &self.get_caller(),
+            &gauge,
+            &week,
+            &reward_token,
+            deposited
+                .checked_add(amount)
+                .unwrap_or_revert_with(Error::BribeAdditionError8),
+        );
+        self.emit(&BribeEvent::RewardDeposited {
+            gauge,
+            week,
+            reward_token,
+            depositor: self.get_caller(),
+            amount,
+        });
+        set_lock(false);
+    }
+
+    #[inline(always)]
+    fn _slope_bias(&self, slope: &VotedSlope, week: U256) -> U256 {
+        if slope.end <= week {
+            return 0.into();
+        }
+        slope
+            .slope
+            .checked_mul(
+                slope
+                    .end
+                    .checked_sub(week)
+                    .unwrap_or_revert_with(Error::BribeSubtractionError1),
+            )
+            .unwrap_or_revert_with(Error::BribeMultiplicationError2)
+    }
+
+    /// @notice Record the bias of the vote of `user` for `gauge` at the start of each week
+    ///         started since it was cast, so that its rewards stay claimable once `user` votes
+    ///         again
+    /// @dev Anyone can call it. Weeks are recorded once, at most MAX_SNAPSHOT_WEEKS per call, and
+    ///      the weeks of a vote that was not checkpointed before the next one are lost
+    #[inline(always)]
+    fn checkpoint_vote(&self, user: Key, gauge: Key) {
+        let last_vote: U256 = self._controller_call(
+            "last_user_vote",
+            runtime_args! {"owner" => user, "spender" => gauge},
+        );
+        if last_vote == 0.into() {
+            return;
+        }
+        let slope: VotedSlope = self._controller_call(
+            "vote_user_slopes",
+            runtime_args! {"owner" => user, "spender" => gauge},
+        );
+        let current_week: U256 = self._week_start(self._now());
+        let mut week: U256 = self
+            ._week_start(last_vote)
+            .checked_add(WEEK)
+            .unwrap_or_revert_with(Error::BribeAdditionError4)
+            .max(
+                SnapshotWeeks::instance()
+                    .get(&user, &gauge)
+                    .checked_add(WEEK)
+                    .unwrap_or_revert_with(Error::BribeAdditionError5),
+            );
+        for _ in 0..MAX_SNAPSHOT_WEEKS {
+            if week > current_week {
+                break;
+            }
+            VoteBiases::instance().set(&user, &gauge, &week, self._slope_bias(&slope, week));
+            SnapshotWeeks::instance().set(&user, &gauge, week);
+            week = week
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::BribeAdditionError6);
+        }
+    }
+
+    /// @notice Bias of the vote of `user` for `gauge` at `week`: the one recorded by
+    ///         `checkpoint_vote`, else the one of the current vote when it was cast before
+    ///         `week`, else zero
+    #[inline(always)]
+    fn _vote_bias(&self, user: Key, gauge: Key, week: U256) -> U256 {
+        if let Some(bias) = VoteBiases::instance().get(&user, &gauge, &week) {
+            return bias;
+        }
+        let last_vote: U256 = self._controller_call(
+            "last_user_vote",
+            runtime_args! {"owner" => user, "spender" => gauge},
+        );
+        if last_vote >= week {
+            return 0.into();
+        }
+        let slope: VotedSlope = self._controller_call(
+            "vote_user_slopes",
+            runtime_args! {"owner" => user, "spender" => gauge},
+        );
+        self._slope_bias(&slope, week)
+    }
+
+    /// @notice Amount of `reward_token` `user` can claim for voting for `gauge` in the week
+    ///         starting at `week`
+    /// @dev The weight of the gauge that week has to be filled on the controller, which any
+    ///      checkpoint of the gauge from that week on does. Nothing is claimable once the claim
+    ///      window of the week is over
+    #[inline(always)]
+    fn claimable(&self, user: Key, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        let week: U256 = self._week_start(week);
+        if week > self._now() || self._now() >= self._claim_window_end(week) {
+            return 0.into();
+        }
+        let rewards: U256 = RewardsPerWeek::instance().get(&gauge, &week, &reward_token);
+        let bias: U256 = self._vote_bias(user, gauge, week);
+        if rewards == 0.into() || bias == 0.into() {
+            return 0.into();
+        }
+        let time_weight: U256 =
+            self._controller_call("time_weight", runtime_args! {"owner" => gauge});
+        if time_weight < week {
+            runtime::revert(ApiError::from(Error::BribeGaugeNotCheckpointed));
+        }
+        let total: Point = self._controller_call(
+            "points_weight",
+            runtime_args! {"owner" => gauge, "spender" => week},
+        );
+        if total.bias == 0.into() {
+            return 0.into();
+        }
+        let share: U256 = rewards
+            .checked_mul(bias)
+            .unwrap_or_revert_with(Error::BribeMultiplicationError3)
+            .checked_div(total.bias)
+            .unwrap_or_revert_with(Error::BribeDivisionError2);
+        let claimed: U256 = UserClaimed::instance().get(&user, &gauge, &week, &reward_token);
+        if share <= claimed {
+            return 0.into();
+        }
+        let remaining: U256 = rewards
+            .checked_sub(ClaimedPerWeek::instance().get(&gauge, &week, &reward_token))
+            .unwrap_or_revert_with(Error::BribeSubtractionError2);
+        share
+            .checked_sub(claimed)
+            .unwrap_or_revert_with(Error::BribeSubtractionError3)
+            .min(remaining)
+    }
+
+    /// @notice Claim the rewards in `reward_token` of the caller for voting for `gauge` in the
+    ///         week starting at `week`
+    /// @dev Rewards deposited after a claim can be claimed in turn, until the claim window of the
+    ///      week is over. The vote of the caller is checkpointed first. The weeks of a vote that
+    ///      was not checkpointed before the caller voted again are lost and revert
+    /// @return Amount of `reward_token` claimed
+    #[inline(always)]
+    fn claim_reward(&self, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::BribeIsLocked2));
+        }
+        set_lock(true);
+        let user: Key = self.get_caller();
+        let week: U256 = self._week_start(week);
+        if week > self._now() {
+            runtime::revert(ApiError::from(Error::BribePeriodNotStarted));
+        }
+        if self._now() >= self._claim_window_end(week) {
+            runtime::revert(ApiError::from(Error::BribeClaimWindowEnded));
+        }
+        self.checkpoint_vote(user, gauge);
+        if VoteBiases::instance().get(&user, &gauge, &week).is_none() {
+            let last_vote: U256 = self._controller_call(
+                "last_user_vote",
+                runtime_args! {"owner" => user, "spender" => gauge},
+            );
+            // Either the current vote was cast after the week started, or it replaced a vote
+            // whose weeks were not recorded in time and are lost
+            if last_vote >= week {
+                runtime::revert(ApiError::from(Error::BribeVoteAfterPeriodStart));
+            }
+        }
+        let amount: U256 = self.claimable(user, gauge, week, reward_token);
+        if amount > 0.into() {
+            let claimed: U256 = UserClaimed::instance().get(&user, &gauge, &week, &reward_token);
+            UserClaimed::instance().set(
+                &user,
+                &gauge,
+                &week,
+                &reward_token,
+                claimed
+                    .checked_add(amount)
+                    .unwrap_or_revert_with(Error::BribeAdditionError2),
+            );
+            let total_claimed: U256 = ClaimedPerWeek::instance().get(&gauge, &week, &reward_token);
+            ClaimedPerWeek::instance().set(
+                &gauge,
+                &week,
+                &reward_token,
+                total_claimed
+                    .checked_add(amount)
+                    .unwrap_or_revert_with(Error::BribeAdditionError3),
+            );
+            let () = runtime::call_versioned_contract(
+                reward_token.into_hash().unwrap_or_revert().into(),
+                None,
+                "transfer",
+                runtime_args! {
+                    "recipient" => Address::from(user),
+                    "amount" => amount
+                },
+            );
+            self.emit(&BribeEvent::RewardClaimed {
+                user,
+                gauge,
+                week,
+                reward_token,
+                amount,
+            });
+        }
+        set_lock(false);
+        amount
+    }
+
+    /// @notice Sweep the share of the caller in the rewards in `reward_token` its voters left
+    ///         unclaimed for `gauge` in the week starting at `week`
+    /// @dev Only once the claim window of the week is over, so that claims are final. Depositors
+    ///      share what is left pro rata to their deposits, which includes the share of the weight
+    ///      the admin gave the gauge and the weeks of votes that were lost
+    /// @return Amount of `reward_token` swept
+    #[inline(always)]
+    fn sweep_unclaimed(&self, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::BribeIsLocked3));
+        }
+        set_lock(true);
+        let depositor: Key = self.get_caller();
+        let week: U256 = self._week_start(week);
+        if self._now() < self._claim_window_end(week) {
+            runtime::revert(ApiError::from(Error::BribeClaimWindowOpen));
+        }
+        let deposited: U256 =
+            DepositorRewards::instance().get(&depositor, &gauge, &week, &reward_token);
+        let mut amount: U256 = 0.into();
+        if deposited > 0.into() {
+            let rewards: U256 = RewardsPerWeek::instance().get(&gauge, &week, &reward_token);
+            amount = rewards
+                .checked_sub(ClaimedPerWeek::instance().get(&gauge, &week, &reward_token))
+                .unwrap_or_revert_with(Error::BribeSubtractionError4)
+                .checked_mul(deposited)
+                .unwrap_or_revert_with(Error::BribeMultiplicationError4)
+                .checked_div(rewards)
+                .unwrap_or_revert_with(Error::BribeDivisionError3);
+            DepositorRewards::instance().set(&depositor, &gauge, &week, &reward_token, 0.into());
+        }
+        if amount > 0.into() {
+            let () = runtime::call_versioned_contract(
+                reward_token.into_hash().unwrap_or_revert().into(),
+                None,
+                "transfer",
+                runtime_args! {
+                    "recipient" => Address::from(depositor),
+                    "amount" => amount
+                },
+            );
+            self.emit(&BribeEvent::RewardSwept {
+                depositor,
+                gauge,
+                week,
+                reward_token,
+                amount,
+            });
+        }
+        set_lock(false);
+        amount
+    }
+
+    /// @notice Amount of `reward_token` deposited for the voters of `gauge` in `week`
+    #[inline(always)]
+    fn rewards_per_week(&self, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        RewardsPerWeek::instance().get(&gauge, &self._week_start(week), &reward_token)
+    }
+
+    /// @notice Amount of `reward_token` claimed by the voters of `gauge` in `week`
+    #[inline(always)]
+    fn claimed_per_week(&self, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        ClaimedPerWeek::instance().get(&gauge, &self._week_start(week), &reward_token)
+    }
+
+    /// @notice Amount of `reward_token` `user` claimed for voting for `gauge` in `week`
+    #[inline(always)]
+    fn user_claimed(&self, user: Key, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        UserClaimed::instance().get(&user, &gauge, &self._week_start(week), &reward_token)
+    }
+
+    fn emit(&self, bribe_event: &BribeEvent) {
+        events::emit(bribe_event);
+    }
+}
@@ -0,0 +1,245 @@
+use alloc::{format, string::String};
+use casper_types::{ContractHash, ContractPackageHash, Key, U256};
+use casperlabs_contract_utils::*;
+use common::{keys::*, utils::*};
+
+pub const WEEK: U256 = U256([604800000, 0, 0, 0]); // rewards are paid per gauge controller week
+pub const MAX_SNAPSHOT_WEEKS: usize = 52; // weeks of a vote recorded per checkpoint
+pub const CLAIM_WINDOW: U256 = U256([31449600000, 0, 0, 0]); // 52 weeks to claim the rewards of a week
+
+/// Dictionary key of the rewards in `reward_token` for the voters of `gauge` in `week`
+pub fn reward_key(gauge: &Key, week: &U256, reward_token: &Key) -> String {
+    hash(format!(
+        "{}_{}_{}",
+        gauge.to_formatted_string(),
+        week,
+        reward_token.to_formatted_string()
+    ))
+}
+
+/// Dictionary key of the rewards in `reward_token` of `user` for voting for `gauge` in `week`
+pub fn user_reward_key(user: &Key, gauge: &Key, week: &U256, reward_token: &Key) -> String {
+    hash(format!(
+        "{}_{}_{}_{}",
+        user.to_formatted_string(),
+        gauge.to_formatted_string(),
+        week,
+        reward_token.to_formatted_string()
+    ))
+}
+
+/// Dictionary key of the vote of `user` for `gauge`
+pub fn vote_key(user: &Key, gauge: &Key) -> String {
+    hash(format!(
+        "{}_{}",
+        user.to_formatted_string(),
+        gauge.to_formatted_string()
+    ))
+}
+
+/// Dictionary key of the vote of `user` for `gauge` in `week`
+pub fn week_vote_key(user: &Key, gauge: &Key, week: &U256) -> String {
+    hash(format!(
+        "{}_{}_{}",
+        user.to_formatted_string(),
+        gauge.to_formatted_string(),
+        week
+    ))
+}
+
+pub struct RewardsPerWeek {
+    dict: Dict,
+}
+
+impl RewardsPerWeek {
+    #[inline(always)]
+    pub fn instance() -> RewardsPerWeek {
+        RewardsPerWeek {
+            dict: Dict::instance(REWARDS_PER_WEEK_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(REWARDS_PER_WEEK_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, gauge: &Key, week: &U256, reward_token: &Key) -> U256 {
+        self.dict
+            .get(&reward_key(gauge, week, reward_token))
+            .unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, gauge: &Key, week: &U256, reward_token: &Key, value: U256) {
+        self.dict.set(&reward_key(gauge, week, reward_token), value);
+    }
+}
+
+pub struct ClaimedPerWeek {
+    dict: Dict,
+}
+
+impl ClaimedPerWeek {
+    #[inline(always)]
+    pub fn instance() -> ClaimedPerWeek {
+        ClaimedPerWeek {
+            dict: Dict::instance(CLAIMED_PER_WEEK_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(CLAIMED_PER_WEEK_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, gauge: &Key, week: &U256, reward_token: &Key) -> U256 {
+        self.dict
+            .get(&reward_key(gauge, week, reward_token))
+            .unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, gauge: &Key, week: &U256, reward_token: &Key, value: U256) {
+        self.dict.set(&reward_key(gauge, week, reward_token), value);
+    }
+}
+
+pub struct UserClaimed {
+    dict: Dict,
+}
+
+impl UserClaimed {
+    #[inline(always)]
+    pub fn instance() -> UserClaimed {
+        UserClaimed {
+            dict: Dict::instance(USER_CLAIMED_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(USER_CLAIMED_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, user: &Key, gauge: &Key, week: &U256, reward_token: &Key) -> U256 {
+        self.dict
+            .get(&user_reward_key(user, gauge, week, reward_token))
+            .unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, user: &Key, gauge: &Key, week: &U256, reward_token: &Key, value: U256) {
+        self.dict
+            .set(&user_reward_key(user, gauge, week, reward_token), value);
+    }
+}
+
+/// Bias of the vote of a user for a gauge at the start of a week, recorded by `checkpoint_vote`
+pub struct VoteBiases {
+    dict: Dict,
+}
+
+impl VoteBiases {
+    #[inline(always)]
+    pub fn instance() -> VoteBiases {
+        VoteBiases {
+            dict: Dict::instance(VOTE_BIASES_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(VOTE_BIASES_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, user: &Key, gauge: &Key, week: &U256) -> Option<U256> {
+        self.dict.get(&week_vote_key(user, gauge, week))
+    }
+    #[inline(always)]
+    pub fn set(&self, user: &Key, gauge: &Key, week: &U256, value: U256) {
+        self.dict.set(&week_vote_key(user, gauge, week), value);
+    }
+}
+
+/// Last week whose vote bias was recorded for a user and a gauge
+pub struct SnapshotWeeks {
+    dict: Dict,
+}
+
+impl SnapshotWeeks {
+    #[inline(always)]
+    pub fn instance() -> SnapshotWeeks {
+        SnapshotWeeks {
+            dict: Dict::instance(SNAPSHOT_WEEKS_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(SNAPSHOT_WEEKS_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, user: &Key, gauge: &Key) -> U256 {
+        self.dict.get(&vote_key(user, gauge)).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, user: &Key, gauge: &Key, value: U256) {
+        self.dict.set(&vote_key(user, gauge), value);
+    }
+}
+
+/// Rewards a depositor deposited for the voters of a gauge in a week and did not sweep yet
+pub struct DepositorRewards {
+    dict: Dict,
+}
+
+impl DepositorRewards {
+    #[inline(always)]
+    pub fn instance() -> DepositorRewards {
+        DepositorRewards {
+            dict: Dict::instance(DEPOSITOR_REWARDS_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(DEPOSITOR_REWARDS_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, depositor: &Key, gauge: &Key, week: &U256, reward_token: &Key) -> U256 {
+        self.dict
+            .get(&user_reward_key(depositor, gauge, week, reward_token))
+            .unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, depositor: &Key, gauge: &Key, week: &U256, reward_token: &Key, value: U256) {
+        self.dict.set(
+            &user_reward_key(depositor, gauge, week, reward_token),
+            value,
+        );
+    }
+}
+
+pub fn get_controller() -> Key {
+    get_key(CONTROLLER).unwrap_or_else(zero_address)
+}
+
+pub fn set_controller(controller: Key) {
+    set_key(CONTROLLER, controller);
+}
+
+pub fn get_lock() -> bool {
+    get_key(LOCK).unwrap_or_default()
+}
+
+pub fn set_lock(lock: bool) {
+    set_key(LOCK, lock);
+}
+
+pub fn get_contract_hash() -> ContractHash {
+    get_key(SELF_CONTRACT_HASH).unwrap_or_default()
+}
+
+pub fn set_contract_hash(contract_hash: ContractHash) {
+    set_key(SELF_CONTRACT_HASH, contract_hash);
+}
+
+pub fn get_package_hash() -> ContractPackageHash {
+    get_key(SELF_CONTRACT_PACKAGE_HASH).unwrap_or_default()
+}
+
+pub fn set_package_hash(package_hash: ContractPackageHash) {
+    set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
+}
@@ -0,0 +1,146 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
+
+pub enum BribeEvent {
+    RewardDeposited {
+        gauge: Key,
+        week: U256,
+        reward_token: Key,
+        depositor: Key,
+        amount: U256,
+    },
+    RewardClaimed {
+        user: Key,
+        gauge: Key,
+        week: U256,
+        reward_token: Key,
+        amount: U256,
+    },
+    RewardSwept {
+        depositor: Key,
+        gauge: Key,
+        week: U256,
+        reward_token: Key,
+        amount: U256,
+    },
+}
+
+impl BribeEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            BribeEvent::RewardDeposited {
+                gauge: _,
+                week: _,
+                reward_token: _,
+                depositor: _,
+                amount: _,
+            } => "rewardDeposited",
+            BribeEvent::RewardClaimed {
+                user: _,
+                gauge: _,
+                week: _,
+                reward_token: _,
+                amount: _,
+            } => "rewardClaimed",
+            BribeEvent::RewardSwept {
+                depositor: _,
+                gauge: _,
+                week: _,
+                reward_token: _,
+                amount: _,
+            } => "rewardSwept",
+        }
+        .to_string()
+    }
+}
+
+impl CasperEvent for BribeEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            BribeEvent::RewardDeposited {
+                gauge,
+                week,
+                reward_token,
+                depositor,
+                amount,
+            } => Ok([
+                gauge.to_bytes()?,
+                week.to_bytes()?,
+                reward_token.to_bytes()?,
+                depositor.to_bytes()?,
+                amount.to_bytes()?,
+            ]
+            .concat()),
+            BribeEvent::RewardClaimed {
+                user,
+                gauge,
+                week,
+                reward_token,
+                amount,
+            } => Ok([
+                user.to_bytes()?,
+                gauge.to_bytes()?,
+                week.to_bytes()?,
+                reward_token.to_bytes()?,
+                amount.to_bytes()?,
+            ]
+            .concat()),
+            BribeEvent::RewardSwept {
+                depositor,
+                gauge,
+                week,
+                reward_token,
+                amount,
+            } => Ok([
+                depositor.to_bytes()?,
+                gauge.to_bytes()?,
+                week.to_bytes()?,
+                reward_token.to_bytes()?,
+                amount.to_bytes()?,
+            ]
+            .concat()),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "rewardDeposited",
+                Schema::new()
+                    .with_elem::<Key>("gauge")
+                    .with_elem::<U256>("week")
+                    .with_elem::<Key>("reward_token")
+                    .with_elem::<Key>("depositor")
+                    .with_elem::<U256>("amount"),
+            )
+            .with(
+                "rewardClaimed",
+                Schema::new()
+                    .with_elem::<Key>("user")
+                    .with_elem::<Key>("gauge")
+                    .with_elem::<U256>("week")
+                    .with_elem::<Key>("reward_token")
+                    .with_elem::<U256>("amount"),
+            )
+            .with(
+                "rewardSwept",
+                Schema::new()
+                    .with_elem::<Key>("depositor")
+                    .with_elem::<Key>("gauge")
+                    .with_elem::<U256>("week")
+                    .with_elem::<Key>("reward_token")
+                    .with_elem::<U256>("amount"),
+            )
+    }
+}
@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+mod bribe;
+pub mod data;
+pub mod event;
+
+pub use bribe::BRIBE;
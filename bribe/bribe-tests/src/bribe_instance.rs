@@ -0,0 +1,349 @@
+use bribe_crate::data::{reward_key, user_reward_key};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, Key, RuntimeArgs, U128, U256,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+use crv20::Address;
+use std::time::SystemTime;
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+pub fn address_to_str(owner: &Address) -> String {
+    let preimage = owner.to_bytes().unwrap();
+    base64::encode(&preimage)
+}
+
+pub fn deploy_erc20(env: &TestEnv, sender: AccountHash, name: &str, time: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "curve-erc20.wasm",
+        name,
+        sender,
+        runtime_args! {
+            "initial_supply" => U256::from(0),
+            "name" => name,
+            "symbol" => "ERC",
+            "decimals" => 9_u8
+        },
+        time,
+    )
+}
+
+pub fn deploy_erc20_crv(env: &TestEnv, sender: AccountHash, time: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "erc20-crv.wasm",
+        "erc20-crv",
+        sender,
+        runtime_args! {
+            "name" => "CRV",
+            "symbol" => "ERC20CRV",
+            "decimals" => 9_u8,
+        },
+        time,
+    )
+}
+
+pub fn deploy_voting_escrow(
+    env: &TestEnv,
+    sender: AccountHash,
+    token_addr: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "voting-escrow.wasm",
+        "Voting Escrow",
+        sender,
+        runtime_args! {
+            "token_addr" => token_addr,
+            "name" => "VotingEscrow".to_string(),
+            "symbol" => "VE".to_string(),
+            "version" => "1".to_string(),
+        },
+        time,
+    )
+}
+
+pub fn deploy_gauge_controller(
+    env: &TestEnv,
+    sender: AccountHash,
+    token: Key,
+    voting_escrow: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "gauge-controller-token.wasm",
+        "Gauge Controller",
+        sender,
+        runtime_args! {
+            "token" => token,
+            "voting_escrow" => voting_escrow,
+        },
+        time,
+    )
+}
+
+pub fn deploy_minter(
+    env: &TestEnv,
+    sender: AccountHash,
+    token: Key,
+    controller: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "minter-token.wasm",
+        "Minter",
+        sender,
+        runtime_args! {
+            "token" => token,
+            "controller" => controller,
+        },
+        time,
+    )
+}
+
+pub fn deploy_liquidity_gauge(
+    env: &TestEnv,
+    sender: AccountHash,
+    lp_addr: Key,
+    minter: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "liquidity-gauge-v3.wasm",
+        "Liquidity Gauge",
+        sender,
+        runtime_args! {
+            "lp_addr" => lp_addr,
+            "minter" => minter,
+            "admin" => Key::Account(sender),
+        },
+        time,
+    )
+}
+
+pub fn add_gauge(gauge_controller: &TestContract, sender: AccountHash, gauge: Key, time: u64) {
+    gauge_controller.call_contract(
+        sender,
+        "add_type",
+        runtime_args! {
+            "name" => "type".to_string(),
+            "weight" => Some(U256::from(1))
+        },
+        time,
+    );
+    gauge_controller.call_contract(
+        sender,
+        "add_gauge",
+        runtime_args! {
+            "addr" => gauge,
+            "gauge_type" => (false, U128::from(0)),
+            "weight" => None::<U256>
+        },
+        time,
+    );
+}
+
+/// Mints `value` of the voting escrow token to `user` and locks it until `unlock_time`
+pub fn create_lock(
+    token: &TestContract,
+    voting_escrow: &TestContract,
+    owner: AccountHash,
+    user: AccountHash,
+    value: U256,
+    unlock_time: U256,
+    time: u64,
+) {
+    token.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(user),
+            "amount" => value
+        },
+        time,
+    );
+    token.call_contract(
+        user,
+        "approve",
+        runtime_args! {
+            "spender" => Key::Hash(voting_escrow.package_hash()),
+            "amount" => value
+        },
+        time,
+    );
+    voting_escrow.call_contract(
+        user,
+        "create_lock",
+        runtime_args! {
+            "value" => value,
+            "unlock_time" => unlock_time
+        },
+        time,
+    );
+}
+
+pub fn vote(gauge_controller: &TestContract, user: AccountHash, gauge: Key, time: u64) {
+    gauge_controller.call_contract(
+        user,
+        "vote_for_gauge_weights",
+        runtime_args! {
+            "gauge_addr" => gauge,
+            "user_weight" => U256::from(10000)
+        },
+        time,
+    );
+}
+
+pub fn balance_of(token: &TestContract, user: AccountHash) -> U256 {
+    token
+        .query_dictionary(BALANCES, address_to_str(&Address::Account(user)))
+        .unwrap_or_default()
+}
+
+pub struct BRIBEInstance(TestContract);
+impl BRIBEInstance {
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        controller: Key,
+        time: u64,
+    ) -> BRIBEInstance {
+        BRIBEInstance(TestContract::new(
+            env,
+            "bribe.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "controller" => controller,
+            },
+            time,
+        ))
+    }
+
+    /// Approves `amount` of `reward_token` to the bribe contract and deposits it
+    pub fn deposit_reward(
+        &self,
+        sender: AccountHash,
+        reward_token: &TestContract,
+        gauge: Key,
+        week: U256,
+        amount: U256,
+        time: u64,
+    ) {
+        reward_token.call_contract(
+            sender,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(self.0.package_hash()),
+                "amount" => amount
+            },
+            time,
+        );
+        self.0.call_contract(
+            sender,
+            "deposit_reward",
+            runtime_args! {
+                "gauge" => gauge,
+                "week" => week,
+                "reward_token" => Key::Hash(reward_token.package_hash()),
+                "amount" => amount
+            },
+            time,
+        );
+    }
+
+    pub fn claim_reward(
+        &self,
+        sender: AccountHash,
+        gauge: Key,
+        week: U256,
+        reward_token: Key,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "claim_reward",
+            runtime_args! {
+                "gauge" => gauge,
+                "week" => week,
+                "reward_token" => reward_token
+            },
+            time,
+        );
+    }
+
+    pub fn sweep_unclaimed(
+        &self,
+        sender: AccountHash,
+        gauge: Key,
+        week: U256,
+        reward_token: Key,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "sweep_unclaimed",
+            runtime_args! {
+                "gauge" => gauge,
+                "week" => week,
+                "reward_token" => reward_token
+            },
+            time,
+        );
+    }
+
+    pub fn checkpoint_vote(&self, sender: AccountHash, user: Key, gauge: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "checkpoint_vote",
+            runtime_args! {
+                "user" => user,
+                "gauge" => gauge
+            },
+            time,
+        );
+    }
+
+    pub fn rewards_per_week(&self, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        self.0
+            .query_dictionary(
+                REWARDS_PER_WEEK_DICT,
+                reward_key(&gauge, &week, &reward_token),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn claimed_per_week(&self, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        self.0
+            .query_dictionary(
+                CLAIMED_PER_WEEK_DICT,
+                reward_key(&gauge, &week, &reward_token),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn user_claimed(&self, user: Key, gauge: Key, week: U256, reward_token: Key) -> U256 {
+        self.0
+            .query_dictionary(
+                USER_CLAIMED_DICT,
+                user_reward_key(&user, &gauge, &week, &reward_token),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+}
@@ -0,0 +1,378 @@
+use crate::bribe_instance::*;
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use casperlabs_test_env::{TestContract, TestEnv};
+
+const NAME: &str = "BRIBE";
+const WEEK: u64 = 604_800_000;
+const MILLI_SECONDS_IN_DAY: u64 = 86_400_000;
+const MAXTIME: u64 = 126_144_000_000;
+const TEN_E_NINE: u128 = 1_000_000_000;
+const CLAIM_WINDOW: u64 = 52 * WEEK;
+
+// Deploys the bribe contract next to a gauge controller with a single liquidity gauge, and locks
+// twice as much for the first voter as for the second one
+fn deploy() -> (
+    TestEnv,
+    BRIBEInstance,
+    TestContract,
+    TestContract,
+    AccountHash,
+    AccountHash,
+    AccountHash,
+    Key,
+    u64,
+) {
+    let time: u64 = now();
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let user = env.next_user();
+    let user_1 = env.next_user();
+    let token = deploy_erc20(&env, owner, "token", time);
+    let reward_token = deploy_erc20(&env, owner, "reward_token", time);
+    let erc20_crv = deploy_erc20_crv(&env, owner, time);
+    let voting_escrow = deploy_voting_escrow(&env, owner, Key::Hash(token.package_hash()), time);
+    let gauge_controller = deploy_gauge_controller(
+        &env,
+        owner,
+        Key::Hash(erc20_crv.package_hash()),
+        Key::Hash(voting_escrow.package_hash()),
+        time,
+    );
+    let minter = deploy_minter(
+        &env,
+        owner,
+        Key::Hash(erc20_crv.package_hash()),
+        Key::Hash(gauge_controller.package_hash()),
+        time,
+    );
+    let gauge = Key::Hash(
+        deploy_liquidity_gauge(
+            &env,
+            owner,
+            Key::Hash(token.package_hash()),
+            Key::Hash(minter.package_hash()),
+            time,
+        )
+        .package_hash(),
+    );
+    add_gauge(&gauge_controller, owner, gauge, time);
+    let bribe = BRIBEInstance::new_deploy(
+        &env,
+        NAME,
+        owner,
+        Key::Hash(gauge_controller.package_hash()),
+        time,
+    );
+    let unlock_time: U256 = U256::from(time + 52 * WEEK);
+    let value: U256 = U256::from(MAXTIME) * 1000;
+    create_lock(
+        &token,
+        &voting_escrow,
+        owner,
+        user,
+        value * 2,
+        unlock_time,
+        time,
+    );
+    create_lock(
+        &token,
+        &voting_escrow,
+        owner,
+        user_1,
+        value,
+        unlock_time,
+        time,
+    );
+    reward_token.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(owner),
+            "amount" => U256::from(10000 * TEN_E_NINE)
+        },
+        time,
+    );
+    (
+        env,
+        bribe,
+        gauge_controller,
+        reward_token,
+        owner,
+        user,
+        user_1,
+        gauge,
+        time,
+    )
+}
+
+fn next_week(time: u64) -> u64 {
+    (time / WEEK + 1) * WEEK
+}
+
+#[test]
+fn test_deploy() {
+    let (_, bribe, _, reward_token, _, _, _, gauge, time) = deploy();
+    let week: U256 = U256::from(next_week(time));
+    assert_eq!(
+        bribe.rewards_per_week(gauge, week, Key::Hash(reward_token.package_hash())),
+        0.into()
+    );
+}
+
+#[test]
+fn test_claim_reward_pro_rata() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, user_1, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    vote(&gauge_controller, user_1, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    let amount: U256 = U256::from(3000 * TEN_E_NINE);
+    bribe.deposit_reward(owner, &reward_token, gauge, week, amount, time);
+    assert_eq!(bribe.rewards_per_week(gauge, week, reward), amount);
+    let claim_time: u64 = next_week(time) + MILLI_SECONDS_IN_DAY;
+    bribe.claim_reward(user, gauge, week, reward, claim_time);
+    bribe.claim_reward(user_1, gauge, week, reward, claim_time);
+    assert_eq!(
+        balance_of(&reward_token, user),
+        U256::from(2000 * TEN_E_NINE)
+    );
+    assert_eq!(
+        balance_of(&reward_token, user_1),
+        U256::from(1000 * TEN_E_NINE)
+    );
+    assert_eq!(
+        bribe.user_claimed(Key::Account(user), gauge, week, reward),
+        U256::from(2000 * TEN_E_NINE)
+    );
+    assert_eq!(bribe.claimed_per_week(gauge, week, reward), amount);
+    // Claiming twice pays nothing more
+    bribe.claim_reward(user, gauge, week, reward, claim_time);
+    assert_eq!(
+        balance_of(&reward_token, user),
+        U256::from(2000 * TEN_E_NINE)
+    );
+}
+
+#[test]
+fn test_claim_reward_after_late_deposit() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, user_1, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    vote(&gauge_controller, user_1, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    let claim_time: u64 = next_week(time) + MILLI_SECONDS_IN_DAY;
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    bribe.claim_reward(user, gauge, week, reward, claim_time);
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(300 * TEN_E_NINE),
+        claim_time,
+    );
+    bribe.claim_reward(user, gauge, week, reward, claim_time);
+    assert_eq!(
+        balance_of(&reward_token, user),
+        U256::from(2200 * TEN_E_NINE)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_claim_reward_for_vote_after_period_start() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, user_1, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    let claim_time: u64 = next_week(time) + MILLI_SECONDS_IN_DAY;
+    vote(&gauge_controller, user_1, gauge, claim_time);
+    bribe.claim_reward(user_1, gauge, week, reward, claim_time);
+}
+
+#[test]
+fn test_claim_reward_after_vote_again() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, user_1, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    vote(&gauge_controller, user_1, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    let vote_time: u64 = next_week(time) + MILLI_SECONDS_IN_DAY;
+    // Anyone can record the vote before the user votes again
+    bribe.checkpoint_vote(owner, Key::Account(user), gauge, vote_time);
+    vote(&gauge_controller, user, gauge, vote_time);
+    bribe.claim_reward(user, gauge, week, reward, vote_time);
+    assert_eq!(
+        balance_of(&reward_token, user),
+        U256::from(2000 * TEN_E_NINE)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_claim_reward_after_vote_again_without_checkpoint() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, user_1, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    vote(&gauge_controller, user_1, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    let vote_time: u64 = next_week(time) + MILLI_SECONDS_IN_DAY;
+    vote(&gauge_controller, user, gauge, vote_time);
+    bribe.claim_reward(user, gauge, week, reward, vote_time);
+}
+
+#[test]
+#[should_panic]
+fn test_claim_reward_before_period_start() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, _, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    bribe.claim_reward(user, gauge, week, reward, time);
+}
+
+#[test]
+fn test_sweep_unclaimed() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, user_1, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    vote(&gauge_controller, user_1, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    bribe.claim_reward(
+        user,
+        gauge,
+        week,
+        reward,
+        next_week(time) + MILLI_SECONDS_IN_DAY,
+    );
+    // The share of the voter who never claimed goes back to the depositor
+    let sweep_time: u64 = next_week(time) + CLAIM_WINDOW;
+    bribe.sweep_unclaimed(owner, gauge, week, reward, sweep_time);
+    assert_eq!(
+        balance_of(&reward_token, owner),
+        U256::from(8000 * TEN_E_NINE)
+    );
+    // Sweeping twice pays nothing more
+    bribe.sweep_unclaimed(owner, gauge, week, reward, sweep_time);
+    assert_eq!(
+        balance_of(&reward_token, owner),
+        U256::from(8000 * TEN_E_NINE)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_sweep_unclaimed_before_claim_window_end() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, _, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    bribe.sweep_unclaimed(
+        owner,
+        gauge,
+        week,
+        reward,
+        next_week(time) + CLAIM_WINDOW - 1,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_claim_reward_after_claim_window_end() {
+    let (_, bribe, gauge_controller, reward_token, owner, user, _, gauge, time) = deploy();
+    let reward: Key = Key::Hash(reward_token.package_hash());
+    vote(&gauge_controller, user, gauge, time);
+    let week: U256 = U256::from(next_week(time));
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        week,
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+    bribe.claim_reward(user, gauge, week, reward, next_week(time) + CLAIM_WINDOW);
+}
+
+#[test]
+#[should_panic]
+fn test_deposit_reward_for_past_week() {
+    let (_, bribe, _, reward_token, owner, _, _, gauge, time) = deploy();
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        gauge,
+        U256::from(time - WEEK),
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_deposit_reward_for_unknown_gauge() {
+    let (_, bribe, _, reward_token, owner, _, _, _, time) = deploy();
+    bribe.deposit_reward(
+        owner,
+        &reward_token,
+        Key::Hash([7u8; 32]),
+        U256::from(next_week(time)),
+        U256::from(3000 * TEN_E_NINE),
+        time,
+    );
+}
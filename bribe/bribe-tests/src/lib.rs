@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod bribe_tests;
+
+#[cfg(test)]
+pub mod bribe_instance;
@@ -0,0 +1,336 @@
+#![no_main]
+#![no_std]
+extern crate alloc;
+use alloc::{collections::BTreeSet, format, vec};
+use bribe_crate::{self, data, BRIBE};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+};
+use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
+
+#[derive(Default)]
+struct Bribe(OnChainContractStorage);
+impl ContractContext<OnChainContractStorage> for Bribe {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl BRIBE<OnChainContractStorage> for Bribe {}
+impl Bribe {
+    fn constructor(
+        &mut self,
+        controller: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        BRIBE::init(self, controller, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let controller: Key = runtime::get_named_arg("controller");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Bribe::default().constructor(controller, contract_hash, package_hash);
+}
+
+/// Deposit reward tokens for the voters of a gauge in a week
+/// # Parameters
+/// * `gauge` - Gauge added to the gauge controller
+/// * `week` - Time in the week, the current week or a later one
+/// * `reward_token` - Token deposited, approved to this contract beforehand
+/// * `amount` - Amount deposited
+#[no_mangle]
+fn deposit_reward() {
+    let gauge: Key = runtime::get_named_arg("gauge");
+    let week: U256 = runtime::get_named_arg("week");
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let amount: U256 = runtime::get_named_arg("amount");
+    Bribe::default().deposit_reward(gauge, week, reward_token, amount);
+}
+
+/// Claim the rewards of the caller for voting for a gauge in a week
+/// # Parameters
+/// * `gauge` - Gauge voted for
+/// * `week` - Time in the week, which has to be started
+/// * `reward_token` - Token claimed
+#[no_mangle]
+fn claim_reward() {
+    let gauge: Key = runtime::get_named_arg("gauge");
+    let week: U256 = runtime::get_named_arg("week");
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let ret: U256 = Bribe::default().claim_reward(gauge, week, reward_token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Sweep the share of the caller in the rewards left unclaimed for a gauge in a week
+/// # Parameters
+/// * `gauge` - Gauge the rewards were deposited for
+/// * `week` - Time in the week, whose claim window has to be over
+/// * `reward_token` - Token swept
+#[no_mangle]
+fn sweep_unclaimed() {
+    let gauge: Key = runtime::get_named_arg("gauge");
+    let week: U256 = runtime::get_named_arg("week");
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let ret: U256 = Bribe::default().sweep_unclaimed(gauge, week, reward_token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Record the bias of the vote of a user for a gauge in the weeks started since it was cast
+/// # Parameters
+/// * `user` - Voter, any user can checkpoint any vote
+/// * `gauge` - Gauge voted for
+#[no_mangle]
+fn checkpoint_vote() {
+    let user: Key = runtime::get_named_arg("user");
+    let gauge: Key = runtime::get_named_arg("gauge");
+    Bribe::default().checkpoint_vote(user, gauge);
+}
+
+/// Amount of a reward token a user can claim for voting for a gauge in a week
+#[no_mangle]
+fn claimable() {
+    let user: Key = runtime::get_named_arg("user");
+    let gauge: Key = runtime::get_named_arg("gauge");
+    let week: U256 = runtime::get_named_arg("week");
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let ret: U256 = Bribe::default().claimable(user, gauge, week, reward_token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn rewards_per_week() {
+    let gauge: Key = runtime::get_named_arg("gauge");
+    let week: U256 = runtime::get_named_arg("week");
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let ret: U256 = Bribe::default().rewards_per_week(gauge, week, reward_token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn claimed_per_week() {
+    let gauge: Key = runtime::get_named_arg("gauge");
+    let week: U256 = runtime::get_named_arg("week");
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let ret: U256 = Bribe::default().claimed_per_week(gauge, week, reward_token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn user_claimed() {
+    let user: Key = runtime::get_named_arg("user");
+    let gauge: Key = runtime::get_named_arg("gauge");
+    let week: U256 = runtime::get_named_arg("week");
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let ret: U256 = Bribe::default().user_claimed(user, gauge, week, reward_token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn controller() {
+    runtime::ret(CLValue::from_t(data::get_controller()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("controller", Key::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "deposit_reward",
+        vec![
+            Parameter::new("gauge", Key::cl_type()),
+            Parameter::new("week", U256::cl_type()),
+            Parameter::new("reward_token", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_reward",
+        vec![
+            Parameter::new("gauge", Key::cl_type()),
+            Parameter::new("week", U256::cl_type()),
+            Parameter::new("reward_token", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "sweep_unclaimed",
+        vec![
+            Parameter::new("gauge", Key::cl_type()),
+            Parameter::new("week", U256::cl_type()),
+            Parameter::new("reward_token", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "checkpoint_vote",
+        vec![
+            Parameter::new("user", Key::cl_type()),
+            Parameter::new("gauge", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claimable",
+        vec![
+            Parameter::new("user", Key::cl_type()),
+            Parameter::new("gauge", Key::cl_type()),
+            Parameter::new("week", U256::cl_type()),
+            Parameter::new("reward_token", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "rewards_per_week",
+        vec![
+            Parameter::new("gauge", Key::cl_type()),
+            Parameter::new("week", U256::cl_type()),
+            Parameter::new("reward_token", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claimed_per_week",
+        vec![
+            Parameter::new("gauge", Key::cl_type()),
+            Parameter::new("week", U256::cl_type()),
+            Parameter::new("reward_token", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "user_claimed",
+        vec![
+            Parameter::new("user", Key::cl_type()),
+            Parameter::new("gauge", Key::cl_type()),
+            Parameter::new("week", U256::cl_type()),
+            Parameter::new("reward_token", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "controller",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        // add a first version to this package
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let controller: Key = runtime::get_named_arg("controller");
+        let constructor_args = runtime_args! {
+            "controller" => controller,
+            "package_hash" => package_hash,
+            "contract_hash" => contract_hash,
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    }
+    // If contract package did already exist
+    else {
+        // get the package
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+        // create new version and install it
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
@@ -1078,6 +1078,192 @@ pub enum Error {
     VotingEscrowInvalidBlockNumber1 = 11406,
     /// (Voting Escrow Invalid Block Number2)
     VotingEscrowInvalidBlockNumber2 = 11407,
+    /// (Voting Escrow Smart Contract Depositors Not Allowed1)
+    VotingEscrowSmartContractDepositorsNotAllowed1 = 11408,
+    /// (Voting Escrow Smart Contract Depositors Not Allowed2)
+    VotingEscrowSmartContractDepositorsNotAllowed2 = 11409,
+    /// (Voting Escrow Smart Contract Depositors Not Allowed3)
+    VotingEscrowSmartContractDepositorsNotAllowed3 = 11410,
+    /// (Voting Escrow Smart Contract Depositors Not Allowed4)
+    VotingEscrowSmartContractDepositorsNotAllowed4 = 11411,
+    /// (Voting Escrow No Existing Lock Found3)
+    VotingEscrowNoExistingLockFound3 = 11412,
+    /// (Voting Escrow Lock Expired2)
+    VotingEscrowLockExpired2 = 11413,
+    /// (Voting Escrow Penalty Receiver Not Set)
+    VotingEscrowPenaltyReceiverNotSet = 11414,
+    /// (Voting Escrow Subtraction Error36)
+    VotingEscrowSubtractionError36 = 11415,
+    /// (Voting Escrow Subtraction Error37)
+    VotingEscrowSubtractionError37 = 11416,
+    /// (Voting Escrow Subtraction Error38)
+    VotingEscrowSubtractionError38 = 11417,
+    /// (Voting Escrow Multiplication Error17)
+    VotingEscrowMultiplicationError17 = 11418,
+    /// (Voting Escrow Division Error13)
+    VotingEscrowDivisionError13 = 11419,
+    /// (Voting Escrow Invalid Timestamp1)
+    VotingEscrowInvalidTimestamp1 = 11420,
+    /// (Voting Escrow Invalid Timestamp2)
+    VotingEscrowInvalidTimestamp2 = 11421,
+    /// (Voting Escrow Addition Error28)
+    VotingEscrowAdditionError28 = 11422,
+    /// (Voting Escrow Addition Error29)
+    VotingEscrowAdditionError29 = 11423,
+    /// (Voting Escrow Addition Error30)
+    VotingEscrowAdditionError30 = 11424,
+    /// (Voting Escrow Addition Error31)
+    VotingEscrowAdditionError31 = 11425,
+    /// (Voting Escrow Subtraction Error39)
+    VotingEscrowSubtractionError39 = 11426,
+    /// (Voting Escrow Subtraction Error40)
+    VotingEscrowSubtractionError40 = 11427,
+    /// (Voting Escrow Subtraction Error41)
+    VotingEscrowSubtractionError41 = 11428,
+    /// (Voting Escrow Subtraction Error42)
+    VotingEscrowSubtractionError42 = 11429,
+    /// (Voting Escrow Multiplication Error18)
+    VotingEscrowMultiplicationError18 = 11430,
+    /// (Voting Escrow Division Error14)
+    VotingEscrowDivisionError14 = 11431,
+    /// (Voting Escrow Division Error15)
+    VotingEscrowDivisionError15 = 11432,
+    /// (Voting Escrow Is Locked5)
+    VotingEscrowIsLocked5 = 11433,
+    /// (Voting Escrow Is Locked6)
+    VotingEscrowIsLocked6 = 11434,
+    /// (Voting Escrow Smart Contract Depositors Not Allowed5)
+    VotingEscrowSmartContractDepositorsNotAllowed5 = 11435,
+    /// (Voting Escrow Smart Contract Depositors Not Allowed6)
+    VotingEscrowSmartContractDepositorsNotAllowed6 = 11436,
+    /// (Voting Escrow Need Non Zero Value4)
+    VotingEscrowNeedNonZeroValue4 = 11437,
+    /// (Voting Escrow Can Only Lock Until Time In The Future2)
+    VotingEscrowCanOnlyLockUntilTimeInTheFuture2 = 11438,
+    /// (Voting Escrow Voting Lock Can Be 4 Years Max3)
+    VotingEscrowVotingLockCanBe4YearsMax3 = 11439,
+    /// (Voting Escrow Not Position Owner1)
+    VotingEscrowNotPositionOwner1 = 11440,
+    /// (Voting Escrow Not Position Owner2)
+    VotingEscrowNotPositionOwner2 = 11441,
+    /// (Voting Escrow Not Position Owner3)
+    VotingEscrowNotPositionOwner3 = 11442,
+    /// (Voting Escrow Not Position Owner4)
+    VotingEscrowNotPositionOwner4 = 11443,
+    /// (Voting Escrow Not Position Owner5)
+    VotingEscrowNotPositionOwner5 = 11444,
+    /// (Voting Escrow Same Position)
+    VotingEscrowSamePosition = 11445,
+    /// (Voting Escrow Position Expired1)
+    VotingEscrowPositionExpired1 = 11446,
+    /// (Voting Escrow Position Expired2)
+    VotingEscrowPositionExpired2 = 11447,
+    /// (Voting Escrow Position Expired3)
+    VotingEscrowPositionExpired3 = 11448,
+    /// (Voting Escrow Invalid Split Amounts)
+    VotingEscrowInvalidSplitAmounts = 11449,
+    /// (Voting Escrow Zero Split Amount)
+    VotingEscrowZeroSplitAmount = 11450,
+    /// (Voting Escrow Zero Address2)
+    VotingEscrowZeroAddress2 = 11451,
+    /// (Voting Escrow The Lock Didnt Expire2)
+    VotingEscrowTheLockDidntExpire2 = 11452,
+    /// (Voting Escrow Addition Error32)
+    VotingEscrowAdditionError32 = 11453,
+    /// (Voting Escrow Addition Error33)
+    VotingEscrowAdditionError33 = 11454,
+    /// (Voting Escrow Addition Error34)
+    VotingEscrowAdditionError34 = 11455,
+    /// (Voting Escrow Addition Error35)
+    VotingEscrowAdditionError35 = 11456,
+    /// (Voting Escrow Addition Error36)
+    VotingEscrowAdditionError36 = 11457,
+    /// (Voting Escrow Addition Error37)
+    VotingEscrowAdditionError37 = 11458,
+    /// (Voting Escrow Addition Error38)
+    VotingEscrowAdditionError38 = 11459,
+    /// (Voting Escrow Addition Error39)
+    VotingEscrowAdditionError39 = 11460,
+    /// (Voting Escrow Addition Error40)
+    VotingEscrowAdditionError40 = 11461,
+    /// (Voting Escrow Subtraction Error43)
+    VotingEscrowSubtractionError43 = 11462,
+    /// (Voting Escrow Subtraction Error44)
+    VotingEscrowSubtractionError44 = 11463,
+    /// (Voting Escrow Addition Error41)
+    VotingEscrowAdditionError41 = 11464,
+    /// (Voting Escrow Addition Error42)
+    VotingEscrowAdditionError42 = 11465,
+    /// (Voting Escrow Division Error16)
+    VotingEscrowDivisionError16 = 11466,
+    /// (Voting Escrow Multiplication Error19)
+    VotingEscrowMultiplicationError19 = 11467,
+    /// (Voting Escrow Zero Address3)
+    VotingEscrowZeroAddress3 = 11468,
+    /// (Voting Escrow Invalid Timestamp3)
+    VotingEscrowInvalidTimestamp3 = 11469,
+    /// (Voting Escrow Division Error17)
+    VotingEscrowDivisionError17 = 11470,
+    /// (Voting Escrow Division Error18)
+    VotingEscrowDivisionError18 = 11471,
+    /// (Voting Escrow Division Error19)
+    VotingEscrowDivisionError19 = 11472,
+    /// (Voting Escrow Multiplication Error20)
+    VotingEscrowMultiplicationError20 = 11473,
+    /// (Voting Escrow Multiplication Error21)
+    VotingEscrowMultiplicationError21 = 11474,
+    /// (Voting Escrow Multiplication Error22)
+    VotingEscrowMultiplicationError22 = 11475,
+    /// (Voting Escrow Subtraction Error45)
+    VotingEscrowSubtractionError45 = 11476,
+    /// (Voting Escrow Subtraction Error46)
+    VotingEscrowSubtractionError46 = 11477,
+    /// (Voting Escrow Subtraction Error47)
+    VotingEscrowSubtractionError47 = 11478,
+    /// (Voting Escrow Subtraction Error48)
+    VotingEscrowSubtractionError48 = 11479,
+    /// (Voting Escrow Subtraction Error49)
+    VotingEscrowSubtractionError49 = 11480,
+    /// (Voting Escrow Subtraction Error50)
+    VotingEscrowSubtractionError50 = 11481,
+    /// (Voting Escrow Addition Error43)
+    VotingEscrowAdditionError43 = 11482,
+    /// (Voting Escrow Addition Error44)
+    VotingEscrowAdditionError44 = 11483,
+    /// (Voting Escrow Addition Error45)
+    VotingEscrowAdditionError45 = 11484,
+    /// (Voting Escrow Addition Error46)
+    VotingEscrowAdditionError46 = 11485,
+    /// (Voting Escrow Addition Error47)
+    VotingEscrowAdditionError47 = 11486,
+    /// (Voting Escrow Addition Error48)
+    VotingEscrowAdditionError48 = 11487,
+    /// (Voting Escrow Addition Error49)
+    VotingEscrowAdditionError49 = 11488,
+    /// (Voting Escrow Addition Error50)
+    VotingEscrowAdditionError50 = 11489,
+    /// (Voting Escrow Addition Error51)
+    VotingEscrowAdditionError51 = 11490,
+    /// (Voting Escrow Auto Max Locked)
+    VotingEscrowAutoMaxLocked = 11491,
+    /// (Voting Escrow Auto Max Lock Unchanged)
+    VotingEscrowAutoMaxLockUnchanged = 11492,
+    /// (Voting Escrow No Existing Lock Found4)
+    VotingEscrowNoExistingLockFound4 = 11493,
+    /// (Voting Escrow Lock Expired3)
+    VotingEscrowLockExpired3 = 11494,
+    /// (Voting Escrow Addition Error52)
+    VotingEscrowAdditionError52 = 11495,
+    /// (Voting Escrow Division Error20)
+    VotingEscrowDivisionError20 = 11496,
+    /// (Voting Escrow Division Error21)
+    VotingEscrowDivisionError21 = 11497,
+    /// (Voting Escrow Multiplication Error23)
+    VotingEscrowMultiplicationError23 = 11498,
+    /// (Voting Escrow Multiplication Error24)
+    VotingEscrowMultiplicationError24 = 11499,
+    /// (Voting Escrow Invalid Series)
+    VotingEscrowInvalidSeries = 11500,
 
     /// (Liquidity Gauge Wrapper Unauthorized)
     GaugeWrapperUnauthorized = 11501,
@@ -1405,6 +1591,422 @@ pub enum Error {
     LiquidityGaugeV4ClaimableRewardMultiplicationOverFlow3 = 12004,
     /// (Liquidity Gauge V4 error 22)
     LiquidityGaugeV4ClaimableRewardSubtractionOverFlow2 = 12005,
+    /// (Liquidity Gauge V4 error 23)
+    LiquidityGaugeV4OnlyAdmin = 12006,
+
+    // SMART WALLET CHECKER
+    /// (Smart Wallet Checker Admin Only)
+    SmartWalletCheckerAdminOnly = 12101,
+    /// (Smart Wallet Checker Admin Not Set)
+    SmartWalletCheckerAdminNotSet = 12102,
+
+    // VE BOOST
+    /// (Ve Boost Is Locked)
+    VeBoostIsLocked = 12201,
+    /// (Ve Boost Zero Amount)
+    VeBoostZeroAmount = 12202,
+    /// (Ve Boost Self Boost)
+    VeBoostSelfBoost = 12203,
+    /// (Ve Boost Endtime In Past)
+    VeBoostEndtimeInPast = 12204,
+    /// (Ve Boost Endtime After Lock End)
+    VeBoostEndtimeAfterLockEnd = 12205,
+    /// (Ve Boost Insufficient Delegable Balance)
+    VeBoostInsufficientDelegableBalance = 12206,
+    /// (Ve Boost Zero Slope)
+    VeBoostZeroSlope = 12207,
+    /// (Ve Boost Addition Error 1)
+    VeBoostAdditionError1 = 12208,
+    /// (Ve Boost Addition Error 2)
+    VeBoostAdditionError2 = 12209,
+    /// (Ve Boost Addition Error 3)
+    VeBoostAdditionError3 = 12210,
+    /// (Ve Boost Addition Error 4)
+    VeBoostAdditionError4 = 12211,
+    /// (Ve Boost Addition Error 5)
+    VeBoostAdditionError5 = 12212,
+    /// (Ve Boost Addition Error 6)
+    VeBoostAdditionError6 = 12213,
+    /// (Ve Boost Addition Error 7)
+    VeBoostAdditionError7 = 12214,
+    /// (Ve Boost Addition Error 8)
+    VeBoostAdditionError8 = 12215,
+    /// (Ve Boost Subtraction Error 1)
+    VeBoostSubtractionError1 = 12216,
+    /// (Ve Boost Subtraction Error 2)
+    VeBoostSubtractionError2 = 12217,
+    /// (Ve Boost Multiplication Error 1)
+    VeBoostMultiplicationError1 = 12218,
+    /// (Ve Boost Multiplication Error 2)
+    VeBoostMultiplicationError2 = 12219,
+    /// (Ve Boost Multiplication Error 3)
+    VeBoostMultiplicationError3 = 12220,
+    /// (Ve Boost Division Error 1)
+    VeBoostDivisionError1 = 12221,
+    /// (Ve Boost Division Error 2)
+    VeBoostDivisionError2 = 12222,
+    /// (Ve Boost Too Many Delegators)
+    VeBoostTooManyDelegators = 12223,
+    /// (Ve Boost Endtime Mismatch)
+    VeBoostEndtimeMismatch = 12224,
+    /// (Ve Boost No Boost)
+    VeBoostNoBoost = 12225,
+    /// (Ve Boost Addition Error 9)
+    VeBoostAdditionError9 = 12226,
+    /// (Ve Boost Multiplication Error 4)
+    VeBoostMultiplicationError4 = 12227,
+    /// (Ve Boost Multiplication Error 5)
+    VeBoostMultiplicationError5 = 12228,
+    /// (Ve Boost Division Error 3)
+    VeBoostDivisionError3 = 12229,
+
+    // VOTING
+    /// (Voting Invalid Quorum)
+    VotingInvalidQuorum = 12301,
+    /// (Voting Invalid Support)
+    VotingInvalidSupport = 12302,
+    /// (Voting No Voting Power)
+    VotingNoVotingPower = 12303,
+    /// (Voting Insufficient Balance)
+    VotingInsufficientBalance = 12304,
+    /// (Voting Invalid Action 1)
+    VotingInvalidAction1 = 12305,
+    /// (Voting Invalid Action 2)
+    VotingInvalidAction2 = 12306,
+    /// (Voting No Such Vote)
+    VotingNoSuchVote = 12307,
+    /// (Voting Cannot Vote)
+    VotingCannotVote = 12308,
+    /// (Voting Cannot Execute)
+    VotingCannotExecute = 12309,
+    /// (Voting Only Self)
+    VotingOnlySelf = 12310,
+    /// (Voting Addition Error 1)
+    VotingAdditionError1 = 12311,
+    /// (Voting Addition Error 2)
+    VotingAdditionError2 = 12312,
+    /// (Voting Addition Error 3)
+    VotingAdditionError3 = 12313,
+    /// (Voting Addition Error 4)
+    VotingAdditionError4 = 12314,
+    /// (Voting Subtraction Error 1)
+    VotingSubtractionError1 = 12315,
+    /// (Voting Subtraction Error 2)
+    VotingSubtractionError2 = 12316,
+    /// (Voting Subtraction Error 3)
+    VotingSubtractionError3 = 12317,
+    /// (Voting Multiplication Error 1)
+    VotingMultiplicationError1 = 12318,
+    /// (Voting Division Error 1)
+    VotingDivisionError1 = 12319,
+
+    // AGENT
+    /// (Agent Access Denied 1)
+    AgentAccessDenied1 = 12401,
+    /// (Agent Access Denied 2)
+    AgentAccessDenied2 = 12402,
+    /// (Agent Access Denied 3)
+    AgentAccessDenied3 = 12403,
+    /// (Agent Access Denied 4)
+    AgentAccessDenied4 = 12404,
+    /// (Agent Is Locked)
+    AgentIsLocked = 12405,
+    /// (Agent Invalid Role)
+    AgentInvalidRole = 12406,
+    /// (Agent Invalid Args)
+    AgentInvalidArgs = 12407,
+
+    // TIMELOCK
+    /// (Timelock Invalid Delay 1)
+    TimelockInvalidDelay1 = 12501,
+    /// (Timelock Invalid Delay 2)
+    TimelockInvalidDelay2 = 12502,
+    /// (Timelock Admin Only)
+    TimelockAdminOnly = 12503,
+    /// (Timelock Only Self)
+    TimelockOnlySelf = 12504,
+    /// (Timelock Future Admin Only)
+    TimelockFutureAdminOnly = 12505,
+    /// (Timelock Access Denied)
+    TimelockAccessDenied = 12506,
+    /// (Timelock No Such Operation)
+    TimelockNoSuchOperation = 12507,
+    /// (Timelock Not Queued)
+    TimelockNotQueued = 12508,
+    /// (Timelock Is Locked)
+    TimelockIsLocked = 12509,
+    /// (Timelock Eta Too Early)
+    TimelockEtaTooEarly = 12510,
+    /// (Timelock Not Ready)
+    TimelockNotReady = 12511,
+    /// (Timelock Stale)
+    TimelockStale = 12512,
+    /// (Timelock Invalid Args 1)
+    TimelockInvalidArgs1 = 12513,
+    /// (Timelock Invalid Args 2)
+    TimelockInvalidArgs2 = 12514,
+    /// (Timelock Invalid Index)
+    TimelockInvalidIndex = 12515,
+    /// (Timelock Addition Error 1)
+    TimelockAdditionError1 = 12516,
+    /// (Timelock Addition Error 2)
+    TimelockAdditionError2 = 12517,
+    /// (Timelock Addition Error 3)
+    TimelockAdditionError3 = 12518,
+    /// (Timelock Addition Error 4)
+    TimelockAdditionError4 = 12519,
+    /// (Timelock Subtraction Error 1)
+    TimelockSubtractionError1 = 12520,
+
+    // INT128
+    /// (Int128 Addition Overflow)
+    Int128AdditionOverflow = 12601,
+    /// (Int128 Subtraction Overflow)
+    Int128SubtractionOverflow = 12602,
+    /// (Int128 Multiplication Overflow)
+    Int128MultiplicationOverflow = 12603,
+    /// (Int128 Division Error)
+    Int128DivisionError = 12604,
+    /// (Int128 Negation Overflow)
+    Int128NegationOverflow = 12605,
+    /// (Int128 Negative To Unsigned)
+    Int128NegativeToUnsigned = 12606,
+    /// (Int128 Unsigned Overflow)
+    Int128UnsignedOverflow = 12607,
+
+    // EVENTS
+    /// (Events Not Initialized)
+    EventsNotInitialized = 12701,
+    /// (Events Length Overflow)
+    EventsLengthOverflow = 12702,
+
+    // GAUGE CONTROLLER
+    /// (Gauge Controller Not Vote Delegate)
+    GaugeControllerNotVoteDelegate = 12801,
+    /// (Gauge Controller Vote Length Mismatch)
+    GaugeControllerVoteLengthMismatch = 12802,
+    /// (Gauge Controller Not Admin5)
+    GaugeControllerNotAdmin5 = 12803,
+    /// (Gauge Controller Gauge Killed)
+    GaugeControllerGaugeKilled = 12804,
+    /// (Gauge Controller UnderFlow25)
+    GaugeControllerUnderFlow25 = 12805,
+    /// (Gauge Controller UnderFlow26)
+    GaugeControllerUnderFlow26 = 12806,
+    /// (Gauge Controller UnderFlow27)
+    GaugeControllerUnderFlow27 = 12807,
+    /// (Gauge Controller UnderFlow28)
+    GaugeControllerUnderFlow28 = 12808,
+    /// (Gauge Controller UnderFlow29)
+    GaugeControllerUnderFlow29 = 12809,
+    /// (Gauge Controller Multiply20)
+    GaugeControllerMultiply20 = 12810,
+    /// (Gauge Controller OverFlow31)
+    GaugeControllerOverFlow31 = 12811,
+    /// (Gauge Controller OverFlow32)
+    GaugeControllerOverFlow32 = 12812,
+    /// (Gauge Controller Divide9)
+    GaugeControllerDivide9 = 12813,
+    /// (Gauge Controller Multiply21)
+    GaugeControllerMultiply21 = 12814,
+    /// (Gauge Controller Not Admin6)
+    GaugeControllerNotAdmin6 = 12815,
+    /// (Gauge Controller Invalid Max Relative Weight)
+    GaugeControllerInvalidMaxRelativeWeight = 12816,
+    /// (Gauge Controller Invalid Cap Receiver)
+    GaugeControllerInvalidCapReceiver = 12817,
+    /// (Gauge Controller OverFlow33)
+    GaugeControllerOverFlow33 = 12818,
+    /// (Gauge Controller OverFlow34)
+    GaugeControllerOverFlow34 = 12819,
+    /// (Gauge Controller OverFlow35)
+    GaugeControllerOverFlow35 = 12820,
+    /// (Gauge Controller OverFlow36)
+    GaugeControllerOverFlow36 = 12821,
+    /// (Gauge Controller UnderFlow30)
+    GaugeControllerUnderFlow30 = 12822,
+    /// (Gauge Controller UnderFlow31)
+    GaugeControllerUnderFlow31 = 12823,
+    /// (Gauge Controller UnderFlow32)
+    GaugeControllerUnderFlow32 = 12824,
+    /// (Gauge Controller Multiply22)
+    GaugeControllerMultiply22 = 12825,
+    /// (Gauge Controller Multiply23)
+    GaugeControllerMultiply23 = 12826,
+    /// (Gauge Controller Divide10)
+    GaugeControllerDivide10 = 12827,
+    /// (Gauge Controller Divide11)
+    GaugeControllerDivide11 = 12828,
+    /// (Gauge Controller Checkpoint Incomplete)
+    GaugeControllerCheckpointIncomplete = 12829,
+    /// (Gauge Controller Divide12)
+    GaugeControllerDivide12 = 12830,
+    /// (Gauge Controller Multiply24)
+    GaugeControllerMultiply24 = 12831,
+    /// (Gauge Controller OverFlow37)
+    GaugeControllerOverFlow37 = 12832,
+    /// (Gauge Controller OverFlow38)
+    GaugeControllerOverFlow38 = 12833,
+    /// (Gauge Controller Divide13)
+    GaugeControllerDivide13 = 12834,
+    /// (Gauge Controller Multiply25)
+    GaugeControllerMultiply25 = 12835,
+    /// (Gauge Controller Multiply26)
+    GaugeControllerMultiply26 = 12836,
+    /// (Gauge Controller OverFlow39)
+    GaugeControllerOverFlow39 = 12837,
+    /// (Gauge Controller OverFlow40)
+    GaugeControllerOverFlow40 = 12838,
+    /// (Gauge Controller Multiply29)
+    GaugeControllerMultiply29 = 12842,
+    /// (Gauge Controller Divide15)
+    GaugeControllerDivide15 = 12843,
+    /// (Gauge Controller OverFlow41)
+    GaugeControllerOverFlow41 = 12844,
+    /// (Gauge Controller Gauge Not Contract)
+    GaugeControllerGaugeNotContract = 12845,
+    /// (Gauge Controller Gauge Controller Mismatch)
+    GaugeControllerGaugeControllerMismatch = 12846,
+    /// (Gauge Controller Not Admin7)
+    GaugeControllerNotAdmin7 = 12847,
+    /// (Gauge Controller Not Admin8)
+    GaugeControllerNotAdmin8 = 12848,
+    /// (Gauge Controller Not Admin9)
+    GaugeControllerNotAdmin9 = 12849,
+    /// (Gauge Controller Invalid Type1)
+    GaugeControllerInvalidType1 = 12850,
+    /// (Gauge Controller Invalid Type2)
+    GaugeControllerInvalidType2 = 12851,
+    /// (Gauge Controller Type Deprecated1)
+    GaugeControllerTypeDeprecated1 = 12852,
+    /// (Gauge Controller Type Deprecated2)
+    GaugeControllerTypeDeprecated2 = 12853,
+    /// (Gauge Controller Type Deprecated3)
+    GaugeControllerTypeDeprecated3 = 12854,
+    /// (Gauge Controller OverFlow42)
+    GaugeControllerOverFlow42 = 12855,
+    /// (Gauge Controller OverFlow43)
+    GaugeControllerOverFlow43 = 12856,
+    /// (Gauge Controller Invalid Gauge 1)
+    GaugeControllerInvalidGauge1 = 12857,
+    /// (Gauge Controller Invalid Gauge 2)
+    GaugeControllerInvalidGauge2 = 12858,
+    /// (Gauge Controller Invalid Gauge 3)
+    GaugeControllerInvalidGauge3 = 12859,
+    /// (Gauge Controller Too Many Types)
+    GaugeControllerTooManyTypes = 12860,
+    /// (Gauge Controller OverFlow44)
+    GaugeControllerOverFlow44 = 12861,
+    /// (Gauge Controller OverFlow45)
+    GaugeControllerOverFlow45 = 12862,
+    /// (Gauge Controller OverFlow46)
+    GaugeControllerOverFlow46 = 12863,
+    /// (Gauge Controller OverFlow47)
+    GaugeControllerOverFlow47 = 12864,
+    /// (Gauge Controller UnderFlow33)
+    GaugeControllerUnderFlow33 = 12865,
+    /// (Gauge Controller Divide16)
+    GaugeControllerDivide16 = 12866,
+    /// (Gauge Controller OverFlow48)
+    GaugeControllerOverFlow48 = 12867,
+    /// (Gauge Controller UnderFlow34)
+    GaugeControllerUnderFlow34 = 12868,
+
+    // BRIBE
+    /// (Bribe Is Locked 1)
+    BribeIsLocked1 = 12901,
+    /// (Bribe Is Locked 2)
+    BribeIsLocked2 = 12902,
+    /// (Bribe Zero Amount)
+    BribeZeroAmount = 12903,
+    /// (Bribe Period Ended)
+    BribePeriodEnded = 12904,
+    /// (Bribe Period Not Started)
+    BribePeriodNotStarted = 12905,
+    /// (Bribe Vote After Period Start)
+    BribeVoteAfterPeriodStart = 12906,
+    /// (Bribe Gauge Not Checkpointed)
+    BribeGaugeNotCheckpointed = 12907,
+    /// (Bribe Addition Error 1)
+    BribeAdditionError1 = 12908,
+    /// (Bribe Addition Error 2)
+    BribeAdditionError2 = 12909,
+    /// (Bribe Addition Error 3)
+    BribeAdditionError3 = 12910,
+    /// (Bribe Subtraction Error 1)
+    BribeSubtractionError1 = 12911,
+    /// (Bribe Subtraction Error 2)
+    BribeSubtractionError2 = 12912,
+    /// (Bribe Subtraction Error 3)
+    BribeSubtractionError3 = 12913,
+    /// (Bribe Multiplication Error 1)
+    BribeMultiplicationError1 = 12914,
+    /// (Bribe Multiplication Error 2)
+    BribeMultiplicationError2 = 12915,
+    /// (Bribe Multiplication Error 3)
+    BribeMultiplicationError3 = 12916,
+    /// (Bribe Division Error 1)
+    BribeDivisionError1 = 12917,
+    /// (Bribe Division Error 2)
+    BribeDivisionError2 = 12918,
+    /// (Bribe Addition Error 4)
+    BribeAdditionError4 = 12919,
+    /// (Bribe Addition Error 5)
+    BribeAdditionError5 = 12920,
+    /// (Bribe Addition Error 6)
+    BribeAdditionError6 = 12921,
+    /// (Bribe Is Locked 3)
+    BribeIsLocked3 = 12922,
+    /// (Bribe Claim Window Ended)
+    BribeClaimWindowEnded = 12923,
+    /// (Bribe Claim Window Open)
+    BribeClaimWindowOpen = 12924,
+    /// (Bribe Addition Error 7)
+    BribeAdditionError7 = 12925,
+    /// (Bribe Addition Error 8)
+    BribeAdditionError8 = 12926,
+    /// (Bribe Subtraction Error 4)
+    BribeSubtractionError4 = 12927,
+    /// (Bribe Multiplication Error 4)
+    BribeMultiplicationError4 = 12928,
+    /// (Bribe Division Error 3)
+    BribeDivisionError3 = 12929,
+
+    // LIQUIDITY GAUGE FACTORY
+    /// (Liquidity Gauge Factory Only Admin)
+    LiquidityGaugeFactoryOnlyAdmin = 12952,
+    /// (Liquidity Gauge Factory Only Future Admin)
+    LiquidityGaugeFactoryOnlyFutureAdmin = 12953,
+    /// (Liquidity Gauge Factory Invalid Lp Token)
+    LiquidityGaugeFactoryInvalidLpToken = 12954,
+    /// (Liquidity Gauge Factory Gauge Already Deployed)
+    LiquidityGaugeFactoryGaugeAlreadyDeployed = 12955,
+    /// (Liquidity Gauge Factory Addition Error 1)
+    LiquidityGaugeFactoryAdditionError1 = 12957,
+
+    // VOTING ESCROW
+    /// (Voting Escrow Too Many Positions)
+    VotingEscrowTooManyPositions = 13001,
+    /// (Voting Escrow Invalid Bias 1)
+    VotingEscrowInvalidBias1 = 13003,
+    /// (Voting Escrow Addition Error 53)
+    VotingEscrowAdditionError53 = 13005,
+    /// (Voting Escrow Addition Error 54)
+    VotingEscrowAdditionError54 = 13006,
+    /// (Voting Escrow Division Error 22)
+    VotingEscrowDivisionError22 = 13007,
+    /// (Voting Escrow Multiplication Error 25)
+    VotingEscrowMultiplicationError25 = 13008,
+    /// (Voting Escrow Invalid Bias 3)
+    VotingEscrowInvalidBias3 = 13009,
+    /// (Voting Escrow Invalid Lock Duration 2)
+    VotingEscrowInvalidLockDuration2 = 13010,
+    /// (Voting Escrow Addition Error 55)
+    VotingEscrowAdditionError55 = 13011,
+    /// (Voting Escrow Addition Error 56)
+    VotingEscrowAdditionError56 = 13012,
+    /// (Voting Escrow Addition Error 57)
+    VotingEscrowAdditionError57 = 13013,
 }
 
 impl From<Error> for ApiError {
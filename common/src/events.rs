@@ -0,0 +1,185 @@
+use crate::{errors::Error, keys::*};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    ApiError, CLType, CLTyped, URef,
+};
+
+// ---- CASPER EVENT STANDARD ----
+// Events are appended to the `__events` dictionary under their sequential index and
+// `__events_length` holds the number of emitted events. Each event is stored as the bytes of
+// its prefixed name ("event_<name>") followed by its fields, which the schema registered in
+// `__events_schema` at install describes.
+// ---- CASPER EVENT STANDARD ----
+
+pub const CES_VERSION: &str = "0.1.0";
+pub const EVENT_PREFIX: &str = "event_";
+
+/// Names and types of the fields of an event, in the order they are serialized.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schema(Vec<(String, CLType)>);
+
+impl Schema {
+    pub fn new() -> Schema {
+        Schema(Vec::new())
+    }
+
+    pub fn with_elem<T: CLTyped>(mut self, name: &str) -> Schema {
+        self.0.push((name.to_string(), T::cl_type()));
+        self
+    }
+
+    pub fn elems(&self) -> &Vec<(String, CLType)> {
+        &self.0
+    }
+}
+
+impl CLTyped for Schema {
+    fn cl_type() -> CLType {
+        CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::String),
+            Box::new(CLType::Any),
+        ])))
+    }
+}
+
+impl ToBytes for Schema {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut bytes: Vec<u8> = (self.0.len() as u32).to_bytes()?;
+        for (name, cl_type) in self.0.iter() {
+            bytes.append(&mut name.to_bytes()?);
+            cl_type.append_bytes(&mut bytes)?;
+        }
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U32_SERIALIZED_LENGTH
+            + self
+                .0
+                .iter()
+                .map(|(name, cl_type)| name.serialized_length() + cl_type.serialized_length())
+                .sum::<usize>()
+    }
+}
+
+impl FromBytes for Schema {
+    fn from_bytes(bytes: &[u8]) -> Result<(Schema, &[u8]), bytesrepr::Error> {
+        let (length, mut remainder) = u32::from_bytes(bytes)?;
+        let mut elems: Vec<(String, CLType)> = Vec::new();
+        for _ in 0..length {
+            let (name, rem) = String::from_bytes(remainder)?;
+            let (cl_type, rem) = CLType::from_bytes(rem)?;
+            elems.push((name, cl_type));
+            remainder = rem;
+        }
+        Ok((Schema(elems), remainder))
+    }
+}
+
+/// Schemas of all events a contract can emit, by event name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schemas(BTreeMap<String, Schema>);
+
+impl Schemas {
+    pub fn new() -> Schemas {
+        Schemas(BTreeMap::new())
+    }
+
+    pub fn with(mut self, name: &str, schema: Schema) -> Schemas {
+        self.0.insert(name.to_string(), schema);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Schema> {
+        self.0.get(name)
+    }
+}
+
+impl CLTyped for Schemas {
+    fn cl_type() -> CLType {
+        BTreeMap::<String, Schema>::cl_type()
+    }
+}
+
+impl ToBytes for Schemas {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for Schemas {
+    fn from_bytes(bytes: &[u8]) -> Result<(Schemas, &[u8]), bytesrepr::Error> {
+        let (schemas, remainder) = BTreeMap::<String, Schema>::from_bytes(bytes)?;
+        Ok((Schemas(schemas), remainder))
+    }
+}
+
+/// Typed event of a contract, usually an enum with one variant per event.
+pub trait CasperEvent {
+    /// Name of the event, its schema is registered under the same name
+    fn name(&self) -> String;
+
+    /// Fields of the event serialized in the order of its schema
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error>;
+
+    /// Schemas of every event of this type
+    fn schemas() -> Schemas
+    where
+        Self: Sized;
+}
+
+/// Creates the events dictionary and registers the schemas of `T`, called once at install.
+pub fn init<T: CasperEvent>() {
+    storage::new_dictionary(EVENTS_DICT).unwrap_or_revert();
+    runtime::put_key(EVENTS_LENGTH, storage::new_uref(0_u32).into());
+    runtime::put_key(EVENTS_SCHEMA, storage::new_uref(T::schemas()).into());
+    runtime::put_key(
+        EVENTS_CES_VERSION,
+        storage::new_uref(CES_VERSION.to_string()).into(),
+    );
+}
+
+/// Appends `event` to the events dictionary under the next index.
+pub fn emit<T: CasperEvent>(event: &T) {
+    let mut bytes: Vec<u8> = format!("{}{}", EVENT_PREFIX, event.name())
+        .to_bytes()
+        .unwrap_or_revert();
+    bytes.append(&mut event.fields_to_bytes().unwrap_or_revert());
+    let length_uref: URef = get_uref(EVENTS_LENGTH);
+    let length: u32 = storage::read(length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(Error::EventsNotInitialized);
+    storage::dictionary_put(
+        get_uref(EVENTS_DICT),
+        &length.to_string(),
+        Bytes::from(bytes),
+    );
+    storage::write(
+        length_uref,
+        length
+            .checked_add(1)
+            .unwrap_or_revert_with(Error::EventsLengthOverflow),
+    );
+}
+
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert_with(Error::EventsNotInitialized)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant)
+}
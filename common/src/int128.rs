@@ -0,0 +1,282 @@
+use crate::errors::Error;
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U128, U256,
+};
+use core::{convert::TryFrom, fmt};
+
+/// Signed 128 bit integer, Vyper's `int128`.
+///
+/// Primitive i128 cannot be handled in structs and entrypoints, so it is serialized as the
+/// tuple (sign:bool {true:(-ve) | false:(+ve)}, value: U128) that was used before, which keeps
+/// values stored with `i128_to_tuple` readable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I128(i128);
+
+impl I128 {
+    pub const ZERO: I128 = I128(0);
+    pub const MAX: I128 = I128(i128::MAX);
+    pub const MIN: I128 = I128(i128::MIN);
+
+    pub const fn new(value: i128) -> I128 {
+        I128(value)
+    }
+
+    pub const fn value(self) -> i128 {
+        self.0
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    pub fn checked_add(self, rhs: I128) -> Result<I128, Error> {
+        self.0
+            .checked_add(rhs.0)
+            .map(I128)
+            .ok_or(Error::Int128AdditionOverflow)
+    }
+
+    pub fn checked_sub(self, rhs: I128) -> Result<I128, Error> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(I128)
+            .ok_or(Error::Int128SubtractionOverflow)
+    }
+
+    pub fn checked_mul(self, rhs: I128) -> Result<I128, Error> {
+        self.0
+            .checked_mul(rhs.0)
+            .map(I128)
+            .ok_or(Error::Int128MultiplicationOverflow)
+    }
+
+    pub fn checked_div(self, rhs: I128) -> Result<I128, Error> {
+        self.0
+            .checked_div(rhs.0)
+            .map(I128)
+            .ok_or(Error::Int128DivisionError)
+    }
+
+    pub fn checked_neg(self) -> Result<I128, Error> {
+        self.0
+            .checked_neg()
+            .map(I128)
+            .ok_or(Error::Int128NegationOverflow)
+    }
+}
+
+impl From<i128> for I128 {
+    fn from(value: i128) -> I128 {
+        I128(value)
+    }
+}
+
+impl From<I128> for i128 {
+    fn from(value: I128) -> i128 {
+        value.0
+    }
+}
+
+impl TryFrom<I128> for U256 {
+    type Error = Error;
+
+    fn try_from(value: I128) -> Result<U256, Error> {
+        if value.is_negative() {
+            return Err(Error::Int128NegativeToUnsigned);
+        }
+        Ok(U256::from(value.0 as u128))
+    }
+}
+
+impl TryFrom<U256> for I128 {
+    type Error = Error;
+
+    fn try_from(value: U256) -> Result<I128, Error> {
+        if value > U256::from(i128::MAX as u128) {
+            return Err(Error::Int128UnsignedOverflow);
+        }
+        Ok(I128(value.as_u128() as i128))
+    }
+}
+
+impl fmt::Display for I128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl CLTyped for I128 {
+    fn cl_type() -> CLType {
+        <(bool, U128)>::cl_type()
+    }
+}
+
+impl ToBytes for I128 {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        (self.is_negative(), U128::from(self.0.unsigned_abs())).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        (self.is_negative(), U128::from(self.0.unsigned_abs())).serialized_length()
+    }
+}
+
+impl FromBytes for I128 {
+    fn from_bytes(bytes: &[u8]) -> Result<(I128, &[u8]), bytesrepr::Error> {
+        let ((negative, magnitude), remainder) = <(bool, U128)>::from_bytes(bytes)?;
+        if magnitude > U128::from(i128::MIN.unsigned_abs())
+            || (!negative && magnitude == U128::from(i128::MIN.unsigned_abs()))
+        {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        // `wrapping_neg` keeps a magnitude of exactly 2^127 as i128::MIN
+        let value: i128 = magnitude.as_u128() as i128;
+        if negative {
+            Ok((I128(value.wrapping_neg()), remainder))
+        } else {
+            Ok((I128(value), remainder))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::I128;
+    use crate::{
+        errors::Error,
+        utils::{i128_to_tuple, tuple_to_i128},
+    };
+    use casper_types::{
+        bytesrepr::{self, FromBytes, ToBytes},
+        U128, U256,
+    };
+    use core::convert::TryFrom;
+
+    const VALUES: [i128; 7] = [
+        0,
+        1,
+        -1,
+        10_000_000_000,
+        -10_000_000_000,
+        i128::MAX,
+        -i128::MAX,
+    ];
+
+    #[test]
+    fn test_serializes_like_tuple() {
+        for value in VALUES.iter() {
+            let bytes = I128::new(*value).to_bytes().unwrap();
+            assert_eq!(bytes, i128_to_tuple(*value).to_bytes().unwrap());
+            assert_eq!(bytes.len(), I128::new(*value).serialized_length());
+        }
+    }
+
+    #[test]
+    fn test_deserializes_tuple() {
+        for value in VALUES.iter() {
+            let bytes = i128_to_tuple(*value).to_bytes().unwrap();
+            let (ret, remainder) = I128::from_bytes(&bytes).unwrap();
+            assert_eq!(ret.value(), *value);
+            assert!(remainder.is_empty());
+            let tuple: (bool, U128) =
+                bytesrepr::deserialize(I128::new(*value).to_bytes().unwrap()).unwrap();
+            assert_eq!(tuple_to_i128(tuple), *value);
+        }
+    }
+
+    #[test]
+    fn test_negative_zero() {
+        let bytes = (true, U128::zero()).to_bytes().unwrap();
+        let (ret, _) = I128::from_bytes(&bytes).unwrap();
+        assert_eq!(ret, I128::ZERO);
+        assert_eq!(tuple_to_i128((true, U128::zero())), 0);
+        // Zero is always written as positive
+        assert_eq!(
+            I128::ZERO.to_bytes().unwrap(),
+            (false, U128::zero()).to_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_min() {
+        let tuple: (bool, U128) = (true, U128::from(i128::MIN.unsigned_abs()));
+        assert_eq!(I128::MIN.to_bytes().unwrap(), tuple.to_bytes().unwrap());
+        let (ret, _) = I128::from_bytes(&tuple.to_bytes().unwrap()).unwrap();
+        assert_eq!(ret, I128::MIN);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let too_big: [(bool, U128); 3] = [
+            (false, U128::from(i128::MIN.unsigned_abs())),
+            (false, U128::MAX),
+            (true, U128::from(i128::MIN.unsigned_abs()) + 1),
+        ];
+        for tuple in too_big.iter() {
+            assert_eq!(
+                I128::from_bytes(&tuple.to_bytes().unwrap()).unwrap_err(),
+                bytesrepr::Error::Formatting
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let a: I128 = I128::new(-7);
+        let b: I128 = I128::new(2);
+        assert_eq!(a.checked_add(b).ok(), Some(I128::new(-5)));
+        assert_eq!(a.checked_sub(b).ok(), Some(I128::new(-9)));
+        assert_eq!(a.checked_mul(b).ok(), Some(I128::new(-14)));
+        assert_eq!(a.checked_div(b).ok(), Some(I128::new(-3)));
+        assert_eq!(a.checked_neg().ok(), Some(I128::new(7)));
+        assert!(matches!(
+            I128::MAX.checked_add(b),
+            Err(Error::Int128AdditionOverflow)
+        ));
+        assert!(matches!(
+            I128::MIN.checked_sub(b),
+            Err(Error::Int128SubtractionOverflow)
+        ));
+        assert!(matches!(
+            I128::MAX.checked_mul(b),
+            Err(Error::Int128MultiplicationOverflow)
+        ));
+        assert!(matches!(
+            a.checked_div(I128::ZERO),
+            Err(Error::Int128DivisionError)
+        ));
+        assert!(matches!(
+            I128::MIN.checked_neg(),
+            Err(Error::Int128NegationOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_try_into_u256() {
+        assert_eq!(U256::try_from(I128::new(42)).ok(), Some(U256::from(42)));
+        assert_eq!(U256::try_from(I128::ZERO).ok(), Some(U256::zero()));
+        assert_eq!(
+            U256::try_from(I128::MAX).ok(),
+            Some(U256::from(i128::MAX as u128))
+        );
+        assert!(matches!(
+            U256::try_from(I128::new(-1)),
+            Err(Error::Int128NegativeToUnsigned)
+        ));
+    }
+
+    #[test]
+    fn test_try_from_u256() {
+        assert_eq!(I128::try_from(U256::from(42)).ok(), Some(I128::new(42)));
+        assert_eq!(
+            I128::try_from(U256::from(i128::MAX as u128)).ok(),
+            Some(I128::MAX)
+        );
+        assert!(matches!(
+            I128::try_from(U256::from(i128::MAX as u128) + 1),
+            Err(Error::Int128UnsignedOverflow)
+        ));
+    }
+}
@@ -41,6 +41,11 @@ pub const FUND_ADMINS_DICT: &str = "fund_admins";
 pub const CAN_DISABLE: &str = "can_disable";
 pub const FUND_ADMINS_ENABLED: &str = "fund_admins_enabled";
 pub const END_TIME: &str = "end_time";
+// Events (Casper Event Standard)
+pub const EVENTS_DICT: &str = "__events";
+pub const EVENTS_LENGTH: &str = "__events_length";
+pub const EVENTS_SCHEMA: &str = "__events_schema";
+pub const EVENTS_CES_VERSION: &str = "__events_ces_version";
 // Voting Escrow
 pub const GET_LAST_USER_SLOPE: &str = "get_last_user_slope";
 pub const USER_POINT_HISTORY_TS: &str = "user_point_history_ts";
@@ -49,6 +54,12 @@ pub const BALANCE_OF_AT: &str = "balance_of_at";
 pub const VE_BALANCE_OF: &str = "ve_balance_of";
 pub const TOTAL_SUPPLY_AT: &str = "total_supply_at";
 pub const VE_TOTAL_SUPPLY: &str = "ve_total_supply";
+pub const BALANCE_OF_AT_TIME: &str = "balance_of_at_time";
+pub const TOTAL_SUPPLY_AT_TIME: &str = "total_supply_at_time";
+pub const POSITION_BALANCE_OF: &str = "position_balance_of";
+pub const GET_VOTES: &str = "get_votes";
+pub const BALANCES_OF_AT: &str = "balances_of_at";
+pub const TOTAL_SUPPLY_SERIES: &str = "total_supply_series";
 // Fee Distributor
 pub const VE_FOR_AT: &str = "ve_for_at";
 pub const CLAIM: &str = "claim";
@@ -107,6 +118,19 @@ pub const SUPPLY: &str = "supply";
 pub const TRANSFERS_ENABLED: &str = "transfers_enabled";
 pub const VERSION: &str = "version";
 pub const EPOCH: &str = "epoch";
+pub const SMART_WALLET_CHECKER: &str = "smart_wallet_checker";
+pub const FUTURE_SMART_WALLET_CHECKER: &str = "future_smart_wallet_checker";
+pub const PENALTY_RECEIVER: &str = "penalty_receiver";
+pub const POSITION_OWNERS_DICT: &str = "position_owners";
+pub const OWNER_POSITIONS_DICT: &str = "owner_positions";
+pub const POSITION_INDEX_DICT: &str = "position_index";
+pub const POSITIONS_COUNT_DICT: &str = "positions_count";
+pub const POSITION_ID: &str = "position_id";
+pub const DELEGATES_DICT: &str = "delegates";
+pub const LOCK_DELEGATES_DICT: &str = "lock_delegates";
+pub const DELEGATE_POINT_HISTORY_DICT: &str = "delegate_point_history";
+pub const DELEGATE_POINT_EPOCH_DICT: &str = "delegate_point_epoch";
+pub const DELEGATE_SLOPE_CHANGES_DICT: &str = "delegate_slope_changes";
 // Curve Token V3
 pub const CURVE_TOKEN_V3_SELF_CONTRACT_HASH: &str = "self_contract_hash";
 pub const CURVE_TOKEN_V3_SELF_PACKAGE_HASH: &str = "self_package_hash";
@@ -126,6 +150,14 @@ pub const GAUGE_TYPES_DICT: &str = "gauge_types_";
 pub const VOTE_USER_SLOPES_DICT: &str = "vote_user_slopes";
 pub const VOTE_USER_POWER_DICT: &str = "vote_user_power";
 pub const LAST_USER_VOTE_DICT: &str = "last_user_vote";
+pub const VOTE_DELEGATES_DICT: &str = "vote_delegates";
+pub const KILLED_GAUGES_DICT: &str = "killed_gauges";
+pub const DEPRECATED_TYPES_DICT: &str = "deprecated_types";
+pub const ACTIVE_TYPES_DICT: &str = "active_types";
+pub const ACTIVE_TYPE_INDEX_DICT: &str = "active_type_index";
+pub const MAX_RELATIVE_WEIGHTS_DICT: &str = "max_relative_weights";
+pub const CAP_RECEIVERS_DICT: &str = "cap_receivers";
+pub const CAPPED_GAUGES_DICT: &str = "capped_gauges";
 pub const POINTS_WEIGHT_DICT: &str = "points_weight";
 pub const CHANGES_WEIGHT_DICT: &str = "changes_weight";
 pub const TIME_WEIGHT_DICT: &str = "time_weight";
@@ -140,12 +172,68 @@ pub const OWNER: &str = "owner";
 pub const TIME_TOTAL: &str = "time_total";
 pub const N_GAUGE_TYPES: &str = "n_gauge_types";
 pub const N_GAUGES: &str = "n_gauges";
+pub const N_CAPPED_GAUGES: &str = "n_capped_gauges";
+pub const N_ACTIVE_TYPES: &str = "n_active_types";
+pub const CHECK_GAUGE_CONTROLLER: &str = "check_gauge_controller";
 pub const LAST_USER_VOTE: &str = "last_user_vote";
 // Gauge Proxy
 pub const OWNERSHIP_ADMIN: &str = "ownership_admin";
 pub const EMERGENCY_ADMIN: &str = "emergency_admin";
 pub const FUTURE_OWNERSHIP_ADMIN: &str = "future_ownership_admin";
 pub const FUTURE_EMERGENCY_ADMIN: &str = "future_emergency_admin";
+// Smart Wallet Checker
+pub const WALLETS_DICT: &str = "wallets";
+pub const CHECKER: &str = "checker";
+pub const FUTURE_CHECKER: &str = "future_checker";
+pub const CHECK: &str = "check";
+// Ve Boost
+pub const DELEGATED_DICT: &str = "delegated";
+pub const RECEIVED_DICT: &str = "received";
+pub const DELEGATED_SLOPE_CHANGES_DICT: &str = "delegated_slope_changes";
+pub const RECEIVED_FROM_DICT: &str = "received_from";
+pub const RECEIVED_FROM_COUNT_DICT: &str = "received_from_count";
+pub const DELEGATED_BALANCE: &str = "delegated_balance";
+pub const RECEIVED_BALANCE: &str = "received_balance";
+pub const DELEGABLE_BALANCE: &str = "delegable_balance";
+pub const ADJUSTED_BALANCE_OF: &str = "adjusted_balance_of";
+pub const VEBOOST_PROXY: &str = "veboost_proxy";
+// Voting
+pub const VOTES_DICT: &str = "votes";
+pub const VOTE_ACTIONS_DICT: &str = "vote_actions";
+pub const VOTER_STATES_DICT: &str = "voter_states";
+pub const SUPPORT_REQUIRED_PCT: &str = "support_required_pct";
+pub const MIN_ACCEPT_QUORUM_PCT: &str = "min_accept_quorum_pct";
+pub const VOTE_TIME: &str = "vote_time";
+pub const VOTES_LENGTH: &str = "votes_length";
+pub const MIN_BALANCE: &str = "min_balance";
+// Agent
+pub const OWNERSHIP_AGENT: &str = "ownership_agent";
+pub const PARAMETER_AGENT: &str = "parameter_agent";
+pub const EMERGENCY_AGENT: &str = "emergency_agent";
+pub const FUTURE_OWNERSHIP_AGENT: &str = "future_ownership_agent";
+pub const FUTURE_PARAMETER_AGENT: &str = "future_parameter_agent";
+pub const FUTURE_EMERGENCY_AGENT: &str = "future_emergency_agent";
+pub const PERMISSIONS_DICT: &str = "permissions";
+// Timelock
+pub const GUARDIAN: &str = "guardian";
+pub const DELAY: &str = "delay";
+pub const OPERATIONS_DICT: &str = "operations";
+pub const OPERATIONS_COUNT: &str = "operations_count";
+pub const QUEUED_OPERATIONS_DICT: &str = "queued_operations";
+pub const QUEUED_INDEX_DICT: &str = "queued_index";
+pub const QUEUED_COUNT: &str = "queued_count";
+// Bribe
+pub const REWARDS_PER_WEEK_DICT: &str = "rewards_per_week";
+pub const CLAIMED_PER_WEEK_DICT: &str = "claimed_per_week";
+pub const USER_CLAIMED_DICT: &str = "user_claimed";
+pub const VOTE_BIASES_DICT: &str = "vote_biases";
+pub const SNAPSHOT_WEEKS_DICT: &str = "snapshot_weeks";
+pub const DEPOSITOR_REWARDS_DICT: &str = "depositor_rewards";
+// Liquidity Gauge Factory
+pub const GAUGE_OF_DICT: &str = "gauge_of";
+pub const IS_FACTORY_GAUGE_DICT: &str = "is_factory_gauge";
+pub const FACTORY_GAUGES_DICT: &str = "factory_gauges";
+pub const FACTORY_GAUGE_COUNT: &str = "factory_gauge_count";
 // Liquidity Gauge Reward
 pub const PERIOD: &str = "period";
 pub const FUTURE_EPOCH_TIME: &str = "future_epoch_time";
@@ -178,6 +266,9 @@ pub const ALLOWANCE: &str = "allowance";
 pub const GAUGE_TYPES: &str = "gauge_types";
 pub const GAUGE_RELATIVE_WEIGHT: &str = "gauge_relative_weight";
 pub const GAUGE_RELATIVE_WEIGHT_WRITE: &str = "gauge_relative_weight_write";
+pub const LIST_GAUGES: &str = "list_gauges";
+pub const LIST_TYPES: &str = "list_types";
+pub const EMISSIONS_FORECAST: &str = "emissions_forecast";
 pub const GET_GAUGE_WEIGHT: &str = "get_gauge_weight";
 pub const GET_TYPE_WEIGHT: &str = "get_type_weight";
 pub const GET_TOTAL_WEIGHT: &str = "get_total_weight";
@@ -1,5 +1,7 @@
 #![no_std]
 extern crate alloc;
 pub mod errors;
+pub mod events;
+pub mod int128;
 pub mod keys;
 pub mod utils;
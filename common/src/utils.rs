@@ -1,6 +1,15 @@
-use alloc::string::{String, ToString};
-use casper_types::{Key, U128};
-use core::convert::TryInto;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::{ext_ffi, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    api_error,
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::ContractVersion,
+    ApiError, ContractPackageHash, Key, RuntimeArgs, U128,
+};
+use core::{convert::TryInto, mem::MaybeUninit};
 use hex::encode;
 use renvm_sig::keccak256;
 
@@ -24,9 +33,79 @@ pub fn key_to_str(key: &Key) -> String {
     }
 }
 
+/// Calls `entry_point_name` on the latest version of `contract_package_hash` like
+/// `runtime::call_versioned_contract`, returning the size of the returned value left in the
+/// host buffer
+fn call_latest_version(
+    contract_package_hash: ContractPackageHash,
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+) -> usize {
+    let contract_package_hash: Vec<u8> = contract_package_hash.to_bytes().unwrap_or_revert();
+    let contract_version: Vec<u8> = Option::<ContractVersion>::None
+        .to_bytes()
+        .unwrap_or_revert();
+    let entry_point_name: Vec<u8> = entry_point_name.to_bytes().unwrap_or_revert();
+    let runtime_args: Vec<u8> = runtime_args.to_bytes().unwrap_or_revert();
+    let mut bytes_written: MaybeUninit<usize> = MaybeUninit::uninit();
+    let ret: i32 = unsafe {
+        ext_ffi::casper_call_versioned_contract(
+            contract_package_hash.as_ptr(),
+            contract_package_hash.len(),
+            contract_version.as_ptr(),
+            contract_version.len(),
+            entry_point_name.as_ptr(),
+            entry_point_name.len(),
+            runtime_args.as_ptr(),
+            runtime_args.len(),
+            bytes_written.as_mut_ptr(),
+        )
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+    unsafe { bytes_written.assume_init() }
+}
+
+/// Same as `runtime::call_versioned_contract` on the latest version, but leaves the returned
+/// value unread, so that entry points can be called whatever their return type
+pub fn call_versioned_contract_ignoring_result(
+    contract_package_hash: ContractPackageHash,
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+) {
+    call_latest_version(contract_package_hash, entry_point_name, runtime_args);
+}
+
+/// Same as `runtime::call_versioned_contract` on the latest version, but reverts with `error`
+/// instead of a deserialization error when the returned value is not a `T`
+pub fn call_versioned_contract_or_revert_with<T: FromBytes, E: Into<ApiError>>(
+    contract_package_hash: ContractPackageHash,
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+    error: E,
+) -> T {
+    let mut bytes: Vec<u8> =
+        alloc::vec![0; call_latest_version(contract_package_hash, entry_point_name, runtime_args)];
+    if !bytes.is_empty() {
+        let mut bytes_read: MaybeUninit<usize> = MaybeUninit::uninit();
+        let ret: i32 = unsafe {
+            ext_ffi::casper_read_host_buffer(
+                bytes.as_mut_ptr(),
+                bytes.len(),
+                bytes_read.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+    }
+    bytesrepr::deserialize(bytes)
+        .ok()
+        .unwrap_or_revert_with(error)
+}
+
 // ---- TUPLE USAGE FOR int128 ----
 // As primtive i128 cannot be handled in structs and entrypoints
 // so changing it to tuple (sign:bool {true:(-ve) | false:(+ve)}, value: U128)
+// Contracts use `crate::int128::I128`, which serializes to the same bytes, these helpers
+// stay for tests and session code that build or read such tuples directly
 // ---- TUPLE USAGE FOR int128 ----
 
 pub fn tuple_to_i128(value: (bool, U128)) -> i128 {
@@ -171,6 +171,10 @@ fn minter() {
 fn rate() {
     runtime::ret(CLValue::from_t(data::get_rate()).unwrap_or_revert());
 }
+#[no_mangle]
+fn start_epoch_time() {
+    runtime::ret(CLValue::from_t(data::get_start_epoch_time()).unwrap_or_revert());
+}
 
 fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -389,6 +393,13 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "start_epoch_time",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "future_epoch_time_write",
         vec![],
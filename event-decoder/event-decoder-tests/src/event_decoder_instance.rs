@@ -0,0 +1,125 @@
+use casper_types::{account::AccountHash, bytesrepr::Bytes, runtime_args, Key, RuntimeArgs, U256};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+use crv20::Address;
+use std::time::SystemTime;
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+pub fn deploy_erc20_crv(env: &TestEnv, sender: AccountHash, time_now: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "erc20-crv.wasm",
+        "erc20-crv",
+        sender,
+        runtime_args! {
+            "name" => "CRV",
+            "symbol" => "ERC20CRV",
+            "decimals" => 9_u8,
+        },
+        time_now,
+    )
+}
+
+pub fn deploy_voting_escrow(
+    env: &TestEnv,
+    sender: AccountHash,
+    token_addr: Key,
+    time_now: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "voting-escrow.wasm",
+        "Vote-escrowed CRV",
+        sender,
+        runtime_args! {
+            "token_addr" => token_addr,
+            "name" => "Vote-escrowed CRV".to_string(),
+            "symbol" => "veCRV".to_string(),
+            "version" => "veCRV_1.0.0".to_string(),
+        },
+        time_now,
+    )
+}
+
+pub fn deploy_gauge_controller(
+    env: &TestEnv,
+    sender: AccountHash,
+    token: Key,
+    voting_escrow: Key,
+    time_now: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "gauge-controller-token.wasm",
+        "Gauge Controller",
+        sender,
+        runtime_args! {
+            "voting_escrow" => voting_escrow,
+            "token" => token,
+        },
+        time_now,
+    )
+}
+
+pub fn create_lock(
+    owner: AccountHash,
+    voting_escrow: &TestContract,
+    erc20_crv: &TestContract,
+    value: U256,
+    unlock_time: U256,
+    time_now: u64,
+) {
+    let spender: Address = Address::Contract(voting_escrow.package_hash().into());
+    erc20_crv.call_contract(
+        owner,
+        "increase_allowance",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => value
+        },
+        time_now,
+    );
+    voting_escrow.call_contract(
+        owner,
+        "create_lock",
+        runtime_args! {
+            "value" => value,
+            "unlock_time" => unlock_time
+        },
+        time_now,
+    );
+}
+
+pub fn add_type(
+    owner: AccountHash,
+    gauge_controller: &TestContract,
+    name: &str,
+    weight: U256,
+    time_now: u64,
+) {
+    gauge_controller.call_contract(
+        owner,
+        "add_type",
+        runtime_args! {
+            "name" => name.to_string(),
+            "weight" => Some(weight)
+        },
+        time_now,
+    );
+}
+
+pub fn events_length(contract: &TestContract) -> u32 {
+    contract.query_named_key(EVENTS_LENGTH.to_string())
+}
+
+pub fn event(contract: &TestContract, index: u32) -> Bytes {
+    contract
+        .query_dictionary(EVENTS_DICT, index.to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,351 @@
+use crate::event_decoder_instance::*;
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    AccessRights, CLType, CLValue, ExecutionEffect, ExecutionResult, Key, Transform,
+    TransformEntry, URef, U256, U512,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::events::{CasperEvent, EVENT_PREFIX};
+use event_decoder::{
+    agent::AgentEvent, decode_cl_value, decode_event, decode_execution_result, decode_transforms,
+    gauge_controller::GAUGECONLTROLLEREvent, minter::MINTEREvent, timelock::TimelockEvent,
+    ve_boost::VeBoostEvent, voting::VotingEvent, voting_escrow::VotingEscrowEvent, Error,
+};
+use voting_escrow_crate::data::WEEK;
+
+const TEN_E_NINE: u128 = 1000000000;
+const MILLI_SECONDS_IN_YEAR: u64 = 86400000 * 365;
+
+fn deploy() -> (TestEnv, AccountHash, TestContract, TestContract, u64) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let time_now: u64 = now();
+    let erc20_crv = deploy_erc20_crv(&env, owner, time_now);
+    let voting_escrow =
+        deploy_voting_escrow(&env, owner, Key::Hash(erc20_crv.package_hash()), time_now);
+    (env, owner, voting_escrow, erc20_crv, time_now)
+}
+
+fn encode<E: CasperEvent>(event: &E) -> Vec<u8> {
+    [
+        format!("{}{}", EVENT_PREFIX, event.name())
+            .to_bytes()
+            .unwrap(),
+        event.fields_to_bytes().unwrap(),
+    ]
+    .concat()
+}
+
+fn dictionary_value(event: Bytes, events: URef, index: u32) -> CLValue {
+    let bytes: Vec<u8> = [
+        CLValue::from_t(event).unwrap().to_bytes().unwrap(),
+        Bytes::from(events.addr().to_vec()).to_bytes().unwrap(),
+        Bytes::from(index.to_string().into_bytes())
+            .to_bytes()
+            .unwrap(),
+    ]
+    .concat();
+    CLValue::from_components(CLType::Any, bytes)
+}
+
+fn write_entry(key: &str, value: CLValue) -> TransformEntry {
+    TransformEntry {
+        key: key.to_string(),
+        transform: Transform::WriteCLValue(value),
+    }
+}
+
+#[test]
+fn test_decode_voting_escrow_events() {
+    let (_, owner, voting_escrow, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_YEAR);
+    create_lock(
+        owner,
+        &voting_escrow,
+        &erc20_crv,
+        amount,
+        unlock_time,
+        time_now,
+    );
+    assert_eq!(events_length(&voting_escrow), 2);
+    let deposit: Bytes = event(&voting_escrow, 0);
+    match decode_event::<VotingEscrowEvent>(&deposit).unwrap() {
+        VotingEscrowEvent::Deposit {
+            provider,
+            value,
+            locktime,
+            _type,
+            ts,
+        } => {
+            assert_eq!(provider, Key::from(owner));
+            assert_eq!(value, amount);
+            assert_eq!(locktime, unlock_time / WEEK * WEEK);
+            assert_eq!(_type, 1);
+            assert_eq!(ts, U256::from(time_now));
+        }
+        _ => panic!("Expected deposit event"),
+    }
+    let supply: Bytes = event(&voting_escrow, 1);
+    let decoded: VotingEscrowEvent = decode_event(&supply).unwrap();
+    match decoded {
+        VotingEscrowEvent::Supply {
+            prev_supply,
+            supply,
+        } => {
+            assert_eq!(prev_supply, 0.into());
+            assert_eq!(supply, amount);
+        }
+        _ => panic!("Expected supply event"),
+    }
+    let deposit_decoded: VotingEscrowEvent = decode_event(&deposit).unwrap();
+    assert_eq!(encode(&deposit_decoded), deposit.to_vec());
+    assert_eq!(encode(&decoded), supply.to_vec());
+}
+
+#[test]
+fn test_decode_gauge_controller_events() {
+    let (env, owner, voting_escrow, erc20_crv, time_now) = deploy();
+    let gauge_controller = deploy_gauge_controller(
+        &env,
+        owner,
+        Key::Hash(erc20_crv.package_hash()),
+        Key::Hash(voting_escrow.package_hash()),
+        time_now,
+    );
+    let weight: U256 = U256::from(TEN_E_NINE);
+    add_type(owner, &gauge_controller, "Liquidity", weight, time_now);
+    assert_eq!(events_length(&gauge_controller), 2);
+    let new_type_weight: Bytes = event(&gauge_controller, 0);
+    let decoded: GAUGECONLTROLLEREvent = decode_event(&new_type_weight).unwrap();
+    match decoded {
+        GAUGECONLTROLLEREvent::NewTypeWeight {
+            type_id,
+            weight: new_weight,
+            ..
+        } => {
+            assert_eq!(type_id, 0);
+            assert_eq!(new_weight, weight);
+        }
+        _ => panic!("Expected new type weight event"),
+    }
+    assert_eq!(encode(&decoded), new_type_weight.to_vec());
+    let add_type_event: Bytes = event(&gauge_controller, 1);
+    let decoded: GAUGECONLTROLLEREvent = decode_event(&add_type_event).unwrap();
+    match decoded {
+        GAUGECONLTROLLEREvent::AddType { ref name, type_id } => {
+            assert_eq!(name, "Liquidity");
+            assert_eq!(type_id, 0);
+        }
+        _ => panic!("Expected add type event"),
+    }
+    assert_eq!(encode(&decoded), add_type_event.to_vec());
+}
+
+#[test]
+fn test_decode_governance_events() {
+    let owner = Key::Account(AccountHash::new([1u8; 32]));
+    let target = Key::Hash([2u8; 32]);
+    match decode_event(&encode(&VotingEvent::CastVote {
+        vote_id: 3.into(),
+        voter: owner,
+        supports: true,
+        stake: 1000.into(),
+    }))
+    .unwrap()
+    {
+        VotingEvent::CastVote {
+            vote_id,
+            voter,
+            supports,
+            stake,
+        } => {
+            assert_eq!(vote_id, 3.into());
+            assert_eq!(voter, owner);
+            assert!(supports);
+            assert_eq!(stake, 1000.into());
+        }
+        _ => panic!("Expected a cast vote event"),
+    }
+    match decode_event(&encode(&TimelockEvent::QueueOperation {
+        id: 0.into(),
+        target,
+        entry_point: "set_admin".to_string(),
+        eta: WEEK,
+    }))
+    .unwrap()
+    {
+        TimelockEvent::QueueOperation {
+            id,
+            target: ret_target,
+            entry_point,
+            eta,
+        } => {
+            assert_eq!(id, 0.into());
+            assert_eq!(ret_target, target);
+            assert_eq!(entry_point, "set_admin");
+            assert_eq!(eta, WEEK);
+        }
+        _ => panic!("Expected a queue operation event"),
+    }
+    match decode_event(&encode(&AgentEvent::SetPermission {
+        role: 1,
+        target,
+        entry_point: "set_admin".to_string(),
+        allowed: false,
+    }))
+    .unwrap()
+    {
+        AgentEvent::SetPermission {
+            role,
+            target: ret_target,
+            entry_point,
+            allowed,
+        } => {
+            assert_eq!(role, 1);
+            assert_eq!(ret_target, target);
+            assert_eq!(entry_point, "set_admin");
+            assert!(!allowed);
+        }
+        _ => panic!("Expected a set permission event"),
+    }
+    match decode_event(&encode(&VeBoostEvent::RejectBoost {
+        delegator: owner,
+        receiver: target,
+        bias: 10.into(),
+        slope: 1.into(),
+    }))
+    .unwrap()
+    {
+        VeBoostEvent::RejectBoost {
+            delegator,
+            receiver,
+            bias,
+            slope,
+        } => {
+            assert_eq!(delegator, owner);
+            assert_eq!(receiver, target);
+            assert_eq!(bias, 10.into());
+            assert_eq!(slope, 1.into());
+        }
+        _ => panic!("Expected a reject boost event"),
+    }
+}
+
+#[test]
+fn test_decode_cl_value() {
+    let (_, owner, voting_escrow, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_YEAR);
+    create_lock(
+        owner,
+        &voting_escrow,
+        &erc20_crv,
+        amount,
+        unlock_time,
+        time_now,
+    );
+    let deposit: Bytes = event(&voting_escrow, 0);
+    let stored: VotingEscrowEvent =
+        decode_cl_value(&CLValue::from_t(deposit.clone()).unwrap()).unwrap();
+    assert_eq!(encode(&stored), deposit.to_vec());
+    let events = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+    let wrapped: VotingEscrowEvent =
+        decode_cl_value(&dictionary_value(deposit.clone(), events, 0)).unwrap();
+    assert_eq!(encode(&wrapped), deposit.to_vec());
+    assert!(matches!(
+        decode_cl_value::<VotingEscrowEvent>(&CLValue::from_t(amount).unwrap()),
+        Err(Error::CLValue(_))
+    ));
+}
+
+#[test]
+fn test_decode_transforms() {
+    let (_, owner, voting_escrow, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_YEAR);
+    create_lock(
+        owner,
+        &voting_escrow,
+        &erc20_crv,
+        amount,
+        unlock_time,
+        time_now,
+    );
+    let deposit: Bytes = event(&voting_escrow, 0);
+    let supply: Bytes = event(&voting_escrow, 1);
+    let events = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+    let other = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
+    let transforms: Vec<TransformEntry> = vec![
+        write_entry("dictionary-01", dictionary_value(supply.clone(), events, 1)),
+        write_entry("dictionary-02", dictionary_value(deposit.clone(), other, 0)),
+        write_entry("uref-03", CLValue::from_t(amount).unwrap()),
+        write_entry(
+            "dictionary-04",
+            dictionary_value(deposit.clone(), events, 0),
+        ),
+        TransformEntry {
+            key: "dictionary-05".to_string(),
+            transform: Transform::Identity,
+        },
+    ];
+    let decoded: Vec<(u32, VotingEscrowEvent)> = decode_transforms(&transforms, events).unwrap();
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded[0].0, 0);
+    assert_eq!(encode(&decoded[0].1), deposit.to_vec());
+    assert_eq!(decoded[1].0, 1);
+    assert_eq!(encode(&decoded[1].1), supply.to_vec());
+    let success = ExecutionResult::Success {
+        effect: ExecutionEffect {
+            operations: Vec::new(),
+            transforms: transforms.clone(),
+        },
+        transfers: Vec::new(),
+        cost: U512::zero(),
+    };
+    let decoded: Vec<(u32, VotingEscrowEvent)> = decode_execution_result(&success, events).unwrap();
+    assert_eq!(decoded.len(), 2);
+    let failure = ExecutionResult::Failure {
+        effect: ExecutionEffect {
+            operations: Vec::new(),
+            transforms,
+        },
+        transfers: Vec::new(),
+        cost: U512::zero(),
+        error_message: "User error: 1".to_string(),
+    };
+    let decoded: Vec<(u32, VotingEscrowEvent)> = decode_execution_result(&failure, events).unwrap();
+    assert!(decoded.is_empty());
+}
+
+#[test]
+fn test_decode_invalid_events() {
+    let (_, owner, voting_escrow, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_YEAR);
+    create_lock(
+        owner,
+        &voting_escrow,
+        &erc20_crv,
+        amount,
+        unlock_time,
+        time_now,
+    );
+    let deposit: Bytes = event(&voting_escrow, 0);
+    assert_eq!(
+        decode_event::<MINTEREvent>(&deposit).err(),
+        Some(Error::UnknownEvent("deposit".to_string()))
+    );
+    let mut trailing: Vec<u8> = deposit.to_vec();
+    trailing.push(0);
+    assert_eq!(
+        decode_event::<VotingEscrowEvent>(&trailing).err(),
+        Some(Error::TrailingBytes(1))
+    );
+    let unprefixed: Vec<u8> = "deposit".to_string().to_bytes().unwrap();
+    assert_eq!(
+        decode_event::<VotingEscrowEvent>(&unprefixed).err(),
+        Some(Error::MissingPrefix("deposit".to_string()))
+    );
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod event_decoder_tests;
+
+#[cfg(test)]
+pub mod event_decoder_instance;
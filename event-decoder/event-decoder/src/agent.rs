@@ -0,0 +1,67 @@
+//! Decoding of the agent events.
+
+use crate::{DecodeEvent, Error};
+pub use agent_crate::event::AgentEvent;
+use casper_types::{bytesrepr::FromBytes, Key};
+
+impl DecodeEvent for AgentEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "commitAgents" => {
+                let (ownership_agent, bytes) = Key::from_bytes(bytes)?;
+                let (parameter_agent, bytes) = Key::from_bytes(bytes)?;
+                let (emergency_agent, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    AgentEvent::CommitAgents {
+                        ownership_agent,
+                        parameter_agent,
+                        emergency_agent,
+                    },
+                    bytes,
+                ))
+            }
+            "applyAgents" => {
+                let (ownership_agent, bytes) = Key::from_bytes(bytes)?;
+                let (parameter_agent, bytes) = Key::from_bytes(bytes)?;
+                let (emergency_agent, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    AgentEvent::ApplyAgents {
+                        ownership_agent,
+                        parameter_agent,
+                        emergency_agent,
+                    },
+                    bytes,
+                ))
+            }
+            "setPermission" => {
+                let (role, bytes) = u8::from_bytes(bytes)?;
+                let (target, bytes) = Key::from_bytes(bytes)?;
+                let (entry_point, bytes) = String::from_bytes(bytes)?;
+                let (allowed, bytes) = bool::from_bytes(bytes)?;
+                Ok((
+                    AgentEvent::SetPermission {
+                        role,
+                        target,
+                        entry_point,
+                        allowed,
+                    },
+                    bytes,
+                ))
+            }
+            "execute" => {
+                let (sender, bytes) = Key::from_bytes(bytes)?;
+                let (target, bytes) = Key::from_bytes(bytes)?;
+                let (entry_point, bytes) = String::from_bytes(bytes)?;
+                Ok((
+                    AgentEvent::Execute {
+                        sender,
+                        target,
+                        entry_point,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
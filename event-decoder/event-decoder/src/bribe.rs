@@ -0,0 +1,64 @@
+//! Decoding of the bribe events.
+
+use crate::{DecodeEvent, Error};
+pub use bribe_crate::event::BribeEvent;
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+
+impl DecodeEvent for BribeEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "rewardDeposited" => {
+                let (gauge, bytes) = Key::from_bytes(bytes)?;
+                let (week, bytes) = U256::from_bytes(bytes)?;
+                let (reward_token, bytes) = Key::from_bytes(bytes)?;
+                let (depositor, bytes) = Key::from_bytes(bytes)?;
+                let (amount, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    BribeEvent::RewardDeposited {
+                        gauge,
+                        week,
+                        reward_token,
+                        depositor,
+                        amount,
+                    },
+                    bytes,
+                ))
+            }
+            "rewardClaimed" => {
+                let (user, bytes) = Key::from_bytes(bytes)?;
+                let (gauge, bytes) = Key::from_bytes(bytes)?;
+                let (week, bytes) = U256::from_bytes(bytes)?;
+                let (reward_token, bytes) = Key::from_bytes(bytes)?;
+                let (amount, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    BribeEvent::RewardClaimed {
+                        user,
+                        gauge,
+                        week,
+                        reward_token,
+                        amount,
+                    },
+                    bytes,
+                ))
+            }
+            "rewardSwept" => {
+                let (depositor, bytes) = Key::from_bytes(bytes)?;
+                let (gauge, bytes) = Key::from_bytes(bytes)?;
+                let (week, bytes) = U256::from_bytes(bytes)?;
+                let (reward_token, bytes) = Key::from_bytes(bytes)?;
+                let (amount, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    BribeEvent::RewardSwept {
+                        depositor,
+                        gauge,
+                        week,
+                        reward_token,
+                        amount,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use casper_types::{bytesrepr, CLValueError};
+use std::fmt::{self, Display, Formatter};
+
+/// Errors returned while decoding a stored event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The bytes could not be deserialized
+    Bytesrepr(bytesrepr::Error),
+    /// The stored value does not hold the event bytes
+    CLValue(CLValueError),
+    /// The event name does not start with `event_`
+    MissingPrefix(String),
+    /// The event name is not one of the events of the contract
+    UnknownEvent(String),
+    /// Bytes are left after the last field of the event
+    TrailingBytes(usize),
+    /// The dictionary item key is not an event index
+    InvalidIndex,
+}
+
+impl From<bytesrepr::Error> for Error {
+    fn from(error: bytesrepr::Error) -> Self {
+        Error::Bytesrepr(error)
+    }
+}
+
+impl From<CLValueError> for Error {
+    fn from(error: CLValueError) -> Self {
+        Error::CLValue(error)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::Bytesrepr(error) => write!(f, "deserialization failed: {}", error),
+            Error::CLValue(error) => write!(f, "unexpected stored value: {}", error),
+            Error::MissingPrefix(name) => write!(f, "missing event prefix in {}", name),
+            Error::UnknownEvent(name) => write!(f, "unknown event {}", name),
+            Error::TrailingBytes(length) => write!(f, "{} trailing bytes after event", length),
+            Error::InvalidIndex => write!(f, "invalid event index"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,48 @@
+//! Decoding of the fee distributor events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+pub use fee_distributor_crate::event::FeeDistributorEvent;
+
+impl DecodeEvent for FeeDistributorEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "commitAdmin" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((FeeDistributorEvent::CommitAdmin { admin }, bytes))
+            }
+            "applyAdmin" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((FeeDistributorEvent::ApplyAdmin { admin }, bytes))
+            }
+            "toggleAllowCheckpointToken" => {
+                let (toggle_flag, bytes) = bool::from_bytes(bytes)?;
+                Ok((
+                    FeeDistributorEvent::ToggleAllowCheckpointToken { toggle_flag },
+                    bytes,
+                ))
+            }
+            "checkpointToken" => {
+                let (time, bytes) = U256::from_bytes(bytes)?;
+                let (tokens, bytes) = U256::from_bytes(bytes)?;
+                Ok((FeeDistributorEvent::CheckpointToken { time, tokens }, bytes))
+            }
+            "claimed" => {
+                let (recipient, bytes) = Key::from_bytes(bytes)?;
+                let (amount, bytes) = U256::from_bytes(bytes)?;
+                let (claim_epoch, bytes) = U256::from_bytes(bytes)?;
+                let (max_epoch, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    FeeDistributorEvent::Claimed {
+                        recipient,
+                        amount,
+                        claim_epoch,
+                        max_epoch,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Decoding of the gauge controller events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+use common::int128::I128;
+pub use gauge_controller_crate::GAUGECONLTROLLEREvent;
+
+impl DecodeEvent for GAUGECONLTROLLEREvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "minted" => {
+                let (recipient, bytes) = Key::from_bytes(bytes)?;
+                let (gauge, bytes) = Key::from_bytes(bytes)?;
+                let (minted, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::Minted {
+                        recipient,
+                        gauge,
+                        minted,
+                    },
+                    bytes,
+                ))
+            }
+            "CommitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((GAUGECONLTROLLEREvent::CommitOwnership { admin }, bytes))
+            }
+            "ApplyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((GAUGECONLTROLLEREvent::ApplyOwnership { admin }, bytes))
+            }
+            "NewTypeWeight" => {
+                let (type_id, bytes) = I128::from_bytes(bytes)?;
+                let (time, bytes) = U256::from_bytes(bytes)?;
+                let (weight, bytes) = U256::from_bytes(bytes)?;
+                let (total_weight, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::NewTypeWeight {
+                        type_id: type_id.into(),
+                        time,
+                        weight,
+                        total_weight,
+                    },
+                    bytes,
+                ))
+            }
+            "NewGaugeWeight" => {
+                let (gauge_address, bytes) = Key::from_bytes(bytes)?;
+                let (time, bytes) = U256::from_bytes(bytes)?;
+                let (weight, bytes) = U256::from_bytes(bytes)?;
+                let (total_weight, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::NewGaugeWeight {
+                        gauge_address,
+                        time,
+                        weight,
+                        total_weight,
+                    },
+                    bytes,
+                ))
+            }
+            "AddType" => {
+                let (name, bytes) = String::from_bytes(bytes)?;
+                let (type_id, bytes) = I128::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::AddType {
+                        name,
+                        type_id: type_id.into(),
+                    },
+                    bytes,
+                ))
+            }
+            "VoteForGauge" => {
+                let (time, bytes) = U256::from_bytes(bytes)?;
+                let (user, bytes) = Key::from_bytes(bytes)?;
+                let (gauge_addr, bytes) = Key::from_bytes(bytes)?;
+                let (weight, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::VoteForGauge {
+                        time,
+                        user,
+                        gauge_addr,
+                        weight,
+                    },
+                    bytes,
+                ))
+            }
+            "NewGauge" => {
+                let (addr, bytes) = Key::from_bytes(bytes)?;
+                let (gauge_type, bytes) = I128::from_bytes(bytes)?;
+                let (weight, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::NewGauge {
+                        addr,
+                        gauge_type: gauge_type.into(),
+                        weight,
+                    },
+                    bytes,
+                ))
+            }
+            "SetVoteDelegate" => {
+                let (user, bytes) = Key::from_bytes(bytes)?;
+                let (delegate, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::SetVoteDelegate { user, delegate },
+                    bytes,
+                ))
+            }
+            "KillGauge" => {
+                let (gauge_address, bytes) = Key::from_bytes(bytes)?;
+                let (time, bytes) = U256::from_bytes(bytes)?;
+                let (total_weight, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::KillGauge {
+                        gauge_address,
+                        time,
+                        total_weight,
+                    },
+                    bytes,
+                ))
+            }
+            "SetMaxRelativeWeight" => {
+                let (gauge_address, bytes) = Key::from_bytes(bytes)?;
+                let (max_relative_weight, bytes) = U256::from_bytes(bytes)?;
+                let (receiver, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::SetMaxRelativeWeight {
+                        gauge_address,
+                        max_relative_weight,
+                        receiver,
+                    },
+                    bytes,
+                ))
+            }
+            "RenameType" => {
+                let (name, bytes) = String::from_bytes(bytes)?;
+                let (type_id, bytes) = I128::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::RenameType {
+                        name,
+                        type_id: type_id.into(),
+                    },
+                    bytes,
+                ))
+            }
+            "DeprecateType" => {
+                let (type_id, bytes) = I128::from_bytes(bytes)?;
+                Ok((
+                    GAUGECONLTROLLEREvent::DeprecateType {
+                        type_id: type_id.into(),
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
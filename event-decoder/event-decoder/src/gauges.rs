@@ -0,0 +1,372 @@
+//! Decoding of the liquidity gauge events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+pub use liquidity_gauge_factory_crate::event::LiquidityGaugeFactoryEvent;
+pub use liquidity_gauge_reward_crate::event::LiquidityGaugeRewardEvent;
+pub use liquidity_gauge_reward_wrapper_crate::event::LiquidityGaugeRewardWrapperEvent;
+pub use liquidity_gauge_v3_crate::event::LiquidityGaugeV3Event;
+pub use liquidity_gauge_v4_crate::event::LiquidityGaugeV4Event;
+pub use liquidity_gauge_wrapper_crate::event::LiquidityGaugeWrapperEvent;
+pub use reward_only_gauge_crate::REWARDONLYGAUGEEvent;
+
+impl DecodeEvent for LiquidityGaugeV3Event {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "Deposit" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV3Event::Deposit { provider, value }, bytes))
+            }
+            "Withdraw" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV3Event::Withdraw { provider, value }, bytes))
+            }
+            "UpdateLiquidityLimit" => {
+                let (user, bytes) = Key::from_bytes(bytes)?;
+                let (original_balance, bytes) = U256::from_bytes(bytes)?;
+                let (original_supply, bytes) = U256::from_bytes(bytes)?;
+                let (working_balance, bytes) = U256::from_bytes(bytes)?;
+                let (working_supply, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeV3Event::UpdateLiquidityLimit {
+                        user,
+                        original_balance,
+                        original_supply,
+                        working_balance,
+                        working_supply,
+                    },
+                    bytes,
+                ))
+            }
+            "CommitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV3Event::CommitOwnership { admin }, bytes))
+            }
+            "ApplyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV3Event::ApplyOwnership { admin }, bytes))
+            }
+            "Transfer" => {
+                let (from, bytes) = Key::from_bytes(bytes)?;
+                let (to, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV3Event::Transfer { from, to, value }, bytes))
+            }
+            "Approval" => {
+                let (owner, bytes) = Key::from_bytes(bytes)?;
+                let (spender, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeV3Event::Approval {
+                        owner,
+                        spender,
+                        value,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
+
+impl DecodeEvent for LiquidityGaugeV4Event {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "Deposit" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV4Event::Deposit { provider, value }, bytes))
+            }
+            "Withdraw" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV4Event::Withdraw { provider, value }, bytes))
+            }
+            "UpdateLiquidityLimit" => {
+                let (user, bytes) = Key::from_bytes(bytes)?;
+                let (original_balance, bytes) = U256::from_bytes(bytes)?;
+                let (original_supply, bytes) = U256::from_bytes(bytes)?;
+                let (working_balance, bytes) = U256::from_bytes(bytes)?;
+                let (working_supply, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeV4Event::UpdateLiquidityLimit {
+                        user,
+                        original_balance,
+                        original_supply,
+                        working_balance,
+                        working_supply,
+                    },
+                    bytes,
+                ))
+            }
+            "CommitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV4Event::CommitOwnership { admin }, bytes))
+            }
+            "ApplyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV4Event::ApplyOwnership { admin }, bytes))
+            }
+            "Transfer" => {
+                let (from, bytes) = Key::from_bytes(bytes)?;
+                let (to, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((LiquidityGaugeV4Event::Transfer { from, to, value }, bytes))
+            }
+            "Approval" => {
+                let (owner, bytes) = Key::from_bytes(bytes)?;
+                let (spender, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeV4Event::Approval {
+                        owner,
+                        spender,
+                        value,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
+
+impl DecodeEvent for LiquidityGaugeRewardEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "deposit" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardEvent::Deposit { provider, value },
+                    bytes,
+                ))
+            }
+            "withdraw" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardEvent::Withdraw { provider, value },
+                    bytes,
+                ))
+            }
+            "updateLiquidityLimit" => {
+                let (user, bytes) = Key::from_bytes(bytes)?;
+                let (original_balance, bytes) = U256::from_bytes(bytes)?;
+                let (original_supply, bytes) = U256::from_bytes(bytes)?;
+                let (working_balance, bytes) = U256::from_bytes(bytes)?;
+                let (working_supply, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardEvent::UpdateLiquidityLimit {
+                        user,
+                        original_balance,
+                        original_supply,
+                        working_balance,
+                        working_supply,
+                    },
+                    bytes,
+                ))
+            }
+            "commitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeRewardEvent::CommitOwnership { admin }, bytes))
+            }
+            "applyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeRewardEvent::ApplyOwnership { admin }, bytes))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
+
+impl DecodeEvent for LiquidityGaugeWrapperEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "deposit" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeWrapperEvent::Deposit { provider, value },
+                    bytes,
+                ))
+            }
+            "withdraw" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeWrapperEvent::Withdraw { provider, value },
+                    bytes,
+                ))
+            }
+            "commitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeWrapperEvent::CommitOwnership { admin }, bytes))
+            }
+            "applyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeWrapperEvent::ApplyOwnership { admin }, bytes))
+            }
+            "approval" => {
+                let (owner, bytes) = Key::from_bytes(bytes)?;
+                let (spender, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeWrapperEvent::Approval {
+                        owner,
+                        spender,
+                        value,
+                    },
+                    bytes,
+                ))
+            }
+            "transfer" => {
+                let (from, bytes) = Key::from_bytes(bytes)?;
+                let (to, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeWrapperEvent::Transfer { from, to, value },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
+
+impl DecodeEvent for LiquidityGaugeRewardWrapperEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "deposit" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardWrapperEvent::Deposit { provider, value },
+                    bytes,
+                ))
+            }
+            "withdraw" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardWrapperEvent::Withdraw { provider, value },
+                    bytes,
+                ))
+            }
+            "commitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardWrapperEvent::CommitOwnership { admin },
+                    bytes,
+                ))
+            }
+            "applyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardWrapperEvent::ApplyOwnership { admin },
+                    bytes,
+                ))
+            }
+            "approval" => {
+                let (owner, bytes) = Key::from_bytes(bytes)?;
+                let (spender, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardWrapperEvent::Approval {
+                        owner,
+                        spender,
+                        value,
+                    },
+                    bytes,
+                ))
+            }
+            "transfer" => {
+                let (from, bytes) = Key::from_bytes(bytes)?;
+                let (to, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeRewardWrapperEvent::Transfer { from, to, value },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
+
+impl DecodeEvent for REWARDONLYGAUGEEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "withdraw" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((REWARDONLYGAUGEEvent::Withdraw { provider, value }, bytes))
+            }
+            "deposit" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((REWARDONLYGAUGEEvent::Deposit { provider, value }, bytes))
+            }
+            "CommitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((REWARDONLYGAUGEEvent::CommitOwnership { admin }, bytes))
+            }
+            "ApplyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((REWARDONLYGAUGEEvent::ApplyOwnership { admin }, bytes))
+            }
+            "approve" => {
+                let (owner, bytes) = Key::from_bytes(bytes)?;
+                let (spender, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    REWARDONLYGAUGEEvent::Approval {
+                        owner,
+                        spender,
+                        value,
+                    },
+                    bytes,
+                ))
+            }
+            "transfer" => {
+                let (from, bytes) = Key::from_bytes(bytes)?;
+                let (to, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                Ok((REWARDONLYGAUGEEvent::Transfer { from, to, value }, bytes))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
+
+impl DecodeEvent for LiquidityGaugeFactoryEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "DeployedGauge" => {
+                let (lp_token, bytes) = Key::from_bytes(bytes)?;
+                let (gauge, bytes) = Key::from_bytes(bytes)?;
+                let (deployer, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    LiquidityGaugeFactoryEvent::DeployedGauge {
+                        lp_token,
+                        gauge,
+                        deployer,
+                    },
+                    bytes,
+                ))
+            }
+            "CommitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeFactoryEvent::CommitOwnership { admin }, bytes))
+            }
+            "ApplyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((LiquidityGaugeFactoryEvent::ApplyOwnership { admin }, bytes))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
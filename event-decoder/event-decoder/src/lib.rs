@@ -0,0 +1,107 @@
+//! Off-chain decoding of the events the DAO contracts append to their `__events` dictionary.
+//!
+//! Events are read either from the stored dictionary values or from the transforms of an
+//! execution result, and decoded into the event enums of the contract crates.
+
+pub mod agent;
+pub mod bribe;
+mod error;
+pub mod fee_distributor;
+pub mod gauge_controller;
+pub mod gauges;
+pub mod minter;
+pub mod smart_wallet_checker;
+pub mod timelock;
+pub mod ve_boost;
+pub mod voting;
+pub mod voting_escrow;
+
+pub use error::Error;
+
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes},
+    CLType, CLValue, ExecutionResult, Transform, TransformEntry, URef,
+};
+use common::events::EVENT_PREFIX;
+
+/// Event enum of a contract that can be decoded from its stored bytes.
+pub trait DecodeEvent: Sized {
+    /// Decodes the fields of the event `name`, returning the bytes left after its last field.
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error>;
+}
+
+/// Decodes an event from its stored bytes, the prefixed event name followed by its fields.
+pub fn decode_event<E: DecodeEvent>(bytes: &[u8]) -> Result<E, Error> {
+    let (name, fields) = String::from_bytes(bytes)?;
+    let name = name
+        .strip_prefix(EVENT_PREFIX)
+        .ok_or_else(|| Error::MissingPrefix(name.clone()))?;
+    let (event, remainder) = E::decode_fields(name, fields)?;
+    if !remainder.is_empty() {
+        return Err(Error::TrailingBytes(remainder.len()));
+    }
+    Ok(event)
+}
+
+/// Decodes an event from a value of the events dictionary.
+///
+/// Accepts both the stored event bytes and the dictionary value wrapping them, as returned by a
+/// global state query or written by a transform.
+pub fn decode_cl_value<E: DecodeEvent>(value: &CLValue) -> Result<E, Error> {
+    if *value.cl_type() == CLType::Any {
+        let (stored, _, _) = dictionary_value(value)?;
+        return decode_cl_value(&stored);
+    }
+    let bytes: Bytes = value.clone().into_t()?;
+    decode_event(&bytes)
+}
+
+/// Decodes the events written to the events dictionary `events` by the transforms of a deploy,
+/// returned with their index and in the order they were emitted.
+pub fn decode_transforms<E: DecodeEvent>(
+    transforms: &[TransformEntry],
+    events: URef,
+) -> Result<Vec<(u32, E)>, Error> {
+    let mut decoded: Vec<(u32, E)> = Vec::new();
+    for entry in transforms.iter() {
+        let value = match &entry.transform {
+            Transform::WriteCLValue(value) if *value.cl_type() == CLType::Any => value,
+            _ => continue,
+        };
+        let (stored, seed_uref_addr, item_key) = match dictionary_value(value) {
+            Ok(parts) => parts,
+            Err(_) => continue,
+        };
+        if seed_uref_addr[..] != events.addr()[..] {
+            continue;
+        }
+        let index: u32 = std::str::from_utf8(&item_key)
+            .ok()
+            .and_then(|key| key.parse().ok())
+            .ok_or(Error::InvalidIndex)?;
+        decoded.push((index, decode_cl_value(&stored)?));
+    }
+    decoded.sort_by_key(|(index, _)| *index);
+    Ok(decoded)
+}
+
+/// Decodes the events written to the events dictionary `events` by a successful deploy, a failed
+/// deploy emits no events.
+pub fn decode_execution_result<E: DecodeEvent>(
+    result: &ExecutionResult,
+    events: URef,
+) -> Result<Vec<(u32, E)>, Error> {
+    match result {
+        ExecutionResult::Success { effect, .. } => decode_transforms(&effect.transforms, events),
+        ExecutionResult::Failure { .. } => Ok(Vec::new()),
+    }
+}
+
+/// Splits a dictionary value into the stored value, the address of the dictionary seed uref and
+/// the item key.
+fn dictionary_value(value: &CLValue) -> Result<(CLValue, Bytes, Bytes), Error> {
+    let (stored, remainder) = CLValue::from_bytes(value.inner_bytes())?;
+    let (seed_uref_addr, remainder) = Bytes::from_bytes(remainder)?;
+    let (item_key, _) = Bytes::from_bytes(remainder)?;
+    Ok((stored, seed_uref_addr, item_key))
+}
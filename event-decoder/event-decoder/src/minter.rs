@@ -0,0 +1,26 @@
+//! Decoding of the minter events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+pub use minter_crate::MINTEREvent;
+
+impl DecodeEvent for MINTEREvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "minted" => {
+                let (recipient, bytes) = Key::from_bytes(bytes)?;
+                let (gauge, bytes) = Key::from_bytes(bytes)?;
+                let (minted, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    MINTEREvent::Minted {
+                        recipient,
+                        gauge,
+                        minted,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
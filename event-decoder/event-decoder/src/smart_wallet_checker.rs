@@ -0,0 +1,29 @@
+//! Decoding of the smart wallet checker events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key};
+pub use smart_wallet_checker_crate::event::SmartWalletCheckerEvent;
+
+impl DecodeEvent for SmartWalletCheckerEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "approveWallet" => {
+                let (wallet, bytes) = Key::from_bytes(bytes)?;
+                Ok((SmartWalletCheckerEvent::ApproveWallet { wallet }, bytes))
+            }
+            "revokeWallet" => {
+                let (wallet, bytes) = Key::from_bytes(bytes)?;
+                Ok((SmartWalletCheckerEvent::RevokeWallet { wallet }, bytes))
+            }
+            "commitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((SmartWalletCheckerEvent::CommitOwnership { admin }, bytes))
+            }
+            "applyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((SmartWalletCheckerEvent::ApplyOwnership { admin }, bytes))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Decoding of the timelock events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+pub use timelock_crate::event::TimelockEvent;
+
+impl DecodeEvent for TimelockEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "queueOperation" => {
+                let (id, bytes) = U256::from_bytes(bytes)?;
+                let (target, bytes) = Key::from_bytes(bytes)?;
+                let (entry_point, bytes) = String::from_bytes(bytes)?;
+                let (eta, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    TimelockEvent::QueueOperation {
+                        id,
+                        target,
+                        entry_point,
+                        eta,
+                    },
+                    bytes,
+                ))
+            }
+            "cancelOperation" => {
+                let (id, bytes) = U256::from_bytes(bytes)?;
+                Ok((TimelockEvent::CancelOperation { id }, bytes))
+            }
+            "executeOperation" => {
+                let (id, bytes) = U256::from_bytes(bytes)?;
+                Ok((TimelockEvent::ExecuteOperation { id }, bytes))
+            }
+            "newDelay" => {
+                let (delay, bytes) = U256::from_bytes(bytes)?;
+                Ok((TimelockEvent::NewDelay { delay }, bytes))
+            }
+            "newGuardian" => {
+                let (guardian, bytes) = Key::from_bytes(bytes)?;
+                Ok((TimelockEvent::NewGuardian { guardian }, bytes))
+            }
+            "commitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((TimelockEvent::CommitOwnership { admin }, bytes))
+            }
+            "applyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((TimelockEvent::ApplyOwnership { admin }, bytes))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Decoding of the veCRV boost events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+pub use ve_boost_crate::event::VeBoostEvent;
+
+impl DecodeEvent for VeBoostEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "boost" => {
+                let (delegator, bytes) = Key::from_bytes(bytes)?;
+                let (receiver, bytes) = Key::from_bytes(bytes)?;
+                let (bias, bytes) = U256::from_bytes(bytes)?;
+                let (slope, bytes) = U256::from_bytes(bytes)?;
+                let (start, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VeBoostEvent::Boost {
+                        delegator,
+                        receiver,
+                        bias,
+                        slope,
+                        start,
+                    },
+                    bytes,
+                ))
+            }
+            "rejectBoost" => {
+                let (delegator, bytes) = Key::from_bytes(bytes)?;
+                let (receiver, bytes) = Key::from_bytes(bytes)?;
+                let (bias, bytes) = U256::from_bytes(bytes)?;
+                let (slope, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VeBoostEvent::RejectBoost {
+                        delegator,
+                        receiver,
+                        bias,
+                        slope,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
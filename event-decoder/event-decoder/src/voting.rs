@@ -0,0 +1,63 @@
+//! Decoding of the voting events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+pub use voting_crate::event::VotingEvent;
+
+impl DecodeEvent for VotingEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "startVote" => {
+                let (vote_id, bytes) = U256::from_bytes(bytes)?;
+                let (creator, bytes) = Key::from_bytes(bytes)?;
+                let (metadata, bytes) = String::from_bytes(bytes)?;
+                Ok((
+                    VotingEvent::StartVote {
+                        vote_id,
+                        creator,
+                        metadata,
+                    },
+                    bytes,
+                ))
+            }
+            "castVote" => {
+                let (vote_id, bytes) = U256::from_bytes(bytes)?;
+                let (voter, bytes) = Key::from_bytes(bytes)?;
+                let (supports, bytes) = bool::from_bytes(bytes)?;
+                let (stake, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEvent::CastVote {
+                        vote_id,
+                        voter,
+                        supports,
+                        stake,
+                    },
+                    bytes,
+                ))
+            }
+            "executeVote" => {
+                let (vote_id, bytes) = U256::from_bytes(bytes)?;
+                Ok((VotingEvent::ExecuteVote { vote_id }, bytes))
+            }
+            "changeSupportRequired" => {
+                let (support_required_pct, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEvent::ChangeSupportRequired {
+                        support_required_pct,
+                    },
+                    bytes,
+                ))
+            }
+            "changeMinQuorum" => {
+                let (min_accept_quorum_pct, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEvent::ChangeMinQuorum {
+                        min_accept_quorum_pct,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Decoding of the voting escrow events.
+
+use crate::{DecodeEvent, Error};
+use casper_types::{bytesrepr::FromBytes, Key, U256};
+use common::int128::I128;
+pub use voting_escrow_crate::event::VotingEscrowEvent;
+
+impl DecodeEvent for VotingEscrowEvent {
+    fn decode_fields<'a>(name: &str, bytes: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        match name {
+            "commitOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((VotingEscrowEvent::CommitOwnership { admin }, bytes))
+            }
+            "applyOwnership" => {
+                let (admin, bytes) = Key::from_bytes(bytes)?;
+                Ok((VotingEscrowEvent::ApplyOwnership { admin }, bytes))
+            }
+            "deposit" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                let (locktime, bytes) = U256::from_bytes(bytes)?;
+                let (_type, bytes) = I128::from_bytes(bytes)?;
+                let (ts, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::Deposit {
+                        provider,
+                        value,
+                        locktime,
+                        _type: _type.into(),
+                        ts,
+                    },
+                    bytes,
+                ))
+            }
+            "withdraw" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                let (ts, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::Withdraw {
+                        provider,
+                        value,
+                        ts,
+                    },
+                    bytes,
+                ))
+            }
+            "withdrawEarly" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (value, bytes) = U256::from_bytes(bytes)?;
+                let (penalty, bytes) = U256::from_bytes(bytes)?;
+                let (ts, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::WithdrawEarly {
+                        provider,
+                        value,
+                        penalty,
+                        ts,
+                    },
+                    bytes,
+                ))
+            }
+            "supply" => {
+                let (prev_supply, bytes) = U256::from_bytes(bytes)?;
+                let (supply, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::Supply {
+                        prev_supply,
+                        supply,
+                    },
+                    bytes,
+                ))
+            }
+            "positionTransfer" => {
+                let (from, bytes) = Key::from_bytes(bytes)?;
+                let (to, bytes) = Key::from_bytes(bytes)?;
+                let (token_id, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::PositionTransfer { from, to, token_id },
+                    bytes,
+                ))
+            }
+            "merge" => {
+                let (owner, bytes) = Key::from_bytes(bytes)?;
+                let (from_id, bytes) = U256::from_bytes(bytes)?;
+                let (to_id, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::Merge {
+                        owner,
+                        from_id,
+                        to_id,
+                    },
+                    bytes,
+                ))
+            }
+            "split" => {
+                let (owner, bytes) = Key::from_bytes(bytes)?;
+                let (token_id, bytes) = U256::from_bytes(bytes)?;
+                let (token_ids, bytes) = Vec::<U256>::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::Split {
+                        owner,
+                        token_id,
+                        token_ids,
+                    },
+                    bytes,
+                ))
+            }
+            "autoMaxLock" => {
+                let (provider, bytes) = Key::from_bytes(bytes)?;
+                let (enabled, bytes) = bool::from_bytes(bytes)?;
+                let (ts, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::AutoMaxLock {
+                        provider,
+                        enabled,
+                        ts,
+                    },
+                    bytes,
+                ))
+            }
+            "delegateChanged" => {
+                let (delegator, bytes) = Key::from_bytes(bytes)?;
+                let (from_delegate, bytes) = Key::from_bytes(bytes)?;
+                let (to_delegate, bytes) = Key::from_bytes(bytes)?;
+                Ok((
+                    VotingEscrowEvent::DelegateChanged {
+                        delegator,
+                        from_delegate,
+                        to_delegate,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(Error::UnknownEvent(name.to_string())),
+        }
+    }
+}
@@ -1,11 +1,9 @@
 use alloc::{string::ToString, vec::Vec};
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::{
-    bytesrepr::ToBytes, CLTyped, ContractHash, ContractPackageHash, Key, U128, U256,
-};
+use casper_types::{bytesrepr::ToBytes, CLTyped, ContractHash, ContractPackageHash, Key, U256};
 use casper_types_derive::{CLTyped, FromBytes, ToBytes};
 use casperlabs_contract_utils::{get_key, set_key, Dict};
-use common::{errors::*, keys::*, utils::*};
+use common::{errors::*, int128::I128, keys::*, utils::*};
 
 pub const WEEK: U256 = U256([604800000, 0, 0, 0]);
 pub const TOKEN_CHECKPOINT_DEADLINE: U256 = U256([86400000, 0, 0, 0]);
@@ -15,8 +13,8 @@ pub const TOKEN_CHECKPOINT_DEADLINE: U256 = U256([86400000, 0, 0, 0]);
 /// What we can do is to extrapolate ***At functions
 #[derive(Clone, Copy, CLTyped, ToBytes, FromBytes, Default)]
 pub struct Point {
-    pub bias: (bool, U128),
-    pub slope: (bool, U128), // - dweight / dt
+    pub bias: I128,
+    pub slope: I128, // - dweight / dt
     pub ts: U256,
     pub blk: U256,
 }
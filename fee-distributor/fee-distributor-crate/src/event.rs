@@ -1,5 +1,12 @@
-use alloc::string::{String, ToString};
-use casper_types::{Key, U256};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
 
 pub enum FeeDistributorEvent {
     CommitAdmin {
@@ -42,3 +49,58 @@ impl FeeDistributorEvent {
         .to_string()
     }
 }
+
+impl CasperEvent for FeeDistributorEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            FeeDistributorEvent::CommitAdmin { admin } => admin.to_bytes(),
+            FeeDistributorEvent::ApplyAdmin { admin } => admin.to_bytes(),
+            FeeDistributorEvent::ToggleAllowCheckpointToken { toggle_flag } => {
+                toggle_flag.to_bytes()
+            }
+            FeeDistributorEvent::CheckpointToken { time, tokens } => {
+                Ok([time.to_bytes()?, tokens.to_bytes()?].concat())
+            }
+            FeeDistributorEvent::Claimed {
+                recipient,
+                amount,
+                claim_epoch,
+                max_epoch,
+            } => Ok([
+                recipient.to_bytes()?,
+                amount.to_bytes()?,
+                claim_epoch.to_bytes()?,
+                max_epoch.to_bytes()?,
+            ]
+            .concat()),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with("commitAdmin", Schema::new().with_elem::<Key>("admin"))
+            .with("applyAdmin", Schema::new().with_elem::<Key>("admin"))
+            .with(
+                "toggleAllowCheckpointToken",
+                Schema::new().with_elem::<bool>("toggle_flag"),
+            )
+            .with(
+                "checkpointToken",
+                Schema::new()
+                    .with_elem::<U256>("time")
+                    .with_elem::<U256>("tokens"),
+            )
+            .with(
+                "claimed",
+                Schema::new()
+                    .with_elem::<Key>("recipient")
+                    .with_elem::<U256>("amount")
+                    .with_elem::<U256>("claim_epoch")
+                    .with_elem::<U256>("max_epoch"),
+            )
+    }
+}
@@ -1,18 +1,15 @@
 use crate::{data::*, event::FeeDistributorEvent};
 use alloc::vec::Vec;
-use alloc::{collections::BTreeMap, string::ToString};
 use casper_contract::{
-    contract_api::{
-        runtime::{self, get_blocktime},
-        storage,
-    },
+    contract_api::runtime::{self, get_blocktime},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
     runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{errors::*, events, int128::I128, utils::*};
+use core::convert::TryFrom;
 use crv20::{self, Address};
 
 #[allow(clippy::too_many_arguments)]
@@ -37,6 +34,7 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
         UserEpochOf::init();
         TokensPerWeek::init();
         VeSupply::init();
+        events::init::<FeeDistributorEvent>();
         let t: U256 = start_time
             .checked_div(WEEK)
             .unwrap_or_revert_with(Error::FeeDistributorDivisionError1)
@@ -247,51 +245,30 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
         min
     }
 
-    /// @notice Get the veCRV balance for `_user` at `_timestamp`
-    /// @param _user Address to query balance for
-    /// @param _timestamp Epoch time
-    /// @return uint256 veCRV balance
+    /// @notice Get the voting escrow balance of `addr` at `timestamp`
+    /// @dev Includes the lock positions `addr` owned, whose slope changes the voting escrow applies
     #[inline(always)]
-    fn ve_for_at(&self, user: Key, timestamp: U256) -> U256 {
-        let ve: Key = get_voting_escrow();
-        let max_user_epoch: U256 = runtime::call_versioned_contract(
-            ve.into_hash().unwrap_or_revert().into(),
-            None,
-            "user_point_epoch",
-            runtime_args! {
-                "user" => user
-            },
-        );
-        let epoch: U256 = self._find_timestamp_user_epoch(ve, user, timestamp, max_user_epoch);
-        let pt: Point = runtime::call_versioned_contract(
+    fn _ve_balance_of(&self, ve: Key, addr: Key, timestamp: U256) -> U256 {
+        runtime::call_versioned_contract(
             ve.into_hash().unwrap_or_revert().into(),
             None,
-            "user_point_history",
+            "balance_of",
             runtime_args! {
-                "user" => user,
-                "user_epoch" => epoch
+                "addr" => addr,
+                "t" => Some(timestamp)
             },
-        );
-        U256::max(
-            (tuple_to_i128(pt.bias)
-                .checked_sub(
-                    tuple_to_i128(pt.slope)
-                        .checked_mul(
-                            timestamp
-                                .checked_sub(pt.ts)
-                                .unwrap_or_revert_with(Error::FeeDistributorSubtractionError7)
-                                .to_string()
-                                .parse()
-                                .unwrap(),
-                        )
-                        .unwrap_or_revert_with(Error::FeeDistributorMultiplicationError5),
-                )
-                .unwrap_or_revert_with(Error::FeeDistributorSubtractionError6))
-            .into(),
-            0.into(),
         )
     }
 
+    /// @notice Get the veCRV balance for `_user` at `_timestamp`
+    /// @param _user Address to query balance for
+    /// @param _timestamp Epoch time
+    /// @return uint256 veCRV balance
+    #[inline(always)]
+    fn ve_for_at(&self, user: Key, timestamp: U256) -> U256 {
+        self._ve_balance_of(get_voting_escrow(), user, timestamp)
+    }
+
     #[inline(always)]
     fn _checkpoint_total_supply(&self) {
         let ve: Key = get_voting_escrow();
@@ -320,32 +297,23 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
                         "epoch" => epoch
                     },
                 );
-                let mut dt: i128 = 0.into();
+                let mut dt: I128 = I128::ZERO;
                 if t > pt.ts {
                     // If the point is at 0 epoch, it can actually be earlier than the first deposit
                     // Then make dt 0
-                    dt = t
-                        .checked_sub(pt.ts)
-                        .unwrap_or_revert_with(Error::FeeDistributorSubtractionError8)
-                        .to_string()
-                        .parse()
-                        .unwrap();
+                    dt = I128::try_from(
+                        t.checked_sub(pt.ts)
+                            .unwrap_or_revert_with(Error::FeeDistributorSubtractionError8),
+                    )
+                    .unwrap_or_revert();
                 }
+                let bias: I128 = pt
+                    .bias
+                    .checked_sub(pt.slope.checked_mul(dt).unwrap_or_revert())
+                    .unwrap_or_revert();
                 VeSupply::instance().set(
                     &t,
-                    i128::max(
-                        tuple_to_i128(pt.bias)
-                            .checked_sub(
-                                tuple_to_i128(pt.slope)
-                                    .checked_mul(dt)
-                                    .unwrap_or_revert_with(
-                                        Error::FeeDistributorMultiplicationError12,
-                                    ),
-                            )
-                            .unwrap_or_revert_with(Error::FeeDistributorSubtractionError9),
-                        0.into(),
-                    )
-                    .into(),
+                    U256::try_from(I128::max(bias, I128::ZERO)).unwrap_or_revert(),
                 );
             }
             t = t
@@ -364,11 +332,8 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
         self._checkpoint_total_supply();
     }
 
-    #[allow(unused_assignments)]
     #[inline(always)]
     fn _claim(&self, addr: Key, ve: Key, last_token_time: U256) -> U256 {
-        // Minimal user_epoch is 0 (if user had no point)
-        let mut user_epoch: U256 = 0.into();
         let mut to_distribute: U256 = 0.into();
         let max_user_epoch: U256 = runtime::call_versioned_contract(
             ve.into_hash().unwrap_or_revert().into(),
@@ -386,23 +351,21 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
         let mut week_cursor: U256 = TimeCursorOf::instance().get(&addr);
         if week_cursor == 0.into() {
             // Need to do the initial binary search
-            user_epoch = self._find_timestamp_user_epoch(ve, addr, start_time, max_user_epoch);
-        } else {
-            user_epoch = UserEpochOf::instance().get(&addr);
-        }
-        if user_epoch == 0.into() {
-            user_epoch = 1.into();
-        }
-        let mut user_point: Point = runtime::call_versioned_contract(
-            ve.into_hash().unwrap_or_revert().into(),
-            None,
-            "user_point_history",
-            runtime_args! {
-                "user" => addr,
-                "user_epoch" => user_epoch
-            },
-        );
-        if week_cursor == 0.into() {
+            // Minimal user_epoch is 0 (if user had no point)
+            let mut user_epoch: U256 =
+                self._find_timestamp_user_epoch(ve, addr, start_time, max_user_epoch);
+            if user_epoch == 0.into() {
+                user_epoch = 1.into();
+            }
+            let user_point: Point = runtime::call_versioned_contract(
+                ve.into_hash().unwrap_or_revert().into(),
+                None,
+                "user_point_history",
+                runtime_args! {
+                    "user" => addr,
+                    "user_epoch" => user_epoch
+                },
+            );
             week_cursor = (user_point
                 .ts
                 .checked_add(WEEK)
@@ -420,77 +383,47 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
         if week_cursor < start_time {
             week_cursor = start_time;
         }
-        let mut old_user_point: Point = Point::default();
+        // Voting power can't come back without a new checkpoint
+        let last_user_point: Point = runtime::call_versioned_contract(
+            ve.into_hash().unwrap_or_revert().into(),
+            None,
+            "user_point_history",
+            runtime_args! {
+                "user" => addr,
+                "user_epoch" => max_user_epoch
+            },
+        );
         // Iterate over weeks
+        // The balance is read from the voting escrow rather than extrapolated from the
+        // checkpoints, lock positions ending in between change the slope
         for _ in 0..50 {
             if week_cursor >= last_token_time {
                 break;
             }
-            if week_cursor >= user_point.ts && user_epoch <= max_user_epoch {
-                user_epoch = user_epoch
-                    .checked_add(1.into())
-                    .unwrap_or_revert_with(Error::FeeDistributorAdditionError12);
-                old_user_point = user_point;
-                if user_epoch > max_user_epoch {
-                    user_point = Point::default();
-                } else {
-                    user_point = runtime::call_versioned_contract(
-                        ve.into_hash().unwrap_or_revert().into(),
-                        None,
-                        "user_point_history",
-                        runtime_args! {
-                            "user" => addr,
-                            "user_epoch" => user_epoch
-                        },
-                    );
-                }
-            } else {
-                // Calc
-                // + i * 2 is for rounding errors
-                let dt: i128 = week_cursor
-                    .checked_sub(old_user_point.ts)
-                    .unwrap_or_revert_with(Error::FeeDistributorSubtractionError12)
-                    .to_string()
-                    .parse()
-                    .unwrap();
-                let balance_of: U256 = U256::max(
-                    tuple_to_i128(old_user_point.bias)
-                        .checked_sub(
-                            dt.checked_mul(tuple_to_i128(old_user_point.slope))
-                                .unwrap_or_revert_with(Error::FeeDistributorMultiplicationError8),
-                        )
-                        .unwrap_or_revert_with(Error::FeeDistributorSubtractionError13)
-                        .into(),
-                    0.into(),
-                );
-                if balance_of == 0.into() && user_epoch > max_user_epoch {
-                    break;
-                }
-                if balance_of > 0.into() {
-                    to_distribute = to_distribute
-                        .checked_add(
-                            balance_of
-                                .checked_mul(
-                                    TokensPerWeek::instance()
-                                        .get(&week_cursor)
-                                        .checked_div(VeSupply::instance().get(&week_cursor))
-                                        .unwrap_or_revert_with(Error::FeeDistributorDivisionError9),
-                                )
-                                .unwrap_or_revert_with(Error::FeeDistributorMultiplicationError9),
-                        )
-                        .unwrap_or_revert_with(Error::FeeDistributorAdditionError13);
-                }
-                week_cursor = week_cursor
-                    .checked_add(WEEK)
-                    .unwrap_or_revert_with(Error::FeeDistributorAdditionError14);
+            let balance_of: U256 = self._ve_balance_of(ve, addr, week_cursor);
+            if balance_of == 0.into() && week_cursor >= last_user_point.ts {
+                break;
             }
+            if balance_of > 0.into() {
+                to_distribute = to_distribute
+                    .checked_add(
+                        balance_of
+                            .checked_mul(
+                                TokensPerWeek::instance()
+                                    .get(&week_cursor)
+                                    .checked_div(VeSupply::instance().get(&week_cursor))
+                                    .unwrap_or_revert_with(Error::FeeDistributorDivisionError9),
+                            )
+                            .unwrap_or_revert_with(Error::FeeDistributorMultiplicationError9),
+                    )
+                    .unwrap_or_revert_with(Error::FeeDistributorAdditionError13);
+            }
+            week_cursor = week_cursor
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::FeeDistributorAdditionError14);
         }
-        user_epoch = U256::min(
-            max_user_epoch,
-            user_epoch
-                .checked_sub(1.into())
-                .unwrap_or_revert_with(Error::FeeDistributorSubtractionError14),
-        );
+        let user_epoch: U256 =
+            self._find_timestamp_user_epoch(ve, addr, week_cursor, max_user_epoch);
         UserEpochOf::instance().set(&addr, user_epoch);
         TimeCursorOf::instance().set(&addr, week_cursor);
         FEEDISTRIBUTOR::emit(
@@ -506,8 +439,8 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
     }
 
     /// @notice Claim fees for `_addr`
-    /// @dev Each call to claim look at a maximum of 50 user veCRV points.
-    ///     For accounts with many veCRV related actions, this function
+    /// @dev Each call to claim look at a maximum of 50 weeks.
+    ///     For accounts that locked long before claiming, this function
     ///     may need to be called more than once to claim all available
     ///     fees. In the `Claimed` event that fires, if `claim_epoch` is
     ///     less than `max_epoch`, the account may claim again.
@@ -782,51 +715,6 @@ pub trait FEEDISTRIBUTOR<Storage: ContractStorage>: ContractContext<Storage> {
     }
 
     fn emit(&self, fee_distributor_event: &FeeDistributorEvent) {
-        match fee_distributor_event {
-            FeeDistributorEvent::CommitAdmin { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", fee_distributor_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            FeeDistributorEvent::ApplyAdmin { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", fee_distributor_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            FeeDistributorEvent::ToggleAllowCheckpointToken { toggle_flag } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", fee_distributor_event.type_name());
-                event.insert("toggle_flag", toggle_flag.to_string());
-                storage::new_uref(event);
-            }
-            FeeDistributorEvent::CheckpointToken { time, tokens } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", fee_distributor_event.type_name());
-                event.insert("time", time.to_string());
-                event.insert("tokens", tokens.to_string());
-                storage::new_uref(event);
-            }
-            FeeDistributorEvent::Claimed {
-                recipient,
-                amount,
-                claim_epoch,
-                max_epoch,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", fee_distributor_event.type_name());
-                event.insert("recipient", recipient.to_string());
-                event.insert("amount", amount.to_string());
-                event.insert("claim_epoch", claim_epoch.to_string());
-                event.insert("max_epoch", max_epoch.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(fee_distributor_event);
     }
 }
@@ -9,6 +9,7 @@ use casper_types_derive::{CLTyped, FromBytes, ToBytes};
 use casperlabs_contract_utils::*;
 use common::{
     errors::*,
+    int128::I128,
     keys::*,
     utils::{key_to_str, *},
 };
@@ -16,6 +17,10 @@ use common::{
 pub const WEEK: U256 = U256([604800000, 0, 0, 0]); // all future times are rounded by week
 pub const WEIGHT_VOTE_DELAY: U256 = U256([86400000, 0, 0, 0]);
 pub const MULTIPLIER: U256 = U256([1000000000, 0, 0, 0]);
+pub const CHECKPOINT_WEEKS: u64 = 500; // weeks filled by a single checkpoint
+pub const MAX_ACTIVE_TYPES: usize = 100; // types that are not deprecated
+pub const MAXTIME: U256 = U256([126144000000, 0, 0, 0]); // 4 years, the longest voting escrow lock
+pub const RATE_REDUCTION_TIME: U256 = U256([31536000000, 0, 0, 0]); // 1 year, the token inflation epoch
 
 #[derive(Clone, Copy, CLTyped, ToBytes, FromBytes, Default)]
 pub struct Point {
@@ -30,6 +35,24 @@ pub struct VotedSlope {
     pub end: U256,
 }
 
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+pub struct GaugeInfo {
+    pub gauge: Key,
+    pub gauge_type: I128,
+    pub weight: U256,      // weight of the current week
+    pub next_weight: U256, // weight of the next week
+    pub relative_weight: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+pub struct TypeInfo {
+    pub type_id: I128,
+    pub name: String,
+    pub weight: U256,
+    pub weights_sum: U256,
+    pub deprecated: bool,
+}
+
 pub struct GaugeTypeNames {
     dict: Dict,
 }
@@ -72,11 +95,12 @@ impl GaugeTypes_ {
     }
     #[inline(always)]
     pub fn get(&self, owner: &Key) -> i128 {
-        tuple_to_i128(self.dict.get(&key_to_str(owner)).unwrap_or_default())
+        let ret: I128 = self.dict.get(&key_to_str(owner)).unwrap_or_default();
+        ret.into()
     }
     #[inline(always)]
     pub fn set(&self, owner: &Key, value: i128) {
-        self.dict.set(&key_to_str(owner), i128_to_tuple(value));
+        self.dict.set(&key_to_str(owner), I128::from(value));
     }
 }
 
@@ -210,6 +234,209 @@ impl VoteUserPower {
     }
 }
 
+pub struct VoteDelegates {
+    dict: Dict,
+}
+
+impl VoteDelegates {
+    pub fn instance() -> VoteDelegates {
+        VoteDelegates {
+            dict: Dict::instance(VOTE_DELEGATES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(VOTE_DELEGATES_DICT)
+    }
+
+    pub fn get(&self, owner: &Key) -> Key {
+        self.dict
+            .get(&key_to_str(owner))
+            .unwrap_or_else(zero_address)
+    }
+
+    pub fn set(&self, owner: &Key, delegate: Key) {
+        self.dict.set(&key_to_str(owner), delegate);
+    }
+}
+
+pub struct KilledGauges {
+    dict: Dict,
+}
+
+impl KilledGauges {
+    pub fn instance() -> KilledGauges {
+        KilledGauges {
+            dict: Dict::instance(KILLED_GAUGES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(KILLED_GAUGES_DICT)
+    }
+
+    pub fn get(&self, owner: &Key) -> bool {
+        self.dict.get(&key_to_str(owner)).unwrap_or_default()
+    }
+
+    pub fn set(&self, owner: &Key, value: bool) {
+        self.dict.set(&key_to_str(owner), value);
+    }
+}
+
+pub struct DeprecatedTypes {
+    dict: Dict,
+}
+
+impl DeprecatedTypes {
+    #[inline(always)]
+    pub fn instance() -> DeprecatedTypes {
+        DeprecatedTypes {
+            dict: Dict::instance(DEPRECATED_TYPES_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(DEPRECATED_TYPES_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, owner: &i128) -> bool {
+        self.dict.get(&owner.to_string()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, owner: &i128, value: bool) {
+        self.dict.set(&owner.to_string(), value);
+    }
+}
+
+pub struct ActiveTypes {
+    dict: Dict,
+}
+
+impl ActiveTypes {
+    #[inline(always)]
+    pub fn instance() -> ActiveTypes {
+        ActiveTypes {
+            dict: Dict::instance(ACTIVE_TYPES_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(ACTIVE_TYPES_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, index: &i128) -> i128 {
+        let ret: I128 = self.dict.get(&index.to_string()).unwrap_or_default();
+        ret.into()
+    }
+    #[inline(always)]
+    pub fn set(&self, index: &i128, value: i128) {
+        self.dict.set(&index.to_string(), I128::from(value));
+    }
+}
+
+pub struct ActiveTypeIndex {
+    dict: Dict,
+}
+
+impl ActiveTypeIndex {
+    #[inline(always)]
+    pub fn instance() -> ActiveTypeIndex {
+        ActiveTypeIndex {
+            dict: Dict::instance(ACTIVE_TYPE_INDEX_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(ACTIVE_TYPE_INDEX_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, type_id: &i128) -> i128 {
+        let ret: I128 = self.dict.get(&type_id.to_string()).unwrap_or_default();
+        ret.into()
+    }
+    #[inline(always)]
+    pub fn set(&self, type_id: &i128, value: i128) {
+        self.dict.set(&type_id.to_string(), I128::from(value));
+    }
+}
+
+pub struct MaxRelativeWeights {
+    dict: Dict,
+}
+
+impl MaxRelativeWeights {
+    pub fn instance() -> MaxRelativeWeights {
+        MaxRelativeWeights {
+            dict: Dict::instance(MAX_RELATIVE_WEIGHTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(MAX_RELATIVE_WEIGHTS_DICT)
+    }
+
+    pub fn get(&self, owner: &Key) -> U256 {
+        self.dict.get(&key_to_str(owner)).unwrap_or_default()
+    }
+
+    pub fn set(&self, owner: &Key, value: U256) {
+        self.dict.set(&key_to_str(owner), value);
+    }
+}
+
+pub struct CapReceivers {
+    dict: Dict,
+}
+
+impl CapReceivers {
+    pub fn instance() -> CapReceivers {
+        CapReceivers {
+            dict: Dict::instance(CAP_RECEIVERS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(CAP_RECEIVERS_DICT)
+    }
+
+    pub fn get(&self, owner: &Key) -> Key {
+        self.dict
+            .get(&key_to_str(owner))
+            .unwrap_or_else(zero_address)
+    }
+
+    pub fn set(&self, owner: &Key, receiver: Key) {
+        self.dict.set(&key_to_str(owner), receiver);
+    }
+}
+
+pub struct CappedGauges {
+    dict: Dict,
+}
+
+impl CappedGauges {
+    pub fn instance() -> CappedGauges {
+        CappedGauges {
+            dict: Dict::instance(CAPPED_GAUGES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(CAPPED_GAUGES_DICT)
+    }
+
+    pub fn get(&self, indx: &U256) -> Key {
+        self.dict
+            .get(indx.to_string().as_str())
+            .unwrap_or_else(zero_address)
+    }
+
+    pub fn set(&self, indx: &U256, value: Key) {
+        self.dict.set(indx.to_string().as_str(), value);
+    }
+}
+
 pub struct LastUserVote {
     dict: Dict,
 }
@@ -653,18 +880,45 @@ pub fn set_token(token: Key) {
 }
 
 pub fn n_gauge_types() -> i128 {
-    tuple_to_i128(get_key(N_GAUGE_TYPES).unwrap_or_default())
+    let ret: I128 = get_key(N_GAUGE_TYPES).unwrap_or_default();
+    ret.into()
 }
 
 pub fn set_n_gauge_types(n_gauge_types: i128) {
-    set_key(N_GAUGE_TYPES, i128_to_tuple(n_gauge_types));
+    set_key(N_GAUGE_TYPES, I128::from(n_gauge_types));
 }
 pub fn n_gauges() -> i128 {
-    tuple_to_i128(get_key(N_GAUGES).unwrap_or_default())
+    let ret: I128 = get_key(N_GAUGES).unwrap_or_default();
+    ret.into()
 }
 
 pub fn set_n_gauges(n_gauges: i128) {
-    set_key(N_GAUGES, i128_to_tuple(n_gauges));
+    set_key(N_GAUGES, I128::from(n_gauges));
+}
+
+pub fn n_active_types() -> i128 {
+    let ret: I128 = get_key(N_ACTIVE_TYPES).unwrap_or_default();
+    ret.into()
+}
+
+pub fn set_n_active_types(n_active_types: i128) {
+    set_key(N_ACTIVE_TYPES, I128::from(n_active_types));
+}
+
+pub fn n_capped_gauges() -> U256 {
+    get_key(N_CAPPED_GAUGES).unwrap_or_default()
+}
+
+pub fn set_n_capped_gauges(n_capped_gauges: U256) {
+    set_key(N_CAPPED_GAUGES, n_capped_gauges);
+}
+
+pub fn check_gauge_controller() -> bool {
+    get_key(CHECK_GAUGE_CONTROLLER).unwrap_or_default()
+}
+
+pub fn set_check_gauge_controller(check: bool) {
+    set_key(CHECK_GAUGE_CONTROLLER, check);
 }
 
 pub fn voting_escrow() -> Key {
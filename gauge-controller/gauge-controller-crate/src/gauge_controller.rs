@@ -1,17 +1,27 @@
 use crate::alloc::string::ToString;
 use crate::data::{
-    self, get_package_hash, ChangesSum, ChangesWeight, GaugeTypeNames, GaugeTypes_, Gauges,
-    LastUserVote, Point, PointsSum, PointsTotal, PointsTypeWeight, PointsWeight, TimeSum,
-    TimeTypeWeight, TimeWeight, VoteUserPower, VoteUserSlopes, VotedSlope, MULTIPLIER, WEEK,
+    self, ActiveTypeIndex, ActiveTypes, CapReceivers, CappedGauges, ChangesSum, ChangesWeight,
+    DeprecatedTypes, GaugeInfo, GaugeTypeNames, GaugeTypes_, Gauges, KilledGauges, LastUserVote,
+    MaxRelativeWeights, Point, PointsSum, PointsTotal, PointsTypeWeight, PointsWeight, TimeSum,
+    TimeTypeWeight, TimeWeight, TypeInfo, VoteDelegates, VoteUserPower, VoteUserSlopes, VotedSlope,
+    CHECKPOINT_WEEKS, MAXTIME, MAX_ACTIVE_TYPES, MULTIPLIER, RATE_REDUCTION_TIME, WEEK,
     WEIGHT_VOTE_DELAY,
 };
-use alloc::collections::BTreeMap;
 use alloc::string::String;
-use casper_contract::contract_api::storage;
+use alloc::vec::Vec;
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{runtime_args, ApiError, ContractPackageHash, Key, RuntimeArgs, U128, U256};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    runtime_args, ApiError, ContractPackageHash, Key, RuntimeArgs, U256,
+};
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{
+    errors::*,
+    events::{self, CasperEvent, Schema, Schemas},
+    int128::I128,
+    utils::*,
+};
+use core::convert::TryFrom;
 
 pub enum GAUGECONLTROLLEREvent {
     Minted {
@@ -52,6 +62,27 @@ pub enum GAUGECONLTROLLEREvent {
         gauge_type: i128,
         weight: U256,
     },
+    SetVoteDelegate {
+        user: Key,
+        delegate: Key,
+    },
+    KillGauge {
+        gauge_address: Key,
+        time: U256,
+        total_weight: U256,
+    },
+    SetMaxRelativeWeight {
+        gauge_address: Key,
+        max_relative_weight: U256,
+        receiver: Key,
+    },
+    RenameType {
+        name: String,
+        type_id: i128,
+    },
+    DeprecateType {
+        type_id: i128,
+    },
 }
 
 impl GAUGECONLTROLLEREvent {
@@ -92,11 +123,201 @@ impl GAUGECONLTROLLEREvent {
                 gauge_type: _,
                 weight: _,
             } => "NewGauge",
+            GAUGECONLTROLLEREvent::SetVoteDelegate {
+                user: _,
+                delegate: _,
+            } => "SetVoteDelegate",
+            GAUGECONLTROLLEREvent::KillGauge {
+                gauge_address: _,
+                time: _,
+                total_weight: _,
+            } => "KillGauge",
+            GAUGECONLTROLLEREvent::SetMaxRelativeWeight {
+                gauge_address: _,
+                max_relative_weight: _,
+                receiver: _,
+            } => "SetMaxRelativeWeight",
+            GAUGECONLTROLLEREvent::RenameType {
+                name: _,
+                type_id: _,
+            } => "RenameType",
+            GAUGECONLTROLLEREvent::DeprecateType { type_id: _ } => "DeprecateType",
         }
         .to_string()
     }
 }
 
+impl CasperEvent for GAUGECONLTROLLEREvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            GAUGECONLTROLLEREvent::Minted {
+                recipient,
+                gauge,
+                minted,
+            } => Ok([recipient.to_bytes()?, gauge.to_bytes()?, minted.to_bytes()?].concat()),
+            GAUGECONLTROLLEREvent::CommitOwnership { admin } => admin.to_bytes(),
+            GAUGECONLTROLLEREvent::ApplyOwnership { admin } => admin.to_bytes(),
+            GAUGECONLTROLLEREvent::NewTypeWeight {
+                type_id,
+                time,
+                weight,
+                total_weight,
+            } => Ok([
+                I128::from(*type_id).to_bytes()?,
+                time.to_bytes()?,
+                weight.to_bytes()?,
+                total_weight.to_bytes()?,
+            ]
+            .concat()),
+            GAUGECONLTROLLEREvent::NewGaugeWeight {
+                gauge_address,
+                time,
+                weight,
+                total_weight,
+            } => Ok([
+                gauge_address.to_bytes()?,
+                time.to_bytes()?,
+                weight.to_bytes()?,
+                total_weight.to_bytes()?,
+            ]
+            .concat()),
+            GAUGECONLTROLLEREvent::AddType { name, type_id } => {
+                Ok([name.to_bytes()?, I128::from(*type_id).to_bytes()?].concat())
+            }
+            GAUGECONLTROLLEREvent::VoteForGauge {
+                time,
+                user,
+                gauge_addr,
+                weight,
+            } => Ok([
+                time.to_bytes()?,
+                user.to_bytes()?,
+                gauge_addr.to_bytes()?,
+                weight.to_bytes()?,
+            ]
+            .concat()),
+            GAUGECONLTROLLEREvent::NewGauge {
+                addr,
+                gauge_type,
+                weight,
+            } => Ok([
+                addr.to_bytes()?,
+                I128::from(*gauge_type).to_bytes()?,
+                weight.to_bytes()?,
+            ]
+            .concat()),
+            GAUGECONLTROLLEREvent::SetVoteDelegate { user, delegate } => {
+                Ok([user.to_bytes()?, delegate.to_bytes()?].concat())
+            }
+            GAUGECONLTROLLEREvent::KillGauge {
+                gauge_address,
+                time,
+                total_weight,
+            } => Ok([
+                gauge_address.to_bytes()?,
+                time.to_bytes()?,
+                total_weight.to_bytes()?,
+            ]
+            .concat()),
+            GAUGECONLTROLLEREvent::SetMaxRelativeWeight {
+                gauge_address,
+                max_relative_weight,
+                receiver,
+            } => Ok([
+                gauge_address.to_bytes()?,
+                max_relative_weight.to_bytes()?,
+                receiver.to_bytes()?,
+            ]
+            .concat()),
+            GAUGECONLTROLLEREvent::RenameType { name, type_id } => {
+                Ok([name.to_bytes()?, I128::from(*type_id).to_bytes()?].concat())
+            }
+            GAUGECONLTROLLEREvent::DeprecateType { type_id } => I128::from(*type_id).to_bytes(),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "minted",
+                Schema::new()
+                    .with_elem::<Key>("recipient")
+                    .with_elem::<Key>("gauge")
+                    .with_elem::<U256>("minted"),
+            )
+            .with("CommitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("ApplyOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with(
+                "NewTypeWeight",
+                Schema::new()
+                    .with_elem::<I128>("type_id")
+                    .with_elem::<U256>("time")
+                    .with_elem::<U256>("weight")
+                    .with_elem::<U256>("total_weight"),
+            )
+            .with(
+                "NewGaugeWeight",
+                Schema::new()
+                    .with_elem::<Key>("gauge_address")
+                    .with_elem::<U256>("time")
+                    .with_elem::<U256>("weight")
+                    .with_elem::<U256>("total_weight"),
+            )
+            .with(
+                "AddType",
+                Schema::new()
+                    .with_elem::<String>("name")
+                    .with_elem::<I128>("type_id"),
+            )
+            .with(
+                "VoteForGauge",
+                Schema::new()
+                    .with_elem::<U256>("time")
+                    .with_elem::<Key>("user")
+                    .with_elem::<Key>("gauge_addr")
+                    .with_elem::<U256>("weight"),
+            )
+            .with(
+                "NewGauge",
+                Schema::new()
+                    .with_elem::<Key>("addr")
+                    .with_elem::<I128>("gauge_type")
+                    .with_elem::<U256>("weight"),
+            )
+            .with(
+                "SetVoteDelegate",
+                Schema::new()
+                    .with_elem::<Key>("user")
+                    .with_elem::<Key>("delegate"),
+            )
+            .with(
+                "KillGauge",
+                Schema::new()
+                    .with_elem::<Key>("gauge_address")
+                    .with_elem::<U256>("time")
+                    .with_elem::<U256>("total_weight"),
+            )
+            .with(
+                "SetMaxRelativeWeight",
+                Schema::new()
+                    .with_elem::<Key>("gauge_address")
+                    .with_elem::<U256>("max_relative_weight")
+                    .with_elem::<Key>("receiver"),
+            )
+            .with(
+                "RenameType",
+                Schema::new()
+                    .with_elem::<String>("name")
+                    .with_elem::<I128>("type_id"),
+            )
+            .with("DeprecateType", Schema::new().with_elem::<I128>("type_id"))
+    }
+}
+
 pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
     /// @notice Contract constructor
     /// @param _token `ERC20CRV` contract address
@@ -122,6 +343,7 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         data::set_hash(contract_hash);
         data::set_package_hash(package_hash);
         data::set_admin(self.get_caller());
+        data::set_check_gauge_controller(false);
         data::set_time_total(
             U256::from(u64::from(runtime::get_blocktime()))
                 .checked_div(WEEK)
@@ -144,6 +366,15 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         PointsTotal::init();
         PointsTypeWeight::init();
         TimeTypeWeight::init();
+        VoteDelegates::init();
+        KilledGauges::init();
+        DeprecatedTypes::init();
+        ActiveTypes::init();
+        ActiveTypeIndex::init();
+        MaxRelativeWeights::init();
+        CapReceivers::init();
+        CappedGauges::init();
+        events::init::<GAUGECONLTROLLEREvent>();
     }
 
     #[inline(always)]
@@ -193,6 +424,37 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         self._get_total();
     }
 
+    /// @notice Fill at most `max_weeks` missed weeks of the type, sum and total weights
+    /// @dev Lets a long idle controller catch up across several deploys, the other entry points
+    ///      revert until the catch-up is complete
+    /// @param max_weeks Number of weeks to fill, at most 500
+    #[inline(always)]
+    fn checkpoint_partial(&mut self, max_weeks: U256) {
+        let max_weeks: u64 = max_weeks.min(U256::from(CHECKPOINT_WEEKS)).as_u64();
+        for gauge_type in self._active_types() {
+            self._checkpoint_sum(gauge_type, max_weeks);
+            self._checkpoint_type_weight(gauge_type, max_weeks);
+        }
+        self._checkpoint_total(max_weeks);
+    }
+
+    /// @notice Fill at most `max_weeks` missed weeks of the weight of gauge `addr`
+    /// @param addr Gauge address
+    /// @param max_weeks Number of weeks to fill, at most 500
+    #[inline(always)]
+    fn checkpoint_gauge_partial(&mut self, addr: Key, max_weeks: U256) {
+        let max_weeks: u64 = max_weeks.min(U256::from(CHECKPOINT_WEEKS)).as_u64();
+        self._checkpoint_weight(addr, max_weeks);
+    }
+
+    /// @notice Revert when a checkpoint stopped at `t` before reaching the future week
+    #[inline(always)]
+    fn _check_checkpoint_complete(&mut self, t: U256) {
+        if t > U256::from(0) && t <= U256::from(u64::from(runtime::get_blocktime())) {
+            runtime::revert(Error::GaugeControllerCheckpointIncomplete);
+        }
+    }
+
     /// @notice Fill historic gauge weights week-over-week for missed checkins
     ///         and return the total for the future week
     /// @param gauge_addr Address of the gauge
@@ -200,53 +462,96 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
 
     #[inline(always)]
     fn _get_weight(&mut self, gauge_addr: Key) -> U256 {
+        let weight: U256 = self._checkpoint_weight(gauge_addr, CHECKPOINT_WEEKS);
+        let t: U256 = self.time_weight(gauge_addr);
+        self._check_checkpoint_complete(t);
+        weight
+    }
+
+    /// @notice Fill at most `max_weeks` historic gauge weights, the catch-up resumes from the
+    ///         last filled week when it is not complete
+    #[inline(always)]
+    fn _checkpoint_weight(&mut self, gauge_addr: Key, max_weeks: u64) -> U256 {
         let mut t: U256 = self.time_weight(gauge_addr);
         if t > U256::from(0) {
             let mut pt: Point = self.points_weight(gauge_addr, t);
-            for _ in 0..(500) {
+            for _ in 0..max_weeks {
                 if t > U256::from(u64::from(runtime::get_blocktime())) {
                     break;
                 }
                 t = t
                     .checked_add(WEEK)
                     .unwrap_or_revert_with(Error::GaugeControllerOverFlow1);
-
-                let d_bias: U256 = pt
-                    .slope
-                    .checked_mul(data::WEEK)
-                    .unwrap_or_revert_with(Error::GaugeControllerMultiply2);
-                if pt.bias > d_bias {
-                    pt.bias = pt
-                        .bias
-                        .checked_sub(d_bias)
-                        .unwrap_or_revert_with(Error::GaugeControllerUnderFlow2);
-                    let d_slope: U256 = self.changes_weight(gauge_addr, t);
-                    pt.slope = pt
-                        .slope
-                        .checked_sub(d_slope)
-                        .unwrap_or_revert_with(Error::GaugeControllerUnderFlow3);
-                } else {
-                    pt.bias = 0.into();
-                    pt.slope = 0.into();
-                }
+                pt = self._next_weight_point(gauge_addr, pt, t);
                 PointsWeight::instance().set(&gauge_addr, &t, pt);
                 if t > U256::from(u64::from(runtime::get_blocktime())) {
                     TimeWeight::instance().set(&gauge_addr, t);
                 }
             }
+            if t <= U256::from(u64::from(runtime::get_blocktime())) {
+                TimeWeight::instance().set(&gauge_addr, t);
+            }
             pt.bias
         } else {
             U256::from(0)
         }
     }
 
+    /// @notice Gauge weight at week `t` from its weight `pt` the week before
+    #[inline(always)]
+    fn _next_weight_point(&mut self, gauge_addr: Key, mut pt: Point, t: U256) -> Point {
+        let d_bias: U256 = pt
+            .slope
+            .checked_mul(data::WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerMultiply2);
+        if pt.bias > d_bias {
+            pt.bias = pt
+                .bias
+                .checked_sub(d_bias)
+                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow2);
+            let d_slope: U256 = self.changes_weight(gauge_addr, t);
+            pt.slope = pt
+                .slope
+                .checked_sub(d_slope)
+                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow3);
+        } else {
+            pt.bias = 0.into();
+            pt.slope = 0.into();
+        }
+        pt
+    }
+
     /// @notice Fill historic total weights week-over-week for missed checkins
     ///         and return the total for the future week
     /// @return Total weight
     #[inline(always)]
     fn _get_total(&mut self) -> U256 {
+        for gauge_type in self._active_types() {
+            self._get_sum(gauge_type);
+            self._get_type_weight(gauge_type);
+        }
+        let total: U256 = self._checkpoint_total(CHECKPOINT_WEEKS);
+        let t: U256 = self.time_total();
+        self._check_checkpoint_complete(t);
+        total
+    }
+
+    /// @notice Ids of the gauge types summed in the total weight
+    /// @dev Read from the list of types that are not deprecated, which `add_type` keeps to at
+    ///      most 100 and `deprecate_type` removes types from
+    #[inline(always)]
+    fn _active_types(&mut self) -> Vec<i128> {
+        (0..data::n_active_types())
+            .map(|index| ActiveTypes::instance().get(&index))
+            .collect()
+    }
+
+    /// @notice Fill at most `max_weeks` historic total weights, never past the weeks already
+    ///         filled for the sums and weights of every type
+    #[inline(always)]
+    fn _checkpoint_total(&mut self, max_weeks: u64) -> U256 {
         let mut t: U256 = self.time_total();
-        let mut _n_gauge_types: i128 = self.n_gauge_types();
+        let active_types: Vec<i128> = self._active_types();
         if t > U256::from(u64::from(runtime::get_blocktime())) {
             // If we have already checkpointed - still need to change the value
             t = t
@@ -254,25 +559,26 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                 .unwrap_or_revert_with(Error::GaugeControllerUnderFlow4);
         }
         let mut pt: U256 = self.points_total(t);
-        for gauge_type in 0..(100) {
-            if gauge_type == _n_gauge_types {
-                break;
+        let mut filled_until: U256 = U256::MAX;
+        for &gauge_type in active_types.iter() {
+            let time_sum: U256 = self.time_sum(U256::from(gauge_type));
+            if time_sum > U256::from(0) {
+                filled_until = filled_until.min(time_sum);
+            }
+            let time_type_weight: U256 = self.time_type_weight(U256::from(gauge_type));
+            if time_type_weight > U256::from(0) {
+                filled_until = filled_until.min(time_type_weight);
             }
-            self._get_sum(gauge_type);
-            self._get_type_weight(gauge_type);
         }
-        for _ in 0..(500) {
-            if t > U256::from(u64::from(runtime::get_blocktime())) {
+        for _ in 0..max_weeks {
+            if t > U256::from(u64::from(runtime::get_blocktime())) || t >= filled_until {
                 break;
             }
             t = t
                 .checked_add(WEEK)
                 .unwrap_or_revert_with(Error::GaugeControllerOverFlow2);
             pt = U256::from(0);
-            for gauge_type in 0..(100) {
-                if gauge_type == _n_gauge_types {
-                    break;
-                }
+            for &gauge_type in active_types.iter() {
                 let type_sum: U256 = self.points_sum(gauge_type, t).bias;
                 let type_weight: U256 = self.points_type_weight(gauge_type, t);
                 pt = pt
@@ -288,6 +594,9 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                 data::set_time_total(t);
             }
         }
+        if t <= U256::from(u64::from(runtime::get_blocktime())) && t > self.time_total() {
+            data::set_time_total(t);
+        }
         pt
     }
 
@@ -297,52 +606,80 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
     /// @return Sum of weights
     #[inline(always)]
     fn _get_sum(&mut self, gauge_type: i128) -> U256 {
+        let sum: U256 = self._checkpoint_sum(gauge_type, CHECKPOINT_WEEKS);
+        let t: U256 = self.time_sum(U256::from(gauge_type));
+        self._check_checkpoint_complete(t);
+        sum
+    }
+
+    /// @notice Fill at most `max_weeks` historic sums of gauge weights for the same type
+    #[inline(always)]
+    fn _checkpoint_sum(&mut self, gauge_type: i128, max_weeks: u64) -> U256 {
         let mut t: U256 = self.time_sum(U256::from(gauge_type));
         if t > U256::from(0) {
             let mut pt: Point = self.points_sum(gauge_type, t);
-            for _ in 0..(500) {
+            for _ in 0..max_weeks {
                 if t > U256::from(u64::from(runtime::get_blocktime())) {
                     break;
                 }
                 t = t
                     .checked_add(WEEK)
                     .unwrap_or_revert_with(Error::GaugeControllerOverFlow4);
-                let d_bias: U256 = pt
-                    .slope
-                    .checked_mul(WEEK)
-                    .unwrap_or_revert_with(Error::GaugeControllerMultiply4);
-                if pt.bias > d_bias {
-                    pt.bias = pt
-                        .bias
-                        .checked_sub(d_bias)
-                        .unwrap_or_revert_with(Error::GaugeControllerUnderFlow5);
-                    let d_slope: U256 = self.changes_sum(gauge_type, t);
-                    pt.slope = d_slope;
-                } else {
-                    pt.bias = U256::from(0);
-                    pt.slope = U256::from(0);
-                }
+                pt = self._next_sum_point(gauge_type, pt, t);
                 PointsSum::instance().set(&gauge_type, &t, pt);
                 if t > U256::from(u64::from(runtime::get_blocktime())) {
                     TimeSum::instance().set(&U256::from(gauge_type), t)
                 }
             }
+            if t <= U256::from(u64::from(runtime::get_blocktime())) {
+                TimeSum::instance().set(&U256::from(gauge_type), t)
+            }
             pt.bias
         } else {
             U256::from(0)
         }
     }
 
+    /// @notice Sum of gauge weights of `gauge_type` at week `t` from the sum `pt` the week before
+    #[inline(always)]
+    fn _next_sum_point(&mut self, gauge_type: i128, mut pt: Point, t: U256) -> Point {
+        let d_bias: U256 = pt
+            .slope
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerMultiply4);
+        if pt.bias > d_bias {
+            pt.bias = pt
+                .bias
+                .checked_sub(d_bias)
+                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow5);
+            let d_slope: U256 = self.changes_sum(gauge_type, t);
+            pt.slope = d_slope;
+        } else {
+            pt.bias = U256::from(0);
+            pt.slope = U256::from(0);
+        }
+        pt
+    }
+
     /// @notice Fill historic type weights week-over-week for missed checkins
     ///         and return the type weight for the future week
     /// @param gauge_type Gauge type id
     /// @return Type weight
     #[inline(always)]
     fn _get_type_weight(&mut self, gauge_type: i128) -> U256 {
+        let weight: U256 = self._checkpoint_type_weight(gauge_type, CHECKPOINT_WEEKS);
+        let t: U256 = self.time_type_weight(U256::from(gauge_type));
+        self._check_checkpoint_complete(t);
+        weight
+    }
+
+    /// @notice Fill at most `max_weeks` historic type weights
+    #[inline(always)]
+    fn _checkpoint_type_weight(&mut self, gauge_type: i128, max_weeks: u64) -> U256 {
         let mut t: U256 = self.time_type_weight(U256::from(gauge_type));
         if t > U256::from(0) {
             let w: U256 = self.points_type_weight(gauge_type, t);
-            for _ in 0..(500) {
+            for _ in 0..max_weeks {
                 if t > U256::from(u64::from(runtime::get_blocktime())) {
                     break;
                 }
@@ -354,6 +691,9 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                     TimeTypeWeight::instance().set(&U256::from(gauge_type), t)
                 }
             }
+            if t <= U256::from(u64::from(runtime::get_blocktime())) {
+                TimeTypeWeight::instance().set(&U256::from(gauge_type), t)
+            }
             w
         } else {
             U256::from(0)
@@ -374,26 +714,201 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
             .unwrap_or_revert_with(Error::GaugeControllerDivide2)
             .checked_mul(WEEK)
             .unwrap_or_revert_with(Error::GaugeControllerMultiply5);
-        let _total_weight = self.points_total(t);
-        if _total_weight > U256::from(0) {
+        let total_weight: U256 = self._total_at(t);
+        self._relative_weight_at(addr, t, total_weight)
+    }
+
+    /// @notice Relative weight of `addr` at week `t` with caps applied, given the total weight
+    ///         `total_weight` of that week
+    #[inline(always)]
+    fn _relative_weight_at(&mut self, addr: Key, t: U256, total_weight: U256) -> U256 {
+        let relative_weight: U256 = self._uncapped_relative_weight(addr, t, total_weight);
+        let max_relative_weight: U256 = self.max_relative_weight(addr);
+        if max_relative_weight > U256::from(0) {
+            return relative_weight.min(max_relative_weight);
+        }
+        relative_weight
+            .checked_add(self._cap_excess_share(addr, t, total_weight))
+            .unwrap_or_revert_with(Error::GaugeControllerOverFlow34)
+    }
+
+    /// @notice Relative weight of `addr` at week `t` from the gauge weights only, before caps
+    ///         are applied, given the total weight `total_weight` of that week
+    #[inline(always)]
+    fn _uncapped_relative_weight(&mut self, addr: Key, t: U256, total_weight: U256) -> U256 {
+        if self.gauge_types_(addr) == 0 {
+            return U256::from(0);
+        }
+        if total_weight > U256::from(0) {
             let gauge_type: i128 = self
                 .gauge_types_(addr)
                 .checked_sub(1.into())
                 .unwrap_or_revert_with(Error::GaugeControllerUnderFlow24);
-            let _type_weight: U256 = self.points_type_weight(gauge_type, t);
-            let _gauge_weight: U256 = self.points_weight(addr, t).bias;
+            let _type_weight: U256 = self._type_weight_at(gauge_type, t);
+            let _gauge_weight: U256 = self._gauge_weight_at(addr, t);
             MULTIPLIER
                 .checked_mul(_type_weight)
                 .unwrap_or_revert_with(Error::GaugeControllerMultiply6)
                 .checked_mul(_gauge_weight)
                 .unwrap_or_revert_with(Error::GaugeControllerMultiply7)
-                .checked_div(_total_weight)
+                .checked_div(total_weight)
                 .unwrap_or_revert_with(Error::GaugeControllerDivide3)
         } else {
             U256::from(0)
         }
     }
 
+    /// @notice Weight of gauge `addr` at week `t`, projected from its last checkpoint when it
+    ///         was not filled up to `t`
+    #[inline(always)]
+    fn _gauge_weight_at(&mut self, addr: Key, t: U256) -> U256 {
+        if t <= self.time_weight(addr) {
+            return self.points_weight(addr, t).bias;
+        }
+        self._projected_gauge_weights(addr, t, 1)[0]
+    }
+
+    /// @notice Sum of the gauge weights of `gauge_type` at week `t`, projected from its last
+    ///         checkpoint when it was not filled up to `t`
+    #[inline(always)]
+    fn _sum_at(&mut self, gauge_type: i128, t: U256) -> U256 {
+        if t <= self.time_sum(U256::from(gauge_type)) {
+            return self.points_sum(gauge_type, t).bias;
+        }
+        self._projected_sums(gauge_type, t, 1)[0]
+    }
+
+    /// @notice Weight of `gauge_type` at week `t`, the weight of its last checkpoint carries
+    ///         over to the weeks after it
+    #[inline(always)]
+    fn _type_weight_at(&mut self, gauge_type: i128, t: U256) -> U256 {
+        let time_type_weight: U256 = self.time_type_weight(U256::from(gauge_type));
+        self.points_type_weight(gauge_type, t.min(time_type_weight))
+    }
+
+    /// @notice Total weight at week `t`, projected from the sums and weights of the types when
+    ///         it was not filled up to `t`
+    #[inline(always)]
+    fn _total_at(&mut self, t: U256) -> U256 {
+        if t <= self.time_total() {
+            return self.points_total(t);
+        }
+        self._projected_totals(t, 1)[0]
+    }
+
+    /// @notice Total weights for `weeks` weeks from `start`, read up to the last checkpoint of
+    ///         the total and projected after it, the sums of each type being projected once
+    #[inline(always)]
+    fn _projected_totals(&mut self, start: U256, weeks: usize) -> Vec<U256> {
+        let time_total: U256 = self.time_total();
+        let mut totals: Vec<U256> = Vec::new();
+        let mut t: U256 = start;
+        while totals.len() < weeks && t <= time_total {
+            totals.push(self.points_total(t));
+            t = t
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow45);
+        }
+        let mut projected: Vec<U256> = alloc::vec![U256::from(0); weeks - totals.len()];
+        if projected.is_empty() {
+            return totals;
+        }
+        for gauge_type in self._active_types() {
+            let sums: Vec<U256> = self._projected_sums(gauge_type, t, projected.len());
+            let mut week: U256 = t;
+            for (total, sum) in projected.iter_mut().zip(sums) {
+                *total = total
+                    .checked_add(
+                        sum.checked_mul(self._type_weight_at(gauge_type, week))
+                            .unwrap_or_revert_with(Error::GaugeControllerMultiply26),
+                    )
+                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow39);
+                week = week
+                    .checked_add(WEEK)
+                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow46);
+            }
+        }
+        totals.extend(projected);
+        totals
+    }
+
+    /// @notice Fill the historic weights of the capped gauges, whose excess is shared with others
+    #[inline(always)]
+    fn _checkpoint_capped_gauges(&mut self) {
+        for i in 0..data::n_capped_gauges().as_u64() {
+            let capped: Key = CappedGauges::instance().get(&U256::from(i));
+            if self.max_relative_weight(capped) > U256::from(0) {
+                self._get_weight(capped);
+            }
+        }
+    }
+
+    /// @notice Relative weight the uncapped gauge `addr` receives at week `t` from capped gauges
+    ///         above their cap, given the total weight `total_weight` of that week
+    /// @dev The excess of a capped gauge goes to its receiver, or pro rata to the uncapped gauges
+    ///      of its type when it has none or its receiver was killed. Without weight in uncapped
+    ///      gauges the excess is not allocated. Weights missing a checkpoint are projected
+    #[inline(always)]
+    fn _cap_excess_share(&mut self, addr: Key, t: U256, total_weight: U256) -> U256 {
+        let gauge_type: i128 = self.gauge_types_(addr);
+        if gauge_type == 0 {
+            return U256::from(0);
+        }
+        let mut share: U256 = 0.into();
+        let mut pro_rata_excess: U256 = 0.into();
+        let mut uncapped_weight: U256 = self._sum_at(
+            gauge_type
+                .checked_sub(1)
+                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow30),
+            t,
+        );
+        for i in 0..data::n_capped_gauges().as_u64() {
+            let capped: Key = CappedGauges::instance().get(&U256::from(i));
+            let max_relative_weight: U256 = self.max_relative_weight(capped);
+            if max_relative_weight == U256::from(0) {
+                continue;
+            }
+            let mut receiver: Key = self.cap_receiver(capped);
+            if receiver != zero_address() && self.is_killed(receiver) {
+                receiver = zero_address();
+            }
+            let same_type: bool = self.gauge_types_(capped) == gauge_type;
+            if same_type {
+                uncapped_weight = uncapped_weight
+                    .checked_sub(self._gauge_weight_at(capped, t))
+                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow31);
+            }
+            let relative_weight: U256 = self._uncapped_relative_weight(capped, t, total_weight);
+            if relative_weight <= max_relative_weight {
+                continue;
+            }
+            let excess: U256 = relative_weight
+                .checked_sub(max_relative_weight)
+                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow32);
+            if receiver == addr {
+                share = share
+                    .checked_add(excess)
+                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow35);
+            } else if receiver == zero_address() && same_type {
+                pro_rata_excess = pro_rata_excess
+                    .checked_add(excess)
+                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow36);
+            }
+        }
+        if pro_rata_excess > U256::from(0) && uncapped_weight > U256::from(0) {
+            share = share
+                .checked_add(
+                    pro_rata_excess
+                        .checked_mul(self._gauge_weight_at(addr, t))
+                        .unwrap_or_revert_with(Error::GaugeControllerMultiply23)
+                        .checked_div(uncapped_weight)
+                        .unwrap_or_revert_with(Error::GaugeControllerDivide11),
+                )
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow35);
+        }
+        share
+    }
+
     /// @notice Change type weight
     /// @param type_id Type id
     /// @param weight New type weight
@@ -411,17 +926,17 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
 
         let _total_weight = _total_weight
             .checked_add(
-                (old_sum
+                old_sum
                     .checked_mul(weight)
-                    .unwrap_or_revert_with(Error::GaugeControllerMultiply9))
-                .checked_sub(
-                    old_sum
-                        .checked_mul(self._get_type_weight(type_id))
-                        .unwrap_or_revert_with(Error::GaugeControllerMultiply10),
-                )
-                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow6),
+                    .unwrap_or_revert_with(Error::GaugeControllerMultiply9),
             )
-            .unwrap_or_revert_with(Error::GaugeControllerOverFlow7);
+            .unwrap_or_revert_with(Error::GaugeControllerOverFlow7)
+            .checked_sub(
+                old_sum
+                    .checked_mul(self._get_type_weight(type_id))
+                    .unwrap_or_revert_with(Error::GaugeControllerMultiply10),
+            )
+            .unwrap_or_revert_with(Error::GaugeControllerUnderFlow6);
 
         PointsTotal::instance().set(&next_time, _total_weight);
         PointsTypeWeight::instance().set(&type_id, &next_time, weight);
@@ -439,6 +954,9 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
     /// Only needed when testing in reality
     #[inline(always)]
     fn _change_gauge_weight(&mut self, addr: Key, weight: U256) {
+        if self.is_killed(addr) {
+            runtime::revert(Error::GaugeControllerGaugeKilled);
+        }
         let gauge_type: i128 = self
             .gauge_types_(addr)
             .checked_sub(1)
@@ -509,11 +1027,15 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
             U256::from(u64::from(runtime::get_blocktime()))
         };
         self._get_weight(addr);
+        self._checkpoint_capped_gauges();
         self._get_total(); // Also calculates get_sum
         self._gauge_relative_weight(addr, time_)
     }
     #[inline(always)]
     fn change_type_weight(&mut self, type_id: i128, weight: U256) {
+        if self.is_deprecated(type_id) {
+            runtime::revert(Error::GaugeControllerTypeDeprecated2);
+        }
         if self.get_caller() == self.admin() {
             self._change_type_weight(type_id, weight);
         } else {
@@ -560,6 +1082,191 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
     fn changes_weight(&mut self, owner: Key, spender: U256) -> U256 {
         ChangesWeight::instance().get(&owner, &spender)
     }
+    /// @notice List at most `limit` gauges starting from index `offset`
+    /// @dev Weights are projected from the last checkpoint of each gauge with the scheduled
+    ///      slope changes
+    /// @param offset Index of the first gauge
+    /// @param limit Maximum number of gauges
+    /// @return Address, type, current and next week weights and relative weight of each gauge
+    #[inline(always)]
+    fn list_gauges(&mut self, offset: U256, limit: U256) -> Vec<GaugeInfo> {
+        let time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+        let current_week: U256 = time
+            .checked_div(WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerDivide12)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerMultiply24);
+        let next_week: U256 = current_week
+            .checked_add(WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerOverFlow44);
+        let end: U256 = offset
+            .checked_add(limit)
+            .unwrap_or_revert_with(Error::GaugeControllerOverFlow37)
+            .min(U256::from(self.n_gauges()));
+        let mut gauges: Vec<GaugeInfo> = Vec::new();
+        let mut i: U256 = offset;
+        while i < end {
+            let gauge: Key = self.gauges(i);
+            gauges.push(GaugeInfo {
+                gauge,
+                gauge_type: I128::from(self.gauge_types(gauge)),
+                weight: self._gauge_weight_at(gauge, current_week),
+                next_weight: self._gauge_weight_at(gauge, next_week),
+                relative_weight: self._gauge_relative_weight(gauge, time),
+            });
+            i = i
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow38);
+        }
+        gauges
+    }
+
+    /// @notice List all gauge types
+    /// @return Id, name, next week weight, next week sum of gauge weights and whether it is
+    ///         deprecated of each type
+    #[inline(always)]
+    fn list_types(&mut self) -> Vec<TypeInfo> {
+        let mut types: Vec<TypeInfo> = Vec::new();
+        for type_id in 0..self.n_gauge_types() {
+            types.push(TypeInfo {
+                type_id: I128::from(type_id),
+                name: self.gauge_type_names(type_id),
+                weight: self.get_type_weight(type_id),
+                weights_sum: self.get_weights_sum_per_type(type_id),
+                deprecated: self.is_deprecated(type_id),
+            });
+        }
+        types
+    }
+
+    /// @notice Forecast the CRV emitted to gauge `addr` in each of the next `weeks` weeks
+    /// @dev Weights are projected from their last checkpoint with the scheduled slope changes,
+    ///      votes cast afterwards are not accounted for. Caps and the excess of capped gauges
+    ///      are applied like in `gauge_relative_weight`. The forecast stops at the last week the
+    ///      token can price, before the end of the epoch after the current one. Only views of
+    ///      the token are called and the total weights are projected once for all weeks
+    /// @param addr Gauge address
+    /// @param weeks Number of weeks, starting with the current one
+    /// @return Emission of the gauge in each week
+    #[inline(always)]
+    fn emissions_forecast(&mut self, addr: Key, weeks: U256) -> Vec<U256> {
+        self.gauge_types(addr);
+        let current_week: U256 = U256::from(u64::from(runtime::get_blocktime()))
+            .checked_div(WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerDivide13)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerMultiply25);
+        let token: Key = data::token();
+        let token_package_hash = match token {
+            Key::Hash(package) => ContractPackageHash::new(package),
+            _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+        };
+        // The token only prices timeframes ending before the epoch after the next one
+        let start_epoch_time: U256 = runtime::call_versioned_contract(
+            token_package_hash,
+            None,
+            "start_epoch_time",
+            runtime_args! {},
+        );
+        let last_end: U256 = start_epoch_time
+            .checked_add(RATE_REDUCTION_TIME)
+            .unwrap_or_revert_with(Error::GaugeControllerOverFlow43)
+            .checked_add(RATE_REDUCTION_TIME)
+            .unwrap_or_revert_with(Error::GaugeControllerOverFlow47);
+        let priced_weeks: U256 = if last_end > current_week {
+            last_end
+                .checked_sub(current_week)
+                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow33)
+                .checked_div(WEEK)
+                .unwrap_or_revert_with(Error::GaugeControllerDivide16)
+        } else {
+            U256::from(0)
+        };
+        let weeks: u64 = weeks
+            .min(priced_weeks)
+            .min(U256::from(CHECKPOINT_WEEKS))
+            .as_u64();
+        let totals: Vec<U256> = self._projected_totals(current_week, weeks as usize);
+        let mut emissions: Vec<U256> = Vec::new();
+        let mut start: U256 = current_week;
+        for total in totals {
+            let end: U256 = start
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow40);
+            let mintable: U256 = runtime::call_versioned_contract(
+                token_package_hash,
+                None,
+                "mintable_in_timeframe",
+                runtime_args! {"start" => start, "end" => end},
+            );
+            emissions.push(
+                mintable
+                    .checked_mul(self._relative_weight_at(addr, start, total))
+                    .unwrap_or_revert_with(Error::GaugeControllerMultiply29)
+                    .checked_div(MULTIPLIER)
+                    .unwrap_or_revert_with(Error::GaugeControllerDivide15),
+            );
+            start = end;
+        }
+        emissions
+    }
+
+    /// @notice Weights of gauge `addr` for `weeks` weeks from `start`, read up to its last
+    ///         checkpoint and projected after it
+    #[inline(always)]
+    fn _projected_gauge_weights(&mut self, addr: Key, start: U256, weeks: usize) -> Vec<U256> {
+        let time_weight: U256 = self.time_weight(addr);
+        let mut weights: Vec<U256> = Vec::new();
+        if time_weight == U256::from(0) {
+            weights.resize(weeks, U256::from(0));
+            return weights;
+        }
+        let mut t: U256 = time_weight.min(start);
+        let mut pt: Point = self.points_weight(addr, t);
+        while weights.len() < weeks {
+            if t >= start {
+                weights.push(pt.bias);
+            }
+            t = t
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow41);
+            if t <= time_weight {
+                pt = self.points_weight(addr, t);
+            } else {
+                pt = self._next_weight_point(addr, pt, t);
+            }
+        }
+        weights
+    }
+
+    /// @notice Sums of gauge weights of `gauge_type` for `weeks` weeks from `start`, read up to
+    ///         its last checkpoint and projected after it
+    #[inline(always)]
+    fn _projected_sums(&mut self, gauge_type: i128, start: U256, weeks: usize) -> Vec<U256> {
+        let time_sum: U256 = self.time_sum(U256::from(gauge_type));
+        let mut sums: Vec<U256> = Vec::new();
+        if time_sum == U256::from(0) {
+            sums.resize(weeks, U256::from(0));
+            return sums;
+        }
+        let mut t: U256 = time_sum.min(start);
+        let mut pt: Point = self.points_sum(gauge_type, t);
+        while sums.len() < weeks {
+            if t >= start {
+                sums.push(pt.bias);
+            }
+            t = t
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow42);
+            if t <= time_sum {
+                pt = self.points_sum(gauge_type, t);
+            } else {
+                pt = self._next_sum_point(gauge_type, pt, t);
+            }
+        }
+        sums
+    }
+
     #[inline(always)]
     fn gauge_type_names(&mut self, owner: i128) -> String {
         GaugeTypeNames::instance().get(&owner)
@@ -621,10 +1328,13 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
             0.into()
         };
 
-        if self.get_caller() != data::admin() { 
-          runtime::revert(Error::GaugeControllerNotAdmin3);
+        if self.get_caller() != data::admin() {
+            runtime::revert(Error::GaugeControllerNotAdmin3);
+        }
+        let n_active_types: i128 = data::n_active_types();
+        if n_active_types >= MAX_ACTIVE_TYPES as i128 {
+            runtime::revert(Error::GaugeControllerTooManyTypes);
         }
-            
         let type_id: i128 = data::n_gauge_types();
         GaugeTypeNames::instance().set(&type_id, _name.clone());
         data::set_n_gauge_types(
@@ -632,6 +1342,13 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                 .checked_add(1)
                 .unwrap_or_revert_with(Error::GaugeControllerOverFlow11),
         );
+        ActiveTypes::instance().set(&n_active_types, type_id);
+        ActiveTypeIndex::instance().set(&type_id, n_active_types);
+        data::set_n_active_types(
+            n_active_types
+                .checked_add(1)
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow48),
+        );
         if weight != U256::from(0) {
             self._change_type_weight(type_id, weight);
             self.emit(&GAUGECONLTROLLEREvent::AddType {
@@ -641,6 +1358,118 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         }
     }
 
+    /// @notice Rename gauge type `type_id` to `name`
+    /// @param type_id Gauge type id
+    /// @param name New name of the type
+    #[inline(always)]
+    fn rename_type(&mut self, type_id: i128, name: String) {
+        if self.get_caller() != self.admin() {
+            runtime::revert(Error::GaugeControllerNotAdmin8);
+        }
+        if type_id < 0 || type_id >= data::n_gauge_types() {
+            runtime::revert(Error::GaugeControllerInvalidType1);
+        }
+        GaugeTypeNames::instance().set(&type_id, name.clone());
+        self.emit(&GAUGECONLTROLLEREvent::RenameType { name, type_id });
+    }
+
+    /// @notice Deprecate gauge type `type_id`, its weight drops to zero from the next week
+    /// @dev Gauges can no longer be added to the type and its weight can no longer be changed.
+    ///      The total weight stops checkpointing the type, so deprecated types do not count
+    ///      towards the 100 types summed in it
+    /// @param type_id Gauge type id
+    #[inline(always)]
+    fn deprecate_type(&mut self, type_id: i128) {
+        if self.get_caller() != self.admin() {
+            runtime::revert(Error::GaugeControllerNotAdmin9);
+        }
+        if type_id < 0 || type_id >= data::n_gauge_types() {
+            runtime::revert(Error::GaugeControllerInvalidType2);
+        }
+        if self.is_deprecated(type_id) {
+            runtime::revert(Error::GaugeControllerTypeDeprecated3);
+        }
+        self._change_type_weight(type_id, 0.into());
+        DeprecatedTypes::instance().set(&type_id, true);
+        self._remove_active_type(type_id);
+        self.emit(&GAUGECONLTROLLEREvent::DeprecateType { type_id });
+    }
+
+    /// @notice Remove `type_id` from the list of active types
+    /// @dev The last active type takes its place
+    #[inline(always)]
+    fn _remove_active_type(&mut self, type_id: i128) {
+        let last_index: i128 = data::n_active_types()
+            .checked_sub(1)
+            .unwrap_or_revert_with(Error::GaugeControllerUnderFlow34);
+        let index: i128 = ActiveTypeIndex::instance().get(&type_id);
+        if index != last_index {
+            let last_type: i128 = ActiveTypes::instance().get(&last_index);
+            ActiveTypes::instance().set(&index, last_type);
+            ActiveTypeIndex::instance().set(&last_type, index);
+        }
+        data::set_n_active_types(last_index);
+    }
+
+    #[inline(always)]
+    fn is_deprecated(&mut self, type_id: i128) -> bool {
+        DeprecatedTypes::instance().get(&type_id)
+    }
+
+    /// @notice Check that `addr` is a gauge the minter can mint for
+    /// @dev `addr` has to be a contract package whose `controller`, `integrate_fraction` and
+    ///      `lp_token` entry points return the values of the gauge interface. Only these views
+    ///      are called, before anything is written. Casper aborts calls to entry points a
+    ///      package lacks before they return, with the host error naming the missing entry
+    ///      point. When enabled, the gauge also has to point back at this controller
+    /// @param addr Gauge address
+    #[inline(always)]
+    fn _check_gauge(&self, addr: Key) {
+        let gauge: ContractPackageHash = addr
+            .into_hash()
+            .unwrap_or_revert_with(Error::GaugeControllerGaugeNotContract)
+            .into();
+        let controller: Key = call_versioned_contract_or_revert_with(
+            gauge,
+            "controller",
+            runtime_args! {},
+            Error::GaugeControllerInvalidGauge1,
+        );
+        if controller.into_hash().is_none() {
+            runtime::revert(Error::GaugeControllerInvalidGauge2);
+        }
+        let _: U256 = call_versioned_contract_or_revert_with(
+            gauge,
+            "integrate_fraction",
+            runtime_args! {"owner" => Key::from(data::get_package_hash())},
+            Error::GaugeControllerInvalidGauge1,
+        );
+        let _: Key = call_versioned_contract_or_revert_with(
+            gauge,
+            "lp_token",
+            runtime_args! {},
+            Error::GaugeControllerInvalidGauge3,
+        );
+        if data::check_gauge_controller() && controller != Key::from(data::get_package_hash()) {
+            runtime::revert(Error::GaugeControllerGaugeControllerMismatch);
+        }
+    }
+
+    /// @notice Require gauges added from now on to point back at this controller
+    /// @param check Whether the controller of new gauges is checked
+    #[inline(always)]
+    fn set_check_gauge_controller(&mut self, check: bool) {
+        if self.get_caller() != self.admin() {
+            runtime::revert(Error::GaugeControllerNotAdmin7);
+        }
+        data::set_check_gauge_controller(check);
+    }
+
+    #[inline(always)]
+    fn check_gauge_controller(&self) -> bool {
+        data::check_gauge_controller()
+    }
+
     #[inline(always)]
     fn add_gauge(&mut self, addr: Key, gauge_type: i128, _weight: Option<U256>) {
         let weight: U256 = if let Some(..) = _weight {
@@ -656,12 +1485,16 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         if gauge_type < 0 || gauge_type >= data::n_gauge_types() {
           runtime::revert(Error::GaugeControllerCannotAddSameGaugeTwice);
         }
+        if self.is_deprecated(gauge_type) {
+            runtime::revert(Error::GaugeControllerTypeDeprecated1);
+        }
         
         if self.gauge_types_(addr) != 0
         // dev: cannot add the same gauge twice
         {
           runtime::revert(Error::GaugeControllerGaugeType1);
         }
+        self._check_gauge(addr);
 
         let n: i128 = data::n_gauges();
         data::set_n_gauges(
@@ -720,8 +1553,229 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         });
     }
 
+    /// @notice Retire a gauge, its weight drops to zero from the next week
+    /// @dev Cancels the slope changes scheduled by the votes for the gauge and blocks new votes,
+    ///      voters reclaim their power by voting 0 for it without waiting for the vote delay
+    /// @param addr Gauge address
+    #[inline(always)]
+    fn kill_gauge(&mut self, addr: Key) {
+        if self.get_caller() != self.admin() {
+            runtime::revert(Error::GaugeControllerNotAdmin5);
+        }
+        if self.gauge_types_(addr) == 0 {
+            runtime::revert(Error::GaugeControllerGaugeNotAdded);
+        }
+        if self.is_killed(addr) {
+            runtime::revert(Error::GaugeControllerGaugeKilled);
+        }
+        let gauge_type: i128 = self.gauge_types(addr);
+        self._get_weight(addr);
+        self._get_sum(gauge_type);
+        let type_weight: U256 = self._get_type_weight(gauge_type);
+        let total_weight: U256 = self._get_total();
+        let next_time: U256 = (U256::from(u64::from(runtime::get_blocktime()))
+            .checked_add(WEEK)
+            .unwrap_or_revert_with(Error::GaugeControllerOverFlow31))
+        .checked_div(WEEK)
+        .unwrap_or_revert_with(Error::GaugeControllerDivide9)
+        .checked_mul(WEEK)
+        .unwrap_or_revert_with(Error::GaugeControllerMultiply21);
+
+        // Remove the gauge point from the sum of its type
+        let gauge_point: Point = self.points_weight(addr, next_time);
+        let mut point_sum: Point = self.points_sum(gauge_type, next_time);
+        point_sum.bias = point_sum
+            .bias
+            .checked_sub(gauge_point.bias)
+            .unwrap_or_revert_with(Error::GaugeControllerUnderFlow25);
+        point_sum.slope = point_sum
+            .slope
+            .checked_sub(gauge_point.slope)
+            .unwrap_or_revert_with(Error::GaugeControllerUnderFlow26);
+        PointsSum::instance().set(&gauge_type, &next_time, point_sum);
+        PointsWeight::instance().set(&addr, &next_time, Point::default());
+        TimeWeight::instance().set(&addr, next_time);
+
+        // Cancel the slope changes scheduled up to the end of the longest lock
+        let mut t: U256 = next_time;
+        for _ in 0..(MAXTIME / WEEK).as_u64() {
+            t = t
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::GaugeControllerOverFlow32);
+            let d_slope: U256 = self.changes_weight(addr, t);
+            if d_slope > U256::from(0) {
+                let changes_sum_result: U256 = self
+                    .changes_sum(gauge_type, t)
+                    .checked_sub(d_slope)
+                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow27);
+                ChangesSum::instance().set(&gauge_type, &t, changes_sum_result);
+                ChangesWeight::instance().set(&addr, &t, 0.into());
+            }
+        }
+
+        let total_weight: U256 = total_weight
+            .checked_sub(
+                gauge_point
+                    .bias
+                    .checked_mul(type_weight)
+                    .unwrap_or_revert_with(Error::GaugeControllerMultiply20),
+            )
+            .unwrap_or_revert_with(Error::GaugeControllerUnderFlow28);
+        PointsTotal::instance().set(&next_time, total_weight);
+        data::set_time_total(next_time);
+        KilledGauges::instance().set(&addr, true);
+
+        self.emit(&GAUGECONLTROLLEREvent::KillGauge {
+            gauge_address: addr,
+            time: U256::from(u64::from(runtime::get_blocktime())),
+            total_weight,
+        });
+    }
+
+    #[inline(always)]
+    fn is_killed(&mut self, addr: Key) -> bool {
+        KilledGauges::instance().get(&addr)
+    }
+
+    /// @notice Cap the relative weight of gauge `addr` to `max_relative_weight`
+    /// @dev The weight above the cap goes to `receiver`, or pro rata to the uncapped gauges of the
+    ///      same type when it is not set or gets killed. A cap of 0 removes the cap
+    /// @param addr Gauge address
+    /// @param max_relative_weight Maximum relative weight normalized to 1e9
+    /// @param receiver Uncapped gauge receiving the weight above the cap
+    #[inline(always)]
+    fn set_max_relative_weight(
+        &mut self,
+        addr: Key,
+        max_relative_weight: U256,
+        receiver: Option<Key>,
+    ) {
+        if self.get_caller() != self.admin() {
+            runtime::revert(Error::GaugeControllerNotAdmin6);
+        }
+        if self.gauge_types_(addr) == 0 {
+            runtime::revert(Error::GaugeControllerGaugeNotAdded);
+        }
+        if max_relative_weight > MULTIPLIER {
+            runtime::revert(Error::GaugeControllerInvalidMaxRelativeWeight);
+        }
+        let receiver: Key = receiver.unwrap_or_else(zero_address);
+        if receiver != zero_address()
+            && (receiver == addr
+                || self.gauge_types_(receiver) == 0
+                || self.is_killed(receiver)
+                || self.max_relative_weight(receiver) > U256::from(0))
+        {
+            runtime::revert(Error::GaugeControllerInvalidCapReceiver);
+        }
+        let n_capped_gauges: U256 = data::n_capped_gauges();
+        let mut listed: bool = false;
+        for i in 0..n_capped_gauges.as_u64() {
+            let capped: Key = CappedGauges::instance().get(&U256::from(i));
+            if capped == addr {
+                listed = true;
+            } else if max_relative_weight > U256::from(0)
+                && self.max_relative_weight(capped) > U256::from(0)
+                && self.cap_receiver(capped) == addr
+            {
+                // dev: a receiver of excess weight cannot be capped
+                runtime::revert(Error::GaugeControllerInvalidCapReceiver);
+            }
+        }
+        if !listed && max_relative_weight > U256::from(0) {
+            CappedGauges::instance().set(&n_capped_gauges, addr);
+            data::set_n_capped_gauges(
+                n_capped_gauges
+                    .checked_add(1.into())
+                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow33),
+            );
+        }
+        MaxRelativeWeights::instance().set(&addr, max_relative_weight);
+        CapReceivers::instance().set(&addr, receiver);
+        self.emit(&GAUGECONLTROLLEREvent::SetMaxRelativeWeight {
+            gauge_address: addr,
+            max_relative_weight,
+            receiver,
+        });
+    }
+
+    #[inline(always)]
+    fn max_relative_weight(&mut self, addr: Key) -> U256 {
+        MaxRelativeWeights::instance().get(&addr)
+    }
+
+    #[inline(always)]
+    fn cap_receiver(&mut self, addr: Key) -> Key {
+        CapReceivers::instance().get(&addr)
+    }
+
+    /// @notice Allow `delegate` to vote for gauge weights on behalf of the caller
+    /// @dev Passing the zero address revokes the delegation
+    /// @param delegate Account voting with the caller's voting power
+    #[inline(always)]
+    fn set_vote_delegate(&mut self, delegate: Key) {
+        let user: Key = self.get_caller();
+        VoteDelegates::instance().set(&user, delegate);
+        self.emit(&GAUGECONLTROLLEREvent::SetVoteDelegate { user, delegate });
+    }
+
+    #[inline(always)]
+    fn vote_delegate(&mut self, user: Key) -> Key {
+        VoteDelegates::instance().get(&user)
+    }
+
+    /// @notice Allocate voting power for changing pool weights
+    /// @param _gauge_addr Gauge which the caller votes for
+    /// @param _user_weight Weight for a gauge in bps (units of 0.01%). Minimal is 0.01%. Ignored if 0
     #[inline(always)]
     fn vote_for_gauge_weights(&mut self, _gauge_addr: Key, _user_weight: U256) {
+        let user: Key = self.get_caller();
+        self._vote_for_gauge_weights(user, _gauge_addr, _user_weight);
+    }
+
+    /// @notice Allocate the voting power of `user` for changing pool weights
+    /// @dev Callable by `user` or its vote delegate, the vote delay and power limits apply to `user`
+    /// @param user Account whose voting power is allocated
+    /// @param _gauge_addr Gauge which `user` votes for
+    /// @param _user_weight Weight for a gauge in bps (units of 0.01%). Minimal is 0.01%. Ignored if 0
+    #[inline(always)]
+    fn vote_for_gauge_weights_for(&mut self, user: Key, _gauge_addr: Key, _user_weight: U256) {
+        let caller: Key = self.get_caller();
+        if caller != user && VoteDelegates::instance().get(&user) != caller {
+            runtime::revert(Error::GaugeControllerNotVoteDelegate);
+        }
+        self._vote_for_gauge_weights(user, _gauge_addr, _user_weight);
+    }
+
+    #[inline(always)]
+    fn _vote_for_gauge_weights(&mut self, user: Key, _gauge_addr: Key, _user_weight: U256) {
+        let (slope, lock_end): (U256, U256) = self._user_lock(user);
+        self._vote_for_gauge(user, _gauge_addr, _user_weight, slope, lock_end);
+        self._check_vote_user_power(user);
+        self._get_total();
+    }
+
+    /// @notice Allocate voting power for changing the weights of several pools at once
+    /// @dev The power used by the caller is checked once all votes are applied
+    /// @param gauges Gauges which the caller votes for
+    /// @param weights Weight for each gauge in bps (units of 0.01%). Minimal is 0.01%. Ignored if 0
+    #[inline(always)]
+    fn vote_for_many_gauge_weights(&mut self, gauges: Vec<Key>, weights: Vec<U256>) {
+        if gauges.len() != weights.len() {
+            runtime::revert(Error::GaugeControllerVoteLengthMismatch);
+        }
+        let user: Key = self.get_caller();
+        let (slope, lock_end): (U256, U256) = self._user_lock(user);
+        for (gauge_addr, user_weight) in gauges.into_iter().zip(weights.into_iter()) {
+            self._vote_for_gauge(user, gauge_addr, user_weight, slope, lock_end);
+        }
+        self._check_vote_user_power(user);
+        self._get_total();
+    }
+
+    /// @notice Last slope and lock end of `user` in the voting escrow
+    #[inline(always)]
+    fn _user_lock(&mut self, user: Key) -> (U256, U256) {
         let escrow: Key = data::voting_escrow();
 
         //convert Key to ContractPackageHash
@@ -731,21 +1785,43 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         };
         let escrow_package_hash = ContractPackageHash::new(escrow_package_hash_add_array);
 
-        let _slope: (bool, U128) = runtime::call_versioned_contract(
+        let _slope: I128 = runtime::call_versioned_contract(
             escrow_package_hash,
             None,
             "get_last_user_slope",
-            runtime_args! {"addr" => self.get_caller()},
+            runtime_args! {"addr" => user},
         );
-        let slope = U256::from(tuple_to_i128(_slope));
+        let slope: U256 = U256::try_from(_slope).unwrap_or_revert();
 
         let lock_end: U256 = runtime::call_versioned_contract(
             escrow_package_hash,
             None,
             "locked_end",
-            runtime_args! {"addr" => self.get_caller()},
+            runtime_args! {"addr" => user},
         );
+        (slope, lock_end)
+    }
+
+    #[inline(always)]
+    fn _check_vote_user_power(&mut self, user: Key) {
+        if self.vote_user_power(user) > U256::from(10000) {
+            runtime::revert(Error::GaugeControllerUsedTooMuchPower);
+        }
+    }
 
+    #[inline(always)]
+    fn _vote_for_gauge(
+        &mut self,
+        user: Key,
+        _gauge_addr: Key,
+        _user_weight: U256,
+        slope: U256,
+        lock_end: U256,
+    ) {
+        if self.is_killed(_gauge_addr) {
+            self._reclaim_killed_vote(user, _gauge_addr, _user_weight);
+            return;
+        }
         let _n_gauges: i128 = data::n_gauges();
         let next_time: U256 = (U256::from(u64::from(runtime::get_blocktime()))
             .checked_add(WEEK)
@@ -759,7 +1835,7 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
             if _user_weight >= U256::from(0) && _user_weight <= U256::from(10000) {
                 if (U256::from(u64::from(runtime::get_blocktime())))
                     >= (self
-                        .last_user_vote(self.get_caller(), _gauge_addr)
+                        .last_user_vote(user, _gauge_addr)
                         .checked_add(WEIGHT_VOTE_DELAY)
                         .unwrap_or_revert_with(Error::GaugeControllerOverFlow18))
                 {
@@ -769,8 +1845,7 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                         .unwrap_or_revert_with(Error::GaugeControllerUnderFlow10);
                     if gauge_type >= 0 {
                         // Prepare slopes and biases in memory
-                        let old_slope: VotedSlope =
-                            self.vote_user_slopes(self.get_caller(), _gauge_addr);
+                        let old_slope: VotedSlope = self.vote_user_slopes(user, _gauge_addr);
                         let mut old_dt: U256 = 0.into();
                         if old_slope.end > next_time {
                             old_dt = old_slope
@@ -801,42 +1876,84 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                             .checked_mul(new_dt)
                             .unwrap_or_revert_with(Error::GaugeControllerMultiply19);
 
-                        // Check and update powers (weights) used
-                        let mut power_used: U256 = self.vote_user_power(self.get_caller());
+                        // Update powers (weights) used, the total is checked once all votes are applied
+                        let mut power_used: U256 = self.vote_user_power(user);
                         power_used = power_used
-                            .checked_add(
-                                new_slope
-                                    .power
-                                    .checked_sub(old_slope.power)
-                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow13),
-                            )
-                            .unwrap_or_revert_with(Error::GaugeControllerOverFlow19);
-                        VoteUserPower::instance().set(&self.get_caller(), power_used);
-
-                        if (power_used >= 0.into()) && (power_used <= 10000.into()) {
-                            // Remove old and schedule new slope changes
-                            // Remove slope changes for old slopes
-                            // Schedule recording of initial slope for next_time
-
-                            let old_weight_bias: U256 = self._get_weight(_gauge_addr);
-                            let old_weight_slope: U256 =
-                                self.points_weight(_gauge_addr, next_time).slope;
-                            let old_sum_bias: U256 = self._get_sum(gauge_type);
-                            let old_sum_slope: U256 = self.points_sum(gauge_type, next_time).slope;
-
-                            let max_weight_bias = old_weight_bias
-                                .checked_add(new_bias)
-                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow20);
-                            let max_sum_bias = old_sum_bias
-                                .checked_add(new_bias)
-                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow21);
-
-                            if max_weight_bias > old_bias {
+                            .checked_add(new_slope.power)
+                            .unwrap_or_revert_with(Error::GaugeControllerOverFlow19)
+                            .checked_sub(old_slope.power)
+                            .unwrap_or_revert_with(Error::GaugeControllerUnderFlow13);
+                        VoteUserPower::instance().set(&user, power_used);
+
+                        // Remove old and schedule new slope changes
+                        // Remove slope changes for old slopes
+                        // Schedule recording of initial slope for next_time
+
+                        let old_weight_bias: U256 = self._get_weight(_gauge_addr);
+                        let old_weight_slope: U256 =
+                            self.points_weight(_gauge_addr, next_time).slope;
+                        let old_sum_bias: U256 = self._get_sum(gauge_type);
+                        let old_sum_slope: U256 = self.points_sum(gauge_type, next_time).slope;
+
+                        let max_weight_bias = old_weight_bias
+                            .checked_add(new_bias)
+                            .unwrap_or_revert_with(Error::GaugeControllerOverFlow20);
+                        let max_sum_bias = old_sum_bias
+                            .checked_add(new_bias)
+                            .unwrap_or_revert_with(Error::GaugeControllerOverFlow21);
+
+                        if max_weight_bias > old_bias {
+                            let mut points_weight_result =
+                                self.points_weight(_gauge_addr, next_time);
+                            (points_weight_result).bias = max_weight_bias
+                                .checked_sub(old_bias)
+                                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow14);
+                            PointsWeight::instance().set(
+                                &_gauge_addr,
+                                &next_time,
+                                points_weight_result,
+                            );
+                        } else {
+                            let mut points_weight_result =
+                                self.points_weight(_gauge_addr, next_time);
+                            (points_weight_result).bias = old_bias
+                                .checked_sub(old_bias)
+                                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow15);
+                            PointsWeight::instance().set(
+                                &_gauge_addr,
+                                &next_time,
+                                points_weight_result,
+                            );
+                        }
+
+                        if max_sum_bias > old_bias {
+                            let mut points_sum_result = self.points_sum(gauge_type, next_time);
+                            (points_sum_result).bias = max_sum_bias
+                                .checked_sub(old_bias)
+                                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow16);
+                            PointsSum::instance().set(&gauge_type, &next_time, points_sum_result);
+                        } else {
+                            let mut points_sum_result = self.points_sum(gauge_type, next_time);
+                            (points_sum_result).bias = old_bias
+                                .checked_sub(old_bias)
+                                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow17);
+                            PointsSum::instance().set(&gauge_type, &next_time, points_sum_result);
+                        }
+
+                        if old_slope.end > next_time {
+                            let max_weight_slope = old_weight_slope
+                                .checked_add(new_slope.slope)
+                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow22);
+                            let max_sum_slope = old_sum_slope
+                                .checked_add(new_slope.slope)
+                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow23);
+
+                            if max_weight_slope > old_slope.slope {
                                 let mut points_weight_result =
                                     self.points_weight(_gauge_addr, next_time);
-                                (points_weight_result).bias = max_weight_bias
-                                    .checked_sub(old_bias)
-                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow14);
+                                (points_weight_result).slope = max_weight_slope
+                                    .checked_sub(old_slope.slope)
+                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow18);
                                 PointsWeight::instance().set(
                                     &_gauge_addr,
                                     &next_time,
@@ -845,9 +1962,10 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                             } else {
                                 let mut points_weight_result =
                                     self.points_weight(_gauge_addr, next_time);
-                                (points_weight_result).bias = old_bias
-                                    .checked_sub(old_bias)
-                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow15);
+                                (points_weight_result).slope = old_slope
+                                    .slope
+                                    .checked_sub(old_slope.slope)
+                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow19);
                                 PointsWeight::instance().set(
                                     &_gauge_addr,
                                     &next_time,
@@ -855,143 +1973,57 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                                 );
                             }
 
-                            if max_sum_bias > old_bias {
+                            if max_sum_slope > old_slope.slope {
                                 let mut points_sum_result = self.points_sum(gauge_type, next_time);
-                                (points_sum_result).bias = max_sum_bias
-                                    .checked_sub(old_bias)
-                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow16);
-                                PointsSum::instance().set(
-                                    &gauge_type,
-                                    &next_time,
-                                    points_sum_result,
-                                );
-                            } else {
-                                let mut points_sum_result = self.points_sum(gauge_type, next_time);
-                                (points_sum_result).bias = old_bias
-                                    .checked_sub(old_bias)
-                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow17);
+                                (points_sum_result).slope = max_sum_slope
+                                    .checked_sub(old_slope.slope)
+                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow20);
                                 PointsSum::instance().set(
                                     &gauge_type,
                                     &next_time,
                                     points_sum_result,
                                 );
-                            }
-
-                            if old_slope.end > next_time {
-                                let max_weight_slope = old_weight_slope
-                                    .checked_add(new_slope.slope)
-                                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow22);
-                                let max_sum_slope = old_sum_slope
-                                    .checked_add(new_slope.slope)
-                                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow23);
-
-                                if max_weight_slope > old_slope.slope {
-                                    let mut points_weight_result =
-                                        self.points_weight(_gauge_addr, next_time);
-                                    (points_weight_result).slope = max_weight_slope
-                                        .checked_sub(old_slope.slope)
-                                        .unwrap_or_revert_with(Error::GaugeControllerUnderFlow18);
-                                    PointsWeight::instance().set(
-                                        &_gauge_addr,
-                                        &next_time,
-                                        points_weight_result,
-                                    );
-                                } else {
-                                    let mut points_weight_result =
-                                        self.points_weight(_gauge_addr, next_time);
-                                    (points_weight_result).slope = old_slope
-                                        .slope
-                                        .checked_sub(old_slope.slope)
-                                        .unwrap_or_revert_with(Error::GaugeControllerUnderFlow19);
-                                    PointsWeight::instance().set(
-                                        &_gauge_addr,
-                                        &next_time,
-                                        points_weight_result,
-                                    );
-                                }
-
-                                if max_sum_slope > old_slope.slope {
-                                    let mut points_sum_result =
-                                        self.points_sum(gauge_type, next_time);
-                                    (points_sum_result).slope = max_sum_slope
-                                        .checked_sub(old_slope.slope)
-                                        .unwrap_or_revert_with(Error::GaugeControllerUnderFlow20);
-                                    PointsSum::instance().set(
-                                        &gauge_type,
-                                        &next_time,
-                                        points_sum_result,
-                                    );
-                                } else {
-                                    let mut points_sum_result =
-                                        self.points_sum(gauge_type, next_time);
-                                    (points_sum_result).slope = old_slope
-                                        .slope
-                                        .checked_sub(old_slope.slope)
-                                        .unwrap_or_revert_with(Error::GaugeControllerUnderFlow21);
-                                    PointsSum::instance().set(
-                                        &gauge_type,
-                                        &next_time,
-                                        points_sum_result,
-                                    );
-                                }
                             } else {
-                                let mut points_weight_result =
-                                    self.points_weight(_gauge_addr, next_time);
-                                (points_weight_result).slope = (points_weight_result)
-                                    .slope
-                                    .checked_add(new_slope.slope)
-                                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow24);
-                                PointsWeight::instance().set(
-                                    &_gauge_addr,
-                                    &next_time,
-                                    points_weight_result,
-                                );
-
                                 let mut points_sum_result = self.points_sum(gauge_type, next_time);
-                                (points_sum_result).slope = (points_sum_result)
+                                (points_sum_result).slope = old_slope
                                     .slope
-                                    .checked_add(new_slope.slope)
-                                    .unwrap_or_revert_with(Error::GaugeControllerOverFlow25);
+                                    .checked_sub(old_slope.slope)
+                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow21);
                                 PointsSum::instance().set(
                                     &gauge_type,
                                     &next_time,
                                     points_sum_result,
                                 );
                             }
+                        } else {
+                            let mut points_weight_result =
+                                self.points_weight(_gauge_addr, next_time);
+                            (points_weight_result).slope = (points_weight_result)
+                                .slope
+                                .checked_add(new_slope.slope)
+                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow24);
+                            PointsWeight::instance().set(
+                                &_gauge_addr,
+                                &next_time,
+                                points_weight_result,
+                            );
 
-                            if old_slope.end > U256::from(u64::from(runtime::get_blocktime())) {
-                                // Cancel old slope changes if they still didn't happen
-
-                                let mut changes_weight_result =
-                                    self.changes_weight(_gauge_addr, old_slope.end);
-                                changes_weight_result = changes_weight_result
-                                    .checked_sub(old_slope.slope)
-                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow22);
-                                ChangesWeight::instance().set(
-                                    &_gauge_addr,
-                                    &old_slope.end,
-                                    changes_weight_result,
-                                );
-
-                                let mut changes_sum_result =
-                                    self.changes_sum(gauge_type, old_slope.end);
-                                changes_sum_result = changes_sum_result
-                                    .checked_sub(old_slope.slope)
-                                    .unwrap_or_revert_with(Error::GaugeControllerUnderFlow23);
-                                ChangesSum::instance().set(
-                                    &gauge_type,
-                                    &old_slope.end,
-                                    changes_sum_result,
-                                );
-                            }
+                            let mut points_sum_result = self.points_sum(gauge_type, next_time);
+                            (points_sum_result).slope = (points_sum_result)
+                                .slope
+                                .checked_add(new_slope.slope)
+                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow25);
+                            PointsSum::instance().set(&gauge_type, &next_time, points_sum_result);
+                        }
 
-                            // Add slope changes for new slopes
+                        if old_slope.end > U256::from(u64::from(runtime::get_blocktime())) {
+                            // Cancel old slope changes if they still didn't happen
 
                             let mut changes_weight_result =
-                                self.changes_weight(_gauge_addr, new_slope.end);
+                                self.changes_weight(_gauge_addr, old_slope.end);
                             changes_weight_result = changes_weight_result
-                                .checked_add(new_slope.slope)
-                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow26);
+                                .checked_sub(old_slope.slope)
+                                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow22);
                             ChangesWeight::instance().set(
                                 &_gauge_addr,
                                 &old_slope.end,
@@ -999,40 +2031,51 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
                             );
 
                             let mut changes_sum_result =
-                                self.changes_sum(gauge_type, new_slope.end);
+                                self.changes_sum(gauge_type, old_slope.end);
                             changes_sum_result = changes_sum_result
-                                .checked_add(new_slope.slope)
-                                .unwrap_or_revert_with(Error::GaugeControllerOverFlow27);
+                                .checked_sub(old_slope.slope)
+                                .unwrap_or_revert_with(Error::GaugeControllerUnderFlow23);
                             ChangesSum::instance().set(
                                 &gauge_type,
                                 &old_slope.end,
                                 changes_sum_result,
                             );
+                        }
 
-                            self._get_total();
+                        // Add slope changes for new slopes
 
-                            VoteUserSlopes::instance().set(
-                                &self.get_caller(),
-                                &_gauge_addr,
-                                new_slope,
-                            );
+                        let mut changes_weight_result =
+                            self.changes_weight(_gauge_addr, new_slope.end);
+                        changes_weight_result = changes_weight_result
+                            .checked_add(new_slope.slope)
+                            .unwrap_or_revert_with(Error::GaugeControllerOverFlow26);
+                        ChangesWeight::instance().set(
+                            &_gauge_addr,
+                            &old_slope.end,
+                            changes_weight_result,
+                        );
 
-                            //Record last action time
-                            LastUserVote::instance().set(
-                                &self.get_caller(),
-                                &_gauge_addr,
-                                U256::from(u64::from(runtime::get_blocktime())),
-                            );
+                        let mut changes_sum_result = self.changes_sum(gauge_type, new_slope.end);
+                        changes_sum_result = changes_sum_result
+                            .checked_add(new_slope.slope)
+                            .unwrap_or_revert_with(Error::GaugeControllerOverFlow27);
+                        ChangesSum::instance().set(&gauge_type, &old_slope.end, changes_sum_result);
 
-                            self.emit(&GAUGECONLTROLLEREvent::VoteForGauge {
-                                time: U256::from(u64::from(runtime::get_blocktime())),
-                                user: self.get_caller(),
-                                gauge_addr: _gauge_addr,
-                                weight: _user_weight,
-                            });
-                        } else {
-                            runtime::revert(Error::GaugeControllerUsedTooMuchPower);
-                        }
+                        VoteUserSlopes::instance().set(&user, &_gauge_addr, new_slope);
+
+                        //Record last action time
+                        LastUserVote::instance().set(
+                            &user,
+                            &_gauge_addr,
+                            U256::from(u64::from(runtime::get_blocktime())),
+                        );
+
+                        self.emit(&GAUGECONLTROLLEREvent::VoteForGauge {
+                            time: U256::from(u64::from(runtime::get_blocktime())),
+                            user,
+                            gauge_addr: _gauge_addr,
+                            weight: _user_weight,
+                        });
                     } else {
                         runtime::revert(Error::GaugeControllerGaugeNotAdded);
                     }
@@ -1047,6 +2090,35 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
         }
     }
 
+    /// @notice Release the power `user` allocated to the killed gauge `_gauge_addr`
+    /// @dev The gauge weight and slope changes were removed when it was killed, so only the vote
+    ///      of `user` is cleared
+    #[inline(always)]
+    fn _reclaim_killed_vote(&mut self, user: Key, _gauge_addr: Key, _user_weight: U256) {
+        if _user_weight != U256::from(0) {
+            runtime::revert(Error::GaugeControllerGaugeKilled);
+        }
+        let old_slope: VotedSlope = self.vote_user_slopes(user, _gauge_addr);
+        let power_used: U256 = self
+            .vote_user_power(user)
+            .checked_sub(old_slope.power)
+            .unwrap_or_revert_with(Error::GaugeControllerUnderFlow29);
+        VoteUserPower::instance().set(&user, power_used);
+        VoteUserSlopes::instance().set(&user, &_gauge_addr, VotedSlope::default());
+        LastUserVote::instance().set(
+            &user,
+            &_gauge_addr,
+            U256::from(u64::from(runtime::get_blocktime())),
+        );
+
+        self.emit(&GAUGECONLTROLLEREvent::VoteForGauge {
+            time: U256::from(u64::from(runtime::get_blocktime())),
+            user,
+            gauge_addr: _gauge_addr,
+            weight: _user_weight,
+        });
+    }
+
     #[inline(always)]
     fn time_total(&mut self) -> U256 {
         data::time_total()
@@ -1078,101 +2150,7 @@ pub trait GAUGECONLTROLLER<Storage: ContractStorage>: ContractContext<Storage> {
 
     #[inline(always)]
     fn emit(&mut self, gauge_controller_event: &GAUGECONLTROLLEREvent) {
-        match gauge_controller_event {
-            GAUGECONLTROLLEREvent::Minted {
-                recipient,
-                gauge,
-                minted,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("recipient", recipient.to_string());
-                event.insert("gauge", gauge.to_string());
-                event.insert("minted", minted.to_string());
-                storage::new_uref(event);
-            }
-            GAUGECONLTROLLEREvent::CommitOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            GAUGECONLTROLLEREvent::ApplyOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            GAUGECONLTROLLEREvent::NewTypeWeight {
-                type_id,
-                time,
-                weight,
-                total_weight,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("type_id", type_id.to_string());
-                event.insert("time", time.to_string());
-                event.insert("weight", weight.to_string());
-                event.insert("total_weight", total_weight.to_string());
-                storage::new_uref(event);
-            }
-            GAUGECONLTROLLEREvent::NewGaugeWeight {
-                gauge_address,
-                time,
-                weight,
-                total_weight,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("gauge_address", gauge_address.to_string());
-                event.insert("time", time.to_string());
-                event.insert("weight", weight.to_string());
-                event.insert("total_weight", total_weight.to_string());
-                storage::new_uref(event);
-            }
-            GAUGECONLTROLLEREvent::AddType { name, type_id } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("name", name.to_string());
-                event.insert("type_id", type_id.to_string());
-                storage::new_uref(event);
-            }
-            GAUGECONLTROLLEREvent::NewGauge {
-                addr,
-                gauge_type,
-                weight,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("addr", addr.to_string());
-                event.insert("gauge_type", gauge_type.to_string());
-                event.insert("weight", weight.to_string());
-                storage::new_uref(event);
-            }
-            GAUGECONLTROLLEREvent::VoteForGauge {
-                time,
-                user,
-                gauge_addr,
-                weight,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", gauge_controller_event.type_name());
-                event.insert("time", time.to_string());
-                event.insert("user", user.to_string());
-                event.insert("gauge_addr", gauge_addr.to_string());
-                event.insert("weight", weight.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(gauge_controller_event);
     }
 
     #[inline(always)]
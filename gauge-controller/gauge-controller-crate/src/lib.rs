@@ -5,5 +5,5 @@ pub mod data;
 mod gauge_controller;
 
 pub use casperlabs_contract_utils;
-pub use common::utils;
-pub use gauge_controller::GAUGECONLTROLLER;
+pub use common::{int128, utils};
+pub use gauge_controller::{GAUGECONLTROLLEREvent, GAUGECONLTROLLER};
@@ -202,6 +202,35 @@ impl GAUGECONLTROLLERInstance {
         );
     }
 
+    pub fn checkpoint_partial(&self, sender: AccountHash, max_weeks: U256, block_time: u64) {
+        self.0.call_contract(
+            sender,
+            "checkpoint_partial",
+            runtime_args! {
+                "max_weeks" => max_weeks,
+            },
+            block_time,
+        );
+    }
+
+    pub fn checkpoint_gauge_partial(
+        &self,
+        sender: AccountHash,
+        addr: Key,
+        max_weeks: U256,
+        block_time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "checkpoint_gauge_partial",
+            runtime_args! {
+                "addr" => addr,
+                "max_weeks" => max_weeks,
+            },
+            block_time,
+        );
+    }
+
     pub fn change_type_weight(
         &self,
         sender: AccountHash,
@@ -253,6 +282,41 @@ impl GAUGECONLTROLLERInstance {
             block_time,
         );
     }
+    pub fn rename_type(
+        &self,
+        sender: AccountHash,
+        type_id: (bool, U128),
+        name: String,
+        block_time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "rename_type",
+            runtime_args! {
+                "type_id" => type_id,
+                "name" => name,
+            },
+            block_time,
+        );
+    }
+
+    pub fn deprecate_type(&self, sender: AccountHash, type_id: (bool, U128), block_time: u64) {
+        self.0.call_contract(
+            sender,
+            "deprecate_type",
+            runtime_args! {
+                "type_id" => type_id,
+            },
+            block_time,
+        );
+    }
+
+    pub fn is_deprecated(&self, type_id: U128) -> bool {
+        self.0
+            .query_dictionary("deprecated_types", type_id.to_string())
+            .unwrap_or_default()
+    }
+
     pub fn add_gauge<T: Into<Key>>(
         &self,
         sender: AccountHash,
@@ -326,6 +390,126 @@ impl GAUGECONLTROLLERInstance {
             .query_dictionary("time_weight", key_to_str(&owner.into()))
             .unwrap_or_default()
     }
+    pub fn vote_for_many_gauge_weights(
+        &self,
+        sender: AccountHash,
+        gauges: Vec<Key>,
+        weights: Vec<U256>,
+        block_time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "vote_for_many_gauge_weights",
+            runtime_args! {
+                "gauges" => gauges,
+                "weights" => weights,
+            },
+            block_time,
+        );
+    }
+
+    pub fn vote_for_gauge_weights_for<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        user: Key,
+        _gauge_addr: T,
+        _user_weight: U256,
+        block_time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "vote_for_gauge_weights_for",
+            runtime_args! {
+                "user" => user,
+                "gauge_addr" => _gauge_addr.into(),
+                "user_weight" => _user_weight,
+            },
+            block_time,
+        );
+    }
+
+    pub fn kill_gauge(&self, sender: AccountHash, addr: Key, block_time: u64) {
+        self.0.call_contract(
+            sender,
+            "kill_gauge",
+            runtime_args! {
+                "addr" => addr,
+            },
+            block_time,
+        );
+    }
+
+    pub fn is_killed(&self, addr: Key) -> bool {
+        self.0
+            .query_dictionary("killed_gauges", key_to_str(&addr))
+            .unwrap_or_default()
+    }
+
+    pub fn set_check_gauge_controller(&self, sender: AccountHash, check: bool, block_time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_check_gauge_controller",
+            runtime_args! {
+                "check" => check,
+            },
+            block_time,
+        );
+    }
+
+    pub fn check_gauge_controller(&self) -> bool {
+        self.0
+            .query_named_key(String::from("check_gauge_controller"))
+    }
+
+    pub fn set_max_relative_weight(
+        &self,
+        sender: AccountHash,
+        addr: Key,
+        max_relative_weight: U256,
+        receiver: Option<Key>,
+        block_time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_max_relative_weight",
+            runtime_args! {
+                "addr" => addr,
+                "max_relative_weight" => max_relative_weight,
+                "receiver" => receiver,
+            },
+            block_time,
+        );
+    }
+
+    pub fn max_relative_weight(&self, addr: Key) -> U256 {
+        self.0
+            .query_dictionary("max_relative_weights", key_to_str(&addr))
+            .unwrap_or_default()
+    }
+
+    pub fn cap_receiver(&self, addr: Key) -> Key {
+        self.0
+            .query_dictionary("cap_receivers", key_to_str(&addr))
+            .unwrap_or_else(|| Key::Hash([0u8; 32]))
+    }
+
+    pub fn set_vote_delegate(&self, sender: AccountHash, delegate: Key, block_time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_vote_delegate",
+            runtime_args! {
+                "delegate" => delegate,
+            },
+            block_time,
+        );
+    }
+
+    pub fn vote_delegate<T: Into<Key>>(&self, user: T) -> Key {
+        self.0
+            .query_dictionary("vote_delegates", key_to_str(&user.into()))
+            .unwrap_or_else(|| Key::Hash([0u8; 32]))
+    }
+
     pub fn vote_user_power<T: Into<Key>>(&self, owner: T) -> U256 {
         self.0
             .query_dictionary("vote_user_power", key_to_str(&owner.into()))
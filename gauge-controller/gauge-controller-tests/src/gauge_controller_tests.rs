@@ -1,7 +1,8 @@
 use crate::gauge_controller_instance::GAUGECONLTROLLERInstance;
 use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U128, U256};
 use casperlabs_test_env::{TestContract, TestEnv};
-use common::keys::*;
+use common::{keys::*, utils::key_to_str};
+use gauge_controller_crate::data::{GaugeInfo, TypeInfo, MAX_ACTIVE_TYPES};
 
 const NAME: &str = "GAUGECONLTROLLER";
 const TOKEN_NAME: &str = "ERC20";
@@ -144,6 +145,35 @@ fn deploy_fail() -> (
         block_time,
     )
 }
+fn gauge_relative_weight(
+    env: &TestEnv,
+    gauge_controller: &GAUGECONLTROLLERInstance,
+    sender: AccountHash,
+    entrypoint: &str,
+    addr: Key,
+    block_time: u64,
+) -> U256 {
+    let (wasm, name) = if entrypoint == GAUGE_RELATIVE_WEIGHT_WRITE {
+        ("gauge-controller-session-code.wasm", "SessionCode")
+    } else {
+        (TEST_SESSION_CODE_WASM, TEST_SESSION_CODE_NAME)
+    };
+    TestContract::new(
+        env,
+        wasm,
+        name,
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(entrypoint),
+            "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+            "addr" => addr,
+            "time" => None::<U256>
+        },
+        block_time,
+    );
+    env.query_account_named_key(sender, &[entrypoint.into()])
+}
+
 mod ownership_and_deploy_test_cases {
     use crate::gauge_controller_tests::*;
     #[test]
@@ -307,6 +337,70 @@ mod checkpoint_function_test_cases {
         let ret: U256 = env.query_account_named_key(_owner, &[GET_TYPE_WEIGHT.into()]);
         assert_eq!(ret, 2.into(), "Invalid result");
     }
+
+    #[test]
+    fn test_gauge_controller_checkpoint_partial() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let user = env.next_user();
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        let next_week: u64 = (blocktime + week) / week * week;
+        assert_eq!(gauge_controller.time_total(), next_week.into());
+        let later: u64 = blocktime + 20 * week;
+        gauge_controller.checkpoint_partial(user, 5.into(), later);
+        assert_eq!(gauge_controller.time_total(), (next_week + 5 * week).into());
+        gauge_controller.checkpoint_gauge_partial(user, liquidity_gauge, 5.into(), later);
+        assert_eq!(
+            gauge_controller.time_weight(liquidity_gauge),
+            (next_week + 5 * week).into()
+        );
+        gauge_controller.checkpoint_partial(user, 500.into(), later);
+        gauge_controller.checkpoint_gauge(user, liquidity_gauge, later);
+        assert_eq!(
+            gauge_controller.time_total(),
+            ((later + week) / week * week).into()
+        );
+        assert_eq!(
+            gauge_controller.time_weight(liquidity_gauge),
+            ((later + week) / week * week).into()
+        );
+    }
+
+    #[test]
+    fn test_gauge_controller_checkpoint_partial_recovers_stalled_controller() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let user = env.next_user();
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        let next_week: u64 = (blocktime + week) / week * week;
+        assert_eq!(gauge_controller.time_total(), next_week.into());
+        let later: u64 = blocktime + 600 * week;
+        gauge_controller.checkpoint_partial(user, 300.into(), later);
+        gauge_controller.checkpoint_partial(user, 300.into(), later);
+        gauge_controller.checkpoint(user, later);
+        assert_eq!(
+            gauge_controller.time_total(),
+            ((later + week) / week * week).into()
+        );
+    }
 }
 mod vote_functions_and_effect_with_period_test_cases {
     use crate::gauge_controller_tests::*;
@@ -533,6 +627,226 @@ mod vote_functions_and_effect_with_period_test_cases {
         let val = gauge_controller.vote_user_power(Key::Account(owner));
         assert_eq!(val, 1000.into(), "Invalid Output")
     }
+
+    #[test]
+    fn test_gauge_controller_kill_gauge_reclaim_vote_power() {
+        let (
+            _env,
+            gauge_controller,
+            owner,
+            token,
+            voting_escrow,
+            blocktime,
+            liquidity_gauge,
+            liquidity_gauge_1,
+        ) = deploy();
+        let value: U256 = 10000000000_u128.into();
+        let unlock_time: U256 = VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + blocktime;
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Key::Account(owner),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        token.call_contract(
+            owner,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(voting_escrow.package_hash()),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        voting_escrow.call_contract(
+            owner,
+            "create_lock",
+            runtime_args! {
+                "value" => value,
+                "unlock_time" => unlock_time
+            },
+            blocktime,
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge_1, gauge_type, None, blocktime);
+        gauge_controller.vote_for_gauge_weights(owner, liquidity_gauge, 5000.into(), blocktime);
+        assert_eq!(
+            gauge_controller.vote_user_power(Key::Account(owner)),
+            5000.into()
+        );
+        gauge_controller.kill_gauge(owner, liquidity_gauge, blocktime);
+        // The power is released right away, without waiting for the vote delay
+        gauge_controller.vote_for_gauge_weights(owner, liquidity_gauge, 0.into(), blocktime);
+        assert_eq!(
+            gauge_controller.vote_user_power(Key::Account(owner)),
+            0.into()
+        );
+        gauge_controller.vote_for_gauge_weights(owner, liquidity_gauge_1, 10000.into(), blocktime);
+        assert_eq!(
+            gauge_controller.vote_user_power(Key::Account(owner)),
+            10000.into()
+        );
+    }
+
+    #[test]
+    fn test_gauge_controller_vote_for_many_gauge_weights() {
+        let (
+            _env,
+            gauge_controller,
+            owner,
+            token,
+            voting_escrow,
+            blocktime,
+            liquidity_gauge,
+            liquidity_gauge_1,
+        ) = deploy();
+        let value: U256 = 10000000000_u128.into();
+        let unlock_time: U256 = VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + blocktime;
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Key::Account(owner),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        token.call_contract(
+            owner,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(voting_escrow.package_hash()),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        voting_escrow.call_contract(
+            owner,
+            "create_lock",
+            runtime_args! {
+                "value" => value,
+                "unlock_time" => unlock_time
+            },
+            blocktime,
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge_1, gauge_type, None, blocktime);
+        gauge_controller.vote_for_many_gauge_weights(
+            owner,
+            vec![liquidity_gauge, liquidity_gauge_1],
+            vec![4000.into(), 6000.into()],
+            blocktime,
+        );
+        assert_eq!(
+            gauge_controller.vote_user_power(Key::Account(owner)),
+            10000.into()
+        );
+        // Raising the first gauge before lowering the second only fits once both are applied
+        let next_day: u64 = blocktime + 86400000;
+        gauge_controller.vote_for_many_gauge_weights(
+            owner,
+            vec![liquidity_gauge, liquidity_gauge_1],
+            vec![6000.into(), 4000.into()],
+            next_day,
+        );
+        assert_eq!(
+            gauge_controller.vote_user_power(Key::Account(owner)),
+            10000.into()
+        );
+        assert_eq!(
+            gauge_controller.last_user_vote(Key::Account(owner), liquidity_gauge),
+            next_day.into()
+        );
+        assert_eq!(
+            gauge_controller.last_user_vote(Key::Account(owner), liquidity_gauge_1),
+            next_day.into()
+        );
+    }
+
+    #[test]
+    fn test_gauge_controller_vote_for_gauge_weights_for() {
+        let (env, gauge_controller, owner, token, voting_escrow, blocktime, liquidity_gauge, _) =
+            deploy();
+        let delegate = env.next_user();
+        let value: U256 = 10000000000_u128.into();
+        let unlock_time: U256 = VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + blocktime;
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Key::Account(owner),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        token.call_contract(
+            owner,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(voting_escrow.package_hash()),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        voting_escrow.call_contract(
+            owner,
+            "create_lock",
+            runtime_args! {
+                "value" => value,
+                "unlock_time" => unlock_time
+            },
+            blocktime,
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.set_vote_delegate(owner, Key::Account(delegate), blocktime);
+        assert_eq!(
+            gauge_controller.vote_delegate(Key::Account(owner)),
+            Key::Account(delegate)
+        );
+        let weight: U256 = 1000.into();
+        gauge_controller.vote_for_gauge_weights_for(
+            delegate,
+            Key::Account(owner),
+            liquidity_gauge,
+            weight,
+            blocktime,
+        );
+        assert_eq!(
+            gauge_controller.vote_user_power(Key::Account(owner)),
+            weight
+        );
+        assert_eq!(
+            gauge_controller.vote_user_power(Key::Account(delegate)),
+            0.into()
+        );
+        assert_eq!(
+            gauge_controller.last_user_vote(Key::Account(owner), liquidity_gauge),
+            blocktime.into()
+        );
+    }
 }
 mod gauge_types_and_add_type_functions_test_cases {
     use crate::gauge_controller_tests::*;
@@ -857,6 +1171,34 @@ mod change_gauge_test_cases {
         let ret: U256 = env.query_account_named_key(_owner, &[GET_GAUGE_WEIGHT.into()]);
         assert_eq!(ret, 3.into(), "Invalid result");
     }
+
+    #[test]
+    fn test_gauge_controller_kill_gauge() {
+        let (env, gauge_controller, _owner, _token, _voting_escrow, blocktime, liquidity_gauge, _) =
+            deploy();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(_owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(_owner, liquidity_gauge, gauge_type, None, blocktime);
+        let weight: U256 = 2.into();
+        gauge_controller.change_gauge_weight(_owner, liquidity_gauge, weight, blocktime);
+        gauge_controller.kill_gauge(_owner, liquidity_gauge, blocktime);
+        assert!(gauge_controller.is_killed(liquidity_gauge));
+        TestContract::new(
+            &env,
+            TEST_SESSION_CODE_WASM,
+            TEST_SESSION_CODE_NAME,
+            _owner,
+            runtime_args! {
+                "entrypoint" => String::from(GET_GAUGE_WEIGHT),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "addr" => liquidity_gauge
+            },
+            blocktime,
+        );
+        let ret: U256 = env.query_account_named_key(_owner, &[GET_GAUGE_WEIGHT.into()]);
+        assert_eq!(ret, 0.into(), "Invalid result");
+    }
 }
 mod gauge_relative_weight_test_cases {
     use crate::gauge_controller_tests::*;
@@ -1234,141 +1576,460 @@ mod gauge_relative_weight_test_cases {
         let ret: U256 = env.query_account_named_key(_user, &[GAUGE_RELATIVE_WEIGHT_WRITE.into()]);
         assert_eq!(ret, 333333333.into());
     }
-}
-mod get_type_and_total_weight_test_cases {
-    use crate::gauge_controller_tests::*;
+
     #[test]
-    fn test_gauge_controller_get_type_weight() {
-        let (
-            env,
-            gauge_controller,
-            _owner,
-            _token,
-            _voting_escrow,
-            blocktime,
-            liquidity_gauge,
-            liquidity_gauge_1,
-        ) = deploy();
-        let _user = env.next_user();
-        let _user1 = env.next_user();
-        assert_eq!(gauge_controller.token(), Key::Hash(_token.package_hash()));
+    fn test_gauge_controller_max_relative_weight_pro_rata() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge_1,
+            gauge_type,
+            Some(3000000.into()),
+            blocktime,
+        );
+        let relative_weight = |addr: Key| -> U256 {
+            gauge_relative_weight(
+                &env,
+                &gauge_controller,
+                owner,
+                GAUGE_RELATIVE_WEIGHT,
+                addr,
+                blocktime + week,
+            )
+        };
+        let weight: U256 = relative_weight(liquidity_gauge);
+        let weight_1: U256 = relative_weight(liquidity_gauge_1);
+        let max_relative_weight: U256 = weight / 2;
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge,
+            max_relative_weight,
+            None,
+            blocktime,
+        );
         assert_eq!(
-            gauge_controller.voting_escrow(),
-            Key::Hash(_voting_escrow.package_hash())
+            gauge_controller.max_relative_weight(liquidity_gauge),
+            max_relative_weight
         );
-        assert_eq!(gauge_controller.admin(), Key::from(_owner));
-        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        assert_eq!(relative_weight(liquidity_gauge), max_relative_weight);
+        // The only uncapped gauge of the type receives the whole excess
         assert_eq!(
-            gauge_controller.time_total(),
-            U256::from(blocktime / week * week)
+            relative_weight(liquidity_gauge_1),
+            weight_1 + weight - max_relative_weight
+        );
+        let written: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT_WRITE,
+            liquidity_gauge_1,
+            blocktime + week,
         );
+        assert_eq!(written, weight_1 + weight - max_relative_weight);
+    }
+
+    #[test]
+    fn test_gauge_controller_max_relative_weight_receiver() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
         let name: String = "type".to_string();
-        gauge_controller.add_type(_owner, name, None, blocktime);
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
         let gauge_type: (bool, U128) = (false, 0.into());
         gauge_controller.add_gauge(
-            _owner,
+            owner,
             liquidity_gauge,
             gauge_type,
-            Some(500.into()),
+            Some(1000000.into()),
             blocktime,
         );
         let name: String = "type2".to_string();
-        gauge_controller.add_type(_owner, name, None, blocktime);
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
         let gauge_type: (bool, U128) = (false, 1.into());
         gauge_controller.add_gauge(
-            _owner,
+            owner,
             liquidity_gauge_1,
             gauge_type,
             Some(1000000.into()),
             blocktime,
         );
-        let type_id: (bool, U128) = (false, 1.into());
-        let weight: U256 = 2.into();
-        gauge_controller.change_type_weight(_owner, type_id, weight, blocktime);
-
-        TestContract::new(
-            &env,
-            TEST_SESSION_CODE_WASM,
-            TEST_SESSION_CODE_NAME,
-            _user1,
-            runtime_args! {
-                "entrypoint" => String::from(GET_TYPE_WEIGHT),
-                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
-                "type_id"=>type_id
-            },
+        let relative_weight = |addr: Key| -> U256 {
+            gauge_relative_weight(
+                &env,
+                &gauge_controller,
+                owner,
+                GAUGE_RELATIVE_WEIGHT,
+                addr,
+                blocktime + week,
+            )
+        };
+        let weight: U256 = relative_weight(liquidity_gauge);
+        let weight_1: U256 = relative_weight(liquidity_gauge_1);
+        let max_relative_weight: U256 = weight / 2;
+        // Without uncapped gauges in its type the excess is not allocated
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge,
+            max_relative_weight,
+            None,
             blocktime,
         );
-
-        let ret: U256 = env.query_account_named_key(_user1, &[GET_TYPE_WEIGHT.into()]);
-        assert_eq!(ret, 2.into());
+        assert_eq!(relative_weight(liquidity_gauge), max_relative_weight);
+        assert_eq!(relative_weight(liquidity_gauge_1), weight_1);
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge,
+            max_relative_weight,
+            Some(liquidity_gauge_1),
+            blocktime,
+        );
+        assert_eq!(
+            gauge_controller.cap_receiver(liquidity_gauge),
+            liquidity_gauge_1
+        );
+        assert_eq!(relative_weight(liquidity_gauge), max_relative_weight);
+        assert_eq!(
+            relative_weight(liquidity_gauge_1),
+            weight_1 + weight - max_relative_weight
+        );
+        gauge_controller.set_max_relative_weight(owner, liquidity_gauge, 0.into(), None, blocktime);
+        assert_eq!(relative_weight(liquidity_gauge), weight);
+        assert_eq!(relative_weight(liquidity_gauge_1), weight_1);
     }
 
     #[test]
-    fn test_gauge_controller_get_total_weight() {
-        let (
-            env,
-            gauge_controller,
-            _owner,
-            _token,
-            _voting_escrow,
+    fn test_gauge_controller_max_relative_weight_stale_capped_gauge() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000000.into()),
             blocktime,
+        );
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge_1,
+            gauge_type,
+            Some(3000000.into()),
+            blocktime,
+        );
+        let weight: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT,
+            liquidity_gauge,
+            blocktime + week,
+        );
+        let weight_1: U256 = weight * 3;
+        let max_relative_weight: U256 = weight / 2;
+        gauge_controller.set_max_relative_weight(
+            owner,
             liquidity_gauge,
+            max_relative_weight,
+            None,
+            blocktime,
+        );
+        // Neither gauge is checkpointed in the following weeks, only the total weight is
+        gauge_controller.checkpoint(owner, blocktime + 3 * week);
+        let viewed: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT,
             liquidity_gauge_1,
-        ) = deploy();
-        let _user = env.next_user();
-        let _user1 = env.next_user();
-        assert_eq!(gauge_controller.token(), Key::Hash(_token.package_hash()));
-        assert_eq!(
-            gauge_controller.voting_escrow(),
-            Key::Hash(_voting_escrow.package_hash())
+            blocktime + 3 * week,
         );
-        assert_eq!(gauge_controller.admin(), Key::from(_owner));
-        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        assert_eq!(viewed, weight_1 + weight - max_relative_weight);
+        let written: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT_WRITE,
+            liquidity_gauge_1,
+            blocktime + 3 * week,
+        );
+        assert_eq!(written, weight_1 + weight - max_relative_weight);
+        let next_week: u64 = (blocktime + 3 * week) / week * week + week;
         assert_eq!(
-            gauge_controller.time_total(),
-            U256::from(blocktime / week * week)
+            gauge_controller.time_weight(liquidity_gauge),
+            next_week.into(),
+            "Capped gauge not checkpointed"
         );
+    }
+
+    #[test]
+    fn test_gauge_controller_max_relative_weight_killed_receiver() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
         let name: String = "type".to_string();
-        gauge_controller.add_type(_owner, name, None, blocktime);
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
         let gauge_type: (bool, U128) = (false, 0.into());
         gauge_controller.add_gauge(
-            _owner,
+            owner,
             liquidity_gauge,
             gauge_type,
-            Some(500.into()),
+            Some(1000000.into()),
             blocktime,
         );
-        let name: String = "type2".to_string();
-        gauge_controller.add_type(_owner, name, None, blocktime);
-        let gauge_type: (bool, U128) = (false, 1.into());
         gauge_controller.add_gauge(
-            _owner,
+            owner,
             liquidity_gauge_1,
             gauge_type,
             Some(1000000.into()),
             blocktime,
         );
-        let type_id: (bool, U128) = (false, 1.into());
-        let weight: U256 = 2.into();
-        gauge_controller.change_type_weight(_owner, type_id, weight, blocktime);
+        let weight: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT,
+            liquidity_gauge,
+            blocktime + week,
+        );
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge,
+            weight / 2,
+            Some(liquidity_gauge_1),
+            blocktime,
+        );
+        gauge_controller.kill_gauge(owner, liquidity_gauge_1, blocktime + week);
+        // The excess falls back to the uncapped gauges of the type, none has weight left
+        let written: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT_WRITE,
+            liquidity_gauge_1,
+            blocktime + 2 * week,
+        );
+        assert_eq!(written, 0.into());
+        let written: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT_WRITE,
+            liquidity_gauge,
+            blocktime + 2 * week,
+        );
+        assert_eq!(written, weight / 2);
+    }
+
+    #[test]
+    fn test_gauge_controller_emissions_forecast() {
+        let (env, _, _, _, voting_escrow, blocktime, liquidity_gauge, liquidity_gauge_1) = deploy();
+        let admin = env.next_user();
+        let erc20_crv = GAUGECONLTROLLERInstance::deploy_erc20_crv(&env, admin, blocktime);
+        let gauge_controller =
+            GAUGECONLTROLLERInstance::instance(GAUGECONLTROLLERInstance::new_deploy(
+                &env,
+                NAME,
+                admin,
+                Key::Hash(erc20_crv.package_hash()),
+                Key::Hash(voting_escrow.package_hash()),
+                blocktime,
+            ));
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_type(admin, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.add_gauge(
+            admin,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000.into()),
+            blocktime,
+        );
+        gauge_controller.add_gauge(
+            admin,
+            liquidity_gauge_1,
+            gauge_type,
+            Some(3000.into()),
+            blocktime,
+        );
+        let time: u64 = blocktime + week;
+        erc20_crv.call_contract(admin, "update_mining_parameters", runtime_args! {}, time);
         TestContract::new(
             &env,
-            TEST_SESSION_CODE_WASM,
-            TEST_SESSION_CODE_NAME,
-            _owner,
+            "gauge-controller-session-code.wasm",
+            "SessionCode",
+            admin,
             runtime_args! {
-                "entrypoint" => String::from(GET_TOTAL_WEIGHT),
+                "entrypoint" => String::from(EMISSIONS_FORECAST),
                 "package_hash" => Key::from(gauge_controller.contract_package_hash()),
-                "type_id"=>type_id
+                "addr" => liquidity_gauge,
+                "weeks" => U256::from(3)
             },
+            time,
+        );
+        let emissions: Vec<U256> = env.query_account_named_key(admin, &[EMISSIONS_FORECAST.into()]);
+        assert_eq!(emissions.len(), 3);
+        let mut start: U256 = U256::from(time / week * week);
+        for emission in emissions {
+            TestContract::new(
+                &env,
+                TEST_SESSION_CODE_WASM,
+                TEST_SESSION_CODE_NAME,
+                admin,
+                runtime_args! {
+                    "entrypoint" => String::from(MINTABLE_IN_TIMEFRAME),
+                    "package_hash" => Key::Hash(erc20_crv.package_hash()),
+                    "start" => start,
+                    "end" => start + VOTING_ESCROW_WEEK
+                },
+                time,
+            );
+            let mintable: U256 =
+                env.query_account_named_key(admin, &[MINTABLE_IN_TIMEFRAME.into()]);
+            assert!(mintable > U256::from(0));
+            assert_eq!(emission, mintable / 4);
+            start = start + VOTING_ESCROW_WEEK;
+        }
+    }
+
+    #[test]
+    fn test_gauge_controller_emissions_forecast_leaves_token_untouched() {
+        let (env, _, _, _, voting_escrow, blocktime, liquidity_gauge, _) = deploy();
+        let admin = env.next_user();
+        let erc20_crv = GAUGECONLTROLLERInstance::deploy_erc20_crv(&env, admin, blocktime);
+        let gauge_controller =
+            GAUGECONLTROLLERInstance::instance(GAUGECONLTROLLERInstance::new_deploy(
+                &env,
+                NAME,
+                admin,
+                Key::Hash(erc20_crv.package_hash()),
+                Key::Hash(voting_escrow.package_hash()),
+                blocktime,
+            ));
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_type(admin, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.add_gauge(
+            admin,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000.into()),
             blocktime,
         );
+        // The mining parameters are due for an update, the forecast must not apply it
+        let time: u64 = blocktime + VOTING_ESCROW_WEEK.as_u64();
+        let start_epoch_time: U256 = erc20_crv.query_named_key(String::from("start_epoch_time"));
+        TestContract::new(
+            &env,
+            "gauge-controller-session-code.wasm",
+            "SessionCode",
+            admin,
+            runtime_args! {
+                "entrypoint" => String::from(EMISSIONS_FORECAST),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "addr" => liquidity_gauge,
+                "weeks" => U256::from(3)
+            },
+            time,
+        );
+        let emissions: Vec<U256> = env.query_account_named_key(admin, &[EMISSIONS_FORECAST.into()]);
+        assert_eq!(emissions.len(), 3);
+        let ret: U256 = erc20_crv.query_named_key(String::from("start_epoch_time"));
+        assert_eq!(ret, start_epoch_time, "Mining parameters updated");
+    }
 
-        let ret: U256 = env.query_account_named_key(_owner, &[GET_TOTAL_WEIGHT.into()]);
-        assert_eq!(ret, 2000000.into());
+    #[test]
+    fn test_gauge_controller_emissions_forecast_capped_until_last_epoch() {
+        let (env, _, _, _, voting_escrow, blocktime, liquidity_gauge, liquidity_gauge_1) = deploy();
+        let admin = env.next_user();
+        let erc20_crv = GAUGECONLTROLLERInstance::deploy_erc20_crv(&env, admin, blocktime);
+        let gauge_controller =
+            GAUGECONLTROLLERInstance::instance(GAUGECONLTROLLERInstance::new_deploy(
+                &env,
+                NAME,
+                admin,
+                Key::Hash(erc20_crv.package_hash()),
+                Key::Hash(voting_escrow.package_hash()),
+                blocktime,
+            ));
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_type(admin, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.add_gauge(
+            admin,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000.into()),
+            blocktime,
+        );
+        gauge_controller.add_gauge(
+            admin,
+            liquidity_gauge_1,
+            gauge_type,
+            Some(3000.into()),
+            blocktime,
+        );
+        // A quarter of the weight is capped at an eighth, the rest goes to the other gauge
+        gauge_controller.set_max_relative_weight(
+            admin,
+            liquidity_gauge,
+            125000000.into(),
+            None,
+            blocktime,
+        );
+        let time: u64 = blocktime + week;
+        erc20_crv.call_contract(admin, "update_mining_parameters", runtime_args! {}, time);
+        TestContract::new(
+            &env,
+            "gauge-controller-session-code.wasm",
+            "SessionCode",
+            admin,
+            runtime_args! {
+                "entrypoint" => String::from(EMISSIONS_FORECAST),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "addr" => liquidity_gauge_1,
+                "weeks" => U256::from(500)
+            },
+            time,
+        );
+        let emissions: Vec<U256> = env.query_account_named_key(admin, &[EMISSIONS_FORECAST.into()]);
+        // Two inflation epochs of a year at most
+        assert!(emissions.len() > 52 && emissions.len() <= 105);
+        let start: U256 = U256::from(time / week * week);
+        TestContract::new(
+            &env,
+            TEST_SESSION_CODE_WASM,
+            TEST_SESSION_CODE_NAME,
+            admin,
+            runtime_args! {
+                "entrypoint" => String::from(MINTABLE_IN_TIMEFRAME),
+                "package_hash" => Key::Hash(erc20_crv.package_hash()),
+                "start" => start,
+                "end" => start + VOTING_ESCROW_WEEK
+            },
+            time,
+        );
+        let mintable: U256 = env.query_account_named_key(admin, &[MINTABLE_IN_TIMEFRAME.into()]);
+        assert_eq!(emissions[0], mintable * 875000000 / 1000000000);
     }
+}
+mod get_type_and_total_weight_test_cases {
+    use crate::gauge_controller_tests::*;
     #[test]
-    fn test_gauge_controller_get_total_weight_by_user() {
+    fn test_gauge_controller_get_type_weight() {
         let (
             env,
             gauge_controller,
@@ -1415,27 +2076,26 @@ mod get_type_and_total_weight_test_cases {
         let type_id: (bool, U128) = (false, 1.into());
         let weight: U256 = 2.into();
         gauge_controller.change_type_weight(_owner, type_id, weight, blocktime);
+
         TestContract::new(
             &env,
             TEST_SESSION_CODE_WASM,
             TEST_SESSION_CODE_NAME,
-            _user,
+            _user1,
             runtime_args! {
-                "entrypoint" => String::from(GET_TOTAL_WEIGHT),
+                "entrypoint" => String::from(GET_TYPE_WEIGHT),
                 "package_hash" => Key::from(gauge_controller.contract_package_hash()),
                 "type_id"=>type_id
             },
             blocktime,
         );
-        let ret: U256 = env.query_account_named_key(_user, &[GET_TOTAL_WEIGHT.into()]);
-        assert_eq!(ret, 2000000.into());
+
+        let ret: U256 = env.query_account_named_key(_user1, &[GET_TYPE_WEIGHT.into()]);
+        assert_eq!(ret, 2.into());
     }
-}
-mod get_gauge_and_sum_per_type_weight_test_cases {
-    use crate::gauge_controller_tests::*;
 
     #[test]
-    fn test_gauge_controller_get_gauge_weight() {
+    fn test_gauge_controller_get_total_weight() {
         let (
             env,
             gauge_controller,
@@ -1466,7 +2126,7 @@ mod get_gauge_and_sum_per_type_weight_test_cases {
             _owner,
             liquidity_gauge,
             gauge_type,
-            Some(1000000.into()),
+            Some(500.into()),
             blocktime,
         );
         let name: String = "type2".to_string();
@@ -1482,14 +2142,329 @@ mod get_gauge_and_sum_per_type_weight_test_cases {
         let type_id: (bool, U128) = (false, 1.into());
         let weight: U256 = 2.into();
         gauge_controller.change_type_weight(_owner, type_id, weight, blocktime);
-
         TestContract::new(
             &env,
             TEST_SESSION_CODE_WASM,
             TEST_SESSION_CODE_NAME,
             _owner,
             runtime_args! {
-                "entrypoint" => String::from(GET_GAUGE_WEIGHT),
+                "entrypoint" => String::from(GET_TOTAL_WEIGHT),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "type_id"=>type_id
+            },
+            blocktime,
+        );
+
+        let ret: U256 = env.query_account_named_key(_owner, &[GET_TOTAL_WEIGHT.into()]);
+        assert_eq!(ret, 2000000.into());
+    }
+
+    #[test]
+    fn test_gauge_controller_add_type_after_deprecating_at_max_active_types() {
+        let (_, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        for i in 0..MAX_ACTIVE_TYPES {
+            gauge_controller.add_type(owner, format!("type{}", i), None, blocktime);
+        }
+        gauge_controller.deprecate_type(owner, (false, 0.into()), blocktime);
+        // The last active type took the slot of type 0 and can be deprecated in turn
+        gauge_controller.deprecate_type(owner, (false, (MAX_ACTIVE_TYPES - 1).into()), blocktime);
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+        gauge_controller.add_type(owner, "type2".to_string(), None, blocktime);
+        assert_eq!(
+            gauge_controller.n_gauge_types(),
+            (false, (MAX_ACTIVE_TYPES + 2).into())
+        );
+    }
+
+    #[test]
+    fn test_gauge_controller_deprecate_type() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let type_id: (bool, U128) = (false, 0.into());
+        let type_id_1: (bool, U128) = (false, 1.into());
+        gauge_controller.add_type(owner, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge, type_id, Some(500.into()), blocktime);
+        gauge_controller.add_type(owner, "type2".to_string(), Some(2.into()), blocktime);
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge_1,
+            type_id_1,
+            Some(1000000.into()),
+            blocktime,
+        );
+        gauge_controller.deprecate_type(owner, type_id, blocktime);
+        assert!(gauge_controller.is_deprecated(0.into()));
+        assert!(!gauge_controller.is_deprecated(1.into()));
+        TestContract::new(
+            &env,
+            TEST_SESSION_CODE_WASM,
+            TEST_SESSION_CODE_NAME,
+            owner,
+            runtime_args! {
+                "entrypoint" => String::from(GET_TYPE_WEIGHT),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "type_id" => type_id
+            },
+            blocktime,
+        );
+        let ret: U256 = env.query_account_named_key(owner, &[GET_TYPE_WEIGHT.into()]);
+        assert_eq!(ret, 0.into());
+        // Only the gauge of the remaining type is left in the total weight
+        gauge_controller.checkpoint(owner, blocktime);
+        TestContract::new(
+            &env,
+            TEST_SESSION_CODE_WASM,
+            TEST_SESSION_CODE_NAME,
+            owner,
+            runtime_args! {
+                "entrypoint" => String::from(GET_TOTAL_WEIGHT),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash())
+            },
+            blocktime,
+        );
+        let ret: U256 = env.query_account_named_key(owner, &[GET_TOTAL_WEIGHT.into()]);
+        assert_eq!(ret, 2000000.into());
+        TestContract::new(
+            &env,
+            TEST_SESSION_CODE_WASM,
+            TEST_SESSION_CODE_NAME,
+            owner,
+            runtime_args! {
+                "entrypoint" => String::from(LIST_TYPES),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash())
+            },
+            blocktime,
+        );
+        let types: Vec<TypeInfo> = env.query_account_named_key(owner, &[LIST_TYPES.into()]);
+        assert!(types[0].deprecated);
+        assert!(!types[1].deprecated);
+    }
+
+    #[test]
+    fn test_gauge_controller_rename_type() {
+        let (_, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+        gauge_controller.rename_type(owner, (false, 0.into()), "stable".to_string(), blocktime);
+        assert_eq!(
+            gauge_controller.gauge_type_names::<Key>(0.into()),
+            "stable".to_string()
+        );
+    }
+    #[test]
+    fn test_gauge_controller_get_total_weight_by_user() {
+        let (
+            env,
+            gauge_controller,
+            _owner,
+            _token,
+            _voting_escrow,
+            blocktime,
+            liquidity_gauge,
+            liquidity_gauge_1,
+        ) = deploy();
+        let _user = env.next_user();
+        let _user1 = env.next_user();
+        assert_eq!(gauge_controller.token(), Key::Hash(_token.package_hash()));
+        assert_eq!(
+            gauge_controller.voting_escrow(),
+            Key::Hash(_voting_escrow.package_hash())
+        );
+        assert_eq!(gauge_controller.admin(), Key::from(_owner));
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        assert_eq!(
+            gauge_controller.time_total(),
+            U256::from(blocktime / week * week)
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(_owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            _owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(500.into()),
+            blocktime,
+        );
+        let name: String = "type2".to_string();
+        gauge_controller.add_type(_owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 1.into());
+        gauge_controller.add_gauge(
+            _owner,
+            liquidity_gauge_1,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        let type_id: (bool, U128) = (false, 1.into());
+        let weight: U256 = 2.into();
+        gauge_controller.change_type_weight(_owner, type_id, weight, blocktime);
+        TestContract::new(
+            &env,
+            TEST_SESSION_CODE_WASM,
+            TEST_SESSION_CODE_NAME,
+            _user,
+            runtime_args! {
+                "entrypoint" => String::from(GET_TOTAL_WEIGHT),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "type_id"=>type_id
+            },
+            blocktime,
+        );
+        let ret: U256 = env.query_account_named_key(_user, &[GET_TOTAL_WEIGHT.into()]);
+        assert_eq!(ret, 2000000.into());
+    }
+
+    #[test]
+    fn test_gauge_controller_list_gauges_and_types() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        let name: String = "type2".to_string();
+        gauge_controller.add_type(owner, name, Some(200.into()), blocktime);
+        let gauge_type: (bool, U128) = (false, 1.into());
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge_1,
+            gauge_type,
+            Some(3000000.into()),
+            blocktime,
+        );
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        TestContract::new(
+            &env,
+            "gauge-controller-session-code.wasm",
+            "SessionCode",
+            owner,
+            runtime_args! {
+                "entrypoint" => String::from(LIST_GAUGES),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "offset" => U256::from(0),
+                "limit" => U256::from(10)
+            },
+            blocktime + week,
+        );
+        let gauges: Vec<GaugeInfo> = env.query_account_named_key(owner, &[LIST_GAUGES.into()]);
+        assert_eq!(gauges.len(), 2);
+        assert_eq!(gauges[0].gauge, liquidity_gauge);
+        assert_eq!(gauges[0].gauge_type, 0.into());
+        assert_eq!(gauges[0].next_weight, 1000000.into());
+        assert_eq!(gauges[1].gauge, liquidity_gauge_1);
+        assert_eq!(gauges[1].gauge_type, 1.into());
+        assert_eq!(gauges[1].weight, 3000000.into());
+        let relative_weight: U256 = gauge_relative_weight(
+            &env,
+            &gauge_controller,
+            owner,
+            GAUGE_RELATIVE_WEIGHT,
+            liquidity_gauge_1,
+            blocktime + week,
+        );
+        assert_eq!(gauges[1].relative_weight, relative_weight);
+        TestContract::new(
+            &env,
+            "gauge-controller-session-code.wasm",
+            "SessionCode",
+            owner,
+            runtime_args! {
+                "entrypoint" => String::from(LIST_GAUGES),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash()),
+                "offset" => U256::from(1),
+                "limit" => U256::from(10)
+            },
+            blocktime + week,
+        );
+        let gauges: Vec<GaugeInfo> = env.query_account_named_key(owner, &[LIST_GAUGES.into()]);
+        assert_eq!(gauges.len(), 1);
+        assert_eq!(gauges[0].gauge, liquidity_gauge_1);
+        TestContract::new(
+            &env,
+            "gauge-controller-session-code.wasm",
+            "SessionCode",
+            owner,
+            runtime_args! {
+                "entrypoint" => String::from(LIST_TYPES),
+                "package_hash" => Key::from(gauge_controller.contract_package_hash())
+            },
+            blocktime + week,
+        );
+        let types: Vec<TypeInfo> = env.query_account_named_key(owner, &[LIST_TYPES.into()]);
+        assert_eq!(types.len(), 2);
+        assert_eq!(types[0].name, "type");
+        assert_eq!(types[0].weight, 100.into());
+        assert_eq!(types[1].type_id, 1.into());
+        assert_eq!(types[1].name, "type2");
+        assert_eq!(types[1].weights_sum, 3000000.into());
+        assert!(!types[1].deprecated);
+    }
+}
+mod get_gauge_and_sum_per_type_weight_test_cases {
+    use crate::gauge_controller_tests::*;
+
+    #[test]
+    fn test_gauge_controller_get_gauge_weight() {
+        let (
+            env,
+            gauge_controller,
+            _owner,
+            _token,
+            _voting_escrow,
+            blocktime,
+            liquidity_gauge,
+            liquidity_gauge_1,
+        ) = deploy();
+        let _user = env.next_user();
+        let _user1 = env.next_user();
+        assert_eq!(gauge_controller.token(), Key::Hash(_token.package_hash()));
+        assert_eq!(
+            gauge_controller.voting_escrow(),
+            Key::Hash(_voting_escrow.package_hash())
+        );
+        assert_eq!(gauge_controller.admin(), Key::from(_owner));
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        assert_eq!(
+            gauge_controller.time_total(),
+            U256::from(blocktime / week * week)
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(_owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            _owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        let name: String = "type2".to_string();
+        gauge_controller.add_type(_owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 1.into());
+        gauge_controller.add_gauge(
+            _owner,
+            liquidity_gauge_1,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        let type_id: (bool, U128) = (false, 1.into());
+        let weight: U256 = 2.into();
+        gauge_controller.change_type_weight(_owner, type_id, weight, blocktime);
+
+        TestContract::new(
+            &env,
+            TEST_SESSION_CODE_WASM,
+            TEST_SESSION_CODE_NAME,
+            _owner,
+            runtime_args! {
+                "entrypoint" => String::from(GET_GAUGE_WEIGHT),
                 "package_hash" => Key::from(gauge_controller.contract_package_hash()),
                 "addr"=>liquidity_gauge_1
             },
@@ -1823,13 +2798,65 @@ mod add_gauge_function_test_cases {
     }
 
     #[test]
-    fn test_gauge_controller_add_gauge_multiple_time() {
-        let (
-            env,
-            gauge_controller,
-            _owner,
-            _token,
-            _voting_escrow,
+    fn test_gauge_controller_add_gauge_checking_controller() {
+        let (_, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        assert!(!gauge_controller.check_gauge_controller());
+        gauge_controller.set_check_gauge_controller(owner, true, blocktime);
+        assert!(gauge_controller.check_gauge_controller());
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        assert_eq!(gauge_controller.n_gauges(), (false, 1.into()));
+    }
+
+    #[test]
+    fn test_gauge_controller_add_gauge_checkpoints_controller() {
+        let (env, gauge_controller, owner, token, _, blocktime, _, _) = deploy();
+        let erc20_crv = GAUGECONLTROLLERInstance::deploy_erc20_crv(&env, owner, blocktime);
+        let minter = GAUGECONLTROLLERInstance::minter(
+            &env,
+            "Minter",
+            owner,
+            Key::Hash(erc20_crv.package_hash()),
+            Key::from(gauge_controller.contract_package_hash()),
+            blocktime,
+        );
+        let liquidity_gauge = GAUGECONLTROLLERInstance::deploy_liquidity_gauge(
+            &env,
+            "Liquidity Gauge",
+            owner,
+            Key::Hash(token.package_hash()),
+            Key::Hash(minter.package_hash()),
+            Key::Account(owner),
+            blocktime,
+        );
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            Key::Hash(liquidity_gauge.package_hash()),
+            gauge_type,
+            None,
+            blocktime,
+        );
+        // The addition checked the gauge by checkpointing the controller in it
+        let checkpoint: U256 = liquidity_gauge
+            .query_dictionary(
+                INTEGRATE_CHECKPOINT_OF,
+                key_to_str(&Key::from(gauge_controller.contract_package_hash())),
+            )
+            .unwrap_or_default();
+        assert_eq!(checkpoint, U256::from(blocktime));
+    }
+
+    #[test]
+    fn test_gauge_controller_add_gauge_multiple_time() {
+        let (
+            env,
+            gauge_controller,
+            _owner,
+            _token,
+            _voting_escrow,
             blocktime,
             liquidity_gauge,
             liquidity_gauge_1,
@@ -2029,6 +3056,355 @@ mod panic_test_cases_1 {
     }
     #[test]
     #[should_panic]
+    fn test_gauge_controller_vote_for_killed_gauge() {
+        let (
+            _env,
+            gauge_controller,
+            owner,
+            token,
+            voting_escrow,
+            blocktime,
+            liquidity_gauge,
+            liquidity_gauge_1,
+        ) = deploy();
+        let value: U256 = 10000000000_u128.into();
+        let unlock_time: U256 = VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + blocktime;
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Key::Account(owner),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        token.call_contract(
+            owner,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(voting_escrow.package_hash()),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        voting_escrow.call_contract(
+            owner,
+            "create_lock",
+            runtime_args! {
+                "value" => value,
+                "unlock_time" => unlock_time
+            },
+            blocktime,
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.kill_gauge(owner, liquidity_gauge, blocktime);
+        gauge_controller.vote_for_gauge_weights(owner, liquidity_gauge, 5000.into(), blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_kill_gauge_by_user() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let user = env.next_user();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.kill_gauge(user, liquidity_gauge, blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_change_killed_gauge_weight() {
+        let (_, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.kill_gauge(owner, liquidity_gauge, blocktime);
+        gauge_controller.change_gauge_weight(owner, liquidity_gauge, 2.into(), blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_set_max_relative_weight_by_user() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let user = env.next_user();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.set_max_relative_weight(
+            user,
+            liquidity_gauge,
+            1000.into(),
+            None,
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_set_max_relative_weight_above_one() {
+        let (_, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge,
+            1000000001.into(),
+            None,
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_set_max_relative_weight_killed_receiver() {
+        let (_, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge_1, gauge_type, None, blocktime);
+        gauge_controller.kill_gauge(owner, liquidity_gauge_1, blocktime);
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge,
+            1000.into(),
+            Some(liquidity_gauge_1),
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_set_max_relative_weight_capped_receiver() {
+        let (_, gauge_controller, owner, _, _, blocktime, liquidity_gauge, liquidity_gauge_1) =
+            deploy();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge_1, gauge_type, None, blocktime);
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge,
+            1000.into(),
+            Some(liquidity_gauge_1),
+            blocktime,
+        );
+        gauge_controller.set_max_relative_weight(
+            owner,
+            liquidity_gauge_1,
+            1000.into(),
+            None,
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_checkpoint_stalled_controller() {
+        let (env, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let user = env.next_user();
+        let week: u64 = VOTING_ESCROW_WEEK.as_u64();
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, Some(100.into()), blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            liquidity_gauge,
+            gauge_type,
+            Some(1000000.into()),
+            blocktime,
+        );
+        let next_week: u64 = (blocktime + week) / week * week;
+        assert_eq!(gauge_controller.time_total(), next_week.into());
+        gauge_controller.checkpoint(user, blocktime + 600 * week);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_vote_for_many_gauge_weights_too_much_power() {
+        let (
+            _env,
+            gauge_controller,
+            owner,
+            token,
+            voting_escrow,
+            blocktime,
+            liquidity_gauge,
+            liquidity_gauge_1,
+        ) = deploy();
+        let value: U256 = 10000000000_u128.into();
+        let unlock_time: U256 = VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + blocktime;
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Key::Account(owner),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        token.call_contract(
+            owner,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(voting_escrow.package_hash()),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        voting_escrow.call_contract(
+            owner,
+            "create_lock",
+            runtime_args! {
+                "value" => value,
+                "unlock_time" => unlock_time
+            },
+            blocktime,
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge_1, gauge_type, None, blocktime);
+        gauge_controller.vote_for_many_gauge_weights(
+            owner,
+            vec![liquidity_gauge, liquidity_gauge_1],
+            vec![6000.into(), 5000.into()],
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_vote_for_many_gauge_weights_length_mismatch() {
+        let (
+            _env,
+            gauge_controller,
+            owner,
+            token,
+            voting_escrow,
+            blocktime,
+            liquidity_gauge,
+            liquidity_gauge_1,
+        ) = deploy();
+        let value: U256 = 10000000000_u128.into();
+        let unlock_time: U256 = VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + blocktime;
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Key::Account(owner),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        token.call_contract(
+            owner,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(voting_escrow.package_hash()),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        voting_escrow.call_contract(
+            owner,
+            "create_lock",
+            runtime_args! {
+                "value" => value,
+                "unlock_time" => unlock_time
+            },
+            blocktime,
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge_1, gauge_type, None, blocktime);
+        gauge_controller.vote_for_many_gauge_weights(
+            owner,
+            vec![liquidity_gauge, liquidity_gauge_1],
+            vec![5000.into()],
+            blocktime,
+        );
+    }
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_vote_for_gauge_weights_for_without_delegation() {
+        let (env, gauge_controller, owner, token, voting_escrow, blocktime, liquidity_gauge, _) =
+            deploy();
+        let user = env.next_user();
+        let value: U256 = 10000000000_u128.into();
+        let unlock_time: U256 = VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + VOTING_ESCROW_WEEK
+            + blocktime;
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Key::Account(owner),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        token.call_contract(
+            owner,
+            "approve",
+            runtime_args! {
+                "spender" => Key::Hash(voting_escrow.package_hash()),
+                "amount" => value + value
+            },
+            blocktime,
+        );
+        voting_escrow.call_contract(
+            owner,
+            "create_lock",
+            runtime_args! {
+                "value" => value,
+                "unlock_time" => unlock_time
+            },
+            blocktime,
+        );
+        let name: String = "type".to_string();
+        gauge_controller.add_type(owner, name, None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+        let weight: U256 = 1000.into();
+        gauge_controller.vote_for_gauge_weights_for(
+            user,
+            Key::Account(owner),
+            liquidity_gauge,
+            weight,
+            blocktime,
+        );
+    }
+    #[test]
+    #[should_panic]
     fn test_gauge_controller_gauge_types_without_adding_gauge_types() {
         let (
             env,
@@ -2094,6 +3470,131 @@ mod panic_test_cases_1 {
 }
 mod panic_test_cases_2 {
     use crate::gauge_controller_tests::*;
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_add_gauge_to_deprecated_type() {
+        let (_, gauge_controller, owner, _, _, blocktime, liquidity_gauge, _) = deploy();
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_type(owner, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.deprecate_type(owner, gauge_type, blocktime);
+        gauge_controller.add_gauge(owner, liquidity_gauge, gauge_type, None, blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_change_deprecated_type_weight() {
+        let (_, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        let type_id: (bool, U128) = (false, 0.into());
+        gauge_controller.add_type(owner, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.deprecate_type(owner, type_id, blocktime);
+        gauge_controller.change_type_weight(owner, type_id, 2.into(), blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_add_type_over_max_active_types() {
+        let (_, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        for i in 0..MAX_ACTIVE_TYPES {
+            gauge_controller.add_type(owner, format!("type{}", i), None, blocktime);
+        }
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_deprecate_type_twice() {
+        let (_, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        let type_id: (bool, U128) = (false, 0.into());
+        gauge_controller.add_type(owner, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.deprecate_type(owner, type_id, blocktime);
+        gauge_controller.deprecate_type(owner, type_id, blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_deprecate_type_by_user() {
+        let (env, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        gauge_controller.add_type(owner, "type".to_string(), Some(1.into()), blocktime);
+        gauge_controller.deprecate_type(env.next_user(), (false, 0.into()), blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_rename_type_by_user() {
+        let (env, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+        gauge_controller.rename_type(
+            env.next_user(),
+            (false, 0.into()),
+            "stable".to_string(),
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_rename_missing_type() {
+        let (_, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        gauge_controller.rename_type(owner, (false, 0.into()), "stable".to_string(), blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_add_gauge_account() {
+        let (env, gauge_controller, owner, _, _, blocktime, _, _) = deploy();
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            Key::Account(env.next_user()),
+            gauge_type,
+            None,
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_add_gauge_not_gauge() {
+        let (_, gauge_controller, owner, _, voting_escrow, blocktime, _, _) = deploy();
+        gauge_controller.add_type(owner, "type".to_string(), None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(
+            owner,
+            Key::Hash(voting_escrow.package_hash()),
+            gauge_type,
+            None,
+            blocktime,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_add_gauge_of_other_controller() {
+        let (env, _, _, token, voting_escrow, blocktime, liquidity_gauge, _) = deploy();
+        let admin = env.next_user();
+        let gauge_controller =
+            GAUGECONLTROLLERInstance::instance(GAUGECONLTROLLERInstance::new_deploy(
+                &env,
+                NAME,
+                admin,
+                Key::Hash(token.package_hash()),
+                Key::Hash(voting_escrow.package_hash()),
+                blocktime,
+            ));
+        gauge_controller.set_check_gauge_controller(admin, true, blocktime);
+        gauge_controller.add_type(admin, "type".to_string(), None, blocktime);
+        let gauge_type: (bool, U128) = (false, 0.into());
+        gauge_controller.add_gauge(admin, liquidity_gauge, gauge_type, None, blocktime);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gauge_controller_set_check_gauge_controller_by_user() {
+        let (env, gauge_controller, _, _, _, blocktime, _, _) = deploy();
+        gauge_controller.set_check_gauge_controller(env.next_user(), true, blocktime);
+    }
+
     #[test]
     #[should_panic]
     fn test_gauge_controller_apply_transfer_ownership_without_commiting_transfer_ownership() {
@@ -4,20 +4,19 @@
 #[macro_use]
 extern crate alloc;
 
-use alloc::{boxed::Box, collections::BTreeSet, format, string::String};
+use alloc::{boxed::Box, collections::BTreeSet, format, string::String, vec::Vec};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
     runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U128,
-    U256,
+    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
 };
 use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
 use gauge_controller_crate::{
-    data::{Point, VotedSlope},
-    utils::*,
+    data::{GaugeInfo, Point, TypeInfo, VotedSlope},
+    int128::I128,
     GAUGECONLTROLLER,
 };
 
@@ -82,7 +81,7 @@ fn apply_transfer_ownership() {
 fn gauge_types() {
     let addr: Key = runtime::get_named_arg::<Key>("addr");
     let ret: i128 = Token::default().gauge_types(addr);
-    runtime::ret(CLValue::from_t(i128_to_tuple(ret)).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(I128::from(ret)).unwrap_or_revert());
 }
 
 /// @notice Checkpoint to fill data common for all gauges
@@ -101,6 +100,26 @@ fn checkpoint_gauge() {
     Token::default().checkpoint_gauge(addr);
 }
 
+/// @notice Fill at most `max_weeks` missed weeks of the data common for all gauges
+/// @param max_weeks Number of weeks to fill, at most 500
+
+#[no_mangle]
+fn checkpoint_partial() {
+    let max_weeks: U256 = runtime::get_named_arg("max_weeks");
+    Token::default().checkpoint_partial(max_weeks);
+}
+
+/// @notice Fill at most `max_weeks` missed weeks of the weight of a specific gauge
+/// @param addr Gauge address
+/// @param max_weeks Number of weeks to fill, at most 500
+
+#[no_mangle]
+fn checkpoint_gauge_partial() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let max_weeks: U256 = runtime::get_named_arg("max_weeks");
+    Token::default().checkpoint_gauge_partial(addr, max_weeks);
+}
+
 #[no_mangle]
 fn package_hash() {
     let ret: ContractPackageHash = Token::default().get_package_hash();
@@ -142,10 +161,10 @@ fn gauge_relative_weight_write() {
 
 #[no_mangle]
 fn change_type_weight() {
-    let type_id: (bool, U128) = runtime::get_named_arg("type_id");
+    let type_id: I128 = runtime::get_named_arg("type_id");
     let weight: U256 = runtime::get_named_arg("weight");
 
-    Token::default().change_type_weight(tuple_to_i128(type_id), weight);
+    Token::default().change_type_weight(type_id.into(), weight);
 }
 
 /// @notice Change weight of gauge `addr` to `weight`
@@ -177,8 +196,8 @@ fn get_gauge_weight() {
 
 #[no_mangle]
 fn get_type_weight() {
-    let type_id: (bool, U128) = runtime::get_named_arg("type_id");
-    let ret: U256 = Token::default().get_type_weight(tuple_to_i128(type_id));
+    let type_id: I128 = runtime::get_named_arg("type_id");
+    let ret: U256 = Token::default().get_type_weight(type_id.into());
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
@@ -197,16 +216,16 @@ fn get_total_weight() {
 
 #[no_mangle]
 fn get_weights_sum_per_type() {
-    let type_id: (bool, U128) = runtime::get_named_arg("type_id");
-    let ret: U256 = Token::default().get_weights_sum_per_type(tuple_to_i128(type_id));
+    let type_id: I128 = runtime::get_named_arg("type_id");
+    let ret: U256 = Token::default().get_weights_sum_per_type(type_id.into());
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
 fn changes_sum() {
-    let owner: (bool, U128) = runtime::get_named_arg("owner");
+    let owner: I128 = runtime::get_named_arg("owner");
     let spender: U256 = runtime::get_named_arg("spender");
-    let ret: U256 = Token::default().changes_sum(tuple_to_i128(owner), spender);
+    let ret: U256 = Token::default().changes_sum(owner.into(), spender);
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 #[no_mangle]
@@ -218,8 +237,8 @@ fn changes_weight() {
 }
 #[no_mangle]
 fn gauge_type_names() {
-    let owner: (bool, U128) = runtime::get_named_arg("owner");
-    let ret: String = Token::default().gauge_type_names(tuple_to_i128(owner));
+    let owner: I128 = runtime::get_named_arg("owner");
+    let ret: String = Token::default().gauge_type_names(owner.into());
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
@@ -230,7 +249,7 @@ fn gauge_type_names() {
 fn gauge_types_() {
     let owner: Key = runtime::get_named_arg("owner");
     let ret: i128 = Token::default().gauge_types_(owner);
-    runtime::ret(CLValue::from_t(i128_to_tuple(ret)).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(I128::from(ret)).unwrap_or_revert());
 }
 #[no_mangle]
 fn gauges() {
@@ -247,9 +266,9 @@ fn last_user_vote() {
 }
 #[no_mangle]
 fn points_sum() {
-    let owner: (bool, U128) = runtime::get_named_arg("owner");
+    let owner: I128 = runtime::get_named_arg("owner");
     let spender: U256 = runtime::get_named_arg("spender");
-    let ret: Point = Token::default().points_sum(tuple_to_i128(owner), spender);
+    let ret: Point = Token::default().points_sum(owner.into(), spender);
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 #[no_mangle]
@@ -260,9 +279,9 @@ fn points_total() {
 }
 #[no_mangle]
 fn points_type_weight() {
-    let owner: (bool, U128) = runtime::get_named_arg("owner");
+    let owner: I128 = runtime::get_named_arg("owner");
     let spender: U256 = runtime::get_named_arg("spender");
-    let ret: U256 = Token::default().points_type_weight(tuple_to_i128(owner), spender);
+    let ret: U256 = Token::default().points_type_weight(owner.into(), spender);
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 #[no_mangle]
@@ -333,12 +352,12 @@ fn voting_escrow() {
 #[no_mangle]
 fn n_gauge_types() {
     let ret: i128 = Token::default().n_gauge_types();
-    runtime::ret(CLValue::from_t(i128_to_tuple(ret)).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(I128::from(ret)).unwrap_or_revert());
 }
 #[no_mangle]
 fn n_gauges() {
     let ret: i128 = Token::default().n_gauges();
-    runtime::ret(CLValue::from_t(i128_to_tuple(ret)).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(I128::from(ret)).unwrap_or_revert());
 }
 
 /// @notice Add gauge type with name `_name` and weight `weight`
@@ -352,6 +371,31 @@ fn add_type() {
     Token::default().add_type(name, weight);
 }
 
+/// @notice Rename gauge type `type_id` to `name`
+/// @param type_id Gauge type id
+/// @param name New name of the type
+#[no_mangle]
+fn rename_type() {
+    let type_id: I128 = runtime::get_named_arg("type_id");
+    let name: String = runtime::get_named_arg("name");
+    Token::default().rename_type(type_id.into(), name);
+}
+
+/// @notice Deprecate gauge type `type_id`, its weight drops to zero from the next week
+/// @param type_id Gauge type id
+#[no_mangle]
+fn deprecate_type() {
+    let type_id: I128 = runtime::get_named_arg("type_id");
+    Token::default().deprecate_type(type_id.into());
+}
+
+#[no_mangle]
+fn is_deprecated() {
+    let type_id: I128 = runtime::get_named_arg("type_id");
+    let ret: bool = Token::default().is_deprecated(type_id.into());
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 /// @notice Add gauge `addr` of type `gauge_type` with weight `weight`
 /// @param addr Gauge address
 /// @param gauge_type Gauge type
@@ -360,9 +404,64 @@ fn add_type() {
 #[no_mangle]
 fn add_gauge() {
     let addr: Key = runtime::get_named_arg("addr");
-    let gauge_type: (bool, U128) = runtime::get_named_arg("gauge_type");
+    let gauge_type: I128 = runtime::get_named_arg("gauge_type");
     let weight: Option<U256> = runtime::get_named_arg("weight");
-    Token::default().add_gauge(addr, tuple_to_i128(gauge_type), weight);
+    Token::default().add_gauge(addr, gauge_type.into(), weight);
+}
+
+/// @notice Require gauges added from now on to point back at this controller
+/// @param check Whether the controller of new gauges is checked
+#[no_mangle]
+fn set_check_gauge_controller() {
+    let check: bool = runtime::get_named_arg("check");
+    Token::default().set_check_gauge_controller(check);
+}
+
+#[no_mangle]
+fn check_gauge_controller() {
+    let ret: bool = Token::default().check_gauge_controller();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Retire gauge `addr`, its weight drops to zero from the next week
+/// @param addr Gauge address
+#[no_mangle]
+fn kill_gauge() {
+    let addr: Key = runtime::get_named_arg("addr");
+    Token::default().kill_gauge(addr);
+}
+
+#[no_mangle]
+fn is_killed() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: bool = Token::default().is_killed(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Cap the relative weight of gauge `addr` to `max_relative_weight`
+/// @param addr Gauge address
+/// @param max_relative_weight Maximum relative weight normalized to 1e9, 0 removes the cap
+/// @param receiver Uncapped gauge receiving the weight above the cap
+#[no_mangle]
+fn set_max_relative_weight() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let max_relative_weight: U256 = runtime::get_named_arg("max_relative_weight");
+    let receiver: Option<Key> = runtime::get_named_arg("receiver");
+    Token::default().set_max_relative_weight(addr, max_relative_weight, receiver);
+}
+
+#[no_mangle]
+fn max_relative_weight() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: U256 = Token::default().max_relative_weight(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn cap_receiver() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: Key = Token::default().cap_receiver(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 /// @notice Allocate voting power for changing pool weights
@@ -375,6 +474,66 @@ fn vote_for_gauge_weights() {
     Token::default().vote_for_gauge_weights(gauge_addr, user_weight);
 }
 
+#[no_mangle]
+fn vote_for_many_gauge_weights() {
+    let gauges: Vec<Key> = runtime::get_named_arg("gauges");
+    let weights: Vec<U256> = runtime::get_named_arg("weights");
+    Token::default().vote_for_many_gauge_weights(gauges, weights);
+}
+
+#[no_mangle]
+fn vote_for_gauge_weights_for() {
+    let user: Key = runtime::get_named_arg("user");
+    let gauge_addr: Key = runtime::get_named_arg("gauge_addr");
+    let user_weight: U256 = runtime::get_named_arg("user_weight");
+    Token::default().vote_for_gauge_weights_for(user, gauge_addr, user_weight);
+}
+
+#[no_mangle]
+fn set_vote_delegate() {
+    let delegate: Key = runtime::get_named_arg("delegate");
+    Token::default().set_vote_delegate(delegate);
+}
+
+#[no_mangle]
+fn vote_delegate() {
+    let user: Key = runtime::get_named_arg("user");
+    let ret: Key = Token::default().vote_delegate(user);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice List at most `limit` gauges starting from index `offset`
+/// @param offset Index of the first gauge
+/// @param limit Maximum number of gauges
+/// @return Address, type, current and next week weights and relative weight of each gauge
+#[no_mangle]
+fn list_gauges() {
+    let offset: U256 = runtime::get_named_arg("offset");
+    let limit: U256 = runtime::get_named_arg("limit");
+    let ret: Vec<GaugeInfo> = Token::default().list_gauges(offset, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice List all gauge types
+/// @return Id, name, weight and sum of gauge weights of each type
+#[no_mangle]
+fn list_types() {
+    let ret: Vec<TypeInfo> = Token::default().list_types();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Forecast the CRV emitted to gauge `addr` in each of the next `weeks` weeks
+/// @param addr Gauge address
+/// @param weeks Number of weeks, starting with the current one
+/// @return Emission of the gauge in each week
+#[no_mangle]
+fn emissions_forecast() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let weeks: U256 = runtime::get_named_arg("weeks");
+    let ret: Vec<U256> = Token::default().emissions_forecast(addr, weeks);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn call() {
     // Contract name must be same for all new versions of the contracts
@@ -504,10 +663,27 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "checkpoint_partial",
+        vec![Parameter::new("max_weeks", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "checkpoint_gauge_partial",
+        vec![
+            Parameter::new("addr", Key::cl_type()),
+            Parameter::new("max_weeks", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "gauge_types",
         vec![Parameter::new("addr", Key::cl_type())],
-        CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
+        I128::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
@@ -521,14 +697,14 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "n_gauge_types",
         vec![],
-        CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
+        I128::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
         "n_gauges",
         vec![],
-        CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
+        I128::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
@@ -626,10 +802,7 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "points_type_weight",
         vec![
-            Parameter::new(
-                "owner",
-                CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-            ),
+            Parameter::new("owner", I128::cl_type()),
             Parameter::new("spender", U256::cl_type()),
         ],
         U256::cl_type(),
@@ -646,10 +819,7 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "points_sum",
         vec![
-            Parameter::new(
-                "owner",
-                CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-            ),
+            Parameter::new("owner", I128::cl_type()),
             Parameter::new("spender", U256::cl_type()),
         ],
         Point::cl_type(),
@@ -676,16 +846,13 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "gauge_types_",
         vec![Parameter::new("owner", Key::cl_type())],
-        CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
+        I128::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
         "gauge_type_names",
-        vec![Parameter::new(
-            "owner",
-            CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-        )],
+        vec![Parameter::new("owner", I128::cl_type())],
         String::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -703,10 +870,7 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "changes_sum",
         vec![
-            Parameter::new(
-                "owner",
-                CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-            ),
+            Parameter::new("owner", I128::cl_type()),
             Parameter::new("spender", U256::cl_type()),
         ],
         U256::cl_type(),
@@ -737,10 +901,7 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "change_type_weight",
         vec![
-            Parameter::new(
-                "type_id",
-                CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-            ),
+            Parameter::new("type_id", I128::cl_type()),
             Parameter::new("weight", U256::cl_type()),
         ],
         <()>::cl_type(),
@@ -766,10 +927,7 @@ fn get_entry_points() -> EntryPoints {
     ));
     entry_points.add_entry_point(EntryPoint::new(
         "get_type_weight",
-        vec![Parameter::new(
-            "type_id",
-            CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-        )],
+        vec![Parameter::new("type_id", I128::cl_type())],
         U256::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -783,10 +941,7 @@ fn get_entry_points() -> EntryPoints {
     ));
     entry_points.add_entry_point(EntryPoint::new(
         "get_weights_sum_per_type",
-        vec![Parameter::new(
-            "type_id",
-            CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-        )],
+        vec![Parameter::new("type_id", I128::cl_type())],
         U256::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -801,20 +956,94 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "rename_type",
+        vec![
+            Parameter::new("type_id", I128::cl_type()),
+            Parameter::new("name", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "deprecate_type",
+        vec![Parameter::new("type_id", I128::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_deprecated",
+        vec![Parameter::new("type_id", I128::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "add_gauge",
         vec![
             Parameter::new("addr", Key::cl_type()),
-            Parameter::new(
-                "type_id",
-                CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-            ),
+            Parameter::new("type_id", I128::cl_type()),
             Parameter::new("weight", CLType::Option(Box::new(U256::cl_type()))),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_check_gauge_controller",
+        vec![Parameter::new("check", bool::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "check_gauge_controller",
+        vec![],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "kill_gauge",
+        vec![Parameter::new("addr", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_killed",
+        vec![Parameter::new("addr", Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_relative_weight",
+        vec![
+            Parameter::new("addr", Key::cl_type()),
+            Parameter::new("max_relative_weight", U256::cl_type()),
+            Parameter::new("receiver", CLType::Option(Box::new(Key::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_relative_weight",
+        vec![Parameter::new("addr", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cap_receiver",
+        vec![Parameter::new("addr", Key::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "vote_for_gauge_weights",
         vec![
@@ -825,5 +1054,67 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "vote_for_many_gauge_weights",
+        vec![
+            Parameter::new("gauges", CLType::List(Box::new(Key::cl_type()))),
+            Parameter::new("weights", CLType::List(Box::new(U256::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "vote_for_gauge_weights_for",
+        vec![
+            Parameter::new("user", Key::cl_type()),
+            Parameter::new("gauge_addr", Key::cl_type()),
+            Parameter::new("user_weight", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_vote_delegate",
+        vec![Parameter::new("delegate", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "vote_delegate",
+        vec![Parameter::new("user", Key::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "list_gauges",
+        vec![
+            Parameter::new("offset", U256::cl_type()),
+            Parameter::new("limit", U256::cl_type()),
+        ],
+        CLType::List(Box::new(GaugeInfo::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "list_types",
+        vec![],
+        CLType::List(Box::new(TypeInfo::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "emissions_forecast",
+        vec![
+            Parameter::new("addr", Key::cl_type()),
+            Parameter::new("weeks", U256::cl_type()),
+        ],
+        CLType::List(Box::new(U256::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points
 }
@@ -4,7 +4,7 @@
 // We need to explicitly import the std alloc crate and `alloc::string::String` as we're in a
 // `no_std` environment.
 extern crate alloc;
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
@@ -13,6 +13,7 @@ use casper_types::{
     bytesrepr::ToBytes, runtime_args, ApiError, CLTyped, Key, RuntimeArgs, URef, U256,
 };
 use common::keys::*;
+use gauge_controller_crate::data::{GaugeInfo, TypeInfo};
 
 // Key is the same a destination
 fn store<T: CLTyped + ToBytes>(key: &str, value: T) {
@@ -46,6 +47,43 @@ pub extern "C" fn call() {
             );
             store(GAUGE_RELATIVE_WEIGHT_WRITE, ret);
         }
+        LIST_GAUGES => {
+            let offset: U256 = runtime::get_named_arg("offset");
+            let limit: U256 = runtime::get_named_arg("limit");
+            let ret: Vec<GaugeInfo> = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                LIST_GAUGES,
+                runtime_args! {
+                    "offset" => offset,
+                    "limit" => limit
+                },
+            );
+            store(LIST_GAUGES, ret);
+        }
+        LIST_TYPES => {
+            let ret: Vec<TypeInfo> = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                LIST_TYPES,
+                runtime_args! {},
+            );
+            store(LIST_TYPES, ret);
+        }
+        EMISSIONS_FORECAST => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let weeks: U256 = runtime::get_named_arg("weeks");
+            let ret: Vec<U256> = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                EMISSIONS_FORECAST,
+                runtime_args! {
+                    "addr" => addr,
+                    "weeks" => weeks
+                },
+            );
+            store(EMISSIONS_FORECAST, ret);
+        }
         _ => runtime::revert(ApiError::UnexpectedKeyVariant),
     };
 }
@@ -0,0 +1,139 @@
+use alloc::string::ToString;
+use casper_types::{ContractHash, ContractPackageHash, Key, U256};
+use casperlabs_contract_utils::*;
+use common::{keys::*, utils::*};
+
+pub struct GaugeOf {
+    dict: Dict,
+}
+
+impl GaugeOf {
+    #[inline(always)]
+    pub fn instance() -> GaugeOf {
+        GaugeOf {
+            dict: Dict::instance(GAUGE_OF_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(GAUGE_OF_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, lp_token: &Key) -> Key {
+        self.dict
+            .get(&key_to_str(lp_token))
+            .unwrap_or_else(zero_address)
+    }
+    #[inline(always)]
+    pub fn set(&self, lp_token: &Key, gauge: Key) {
+        self.dict.set(&key_to_str(lp_token), gauge);
+    }
+}
+
+pub struct IsFactoryGauge {
+    dict: Dict,
+}
+
+impl IsFactoryGauge {
+    #[inline(always)]
+    pub fn instance() -> IsFactoryGauge {
+        IsFactoryGauge {
+            dict: Dict::instance(IS_FACTORY_GAUGE_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(IS_FACTORY_GAUGE_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, gauge: &Key) -> bool {
+        self.dict.get(&key_to_str(gauge)).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, gauge: &Key, value: bool) {
+        self.dict.set(&key_to_str(gauge), value);
+    }
+}
+
+pub struct FactoryGauges {
+    dict: Dict,
+}
+
+impl FactoryGauges {
+    #[inline(always)]
+    pub fn instance() -> FactoryGauges {
+        FactoryGauges {
+            dict: Dict::instance(FACTORY_GAUGES_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(FACTORY_GAUGES_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, index: &U256) -> Key {
+        self.dict
+            .get(&index.to_string())
+            .unwrap_or_else(zero_address)
+    }
+    #[inline(always)]
+    pub fn set(&self, index: &U256, gauge: Key) {
+        self.dict.set(&index.to_string(), gauge);
+    }
+}
+
+pub fn get_gauge_count() -> U256 {
+    get_key(FACTORY_GAUGE_COUNT).unwrap_or_default()
+}
+
+pub fn set_gauge_count(gauge_count: U256) {
+    set_key(FACTORY_GAUGE_COUNT, gauge_count);
+}
+
+pub fn get_minter() -> Key {
+    get_key(MINTER).unwrap_or_else(zero_address)
+}
+
+pub fn set_minter(minter: Key) {
+    set_key(MINTER, minter);
+}
+
+pub fn get_controller() -> Key {
+    get_key(CONTROLLER).unwrap_or_else(zero_address)
+}
+
+pub fn set_controller(controller: Key) {
+    set_key(CONTROLLER, controller);
+}
+
+pub fn get_admin() -> Key {
+    get_key(ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_admin(admin: Key) {
+    set_key(ADMIN, admin);
+}
+
+pub fn get_future_admin() -> Key {
+    get_key(FUTURE_ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_admin(future_admin: Key) {
+    set_key(FUTURE_ADMIN, future_admin);
+}
+
+pub fn get_contract_hash() -> ContractHash {
+    get_key(SELF_CONTRACT_HASH).unwrap_or_default()
+}
+
+pub fn set_contract_hash(contract_hash: ContractHash) {
+    set_key(SELF_CONTRACT_HASH, contract_hash);
+}
+
+pub fn get_package_hash() -> ContractPackageHash {
+    get_key(SELF_CONTRACT_PACKAGE_HASH).unwrap_or_default()
+}
+
+pub fn set_package_hash(package_hash: ContractPackageHash) {
+    set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
+}
@@ -0,0 +1,74 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key,
+};
+use common::events::{CasperEvent, Schema, Schemas};
+
+pub enum LiquidityGaugeFactoryEvent {
+    DeployedGauge {
+        lp_token: Key,
+        gauge: Key,
+        deployer: Key,
+    },
+    CommitOwnership {
+        admin: Key,
+    },
+    ApplyOwnership {
+        admin: Key,
+    },
+}
+
+impl LiquidityGaugeFactoryEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            LiquidityGaugeFactoryEvent::DeployedGauge {
+                lp_token: _,
+                gauge: _,
+                deployer: _,
+            } => "DeployedGauge",
+            LiquidityGaugeFactoryEvent::CommitOwnership { admin: _ } => "CommitOwnership",
+            LiquidityGaugeFactoryEvent::ApplyOwnership { admin: _ } => "ApplyOwnership",
+        }
+        .to_string()
+    }
+}
+
+impl CasperEvent for LiquidityGaugeFactoryEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            LiquidityGaugeFactoryEvent::DeployedGauge {
+                lp_token,
+                gauge,
+                deployer,
+            } => Ok([
+                lp_token.to_bytes()?,
+                gauge.to_bytes()?,
+                deployer.to_bytes()?,
+            ]
+            .concat()),
+            LiquidityGaugeFactoryEvent::CommitOwnership { admin } => admin.to_bytes(),
+            LiquidityGaugeFactoryEvent::ApplyOwnership { admin } => admin.to_bytes(),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "DeployedGauge",
+                Schema::new()
+                    .with_elem::<Key>("lp_token")
+                    .with_elem::<Key>("gauge")
+                    .with_elem::<Key>("deployer"),
+            )
+            .with("CommitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("ApplyOwnership", Schema::new().with_elem::<Key>("admin"))
+    }
+}
@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod data;
+pub mod event;
+mod liquidity_gauge_factory;
+
+pub use liquidity_gauge_factory::LIQUIDITYGAUGEFACTORY;
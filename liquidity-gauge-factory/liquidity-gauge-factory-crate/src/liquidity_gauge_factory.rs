@@ -0,0 +1,139 @@
+use crate::{
+    data::{self, FactoryGauges, GaugeOf, IsFactoryGauge},
+    event::LiquidityGaugeFactoryEvent,
+};
+use alloc::{collections::BTreeSet, string::ToString};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, URef, U256,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use common::{errors::*, events, utils::*};
+use crv20::CURVEERC20;
+use liquidity_gauge_v4_crate::entry_points::get_entry_points;
+
+/// @notice Permissionless deployment of liquidity gauges
+/// @dev Each gauge is a new package holding a version of the code of this contract, which also
+///      carries the entry points of `LiquidityGaugeV4`. An lp token gets a single gauge, whose
+///      admin is the admin of the factory at deployment. The admin of the gauge controller adds
+///      factory gauges to it directly, checking them against `is_factory_gauge` first
+pub trait LIQUIDITYGAUGEFACTORY<Storage: ContractStorage>:
+    ContractContext<Storage> + CURVEERC20<Storage>
+{
+    fn init(
+        &mut self,
+        minter: Key,
+        admin: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        GaugeOf::init();
+        IsFactoryGauge::init();
+        FactoryGauges::init();
+        events::init::<LiquidityGaugeFactoryEvent>();
+        let controller: Key = runtime::call_versioned_contract(
+            minter.into_hash().unwrap_or_revert().into(),
+            None,
+            "controller",
+            runtime_args! {},
+        );
+        data::set_minter(minter);
+        data::set_controller(controller);
+        data::set_admin(admin);
+        data::set_gauge_count(0.into());
+        data::set_contract_hash(contract_hash);
+        data::set_package_hash(package_hash);
+    }
+
+    /// @notice Deploy a liquidity gauge for `lp_token`
+    /// @dev Callable by anyone, once per lp token
+    /// @return Package hash of the new gauge
+    #[inline(always)]
+    fn deploy_gauge(&mut self, lp_token: Key) -> Key {
+        if lp_token.into_hash().is_none() {
+            runtime::revert(ApiError::from(Error::LiquidityGaugeFactoryInvalidLpToken));
+        }
+        if GaugeOf::instance().get(&lp_token) != zero_address() {
+            runtime::revert(ApiError::from(
+                Error::LiquidityGaugeFactoryGaugeAlreadyDeployed,
+            ));
+        }
+        let (package_hash, _) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) = storage::add_contract_version(
+            package_hash,
+            get_entry_points(),
+            CURVEERC20::named_keys(self, "".to_string(), "".to_string(), 9, 0.into())
+                .unwrap_or_revert(),
+        );
+        // Add the constructor group to the new package with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+        let () = runtime::call_versioned_contract(
+            package_hash,
+            None,
+            "constructor",
+            runtime_args! {
+                "lp_addr" => lp_token,
+                "minter" => data::get_minter(),
+                "admin" => data::get_admin(),
+                "contract_hash" => contract_hash,
+                "package_hash" => package_hash,
+            },
+        );
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        let gauge: Key = Key::from(package_hash);
+        GaugeOf::instance().set(&lp_token, gauge);
+        IsFactoryGauge::instance().set(&gauge, true);
+        let gauge_count: U256 = data::get_gauge_count();
+        FactoryGauges::instance().set(&gauge_count, gauge);
+        data::set_gauge_count(
+            gauge_count
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::LiquidityGaugeFactoryAdditionError1),
+        );
+        self.emit(&LiquidityGaugeFactoryEvent::DeployedGauge {
+            lp_token,
+            gauge,
+            deployer: self.get_caller(),
+        });
+        gauge
+    }
+
+    /// @notice Transfer ownership of the factory to `addr`
+    #[inline(always)]
+    fn commit_transfer_ownership(&mut self, addr: Key) {
+        if self.get_caller() != data::get_admin() {
+            runtime::revert(ApiError::from(Error::LiquidityGaugeFactoryOnlyAdmin));
+        }
+        data::set_future_admin(addr);
+        self.emit(&LiquidityGaugeFactoryEvent::CommitOwnership { admin: addr });
+    }
+
+    /// @notice Accept a pending ownership transfer
+    #[inline(always)]
+    fn accept_transfer_ownership(&mut self) {
+        let future_admin: Key = data::get_future_admin();
+        if self.get_caller() != future_admin {
+            runtime::revert(ApiError::from(Error::LiquidityGaugeFactoryOnlyFutureAdmin));
+        }
+        data::set_admin(future_admin);
+        self.emit(&LiquidityGaugeFactoryEvent::ApplyOwnership {
+            admin: future_admin,
+        });
+    }
+
+    fn emit(&self, liquidity_gauge_factory_event: &LiquidityGaugeFactoryEvent) {
+        events::emit(liquidity_gauge_factory_event);
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod liquidity_gauge_factory_tests;
+
+#[cfg(test)]
+pub mod liquidity_gauge_factory_instance;
@@ -0,0 +1,216 @@
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::{
+    keys::*,
+    utils::{key_to_str, zero_address},
+};
+use std::time::SystemTime;
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+pub fn deploy_erc20(env: &TestEnv, sender: AccountHash, name: &str, time: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "curve-erc20.wasm",
+        name,
+        sender,
+        runtime_args! {
+            "initial_supply" => U256::from(0),
+            "name" => name,
+            "symbol" => "ERC",
+            "decimals" => 9_u8
+        },
+        time,
+    )
+}
+
+pub fn deploy_erc20_crv(env: &TestEnv, sender: AccountHash, time: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "erc20-crv.wasm",
+        "erc20-crv",
+        sender,
+        runtime_args! {
+            "name" => "CRV",
+            "symbol" => "ERC20CRV",
+            "decimals" => 9_u8,
+        },
+        time,
+    )
+}
+
+pub fn deploy_voting_escrow(
+    env: &TestEnv,
+    sender: AccountHash,
+    token_addr: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "voting-escrow.wasm",
+        "Voting Escrow",
+        sender,
+        runtime_args! {
+            "token_addr" => token_addr,
+            "name" => "VotingEscrow".to_string(),
+            "symbol" => "VE".to_string(),
+            "version" => "1".to_string(),
+        },
+        time,
+    )
+}
+
+pub fn deploy_gauge_controller(
+    env: &TestEnv,
+    sender: AccountHash,
+    token: Key,
+    voting_escrow: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "gauge-controller-token.wasm",
+        "Gauge Controller",
+        sender,
+        runtime_args! {
+            "token" => token,
+            "voting_escrow" => voting_escrow,
+        },
+        time,
+    )
+}
+
+pub fn deploy_minter(
+    env: &TestEnv,
+    sender: AccountHash,
+    token: Key,
+    controller: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "minter-token.wasm",
+        "Minter",
+        sender,
+        runtime_args! {
+            "token" => token,
+            "controller" => controller,
+        },
+        time,
+    )
+}
+
+/// Adds a gauge type to the gauge controller
+pub fn add_gauge_type(gauge_controller: &TestContract, sender: AccountHash, time: u64) {
+    gauge_controller.call_contract(
+        sender,
+        "add_type",
+        runtime_args! {
+            "name" => "type".to_string(),
+            "weight" => Some(U256::from(1))
+        },
+        time,
+    );
+}
+
+pub struct LIQUIDITYGAUGEFACTORYInstance(TestContract);
+impl LIQUIDITYGAUGEFACTORYInstance {
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        minter: Key,
+        admin: Key,
+        time: u64,
+    ) -> LIQUIDITYGAUGEFACTORYInstance {
+        LIQUIDITYGAUGEFACTORYInstance(TestContract::new(
+            env,
+            "liquidity-gauge-factory.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "minter" => minter,
+                "admin" => admin,
+            },
+            time,
+        ))
+    }
+
+    pub fn deploy_gauge(&self, sender: AccountHash, lp_token: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "deploy_gauge",
+            runtime_args! {
+                "lp_token" => lp_token
+            },
+            time,
+        );
+    }
+
+    pub fn commit_transfer_ownership(&self, sender: AccountHash, addr: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "commit_transfer_ownership_lgf",
+            runtime_args! {
+                "addr" => addr
+            },
+            time,
+        );
+    }
+
+    pub fn accept_transfer_ownership(&self, sender: AccountHash, time: u64) {
+        self.0.call_contract(
+            sender,
+            "accept_transfer_ownership_lgf",
+            runtime_args! {},
+            time,
+        );
+    }
+
+    pub fn gauge_of(&self, lp_token: Key) -> Key {
+        self.0
+            .query_dictionary(GAUGE_OF_DICT, key_to_str(&lp_token))
+            .unwrap_or_else(zero_address)
+    }
+
+    pub fn is_factory_gauge(&self, gauge: Key) -> bool {
+        self.0
+            .query_dictionary(IS_FACTORY_GAUGE_DICT, key_to_str(&gauge))
+            .unwrap_or_default()
+    }
+
+    pub fn factory_gauges(&self, index: U256) -> Key {
+        self.0
+            .query_dictionary(FACTORY_GAUGES_DICT, index.to_string())
+            .unwrap_or_else(zero_address)
+    }
+
+    pub fn gauge_count(&self) -> U256 {
+        self.0.query_named_key(FACTORY_GAUGE_COUNT.to_string())
+    }
+
+    pub fn admin(&self) -> Key {
+        self.0.query_named_key(ADMIN.to_string())
+    }
+
+    pub fn future_admin(&self) -> Key {
+        self.0.query_named_key(FUTURE_ADMIN.to_string())
+    }
+
+    pub fn minter(&self) -> Key {
+        self.0.query_named_key(MINTER.to_string())
+    }
+
+    pub fn controller(&self) -> Key {
+        self.0.query_named_key(CONTROLLER.to_string())
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+}
@@ -0,0 +1,167 @@
+use crate::liquidity_gauge_factory_instance::*;
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U128, U256};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::{keys::*, utils::zero_address};
+
+const NAME: &str = "LIQUIDITYGAUGEFACTORY";
+
+fn deploy() -> (
+    TestEnv,
+    LIQUIDITYGAUGEFACTORYInstance,
+    TestContract,
+    TestContract,
+    TestContract,
+    AccountHash,
+    u64,
+) {
+    let time: u64 = now();
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let token = deploy_erc20(&env, owner, "token", time);
+    let lp_token = deploy_erc20(&env, owner, "lp_token", time);
+    let erc20_crv = deploy_erc20_crv(&env, owner, time);
+    let voting_escrow = deploy_voting_escrow(&env, owner, Key::Hash(token.package_hash()), time);
+    let gauge_controller = deploy_gauge_controller(
+        &env,
+        owner,
+        Key::Hash(erc20_crv.package_hash()),
+        Key::Hash(voting_escrow.package_hash()),
+        time,
+    );
+    let minter = deploy_minter(
+        &env,
+        owner,
+        Key::Hash(erc20_crv.package_hash()),
+        Key::Hash(gauge_controller.package_hash()),
+        time,
+    );
+    let factory = LIQUIDITYGAUGEFACTORYInstance::new_deploy(
+        &env,
+        NAME,
+        owner,
+        Key::Hash(minter.package_hash()),
+        Key::Account(owner),
+        time,
+    );
+    (
+        env,
+        factory,
+        gauge_controller,
+        minter,
+        lp_token,
+        owner,
+        time,
+    )
+}
+
+#[test]
+fn test_deploy() {
+    let (_, factory, gauge_controller, minter, _, owner, _) = deploy();
+    assert_eq!(factory.admin(), Key::Account(owner));
+    assert_eq!(factory.minter(), Key::Hash(minter.package_hash()));
+    assert_eq!(
+        factory.controller(),
+        Key::Hash(gauge_controller.package_hash())
+    );
+    assert_eq!(factory.gauge_count(), 0.into());
+}
+
+#[test]
+fn test_deploy_gauge() {
+    let (env, factory, _, _, lp_token, owner, time) = deploy();
+    let user = env.next_user();
+    let lp_token: Key = Key::Hash(lp_token.package_hash());
+    factory.deploy_gauge(user, lp_token, time);
+    let gauge: Key = factory.gauge_of(lp_token);
+    assert_ne!(gauge, zero_address());
+    assert!(factory.is_factory_gauge(gauge));
+    assert_eq!(factory.factory_gauges(0.into()), gauge);
+    assert_eq!(factory.gauge_count(), 1.into());
+    // The gauge is initialized with the admin of the factory
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(ADMIN),
+            "package_hash" => gauge
+        },
+        time,
+    );
+    let ret: Key = env.query_account_named_key(owner, &[ADMIN.into()]);
+    assert_eq!(ret, Key::Account(owner));
+}
+
+#[test]
+fn test_deploy_gauges_for_different_lp_tokens() {
+    let (env, factory, _, _, lp_token, owner, time) = deploy();
+    let other_lp_token = deploy_erc20(&env, owner, "other_lp_token", time);
+    let lp_token: Key = Key::Hash(lp_token.package_hash());
+    let other_lp_token: Key = Key::Hash(other_lp_token.package_hash());
+    factory.deploy_gauge(owner, lp_token, time);
+    factory.deploy_gauge(owner, other_lp_token, time);
+    assert_ne!(factory.gauge_of(lp_token), factory.gauge_of(other_lp_token));
+    assert_eq!(
+        factory.factory_gauges(1.into()),
+        factory.gauge_of(other_lp_token)
+    );
+    assert_eq!(factory.gauge_count(), 2.into());
+}
+
+#[test]
+#[should_panic]
+fn test_deploy_gauge_twice() {
+    let (env, factory, _, _, lp_token, owner, time) = deploy();
+    let lp_token: Key = Key::Hash(lp_token.package_hash());
+    factory.deploy_gauge(owner, lp_token, time);
+    factory.deploy_gauge(env.next_user(), lp_token, time);
+}
+
+#[test]
+fn test_add_factory_gauge_to_controller() {
+    let (_, factory, gauge_controller, _, lp_token, owner, time) = deploy();
+    let lp_token: Key = Key::Hash(lp_token.package_hash());
+    add_gauge_type(&gauge_controller, owner, time);
+    factory.deploy_gauge(owner, lp_token, time);
+    let gauge: Key = factory.gauge_of(lp_token);
+    // The admin of the controller adds gauges the factory vouches for
+    assert!(factory.is_factory_gauge(gauge));
+    assert!(!factory.is_factory_gauge(lp_token));
+    gauge_controller.call_contract(
+        owner,
+        "add_gauge",
+        runtime_args! {
+            "addr" => gauge,
+            "gauge_type" => (false, U128::from(0)),
+            "weight" => Some(U256::from(100))
+        },
+        time,
+    );
+    let n_gauges: (bool, U128) = gauge_controller.query_named_key(N_GAUGES.to_string());
+    assert_eq!(n_gauges, (false, U128::from(1)));
+    let added: Key = gauge_controller
+        .query_dictionary(GAUGES_DICT, 0.to_string())
+        .unwrap();
+    assert_eq!(added, gauge);
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let (env, factory, _, _, _, owner, time) = deploy();
+    let new_admin = env.next_user();
+    factory.commit_transfer_ownership(owner, Key::Account(new_admin), time);
+    assert_eq!(factory.future_admin(), Key::Account(new_admin));
+    assert_eq!(factory.admin(), Key::Account(owner));
+    factory.accept_transfer_ownership(new_admin, time);
+    assert_eq!(factory.admin(), Key::Account(new_admin));
+}
+
+#[test]
+#[should_panic]
+fn test_accept_transfer_ownership_by_user() {
+    let (env, factory, _, _, _, owner, time) = deploy();
+    let new_admin = env.next_user();
+    factory.commit_transfer_ownership(owner, Key::Account(new_admin), time);
+    factory.accept_transfer_ownership(env.next_user(), time);
+}
@@ -0,0 +1,758 @@
+#![no_main]
+#![no_std]
+extern crate alloc;
+
+use alloc::{collections::BTreeSet, format, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+};
+use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
+use crv20::{self, Address, CURVEERC20};
+use liquidity_gauge_factory_crate::{
+    data::{self, FactoryGauges, GaugeOf, IsFactoryGauge},
+    LIQUIDITYGAUGEFACTORY,
+};
+use liquidity_gauge_v4_crate::{data as gauge_data, int128::I128, utils::*, LIQUIDITYTGAUGEV4};
+
+#[derive(Default)]
+struct LiquidityGaugeFactory(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for LiquidityGaugeFactory {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+impl CURVEERC20<OnChainContractStorage> for LiquidityGaugeFactory {}
+impl LIQUIDITYTGAUGEV4<OnChainContractStorage> for LiquidityGaugeFactory {}
+impl LIQUIDITYGAUGEFACTORY<OnChainContractStorage> for LiquidityGaugeFactory {}
+
+#[no_mangle]
+fn constructor_lgf() {
+    let minter: Key = runtime::get_named_arg("minter");
+    let admin: Key = runtime::get_named_arg("admin");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    LIQUIDITYGAUGEFACTORY::init(
+        &mut LiquidityGaugeFactory::default(),
+        minter,
+        admin,
+        contract_hash,
+        package_hash,
+    );
+}
+
+/// Deploy a liquidity gauge for an lp token, callable by anyone once per lp token
+/// # Parameters
+/// * `lp_token` - Package hash of the lp token staked in the gauge
+#[no_mangle]
+fn deploy_gauge() {
+    let lp_token: Key = runtime::get_named_arg("lp_token");
+    let ret: Key = LiquidityGaugeFactory::default().deploy_gauge(lp_token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn commit_transfer_ownership_lgf() {
+    let addr: Key = runtime::get_named_arg("addr");
+    LIQUIDITYGAUGEFACTORY::commit_transfer_ownership(&mut LiquidityGaugeFactory::default(), addr);
+}
+
+#[no_mangle]
+fn accept_transfer_ownership_lgf() {
+    LIQUIDITYGAUGEFACTORY::accept_transfer_ownership(&mut LiquidityGaugeFactory::default());
+}
+
+#[no_mangle]
+fn gauge_of() {
+    let lp_token: Key = runtime::get_named_arg("lp_token");
+    runtime::ret(CLValue::from_t(GaugeOf::instance().get(&lp_token)).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn is_factory_gauge() {
+    let gauge: Key = runtime::get_named_arg("gauge");
+    runtime::ret(CLValue::from_t(IsFactoryGauge::instance().get(&gauge)).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn factory_gauges() {
+    let index: U256 = runtime::get_named_arg("index");
+    runtime::ret(CLValue::from_t(FactoryGauges::instance().get(&index)).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn gauge_count() {
+    runtime::ret(CLValue::from_t(data::get_gauge_count()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn minter_lgf() {
+    runtime::ret(CLValue::from_t(data::get_minter()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn controller_lgf() {
+    runtime::ret(CLValue::from_t(data::get_controller()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn admin_lgf() {
+    runtime::ret(CLValue::from_t(data::get_admin()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn future_admin_lgf() {
+    runtime::ret(CLValue::from_t(data::get_future_admin()).unwrap_or_revert());
+}
+
+//LIQUIDITY GAUGE V4 NO MANGLE
+#[no_mangle]
+fn constructor() {
+    let lp_addr: Key = runtime::get_named_arg("lp_addr");
+    let minter: Key = runtime::get_named_arg("minter");
+    let admin: Key = runtime::get_named_arg("admin");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    LIQUIDITYTGAUGEV4::init(
+        &mut LiquidityGaugeFactory::default(),
+        lp_addr,
+        minter,
+        admin,
+        contract_hash,
+        package_hash,
+    );
+}
+
+/// """
+/// @notice Get the number of decimals for this token
+/// @dev Implemented as a view method to reduce gas costs
+/// @return u8 decimal places
+/// """
+#[no_mangle]
+fn decimals() {
+    let ret: u8 = LiquidityGaugeFactory::default().decimals();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn integrate_checkpoint() {
+    let ret: U256 = LiquidityGaugeFactory::default().integrate_checkpoint();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+///"""
+///    @notice Record a checkpoint for `addr`
+///    @param addr User address
+///    @return bool success
+///"""
+#[no_mangle]
+fn user_checkpoint() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: bool = LiquidityGaugeFactory::default().user_checkpoint(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+///"""
+///    @notice Get the number of claimable tokens per user
+///    @return uint256 number of claimable tokens per user
+///    """
+#[no_mangle]
+fn claimable_tokens() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: U256 = LiquidityGaugeFactory::default().claimable_tokens(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+/// """
+/// @notice Get the number of already-claimed reward tokens for a user
+/// @param _addr Account to get reward amount for
+/// @param _token Token to get reward amount for
+/// @return uint256 Total amount of `_token` already claimed by `_addr`
+/// """
+#[no_mangle]
+fn claimed_reward() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let token: Key = runtime::get_named_arg("token");
+    let ret = LiquidityGaugeFactory::default().claimed_reward(addr, token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+/// """
+/// @notice Get the number of claimable reward tokens for a user
+/// @dev This call does not consider pending claimable amount in `reward_contract`.
+///      Off-chain callers should instead use `claimable_rewards_write` as a
+///      view method.
+/// @param _addr Account to get reward amount for
+/// @param _token Token to get reward amount for
+/// @return uint256 Claimable reward token amount
+/// """
+
+#[no_mangle]
+fn claimable_reward() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let token: Key = runtime::get_named_arg("token");
+
+    let ret: U256 = LiquidityGaugeFactory::default().claimable_reward(addr, token);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+/// """
+/// @notice Set the default reward receiver for the caller.
+/// @dev When set to ZERO_ADDRESS, rewards are sent to the caller
+/// @param _receiver Receiver address for any rewards claimed via `claim_rewards`
+/// """
+
+#[no_mangle]
+fn set_rewards_receiver() {
+    let receiver: Key = runtime::get_named_arg("receiver");
+    LiquidityGaugeFactory::default().set_rewards_receiver(receiver);
+}
+///"""
+///    @notice Claim available reward tokens for `addr`
+///    @param addr Address to claim for
+///    @param receiver Address to transfer rewards to - if set to
+///                     ZERO_ADDRESS, uses the default reward receiver
+///                     for the caller
+///"""
+
+#[no_mangle]
+fn claim_rewards() {
+    let addr: Option<Key> = runtime::get_named_arg("addr");
+    let receiver: Option<Key> = runtime::get_named_arg("receiver");
+    LiquidityGaugeFactory::default().claim_rewards(addr, receiver);
+}
+///"""
+///    @notice Kick `addr` for abusing their boost
+///    @dev Only if either they had another voting event, or their voting escrow lock expired
+///    @param addr Address to kick
+///    """
+
+#[no_mangle]
+fn kick() {
+    let addr: Key = runtime::get_named_arg("addr");
+    LiquidityGaugeFactory::default().kick(addr);
+}
+/// """
+/// @notice Deposit `_value` LP tokens
+/// @dev Depositting also claims pending reward tokens
+/// @param _value Number of tokens to deposit
+/// @param _addr Address to deposit for
+/// """
+
+#[no_mangle]
+fn deposit() {
+    let value: U256 = runtime::get_named_arg("value");
+    let addr: Option<Key> = runtime::get_named_arg("addr");
+    let claim_rewards: Option<bool> = runtime::get_named_arg("claim_rewards");
+    LiquidityGaugeFactory::default().deposit(value, addr, claim_rewards);
+}
+/// """
+/// @notice Withdraw `value` LP tokens
+/// @dev Withdrawing also claims pending reward tokens
+/// @param _value Number of tokens to withdraw
+/// """
+#[no_mangle]
+fn withdraw() {
+    let value: U256 = runtime::get_named_arg("value");
+    let claim_rewards: Option<bool> = runtime::get_named_arg("claim_rewards");
+
+    LiquidityGaugeFactory::default().withdraw(value, claim_rewards);
+}
+/// """
+/// @notice Transfer token for a specified address
+/// @dev Transferring claims pending reward tokens for the sender and receiver
+/// @param _to The address to transfer to.
+/// @param _value The amount to be transferred.
+/// """
+
+#[no_mangle]
+fn transfer() {
+    let recipient: Address = runtime::get_named_arg("recipient");
+    let amount: U256 = runtime::get_named_arg("amount");
+    LIQUIDITYTGAUGEV4::transfer(&mut LiquidityGaugeFactory::default(), recipient, amount)
+        .unwrap_or_revert();
+}
+/// """
+/// @notice Transfer tokens from one address to another.
+/// @dev Transferring claims pending reward tokens for the sender and receiver
+/// @param _from address The address which you want to send tokens from
+/// @param _to address The address which you want to transfer to
+/// @param _value uint256 the amount of tokens to be transferred
+/// """
+#[no_mangle]
+fn transfer_from() {
+    let owner: Address = runtime::get_named_arg("owner");
+    let recipient: Address = runtime::get_named_arg("recipient");
+    let amount: U256 = runtime::get_named_arg("amount");
+    LIQUIDITYTGAUGEV4::transfer_from(
+        &mut LiquidityGaugeFactory::default(),
+        owner,
+        recipient,
+        amount,
+    )
+    .unwrap_or_revert();
+}
+
+/// @notice Approve the passed address to transfer the specified amount of
+///            tokens on behalf of self.get_caller
+///    @dev Beware that changing an allowance via this method brings the risk
+///         that someone may use both the old and new allowance by unfortunate
+///         transaction ordering. This may be mitigated with the use of
+///         {increase_allowance} and {decrease_allowance}.
+///    @param spender The address which will transfer the funds
+///    @param amount The amount of tokens that may be transferred
+#[no_mangle]
+fn approve() {
+    let spender: Address = runtime::get_named_arg("spender");
+    let amount: U256 = runtime::get_named_arg("amount");
+    LIQUIDITYTGAUGEV4::approve(&LiquidityGaugeFactory::default(), spender, amount)
+        .unwrap_or_revert();
+}
+///@notice Increase the allowance granted to `spender` by the caller
+///    @dev This is alternative to {approve} that can be used as a mitigation for
+///         the potential race condition
+///    @param spender The address which will transfer the funds
+///    @param added_value The amount of to increase the allowance
+///   @return ok success
+#[no_mangle]
+fn increase_allowance() {
+    let spender: Address = runtime::get_named_arg("spender");
+    let amount: U256 = runtime::get_named_arg("amount");
+    LIQUIDITYTGAUGEV4::increase_allowance(&LiquidityGaugeFactory::default(), spender, amount)
+        .unwrap_or_revert();
+}
+
+///@notice Decrease the allowance granted to `spender` by the caller
+///    @dev This is alternative to {approve} that can be used as a mitigation for
+///         the potential race condition
+///    @param spender The address which will transfer the funds
+///    @param amount The amount of to decrease the allowance
+///    @return ok success
+#[no_mangle]
+fn decrease_allowance() {
+    let spender: Address = runtime::get_named_arg("spender");
+    let amount: U256 = runtime::get_named_arg("amount");
+    LIQUIDITYTGAUGEV4::decrease_allowance(&LiquidityGaugeFactory::default(), spender, amount)
+        .unwrap_or_revert();
+}
+/// """
+/// @notice Set the active reward contract
+/// """
+#[no_mangle]
+fn add_reward() {
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let distributor: Key = runtime::get_named_arg("distributor");
+    LiquidityGaugeFactory::default().add_reward(reward_token, distributor);
+}
+
+#[no_mangle]
+fn set_reward_distributor() {
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let distributor: Key = runtime::get_named_arg("distributor");
+    LiquidityGaugeFactory::default().set_reward_distributor(reward_token, distributor);
+}
+
+#[no_mangle]
+fn deposit_reward_token() {
+    let reward_token: Key = runtime::get_named_arg("reward_token");
+    let amount: U256 = runtime::get_named_arg("amount");
+    LiquidityGaugeFactory::default().deposit_reward_token(reward_token, amount);
+}
+
+///"""
+///    @notice Set the killed status for this contract
+///    @dev When killed, the gauge always yields a rate of 0 and so cannot mint CRV
+///    @param _is_killed Killed status to set
+///    """
+#[no_mangle]
+fn set_killed() {
+    let is_killed: bool = runtime::get_named_arg("is_killed");
+    LiquidityGaugeFactory::default().set_killed(is_killed);
+}
+/// """
+/// @notice Set the contract boosted balances are read from
+/// @param veboost_proxy Contract exposing `adjusted_balance_of`, zero address to disable
+/// """
+#[no_mangle]
+fn set_veboost_proxy() {
+    let veboost_proxy: Key = runtime::get_named_arg("veboost_proxy");
+    LiquidityGaugeFactory::default().set_veboost_proxy(veboost_proxy);
+}
+/// """
+/// @notice Transfer ownership of GaugeController to `addr`
+/// @param addr Address to have ownership transferred to
+/// """
+
+#[no_mangle]
+fn commit_transfer_ownership() {
+    let addr: Key = runtime::get_named_arg("addr");
+    LIQUIDITYTGAUGEV4::commit_transfer_ownership(&mut LiquidityGaugeFactory::default(), addr);
+}
+
+// /// """
+// /// @notice Accept a pending ownership transfer
+// /// """
+
+#[no_mangle]
+fn accept_transfer_ownership() {
+    LIQUIDITYTGAUGEV4::accept_transfer_ownership(&mut LiquidityGaugeFactory::default());
+}
+
+// public Variables
+#[no_mangle]
+fn minter() {
+    runtime::ret(CLValue::from_t(gauge_data::get_minter()).unwrap_or_revert());
+}
+#[no_mangle]
+fn crv_token() {
+    runtime::ret(CLValue::from_t(gauge_data::get_crv_token()).unwrap_or_revert());
+}
+#[no_mangle]
+fn lp_token() {
+    runtime::ret(CLValue::from_t(gauge_data::get_lp_token()).unwrap_or_revert());
+}
+#[no_mangle]
+fn controller() {
+    runtime::ret(CLValue::from_t(gauge_data::get_controller()).unwrap_or_revert());
+}
+#[no_mangle]
+fn voting_escrow() {
+    runtime::ret(CLValue::from_t(gauge_data::get_voting_escrow()).unwrap_or_revert());
+}
+#[no_mangle]
+fn veboost_proxy() {
+    runtime::ret(CLValue::from_t(gauge_data::get_veboost_proxy()).unwrap_or_revert());
+}
+#[no_mangle]
+fn future_epoch_time() {
+    runtime::ret(CLValue::from_t(gauge_data::get_future_epoch_time()).unwrap_or_revert());
+}
+#[no_mangle]
+fn balance_of() {
+    let address: Address = runtime::get_named_arg("address");
+    let ret: U256 = CURVEERC20::balance_of(&LiquidityGaugeFactory::default(), address);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn total_supply() {
+    let ret: U256 = LiquidityGaugeFactory::default().total_supply();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn allowance() {
+    let owner: Address = runtime::get_named_arg("owner");
+    let spender: Address = runtime::get_named_arg("spender");
+    runtime::ret(
+        CLValue::from_t(CURVEERC20::allowance(
+            &LiquidityGaugeFactory::default(),
+            owner,
+            spender,
+        ))
+        .unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn name() {
+    runtime::ret(
+        CLValue::from_t(CURVEERC20::name(&LiquidityGaugeFactory::default())).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn symbol() {
+    runtime::ret(
+        CLValue::from_t(CURVEERC20::symbol(&LiquidityGaugeFactory::default())).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn working_balances() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::WorkingBalances::instance().get(&owner)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn working_supply() {
+    runtime::ret(CLValue::from_t(gauge_data::get_working_supply()).unwrap_or_revert());
+}
+#[no_mangle]
+fn period() {
+    runtime::ret(CLValue::from_t(I128::from(gauge_data::get_period())).unwrap_or_revert());
+}
+#[no_mangle]
+fn period_timestamp() {
+    let owner: U256 = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::PeriodTimestamp::instance().get(&owner)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn integrate_inv_supply() {
+    let owner: U256 = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::IntegrateInvSupply::instance().get(&owner)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn integrate_inv_supply_of() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::IntegrateInvSupplyOf::instance().get(&owner))
+            .unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn integrate_checkpoint_of() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::IntegrateCheckpointOf::instance().get(&owner))
+            .unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn integrate_fraction() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::IntegrateFraction::instance().get(&owner)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn inflation_rate() {
+    runtime::ret(CLValue::from_t(gauge_data::get_inflation_rate()).unwrap_or_revert());
+}
+#[no_mangle]
+fn reward_count() {
+    runtime::ret(CLValue::from_t(gauge_data::get_reward_count()).unwrap_or_revert());
+}
+#[no_mangle]
+fn reward_tokens() {
+    let owner: U256 = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::RewardTokens::instance().get(&owner)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn reward_data() {
+    let address: Key = runtime::get_named_arg("address");
+    runtime::ret(
+        CLValue::from_t(gauge_data::RewardData::instance().get(&address)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn rewards_receiver() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::RewardsReceiver::instance().get(&owner)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn reward_integral() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(
+        CLValue::from_t(gauge_data::RewardIntegral::instance().get(&owner)).unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn reward_integral_for() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let spender: Key = runtime::get_named_arg("spender");
+    runtime::ret(
+        CLValue::from_t(gauge_data::RewardIntegralFor::instance().get(&owner, &spender))
+            .unwrap_or_revert(),
+    );
+}
+#[no_mangle]
+fn admin() {
+    runtime::ret(CLValue::from_t(gauge_data::get_admin()).unwrap_or_revert());
+}
+#[no_mangle]
+fn future_admin() {
+    runtime::ret(CLValue::from_t(gauge_data::get_future_admin()).unwrap_or_revert());
+}
+#[no_mangle]
+fn is_killed() {
+    runtime::ret(CLValue::from_t(gauge_data::get_is_killed()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor_lgf",
+        vec![
+            Parameter::new("minter", Key::cl_type()),
+            Parameter::new("admin", Key::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "deploy_gauge",
+        vec![Parameter::new("lp_token", Key::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "commit_transfer_ownership_lgf",
+        vec![Parameter::new("addr", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "accept_transfer_ownership_lgf",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gauge_of",
+        vec![Parameter::new("lp_token", Key::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_factory_gauge",
+        vec![Parameter::new("gauge", Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "factory_gauges",
+        vec![Parameter::new("index", U256::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gauge_count",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "minter_lgf",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "controller_lgf",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin_lgf",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_admin_lgf",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        // add a first version to this package
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let minter: Key = runtime::get_named_arg("minter");
+        let admin: Key = runtime::get_named_arg("admin");
+        let constructor_args = runtime_args! {
+            "minter" => minter,
+            "admin" => admin,
+            "package_hash" => package_hash,
+            "contract_hash" => contract_hash,
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () = runtime::call_versioned_contract(
+            package_hash,
+            None,
+            "constructor_lgf",
+            constructor_args,
+        );
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    }
+    // If contract package did already exist
+    else {
+        // get the package
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+        // create new version and install it
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
@@ -1,18 +1,11 @@
 use crate::{data::*, event::LiquidityGaugeRewardWrapperEvent};
 use alloc::string::String;
-use alloc::{collections::BTreeMap, string::ToString};
-use casper_contract::{
-    contract_api::{
-        runtime::{self},
-        storage,
-    },
-    unwrap_or_revert::UnwrapOrRevert,
-};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{
     runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{errors::*, events, utils::*};
 use crv20::{self, Address, CURVEERC20};
 use curve_casper_erc20::Error as Erc20Error;
 
@@ -80,6 +73,7 @@ pub trait LIQUIDITYGAUGEREWARDWRAPPER<Storage: ContractStorage>:
         ClaimableCrv::init();
         ClaimableRewards::init();
         ApprovedToDeposit::init();
+        events::init::<LiquidityGaugeRewardWrapperEvent>();
         set_contract_hash(contract_hash);
         set_package_hash(package_hash);
     }
@@ -635,77 +629,6 @@ pub trait LIQUIDITYGAUGEREWARDWRAPPER<Storage: ContractStorage>:
     }
 
     fn emit(&self, liquidity_gauge_reward_wrapper_event: &LiquidityGaugeRewardWrapperEvent) {
-        match liquidity_gauge_reward_wrapper_event {
-            LiquidityGaugeRewardWrapperEvent::Deposit { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert(
-                    "event_type",
-                    liquidity_gauge_reward_wrapper_event.type_name(),
-                );
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardWrapperEvent::Withdraw { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert(
-                    "event_type",
-                    liquidity_gauge_reward_wrapper_event.type_name(),
-                );
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardWrapperEvent::CommitOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert(
-                    "event_type",
-                    liquidity_gauge_reward_wrapper_event.type_name(),
-                );
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardWrapperEvent::ApplyOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert(
-                    "event_type",
-                    liquidity_gauge_reward_wrapper_event.type_name(),
-                );
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardWrapperEvent::Transfer { from, to, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert(
-                    "event_type",
-                    liquidity_gauge_reward_wrapper_event.type_name(),
-                );
-                event.insert("from", from.to_string());
-                event.insert("to", to.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardWrapperEvent::Approval {
-                owner,
-                spender,
-                value,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert(
-                    "event_type",
-                    liquidity_gauge_reward_wrapper_event.type_name(),
-                );
-                event.insert("from", owner.to_string());
-                event.insert("to", spender.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(liquidity_gauge_reward_wrapper_event);
     }
 }
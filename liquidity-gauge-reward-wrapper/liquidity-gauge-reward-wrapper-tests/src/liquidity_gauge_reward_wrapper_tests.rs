@@ -274,7 +274,18 @@ fn deploy() -> (TestEnv, AccountHash, TestContract, u64) {
         runtime_args! {"name" => _name, "weight" => Some(U256::from(1)) },
         block_time,
     );
-    let addr: Key = Key::Account(owner);
+    // Reward gauge on its own LP token for type 0, the wrapped gauge is added with type 1 below
+    let addr: Key = Key::Hash(
+        deploy_liquidity_gauge_reward(
+            &env,
+            owner,
+            Key::Hash(minter.package_hash()),
+            deploy_erc20(&env, owner, block_time),
+            Key::Hash(reward.package_hash()),
+            block_time,
+        )
+        .package_hash(),
+    );
     let gauge_type: (bool, U128) = (false, 0.into());
     gauge_controller.call_contract(
         owner,
@@ -1,5 +1,12 @@
-use alloc::string::{String, ToString};
-use casper_types::{Key, U256};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
 
 pub enum LiquidityGaugeRewardEvent {
     Deposit {
@@ -49,3 +56,63 @@ impl LiquidityGaugeRewardEvent {
         .to_string()
     }
 }
+
+impl CasperEvent for LiquidityGaugeRewardEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            LiquidityGaugeRewardEvent::Deposit { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            LiquidityGaugeRewardEvent::Withdraw { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            LiquidityGaugeRewardEvent::UpdateLiquidityLimit {
+                user,
+                original_balance,
+                original_supply,
+                working_balance,
+                working_supply,
+            } => Ok([
+                user.to_bytes()?,
+                original_balance.to_bytes()?,
+                original_supply.to_bytes()?,
+                working_balance.to_bytes()?,
+                working_supply.to_bytes()?,
+            ]
+            .concat()),
+            LiquidityGaugeRewardEvent::CommitOwnership { admin } => admin.to_bytes(),
+            LiquidityGaugeRewardEvent::ApplyOwnership { admin } => admin.to_bytes(),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "deposit",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "withdraw",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "updateLiquidityLimit",
+                Schema::new()
+                    .with_elem::<Key>("user")
+                    .with_elem::<U256>("original_balance")
+                    .with_elem::<U256>("original_supply")
+                    .with_elem::<U256>("working_balance")
+                    .with_elem::<U256>("working_supply"),
+            )
+            .with("commitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("applyOwnership", Schema::new().with_elem::<Key>("admin"))
+    }
+}
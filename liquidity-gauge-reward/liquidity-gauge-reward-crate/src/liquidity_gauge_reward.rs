@@ -1,17 +1,13 @@
 use crate::{data::*, event::LiquidityGaugeRewardEvent};
-use alloc::{collections::BTreeMap, string::ToString};
 use casper_contract::{
-    contract_api::{
-        runtime::{self, get_blocktime},
-        storage,
-    },
+    contract_api::runtime::{self, get_blocktime},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
     runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U128, U256,
 };
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{errors::*, events, utils::*};
 use crv20::{self, get_package_hash, Address, CURVEERC20};
 
 #[allow(clippy::too_many_arguments)]
@@ -56,6 +52,7 @@ pub trait LIQUIDITYGAUGEREWARD<Storage: ContractStorage>:
         RewardIntegralFor::init();
         RewardsFor::init();
         ClaimedRewardsFor::init();
+        events::init::<LiquidityGaugeRewardEvent>();
 
         set_lp_token(lp_addr);
         set_minter(minter);
@@ -774,54 +771,6 @@ pub trait LIQUIDITYGAUGEREWARD<Storage: ContractStorage>:
     }
 
     fn emit(&self, liquidity_gauge_reward_event: &LiquidityGaugeRewardEvent) {
-        match liquidity_gauge_reward_event {
-            LiquidityGaugeRewardEvent::Deposit { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_reward_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardEvent::Withdraw { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_reward_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardEvent::UpdateLiquidityLimit {
-                user,
-                original_balance,
-                original_supply,
-                working_balance,
-                working_supply,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_reward_event.type_name());
-                event.insert("user", user.to_string());
-                event.insert("original_balance", original_balance.to_string());
-                event.insert("original_supply", original_supply.to_string());
-                event.insert("working_balance", working_balance.to_string());
-                event.insert("working_supply", working_supply.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardEvent::CommitOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_reward_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeRewardEvent::ApplyOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_reward_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(liquidity_gauge_reward_event);
     }
 }
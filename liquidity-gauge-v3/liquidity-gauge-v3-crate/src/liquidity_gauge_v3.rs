@@ -3,18 +3,15 @@ use crate::data::{
     RewardIntegral, RewardIntegralFor, RewardTokens, RewardsReceiver, CLAIM_FREQUENCY, MAX_REWARDS,
 };
 use crate::{alloc::string::ToString, event::*};
+use alloc::string::String;
 use alloc::vec::Vec;
-use alloc::{collections::BTreeMap, string::String};
-use casper_contract::{
-    contract_api::{runtime, storage},
-    unwrap_or_revert::UnwrapOrRevert,
-};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::bytesrepr::Bytes;
 use casper_types::{
     runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{errors::*, events, utils::*};
 use crv20::{self, Address, CURVEERC20};
 use curve_casper_erc20::Error as Erc20Error;
 
@@ -38,6 +35,7 @@ pub trait LIQUIDITYTGAUGEV3<Storage: ContractStorage>:
         data::RewardTokens::init();
         ClaimData::init();
         RewardsReceiver::init();
+        events::init::<LiquidityGaugeV3Event>();
         data::set_package_hash(package_hash);
         data::set_contract_hash(contract_hash);
         CURVEERC20::init(self, data::get_contract_hash(), data::get_package_hash());
@@ -1064,76 +1062,6 @@ pub trait LIQUIDITYTGAUGEV3<Storage: ContractStorage>:
     }
 
     fn emit(&self, liquidity_gauge_event: &LiquidityGaugeV3Event) {
-        match liquidity_gauge_event {
-            LiquidityGaugeV3Event::Deposit { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV3Event::Withdraw { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV3Event::Approval {
-                owner,
-                spender,
-                value,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("owner", owner.to_string());
-                event.insert("spender", spender.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV3Event::Transfer { from, to, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("from", from.to_string());
-                event.insert("to", to.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV3Event::UpdateLiquidityLimit {
-                user,
-                original_balance,
-                original_supply,
-                working_balance,
-                working_supply,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("user", user.to_string());
-                event.insert("original_balance", original_balance.to_string());
-                event.insert("original_supply", original_supply.to_string());
-                event.insert("working_balance", working_balance.to_string());
-                event.insert("working_supply", working_supply.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV3Event::CommitOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV3Event::ApplyOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(liquidity_gauge_event);
     }
 }
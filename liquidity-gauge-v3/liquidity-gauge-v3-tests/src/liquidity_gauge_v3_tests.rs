@@ -159,7 +159,18 @@ fn deploy() -> (TestEnv, AccountHash, TestContract, u64) {
         runtime_args! {"name" => _name, "weight" => Some(U256::from(1)) },
         time_now,
     );
-    let addr: Key = Key::Account(owner);
+    // Separate gauge for type 0, the gauge under test is added with type 1 below
+    let addr: Key = Key::Hash(
+        LIQUIDITYGUAGEV3INSTANCEInstance::new_deploy(
+            &env,
+            "LiquidityGaugeV3 1",
+            owner,
+            Key::Hash(erc20.package_hash()),
+            Key::Hash(minter.package_hash()),
+            Key::Account(owner),
+        )
+        .package_hash(),
+    );
     let gauge_type: (bool, U128) = (false, 0.into());
     gauge_controller.call_contract(
         owner,
@@ -20,7 +20,7 @@ use casper_types::{
 };
 use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
 use crv20::{self, Address, CURVEERC20};
-use liquidity_gauge_v3_crate::{self, data, utils::*, LIQUIDITYTGAUGEV3};
+use liquidity_gauge_v3_crate::{self, data, int128::I128, utils::*, LIQUIDITYTGAUGEV3};
 #[derive(Default)]
 struct LiquidityGaugeV3(OnChainContractStorage);
 
@@ -410,7 +410,7 @@ fn working_supply() {
 }
 #[no_mangle]
 fn period() {
-    runtime::ret(CLValue::from_t(i128_to_tuple(data::get_period())).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(I128::from(data::get_period())).unwrap_or_revert());
 }
 #[no_mangle]
 fn period_timestamp() {
@@ -6,7 +6,7 @@ use casper_types::bytesrepr::Bytes;
 use casper_types::{ContractHash, ContractPackageHash, Key, U256};
 use casper_types_derive::{CLTyped, FromBytes, ToBytes};
 use casperlabs_contract_utils::{get_key, key_to_str, set_key, Dict};
-use common::{errors::*, keys::*, utils::*};
+use common::{errors::*, int128::I128, keys::*, utils::*};
 
 pub const MAX_REWARDS: U256 = U256([8, 0, 0, 0]);
 pub const TOKENLESS_PRODUCTION: U256 = U256([40, 0, 0, 0]);
@@ -595,6 +595,14 @@ pub fn get_voting_escrow() -> Key {
     get_key(VOTING_ESCROW).unwrap_or_else(zero_address)
 }
 
+pub fn set_veboost_proxy(veboost_proxy: Key) {
+    set_key(VEBOOST_PROXY, veboost_proxy);
+}
+
+pub fn get_veboost_proxy() -> Key {
+    get_key(VEBOOST_PROXY).unwrap_or_else(zero_address)
+}
+
 pub fn set_future_epoch_time(future_epoch_time: U256) {
     set_key(FUTURE_EPOCH_TIME, future_epoch_time);
 }
@@ -611,11 +619,11 @@ pub fn get_working_supply() -> U256 {
 }
 
 pub fn set_period(period: i128) {
-    set_key(PERIOD, i128_to_tuple(period));
+    set_key(PERIOD, I128::from(period));
 }
 
 pub fn get_period() -> i128 {
-    tuple_to_i128(get_key(PERIOD).unwrap_or_default())
+    get_key::<I128>(PERIOD).unwrap_or_default().into()
 }
 
 pub fn set_inflation_rate(inflation_rate: U256) {
@@ -1,5 +1,12 @@
-use alloc::string::{String, ToString};
-use casper_types::{Key, U256};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
 
 pub enum LiquidityGaugeV4Event {
     Deposit {
@@ -69,3 +76,85 @@ impl LiquidityGaugeV4Event {
         .to_string()
     }
 }
+
+impl CasperEvent for LiquidityGaugeV4Event {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            LiquidityGaugeV4Event::Deposit { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            LiquidityGaugeV4Event::Withdraw { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            LiquidityGaugeV4Event::UpdateLiquidityLimit {
+                user,
+                original_balance,
+                original_supply,
+                working_balance,
+                working_supply,
+            } => Ok([
+                user.to_bytes()?,
+                original_balance.to_bytes()?,
+                original_supply.to_bytes()?,
+                working_balance.to_bytes()?,
+                working_supply.to_bytes()?,
+            ]
+            .concat()),
+            LiquidityGaugeV4Event::CommitOwnership { admin } => admin.to_bytes(),
+            LiquidityGaugeV4Event::ApplyOwnership { admin } => admin.to_bytes(),
+            LiquidityGaugeV4Event::Transfer { from, to, value } => {
+                Ok([from.to_bytes()?, to.to_bytes()?, value.to_bytes()?].concat())
+            }
+            LiquidityGaugeV4Event::Approval {
+                owner,
+                spender,
+                value,
+            } => Ok([owner.to_bytes()?, spender.to_bytes()?, value.to_bytes()?].concat()),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "Deposit",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "Withdraw",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "UpdateLiquidityLimit",
+                Schema::new()
+                    .with_elem::<Key>("user")
+                    .with_elem::<U256>("original_balance")
+                    .with_elem::<U256>("original_supply")
+                    .with_elem::<U256>("working_balance")
+                    .with_elem::<U256>("working_supply"),
+            )
+            .with("CommitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("ApplyOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with(
+                "Transfer",
+                Schema::new()
+                    .with_elem::<Key>("from")
+                    .with_elem::<Key>("to")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "Approval",
+                Schema::new()
+                    .with_elem::<Key>("owner")
+                    .with_elem::<Key>("spender")
+                    .with_elem::<U256>("value"),
+            )
+    }
+}
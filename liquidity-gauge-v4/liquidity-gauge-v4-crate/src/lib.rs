@@ -3,8 +3,9 @@
 extern crate alloc;
 
 pub mod data;
+pub mod entry_points;
 pub mod event;
 pub mod liquidity_gauge_v4;
 
-pub use common::utils;
+pub use common::{int128, utils};
 pub use liquidity_gauge_v4::LIQUIDITYTGAUGEV4;
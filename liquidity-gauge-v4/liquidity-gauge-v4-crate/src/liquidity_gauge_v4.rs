@@ -1,19 +1,15 @@
 use crate::data::{
-    self, get_package_hash, ClaimData, ClaimDataStruct, PeriodTimestamp, RewardData,
-    RewardDataStruct, RewardIntegral, RewardIntegralFor, RewardTokens, RewardsReceiver,
-    MAX_REWARDS,
+    self, ClaimData, ClaimDataStruct, PeriodTimestamp, RewardData, RewardDataStruct,
+    RewardIntegral, RewardIntegralFor, RewardTokens, RewardsReceiver, MAX_REWARDS,
 };
 use crate::{alloc::string::ToString, event::*};
-use alloc::{collections::BTreeMap, string::String};
-use casper_contract::{
-    contract_api::{runtime, storage},
-    unwrap_or_revert::UnwrapOrRevert,
-};
+use alloc::string::String;
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{
     runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{errors::*, events, utils::*};
 use crv20::{self, Address, CURVEERC20};
 use curve_casper_erc20::Error as Erc20Error;
 
@@ -39,6 +35,7 @@ pub trait LIQUIDITYTGAUGEV4<Storage: ContractStorage>:
         ClaimData::init();
         RewardData::init();
         RewardsReceiver::init();
+        events::init::<LiquidityGaugeV4Event>();
         data::set_package_hash(package_hash);
         data::set_contract_hash(contract_hash);
         CURVEERC20::init(self, data::get_contract_hash(), data::get_package_hash());
@@ -146,15 +143,29 @@ pub trait LIQUIDITYTGAUGEV4<Storage: ContractStorage>:
     #[inline(always)]
     fn _update_liquidity_limit(&self, addr: Key, l: U256, _supply: U256) {
         let voting_escrow: Key = data::get_voting_escrow();
-        let voting_balance: U256 = runtime::call_versioned_contract(
-            voting_escrow.into_hash().unwrap_or_revert().into(),
-            None,
-            "balance_of",
-            runtime_args! {
-                "addr" => addr,
-                "t" => None::<U256>
-            },
-        );
+        let veboost_proxy: Key = data::get_veboost_proxy();
+        // Boost delegations are only taken into account if a proxy is configured
+        let voting_balance: U256 =
+            if veboost_proxy != zero_address() && veboost_proxy != account_zero_address() {
+                runtime::call_versioned_contract(
+                    veboost_proxy.into_hash().unwrap_or_revert().into(),
+                    None,
+                    "adjusted_balance_of",
+                    runtime_args! {
+                        "addr" => addr
+                    },
+                )
+            } else {
+                runtime::call_versioned_contract(
+                    voting_escrow.into_hash().unwrap_or_revert().into(),
+                    None,
+                    "balance_of",
+                    runtime_args! {
+                        "addr" => addr,
+                        "t" => None::<U256>
+                    },
+                )
+            };
         let voting_total: U256 = runtime::call_versioned_contract(
             voting_escrow.into_hash().unwrap_or_revert().into(),
             None,
@@ -957,6 +968,16 @@ pub trait LIQUIDITYTGAUGEV4<Storage: ContractStorage>:
         data::set_is_killed(is_killed);
     }
 
+    /// @notice Set the contract `_update_liquidity_limit` reads boosted balances from
+    /// @param veboost_proxy Contract exposing `adjusted_balance_of`, zero address to disable
+    #[inline(always)]
+    fn set_veboost_proxy(&mut self, veboost_proxy: Key) {
+        if self.get_caller() != self.admin() {
+            runtime::revert(Error::LiquidityGaugeV4OnlyAdmin);
+        }
+        data::set_veboost_proxy(veboost_proxy);
+    }
+
     #[inline(always)]
     fn commit_transfer_ownership(&mut self, addr: Key) {
         if self.get_caller() != self.admin() {
@@ -977,76 +998,6 @@ pub trait LIQUIDITYTGAUGEV4<Storage: ContractStorage>:
     }
 
     fn emit(&self, liquidity_gauge_event: &LiquidityGaugeV4Event) {
-        match liquidity_gauge_event {
-            LiquidityGaugeV4Event::Deposit { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV4Event::Withdraw { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV4Event::Approval {
-                owner,
-                spender,
-                value,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("owner", owner.to_string());
-                event.insert("spender", spender.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV4Event::Transfer { from, to, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("from", from.to_string());
-                event.insert("to", to.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV4Event::UpdateLiquidityLimit {
-                user,
-                original_balance,
-                original_supply,
-                working_balance,
-                working_supply,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("user", user.to_string());
-                event.insert("original_balance", original_balance.to_string());
-                event.insert("original_supply", original_supply.to_string());
-                event.insert("working_balance", working_balance.to_string());
-                event.insert("working_supply", working_supply.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV4Event::CommitOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            LiquidityGaugeV4Event::ApplyOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", liquidity_gauge_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(liquidity_gauge_event);
     }
 }
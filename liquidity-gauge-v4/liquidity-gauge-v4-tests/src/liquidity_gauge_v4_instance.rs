@@ -92,6 +92,16 @@ impl LIQUIDITYGUAGEV4INSTANCEInstance {
             time_now,
         );
     }
+    pub fn set_veboost_proxy(&self, sender: AccountHash, veboost_proxy: Key, time_now: u64) {
+        self.0.call_contract(
+            sender,
+            "set_veboost_proxy",
+            runtime_args! {
+                "veboost_proxy"=>veboost_proxy,
+            },
+            time_now,
+        );
+    }
     pub fn approve(&self, sender: AccountHash, spender: Address, amount: U256, time_now: u64) {
         self.0.call_contract(
             sender,
@@ -276,6 +286,9 @@ impl LIQUIDITYGUAGEV4INSTANCEInstance {
     pub fn is_killed(&self) -> bool {
         self.0.query_named_key(String::from("is_killed"))
     }
+    pub fn veboost_proxy(&self) -> Key {
+        self.0.query_named_key(String::from("veboost_proxy"))
+    }
     pub fn allowance(&self, owner: Address, spender: Address) -> U256 {
         let ret: U256 = self.0.query(ALLOWANCES, addresses_to_str(owner, spender));
         ret
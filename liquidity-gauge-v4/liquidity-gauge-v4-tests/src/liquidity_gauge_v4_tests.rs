@@ -60,6 +60,19 @@ fn deploy_voting_escrow(
         LIQUIDITYGUAGEV4INSTANCEInstance::now(),
     )
 }
+// Ve Boost
+fn deploy_ve_boost(env: &TestEnv, sender: AccountHash, voting_escrow: Key) -> TestContract {
+    TestContract::new(
+        env,
+        "ve-boost.wasm",
+        "ve-boost",
+        sender,
+        runtime_args! {
+            "voting_escrow" => voting_escrow,
+        },
+        LIQUIDITYGUAGEV4INSTANCEInstance::now(),
+    )
+}
 //gauge_controller
 fn deploy_gauge_controller(
     env: &TestEnv,
@@ -507,6 +520,24 @@ mod t12 {
         contract.set_killed(owner, is_killed, time_now);
         assert_eq!(contract.is_killed(), is_killed);
     }
+    #[test]
+    fn test_set_veboost_proxy() {
+        let (env, owner, contract, _, _, time_now, _, _, _) = deploy();
+        let contract = LIQUIDITYGUAGEV4INSTANCEInstance::instance(contract);
+        let ve_boost = deploy_ve_boost(&env, owner, contract.key_value(VOTING_ESCROW.into()));
+        contract.set_veboost_proxy(owner, Key::Hash(ve_boost.package_hash()), time_now);
+        assert_eq!(contract.veboost_proxy(), Key::Hash(ve_boost.package_hash()));
+        // Liquidity limit is now read through the proxy
+        contract.deposit(owner, 100_000_000_000u64.into(), None, None, time_now);
+    }
+    #[test]
+    #[should_panic]
+    fn test_set_veboost_proxy_by_non_admin() {
+        let (env, _, contract, _, _, time_now, _, _, _) = deploy();
+        let contract = LIQUIDITYGUAGEV4INSTANCEInstance::instance(contract);
+        let user = env.next_user();
+        contract.set_veboost_proxy(user, Key::Account(user), time_now);
+    }
 }
 mod value_checks {
     use crate::{liquidity_gauge_v4_instance::address_to_str, liquidity_gauge_v4_tests::*};
@@ -2,24 +2,19 @@
 #![no_std]
 extern crate alloc;
 
-use alloc::{
-    boxed::Box,
-    collections::BTreeSet,
-    format,
-    string::{String, ToString},
-    vec,
-};
+use alloc::{collections::BTreeSet, format, string::ToString};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+    runtime_args, CLValue, ContractHash, ContractPackageHash, Key, RuntimeArgs, URef, U256,
 };
 use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
 use crv20::{self, Address, CURVEERC20};
-use liquidity_gauge_v4_crate::{self, data, utils::*, LIQUIDITYTGAUGEV4};
+use liquidity_gauge_v4_crate::{
+    self, data, entry_points::get_entry_points, int128::I128, utils::*, LIQUIDITYTGAUGEV4,
+};
 #[derive(Default)]
 struct LiquidityGaugeV4(OnChainContractStorage);
 
@@ -289,6 +284,15 @@ fn set_killed() {
     LiquidityGaugeV4::default().set_killed(is_killed);
 }
 /// """
+/// @notice Set the contract boosted balances are read from
+/// @param veboost_proxy Contract exposing `adjusted_balance_of`, zero address to disable
+/// """
+#[no_mangle]
+fn set_veboost_proxy() {
+    let veboost_proxy: Key = runtime::get_named_arg("veboost_proxy");
+    LiquidityGaugeV4::default().set_veboost_proxy(veboost_proxy);
+}
+/// """
 /// @notice Transfer ownership of GaugeController to `addr`
 /// @param addr Address to have ownership transferred to
 /// """
@@ -330,6 +334,10 @@ fn voting_escrow() {
     runtime::ret(CLValue::from_t(data::get_voting_escrow()).unwrap_or_revert());
 }
 #[no_mangle]
+fn veboost_proxy() {
+    runtime::ret(CLValue::from_t(data::get_veboost_proxy()).unwrap_or_revert());
+}
+#[no_mangle]
 fn future_epoch_time() {
     runtime::ret(CLValue::from_t(data::get_future_epoch_time()).unwrap_or_revert());
 }
@@ -380,7 +388,7 @@ fn working_supply() {
 }
 #[no_mangle]
 fn period() {
-    runtime::ret(CLValue::from_t(i128_to_tuple(data::get_period())).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(I128::from(data::get_period())).unwrap_or_revert());
 }
 #[no_mangle]
 fn period_timestamp() {
@@ -464,430 +472,6 @@ fn future_admin() {
 fn is_killed() {
     runtime::ret(CLValue::from_t(data::get_is_killed()).unwrap_or_revert());
 }
-fn get_entry_points() -> EntryPoints {
-    let mut entry_points = EntryPoints::new();
-    entry_points.add_entry_point(EntryPoint::new(
-        "constructor",
-        vec![
-            Parameter::new("lp_addr", Key::cl_type()),
-            Parameter::new("minter", Key::cl_type()),
-            Parameter::new("admin", Key::cl_type()),
-            Parameter::new("contract_hash", ContractHash::cl_type()),
-            Parameter::new("package_hash", ContractPackageHash::cl_type()),
-        ],
-        <()>::cl_type(),
-        EntryPointAccess::Groups(vec![Group::new("constructor")]),
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "decimals",
-        vec![],
-        u8::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "integrate_checkpoint",
-        vec![],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "user_checkpoint",
-        vec![Parameter::new("addr", Key::cl_type())],
-        bool::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "claimable_tokens",
-        vec![Parameter::new("addr", Key::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "claimed_reward",
-        vec![
-            Parameter::new("addr", Key::cl_type()),
-            Parameter::new("token", Key::cl_type()),
-        ],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "claimable_reward",
-        vec![
-            Parameter::new("addr", Key::cl_type()),
-            Parameter::new("token", Key::cl_type()),
-        ],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "set_rewards_receiver",
-        vec![Parameter::new("receiver", Key::cl_type())],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "claim_rewards",
-        vec![
-            Parameter::new("addr", CLType::Option(Box::new(CLType::Key))),
-            Parameter::new("receiver", CLType::Option(Box::new(CLType::Key))),
-        ],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "kick",
-        vec![Parameter::new("addr", Key::cl_type())],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "deposit",
-        vec![
-            Parameter::new("value", U256::cl_type()),
-            Parameter::new("addr", CLType::Option(Box::new(CLType::Key))),
-            Parameter::new("claim_rewards", CLType::Option(Box::new(bool::cl_type()))),
-        ],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "withdraw",
-        vec![
-            Parameter::new("value", U256::cl_type()),
-            Parameter::new("claim_rewards", CLType::Option(Box::new(bool::cl_type()))),
-        ],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "transfer",
-        vec![
-            Parameter::new("recipient", Address::cl_type()),
-            Parameter::new("amount", U256::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "transfer_from",
-        vec![
-            Parameter::new("owner", Address::cl_type()),
-            Parameter::new("recipient", Address::cl_type()),
-            Parameter::new("amount", U256::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "approve",
-        vec![
-            Parameter::new("spender", Address::cl_type()),
-            Parameter::new("amount", U256::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "increase_allowance",
-        vec![
-            Parameter::new("spender", Address::cl_type()),
-            Parameter::new("amount", U256::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "decrease_allowance",
-        vec![
-            Parameter::new("spender", Address::cl_type()),
-            Parameter::new("amount", U256::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "add_reward",
-        vec![
-          Parameter::new("reward_token", Key::cl_type()),
-          Parameter::new("distributor", Key::cl_type()),
-        ],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "set_reward_distributor",
-        vec![
-          Parameter::new("reward_token", Key::cl_type()),
-          Parameter::new("distributor", Key::cl_type()),
-        ],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "deposit_reward_token",
-        vec![
-          Parameter::new("reward_token", Key::cl_type()),
-          Parameter::new("amount", U256::cl_type()),
-        ],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "set_killed",
-        vec![Parameter::new("is_killed", bool::cl_type())],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "commit_transfer_ownership",
-        vec![Parameter::new("addr", Key::cl_type())],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "accept_transfer_ownership",
-        vec![],
-        <()>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-
-    //entry points of public variables
-    entry_points.add_entry_point(EntryPoint::new(
-        "minter",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "crv_token",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "lp_token",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "controller",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "voting_escrow",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "future_epoch_time",
-        vec![],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "balance_of",
-        vec![Parameter::new("address", Address::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "total_supply",
-        vec![],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "allowance",
-        vec![
-            Parameter::new("owner", Address::cl_type()),
-            Parameter::new("spender", Address::cl_type()),
-        ],
-        CLType::U256,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "name",
-        vec![],
-        String::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "symbol",
-        vec![],
-        String::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "working_balances",
-        vec![Parameter::new("owner", Key::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "working_supply",
-        vec![],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "period",
-        vec![],
-        CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "period_timestamp",
-        vec![Parameter::new("owner", U256::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "integrate_inv_supply",
-        vec![Parameter::new("owner", U256::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "integrate_inv_supply_of",
-        vec![Parameter::new("owner", U256::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "integrate_checkpoint_of",
-        vec![Parameter::new("owner", Key::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "integrate_fraction",
-        vec![Parameter::new("owner", Key::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "inflation_rate",
-        vec![],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "reward_count",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "reward_tokens",
-        vec![Parameter::new("owner", U256::cl_type())],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "reward_data",
-        vec![Parameter::new("address", Key::cl_type())],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "rewards_receiver",
-        vec![Parameter::new("owner", Key::cl_type())],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "reward_integral",
-        vec![Parameter::new("owner", Key::cl_type())],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "reward_integral_for",
-        vec![
-            Parameter::new("owner", Key::cl_type()),
-            Parameter::new("spender", Key::cl_type()),
-        ],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "admin",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "future_admin",
-        vec![],
-        Key::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        "is_killed",
-        vec![],
-        bool::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points
-}
-
 #[no_mangle]
 fn call() {
     // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
@@ -1,5 +1,12 @@
-use alloc::string::{String, ToString};
-use casper_types::{Key, U256};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
 
 pub enum LiquidityGaugeWrapperEvent {
     Deposit {
@@ -55,3 +62,62 @@ impl LiquidityGaugeWrapperEvent {
         .to_string()
     }
 }
+
+impl CasperEvent for LiquidityGaugeWrapperEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            LiquidityGaugeWrapperEvent::Deposit { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            LiquidityGaugeWrapperEvent::Withdraw { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            LiquidityGaugeWrapperEvent::CommitOwnership { admin } => admin.to_bytes(),
+            LiquidityGaugeWrapperEvent::ApplyOwnership { admin } => admin.to_bytes(),
+            LiquidityGaugeWrapperEvent::Approval {
+                owner,
+                spender,
+                value,
+            } => Ok([owner.to_bytes()?, spender.to_bytes()?, value.to_bytes()?].concat()),
+            LiquidityGaugeWrapperEvent::Transfer { from, to, value } => {
+                Ok([from.to_bytes()?, to.to_bytes()?, value.to_bytes()?].concat())
+            }
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "deposit",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "withdraw",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with("commitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("applyOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with(
+                "approval",
+                Schema::new()
+                    .with_elem::<Key>("owner")
+                    .with_elem::<Key>("spender")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "transfer",
+                Schema::new()
+                    .with_elem::<Key>("from")
+                    .with_elem::<Key>("to")
+                    .with_elem::<U256>("value"),
+            )
+    }
+}
@@ -212,7 +212,18 @@ fn deploy() -> (TestEnv, AccountHash, TestContract, u64) {
         runtime_args! {"name" => _name, "weight" => Some(U256::from(1)) },
         block_time,
     );
-    let addr: Key = Key::Account(owner);
+    // Weight for type 0 comes from another gauge, the wrapped gauge is added with type 1 below
+    let addr: Key = Key::Hash(
+        deploy_liquidity_gauge_v3(
+            &env,
+            owner,
+            Key::Hash(erc20.package_hash()),
+            Key::Hash(minter.package_hash()),
+            Key::Account(owner),
+            block_time,
+        )
+        .package_hash(),
+    );
     let gauge_type: (bool, U128) = (false, 0.into());
     gauge_controller.call_contract(
         owner,
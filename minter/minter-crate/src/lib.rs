@@ -6,4 +6,4 @@ pub mod event;
 mod minter;
 
 pub use casperlabs_contract_utils;
-pub use minter::MINTER;
+pub use minter::{MINTEREvent, MINTER};
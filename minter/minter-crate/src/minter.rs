@@ -1,13 +1,19 @@
 use crate::alloc::string::ToString;
-use crate::data::{self, get_package_hash, AllowedToMintFor, Minted};
-use alloc::collections::BTreeMap;
+use crate::data::{self, AllowedToMintFor, Minted};
 use alloc::{string::String, vec::Vec};
 use casper_contract::contract_api::runtime;
-use casper_contract::contract_api::storage;
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::{runtime_args, ApiError, ContractPackageHash, Key, RuntimeArgs, U128, U256};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    runtime_args, ApiError, ContractPackageHash, Key, RuntimeArgs, U256,
+};
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{
+    errors::*,
+    events::{self, CasperEvent, Schema, Schemas},
+    int128::I128,
+    utils::*,
+};
 
 pub enum MINTEREvent {
     Minted {
@@ -30,6 +36,32 @@ impl MINTEREvent {
     }
 }
 
+impl CasperEvent for MINTEREvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            MINTEREvent::Minted {
+                recipient,
+                gauge,
+                minted,
+            } => Ok([recipient.to_bytes()?, gauge.to_bytes()?, minted.to_bytes()?].concat()),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new().with(
+            "minted",
+            Schema::new()
+                .with_elem::<Key>("recipient")
+                .with_elem::<Key>("gauge")
+                .with_elem::<U256>("minted"),
+        )
+    }
+}
+
 pub trait MINTER<Storage: ContractStorage>: ContractContext<Storage> {
     fn init(
         &mut self,
@@ -45,6 +77,7 @@ pub trait MINTER<Storage: ContractStorage>: ContractContext<Storage> {
         data::set_lock(0);
         Minted::init();
         AllowedToMintFor::init();
+        events::init::<MINTEREvent>();
     }
 
     #[inline(always)]
@@ -55,15 +88,14 @@ pub trait MINTER<Storage: ContractStorage>: ContractContext<Storage> {
             _ => runtime::revert(ApiError::UnexpectedKeyVariant),
         };
         let controller_package_hash = ContractPackageHash::new(controller_hash_add_array);
-        let ret: (bool, U128) = runtime::call_versioned_contract(
+        let ret: I128 = runtime::call_versioned_contract(
             controller_package_hash,
             None,
             "gauge_types",
             runtime_args! {"addr" => gauge_addr},
         );
-        let ret: i128 = tuple_to_i128(ret);
 
-        if ret < 0.into() {
+        if ret.is_negative() {
             //dev: gauge is not added
             runtime::revert(Error::MinterIsNotAdded);
         }
@@ -177,21 +209,7 @@ pub trait MINTER<Storage: ContractStorage>: ContractContext<Storage> {
     }
 
     fn emit(&mut self, minter_event: &MINTEREvent) {
-        match minter_event {
-            MINTEREvent::Minted {
-                recipient,
-                gauge,
-                minted,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", minter_event.type_name());
-                event.insert("recipient", recipient.to_string());
-                event.insert("gauge", gauge.to_string());
-                event.insert("minted", minted.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(minter_event);
     }
 
     #[inline(always)]
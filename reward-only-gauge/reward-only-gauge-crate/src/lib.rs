@@ -5,4 +5,4 @@ pub mod data;
 mod reward_only_gauge;
 
 pub use casperlabs_contract_utils;
-pub use reward_only_gauge::REWARDONLYGAUGE;
+pub use reward_only_gauge::{REWARDONLYGAUGEEvent, REWARDONLYGAUGE};
@@ -1,18 +1,22 @@
 use crate::alloc::string::ToString;
 use crate::data::{
-    self, get_package_hash, ClaimData, ClaimDataStruct, RewardBalances, RewardData, RewardIntegral,
+    self, ClaimData, ClaimDataStruct, RewardBalances, RewardData, RewardIntegral,
     RewardIntegralFor, RewardTokens, RewardsReceiver, CLAIM_FREQUENCY, MAX_REWARDS,
 };
 use alloc::collections::BTreeMap;
 use alloc::{string::String, vec::Vec};
-use casper_contract::contract_api::storage;
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 
 use casper_types::{
+    bytesrepr::{self, ToBytes},
     runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{
+    errors::*,
+    events::{self, CasperEvent, Schema, Schemas},
+    utils::*,
+};
 use crv20::{self, Address, CURVEERC20};
 use curve_casper_erc20::Error as Erc20Error;
 pub enum REWARDONLYGAUGEEvent {
@@ -70,6 +74,65 @@ impl REWARDONLYGAUGEEvent {
     }
 }
 
+impl CasperEvent for REWARDONLYGAUGEEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            REWARDONLYGAUGEEvent::Withdraw { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            REWARDONLYGAUGEEvent::Deposit { provider, value } => {
+                Ok([provider.to_bytes()?, value.to_bytes()?].concat())
+            }
+            REWARDONLYGAUGEEvent::CommitOwnership { admin } => admin.to_bytes(),
+            REWARDONLYGAUGEEvent::ApplyOwnership { admin } => admin.to_bytes(),
+            REWARDONLYGAUGEEvent::Approval {
+                owner,
+                spender,
+                value,
+            } => Ok([owner.to_bytes()?, spender.to_bytes()?, value.to_bytes()?].concat()),
+            REWARDONLYGAUGEEvent::Transfer { from, to, value } => {
+                Ok([from.to_bytes()?, to.to_bytes()?, value.to_bytes()?].concat())
+            }
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "withdraw",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "deposit",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value"),
+            )
+            .with("CommitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("ApplyOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with(
+                "approve",
+                Schema::new()
+                    .with_elem::<Key>("owner")
+                    .with_elem::<Key>("spender")
+                    .with_elem::<U256>("value"),
+            )
+            .with(
+                "transfer",
+                Schema::new()
+                    .with_elem::<Key>("from")
+                    .with_elem::<Key>("to")
+                    .with_elem::<U256>("value"),
+            )
+    }
+}
+
 pub trait REWARDONLYGAUGE<Storage: ContractStorage>:
     ContractContext<Storage> + CURVEERC20<Storage>
 {
@@ -113,6 +176,7 @@ pub trait REWARDONLYGAUGE<Storage: ContractStorage>:
         RewardIntegral::init();
         RewardIntegralFor::init();
         ClaimData::init();
+        events::init::<REWARDONLYGAUGEEvent>();
     }
 
     #[inline(always)]
@@ -646,60 +710,7 @@ pub trait REWARDONLYGAUGE<Storage: ContractStorage>:
         CURVEERC20::named_keys(self, "".to_string(), "".to_string(), 9, 0.into())
     }
     fn emit(&mut self, reward_only_gauge_event: &REWARDONLYGAUGEEvent) {
-        match reward_only_gauge_event {
-            REWARDONLYGAUGEEvent::Withdraw { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", reward_only_gauge_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            REWARDONLYGAUGEEvent::Deposit { provider, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", reward_only_gauge_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            REWARDONLYGAUGEEvent::CommitOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", reward_only_gauge_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            REWARDONLYGAUGEEvent::ApplyOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", reward_only_gauge_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            REWARDONLYGAUGEEvent::Approval {
-                owner,
-                spender,
-                value,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", reward_only_gauge_event.type_name());
-                event.insert("owner", owner.to_string());
-                event.insert("spender", spender.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-            REWARDONLYGAUGEEvent::Transfer { from, to, value } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", reward_only_gauge_event.type_name());
-                event.insert("from", from.to_string());
-                event.insert("to", to.to_string());
-                event.insert("value", value.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(reward_only_gauge_event);
     }
 
     fn get_package_hash(&mut self) -> ContractPackageHash {
@@ -0,0 +1,77 @@
+use casper_types::{ContractHash, ContractPackageHash, Key};
+use casper_types_derive::{CLTyped, FromBytes, ToBytes};
+use casperlabs_contract_utils::{get_key, set_key, Dict};
+use common::{keys::*, utils::*};
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct Wallets {
+    dict: Dict,
+}
+
+impl Wallets {
+    pub fn instance() -> Wallets {
+        Wallets {
+            dict: Dict::instance(WALLETS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(WALLETS_DICT)
+    }
+
+    pub fn get(&self, wallet: &Key) -> bool {
+        self.dict.get_by_key(wallet).unwrap_or_default()
+    }
+
+    pub fn set(&self, wallet: &Key, value: bool) {
+        self.dict.set_by_key(wallet, value);
+    }
+}
+
+pub fn get_admin() -> Key {
+    get_key(ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_admin(admin: Key) {
+    set_key(ADMIN, admin);
+}
+
+pub fn get_future_admin() -> Key {
+    get_key(FUTURE_ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_admin(future_admin: Key) {
+    set_key(FUTURE_ADMIN, future_admin);
+}
+
+pub fn get_checker() -> Key {
+    get_key(CHECKER).unwrap_or_else(zero_address)
+}
+
+pub fn set_checker(checker: Key) {
+    set_key(CHECKER, checker);
+}
+
+pub fn get_future_checker() -> Key {
+    get_key(FUTURE_CHECKER).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_checker(future_checker: Key) {
+    set_key(FUTURE_CHECKER, future_checker);
+}
+
+pub fn get_contract_hash() -> ContractHash {
+    get_key(SELF_CONTRACT_HASH).unwrap_or_default()
+}
+
+pub fn set_contract_hash(contract_hash: ContractHash) {
+    set_key(SELF_CONTRACT_HASH, contract_hash);
+}
+
+pub fn get_package_hash() -> ContractPackageHash {
+    get_key(SELF_CONTRACT_PACKAGE_HASH).unwrap_or_default()
+}
+
+pub fn set_package_hash(package_hash: ContractPackageHash) {
+    set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
+}
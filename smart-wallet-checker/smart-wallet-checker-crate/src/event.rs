@@ -0,0 +1,51 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key,
+};
+use common::events::{CasperEvent, Schema, Schemas};
+
+pub enum SmartWalletCheckerEvent {
+    ApproveWallet { wallet: Key },
+    RevokeWallet { wallet: Key },
+    CommitOwnership { admin: Key },
+    ApplyOwnership { admin: Key },
+}
+
+impl SmartWalletCheckerEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            SmartWalletCheckerEvent::ApproveWallet { wallet: _ } => "approveWallet",
+            SmartWalletCheckerEvent::RevokeWallet { wallet: _ } => "revokeWallet",
+            SmartWalletCheckerEvent::CommitOwnership { admin: _ } => "commitOwnership",
+            SmartWalletCheckerEvent::ApplyOwnership { admin: _ } => "applyOwnership",
+        }
+        .to_string()
+    }
+}
+
+impl CasperEvent for SmartWalletCheckerEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            SmartWalletCheckerEvent::ApproveWallet { wallet } => wallet.to_bytes(),
+            SmartWalletCheckerEvent::RevokeWallet { wallet } => wallet.to_bytes(),
+            SmartWalletCheckerEvent::CommitOwnership { admin } => admin.to_bytes(),
+            SmartWalletCheckerEvent::ApplyOwnership { admin } => admin.to_bytes(),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with("approveWallet", Schema::new().with_elem::<Key>("wallet"))
+            .with("revokeWallet", Schema::new().with_elem::<Key>("wallet"))
+            .with("commitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("applyOwnership", Schema::new().with_elem::<Key>("admin"))
+    }
+}
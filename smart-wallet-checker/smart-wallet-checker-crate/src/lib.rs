@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod data;
+pub mod event;
+mod smart_wallet_checker;
+
+pub use smart_wallet_checker::SMARTWALLETCHECKER;
@@ -0,0 +1,94 @@
+use crate::{data::*, event::SmartWalletCheckerEvent};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use common::{errors::Error, events, utils::*};
+
+/// @notice Allowlist of smart contract wallets permitted to lock in the voting escrow
+/// @dev Wallets not found here can still be approved by an optional chained checker
+pub trait SMARTWALLETCHECKER<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&self, admin: Key, contract_hash: ContractHash, package_hash: ContractPackageHash) {
+        Wallets::init();
+        events::init::<SmartWalletCheckerEvent>();
+        set_admin(admin);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    #[inline(always)]
+    fn only_admin(&self) {
+        if self.get_caller() != get_admin() {
+            runtime::revert(ApiError::from(Error::SmartWalletCheckerAdminOnly));
+        }
+    }
+
+    #[inline(always)]
+    fn commit_set_checker(&self, checker: Key) {
+        self.only_admin();
+        set_future_checker(checker);
+    }
+
+    #[inline(always)]
+    fn apply_set_checker(&self) {
+        self.only_admin();
+        set_checker(get_future_checker());
+    }
+
+    #[inline(always)]
+    fn approve_wallet(&self, wallet: Key) {
+        self.only_admin();
+        Wallets::instance().set(&wallet, true);
+        self.emit(&SmartWalletCheckerEvent::ApproveWallet { wallet });
+    }
+
+    #[inline(always)]
+    fn revoke_wallet(&self, wallet: Key) {
+        self.only_admin();
+        Wallets::instance().set(&wallet, false);
+        self.emit(&SmartWalletCheckerEvent::RevokeWallet { wallet });
+    }
+
+    /// @notice Check if `addr` is allowed to lock in the voting escrow
+    /// @param addr Address to check
+    /// @return bool success
+    #[inline(always)]
+    fn check(&self, addr: Key) -> bool {
+        if Wallets::instance().get(&addr) {
+            return true;
+        }
+        let checker: Key = get_checker();
+        if checker != zero_address() && checker != account_zero_address() {
+            return runtime::call_versioned_contract(
+                checker.into_hash().unwrap_or_revert().into(),
+                None,
+                "check",
+                runtime_args! {
+                    "addr" => addr
+                },
+            );
+        }
+        false
+    }
+
+    #[inline(always)]
+    fn commit_transfer_ownership(&self, addr: Key) {
+        self.only_admin();
+        set_future_admin(addr);
+        self.emit(&SmartWalletCheckerEvent::CommitOwnership { admin: addr });
+    }
+
+    #[inline(always)]
+    fn apply_transfer_ownership(&self) {
+        self.only_admin();
+        let admin: Key = get_future_admin();
+        if admin == zero_address() || admin == account_zero_address() {
+            runtime::revert(ApiError::from(Error::SmartWalletCheckerAdminNotSet));
+        }
+        set_admin(admin);
+        self.emit(&SmartWalletCheckerEvent::ApplyOwnership { admin });
+    }
+
+    fn emit(&self, smart_wallet_checker_event: &SmartWalletCheckerEvent) {
+        events::emit(smart_wallet_checker_event);
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod smart_wallet_checker_tests;
+
+#[cfg(test)]
+pub mod smart_wallet_checker_instance;
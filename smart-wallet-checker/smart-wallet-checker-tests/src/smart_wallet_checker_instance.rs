@@ -0,0 +1,89 @@
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, runtime_args, CLTyped, Key, RuntimeArgs,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+
+pub struct SMARTWALLETCHECKERInstance(TestContract);
+impl SMARTWALLETCHECKERInstance {
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        admin: Key,
+        time: u64,
+    ) -> SMARTWALLETCHECKERInstance {
+        SMARTWALLETCHECKERInstance(TestContract::new(
+            env,
+            "smart-wallet-checker.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "admin" => admin,
+            },
+            time,
+        ))
+    }
+
+    pub fn commit_set_checker(&self, owner: AccountHash, checker: Key, time: u64) {
+        self.0.call_contract(
+            owner,
+            "commit_set_checker",
+            runtime_args! {
+                "checker" => checker
+            },
+            time,
+        );
+    }
+
+    pub fn apply_set_checker(&self, owner: AccountHash, time: u64) {
+        self.0
+            .call_contract(owner, "apply_set_checker", runtime_args! {}, time);
+    }
+
+    pub fn approve_wallet(&self, owner: AccountHash, wallet: Key, time: u64) {
+        self.0.call_contract(
+            owner,
+            "approve_wallet",
+            runtime_args! {
+                "wallet" => wallet
+            },
+            time,
+        );
+    }
+
+    pub fn revoke_wallet(&self, owner: AccountHash, wallet: Key, time: u64) {
+        self.0.call_contract(
+            owner,
+            "revoke_wallet",
+            runtime_args! {
+                "wallet" => wallet
+            },
+            time,
+        );
+    }
+
+    pub fn commit_transfer_ownership(&self, owner: AccountHash, addr: Key, time: u64) {
+        self.0.call_contract(
+            owner,
+            "commit_transfer_ownership",
+            runtime_args! {
+                "addr" => addr
+            },
+            time,
+        );
+    }
+
+    pub fn apply_transfer_ownership(&self, owner: AccountHash, time: u64) {
+        self.0
+            .call_contract(owner, "apply_transfer_ownership", runtime_args! {}, time);
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+
+    // Get stored key values
+    pub fn key_value<T: CLTyped + FromBytes>(&self, key: String) -> T {
+        self.0.query_named_key(key)
+    }
+}
@@ -0,0 +1,107 @@
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+
+use crate::smart_wallet_checker_instance::SMARTWALLETCHECKERInstance;
+
+const NAME: &str = "SMARTWALLETCHECKER";
+
+fn deploy() -> (TestEnv, SMARTWALLETCHECKERInstance, AccountHash) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let checker = SMARTWALLETCHECKERInstance::new_deploy(&env, NAME, owner, Key::from(owner), 0);
+    (env, checker, owner)
+}
+
+fn check(
+    env: &TestEnv,
+    owner: AccountHash,
+    checker: &SMARTWALLETCHECKERInstance,
+    addr: Key,
+) -> bool {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(CHECK),
+            "package_hash" => Key::Hash(checker.package_hash()),
+            "addr" => addr
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[CHECK.into()])
+}
+
+#[test]
+fn test_deploy() {
+    let (_env, checker, owner) = deploy();
+    let admin: Key = checker.key_value(ADMIN.to_string());
+    assert_eq!(admin, Key::from(owner), "Admin not set");
+}
+
+#[test]
+fn test_approve_wallet() {
+    let (env, checker, owner) = deploy();
+    let wallet: Key = Key::Hash([7u8; 32]);
+    assert!(
+        !check(&env, owner, &checker, wallet),
+        "Wallet approved by default"
+    );
+    checker.approve_wallet(owner, wallet, 0);
+    assert!(check(&env, owner, &checker, wallet), "Wallet not approved");
+}
+
+#[test]
+fn test_revoke_wallet() {
+    let (env, checker, owner) = deploy();
+    let wallet: Key = Key::Hash([7u8; 32]);
+    checker.approve_wallet(owner, wallet, 0);
+    checker.revoke_wallet(owner, wallet, 0);
+    assert!(!check(&env, owner, &checker, wallet), "Wallet not revoked");
+}
+
+#[test]
+#[should_panic]
+fn test_approve_wallet_by_non_admin() {
+    let (env, checker, _owner) = deploy();
+    let user = env.next_user();
+    checker.approve_wallet(user, Key::Hash([7u8; 32]), 0);
+}
+
+#[test]
+fn test_chained_checker() {
+    let (env, checker, owner) = deploy();
+    let chained = SMARTWALLETCHECKERInstance::new_deploy(
+        &env,
+        "CHAINEDSMARTWALLETCHECKER",
+        owner,
+        Key::from(owner),
+        0,
+    );
+    let wallet: Key = Key::Hash([7u8; 32]);
+    chained.approve_wallet(owner, wallet, 0);
+    checker.commit_set_checker(owner, Key::Hash(chained.package_hash()), 0);
+    assert!(
+        !check(&env, owner, &checker, wallet),
+        "Checker applied before commit"
+    );
+    checker.apply_set_checker(owner, 0);
+    let ret: Key = checker.key_value(CHECKER.to_string());
+    assert_eq!(ret, Key::Hash(chained.package_hash()), "Checker not set");
+    assert!(
+        check(&env, owner, &checker, wallet),
+        "Chained checker not consulted"
+    );
+}
+
+#[test]
+fn test_apply_transfer_ownership() {
+    let (env, checker, owner) = deploy();
+    let addr: Key = Key::Account(env.next_user());
+    checker.commit_transfer_ownership(owner, addr, 0);
+    checker.apply_transfer_ownership(owner, 0);
+    let ret: Key = checker.key_value(ADMIN.to_string());
+    assert_eq!(ret, addr, "Ownership transfer not applied");
+}
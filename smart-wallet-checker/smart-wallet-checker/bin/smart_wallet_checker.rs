@@ -0,0 +1,309 @@
+#![no_main]
+#![no_std]
+extern crate alloc;
+use alloc::{collections::BTreeSet, format, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef,
+};
+use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
+use smart_wallet_checker_crate::{self, data, SMARTWALLETCHECKER};
+
+#[derive(Default)]
+struct SmartWalletChecker(OnChainContractStorage);
+impl ContractContext<OnChainContractStorage> for SmartWalletChecker {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl SMARTWALLETCHECKER<OnChainContractStorage> for SmartWalletChecker {}
+impl SmartWalletChecker {
+    fn constructor(
+        &mut self,
+        admin: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        SMARTWALLETCHECKER::init(self, admin, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let admin: Key = runtime::get_named_arg("admin");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    SmartWalletChecker::default().constructor(admin, contract_hash, package_hash);
+}
+
+/// Set a chained checker which is consulted for wallets not approved here
+/// # Parameters
+/// * `checker` - Address of the chained checker contract
+#[no_mangle]
+fn commit_set_checker() {
+    let checker: Key = runtime::get_named_arg("checker");
+    SmartWalletChecker::default().commit_set_checker(checker);
+}
+
+/// Apply the effects of `commit_set_checker`
+#[no_mangle]
+fn apply_set_checker() {
+    SmartWalletChecker::default().apply_set_checker();
+}
+
+/// Allow `wallet` to lock in the voting escrow
+/// # Parameters
+/// * `wallet` - Smart contract wallet package hash
+#[no_mangle]
+fn approve_wallet() {
+    let wallet: Key = runtime::get_named_arg("wallet");
+    SmartWalletChecker::default().approve_wallet(wallet);
+}
+
+/// Remove `wallet` from the allowlist
+/// # Parameters
+/// * `wallet` - Smart contract wallet package hash
+#[no_mangle]
+fn revoke_wallet() {
+    let wallet: Key = runtime::get_named_arg("wallet");
+    SmartWalletChecker::default().revoke_wallet(wallet);
+}
+
+/// Check if `addr` is allowed to lock in the voting escrow
+/// # Parameters
+/// * `addr` - Address to check
+#[no_mangle]
+fn check() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: bool = SmartWalletChecker::default().check(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Transfer ownership of the checker to `addr`
+/// # Parameters
+/// * `addr` - Address to have ownership transferred to
+#[no_mangle]
+fn commit_transfer_ownership() {
+    let addr: Key = runtime::get_named_arg("addr");
+    SmartWalletChecker::default().commit_transfer_ownership(addr);
+}
+
+/// Apply ownership transfer
+#[no_mangle]
+fn apply_transfer_ownership() {
+    SmartWalletChecker::default().apply_transfer_ownership();
+}
+
+#[no_mangle]
+fn wallets() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(CLValue::from_t(data::Wallets::instance().get(&owner)).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn checker() {
+    runtime::ret(CLValue::from_t(data::get_checker()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn future_checker() {
+    runtime::ret(CLValue::from_t(data::get_future_checker()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn admin() {
+    runtime::ret(CLValue::from_t(data::get_admin()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn future_admin() {
+    runtime::ret(CLValue::from_t(data::get_future_admin()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("admin", Key::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "commit_set_checker",
+        vec![Parameter::new("checker", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "apply_set_checker",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve_wallet",
+        vec![Parameter::new("wallet", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_wallet",
+        vec![Parameter::new("wallet", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "check",
+        vec![Parameter::new("addr", Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "commit_transfer_ownership",
+        vec![Parameter::new("addr", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "apply_transfer_ownership",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "wallets",
+        vec![Parameter::new("owner", Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "checker",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_checker",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_admin",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        // add a first version to this package
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let admin: Key = runtime::get_named_arg("admin");
+        let constructor_args = runtime_args! {
+            "admin" => admin,
+            "package_hash" => package_hash,
+            "contract_hash" => contract_hash,
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    }
+    // If contract package did already exist
+    else {
+        // get the package
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+        // create new version and install it
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
@@ -4,7 +4,7 @@
 // We need to explicitly import the std alloc crate and `alloc::string::String` as we're in a
 // `no_std` environment.
 extern crate alloc;
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
@@ -98,6 +98,20 @@ pub extern "C" fn call() {
             );
             store(BALANCE_OF, ret);
         }
+        POSITION_BALANCE_OF => {
+            let token_id: U256 = runtime::get_named_arg("token_id");
+            let t: U256 = runtime::get_named_arg("t");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                POSITION_BALANCE_OF,
+                runtime_args! {
+                    "token_id" => token_id,
+                    "t" => Some(t)
+                },
+            );
+            store(POSITION_BALANCE_OF, ret);
+        }
         BALANCE_OF_AT => {
             let addr: Key = runtime::get_named_arg("addr");
             let block: U256 = runtime::get_named_arg("block");
@@ -195,6 +209,76 @@ pub extern "C" fn call() {
             );
             store(TOTAL_SUPPLY_AT, ret);
         }
+        BALANCE_OF_AT_TIME => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let time: U256 = runtime::get_named_arg("time");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                BALANCE_OF_AT_TIME,
+                runtime_args! {
+                    "addr" => addr,
+                    "time" => time
+                },
+            );
+            store(BALANCE_OF_AT_TIME, ret);
+        }
+        TOTAL_SUPPLY_AT_TIME => {
+            let time: U256 = runtime::get_named_arg("time");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                TOTAL_SUPPLY_AT_TIME,
+                runtime_args! {
+                    "time" => time,
+                },
+            );
+            store(TOTAL_SUPPLY_AT_TIME, ret);
+        }
+        BALANCES_OF_AT => {
+            let addrs: Vec<Key> = runtime::get_named_arg("addrs");
+            let t: U256 = runtime::get_named_arg("t");
+            let ret: Vec<U256> = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                BALANCES_OF_AT,
+                runtime_args! {
+                    "addrs" => addrs,
+                    "t" => t
+                },
+            );
+            store(BALANCES_OF_AT, ret);
+        }
+        TOTAL_SUPPLY_SERIES => {
+            let from: U256 = runtime::get_named_arg("from");
+            let to: U256 = runtime::get_named_arg("to");
+            let step: U256 = runtime::get_named_arg("step");
+            let ret: Vec<U256> = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                TOTAL_SUPPLY_SERIES,
+                runtime_args! {
+                    "from" => from,
+                    "to" => to,
+                    "step" => step
+                },
+            );
+            store(TOTAL_SUPPLY_SERIES, ret);
+        }
+        GET_VOTES => {
+            let delegate: Key = runtime::get_named_arg("delegate");
+            let time: U256 = runtime::get_named_arg("time");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                GET_VOTES,
+                runtime_args! {
+                    "delegate" => delegate,
+                    "time" => time
+                },
+            );
+            store(GET_VOTES, ret);
+        }
         LAST_TIME_REWARD_APPLICABLE => {
             let ret: U256 = runtime::call_versioned_contract(
                 package_hash.into_hash().unwrap_or_revert().into(),
@@ -472,6 +556,80 @@ pub extern "C" fn call() {
             );
             store(GET_LAST_USER_SLOPE, ret);
         }
+        LOCKED_END => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                LOCKED_END,
+                runtime_args! {
+                    "addr" => addr
+                },
+            );
+            store(LOCKED_END, ret);
+        }
+        USER_POINT_HISTORY_TS => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let idx: U256 = runtime::get_named_arg("idx");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                USER_POINT_HISTORY_TS,
+                runtime_args! {
+                    "addr" => addr,
+                    "idx" => idx
+                },
+            );
+            store(USER_POINT_HISTORY_TS, ret);
+        }
+        CHECK => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let ret: bool = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                CHECK,
+                runtime_args! {
+                    "addr" => addr
+                },
+            );
+            store(CHECK, ret);
+        }
+        ADJUSTED_BALANCE_OF => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                ADJUSTED_BALANCE_OF,
+                runtime_args! {
+                    "addr" => addr
+                },
+            );
+            store(ADJUSTED_BALANCE_OF, ret);
+        }
+        DELEGATED_BALANCE => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                DELEGATED_BALANCE,
+                runtime_args! {
+                    "addr" => addr
+                },
+            );
+            store(DELEGATED_BALANCE, ret);
+        }
+        RECEIVED_BALANCE => {
+            let addr: Key = runtime::get_named_arg("addr");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                RECEIVED_BALANCE,
+                runtime_args! {
+                    "addr" => addr
+                },
+            );
+            store(RECEIVED_BALANCE, ret);
+        }
         _ => runtime::revert(ApiError::UnexpectedKeyVariant),
     };
-}
\ No newline at end of file
+}
@@ -0,0 +1,169 @@
+use alloc::string::{String, ToString};
+use casper_types::{bytesrepr::Bytes, ContractHash, ContractPackageHash, Key, U256};
+use casper_types_derive::{CLTyped, FromBytes, ToBytes};
+use casperlabs_contract_utils::*;
+use common::{keys::*, utils::*};
+
+pub const GRACE_PERIOD: U256 = U256([1209600000, 0, 0, 0]); // 14 days
+pub const MINIMUM_DELAY: U256 = U256([172800000, 0, 0, 0]); // 2 days
+pub const MAXIMUM_DELAY: U256 = U256([2592000000, 0, 0, 0]); // 30 days
+
+pub const QUEUED: u8 = 1;
+pub const EXECUTED: u8 = 2;
+pub const CANCELLED: u8 = 3;
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+pub struct Operation {
+    pub target: Key,
+    pub entry_point: String,
+    pub args: Bytes,
+    pub eta: U256,
+    pub state: u8,
+}
+
+pub struct Operations {
+    dict: Dict,
+}
+
+impl Operations {
+    #[inline(always)]
+    pub fn instance() -> Operations {
+        Operations {
+            dict: Dict::instance(OPERATIONS_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(OPERATIONS_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, id: &U256) -> Option<Operation> {
+        self.dict.get(&id.to_string())
+    }
+    #[inline(always)]
+    pub fn set(&self, id: &U256, value: Operation) {
+        self.dict.set(&id.to_string(), value);
+    }
+}
+
+pub struct QueuedOperations {
+    dict: Dict,
+}
+
+impl QueuedOperations {
+    #[inline(always)]
+    pub fn instance() -> QueuedOperations {
+        QueuedOperations {
+            dict: Dict::instance(QUEUED_OPERATIONS_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(QUEUED_OPERATIONS_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, index: &U256) -> U256 {
+        self.dict.get(&index.to_string()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, index: &U256, value: U256) {
+        self.dict.set(&index.to_string(), value);
+    }
+}
+
+pub struct QueuedIndex {
+    dict: Dict,
+}
+
+impl QueuedIndex {
+    #[inline(always)]
+    pub fn instance() -> QueuedIndex {
+        QueuedIndex {
+            dict: Dict::instance(QUEUED_INDEX_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(QUEUED_INDEX_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, id: &U256) -> U256 {
+        self.dict.get(&id.to_string()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, id: &U256, value: U256) {
+        self.dict.set(&id.to_string(), value);
+    }
+}
+
+pub fn get_admin() -> Key {
+    get_key(ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_admin(admin: Key) {
+    set_key(ADMIN, admin);
+}
+
+pub fn get_future_admin() -> Key {
+    get_key(FUTURE_ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_admin(future_admin: Key) {
+    set_key(FUTURE_ADMIN, future_admin);
+}
+
+pub fn get_guardian() -> Key {
+    get_key(GUARDIAN).unwrap_or_else(zero_address)
+}
+
+pub fn set_guardian(guardian: Key) {
+    set_key(GUARDIAN, guardian);
+}
+
+pub fn get_delay() -> U256 {
+    get_key(DELAY).unwrap_or_default()
+}
+
+pub fn set_delay(delay: U256) {
+    set_key(DELAY, delay);
+}
+
+pub fn get_operations_count() -> U256 {
+    get_key(OPERATIONS_COUNT).unwrap_or_default()
+}
+
+pub fn set_operations_count(operations_count: U256) {
+    set_key(OPERATIONS_COUNT, operations_count);
+}
+
+pub fn get_queued_count() -> U256 {
+    get_key(QUEUED_COUNT).unwrap_or_default()
+}
+
+pub fn set_queued_count(queued_count: U256) {
+    set_key(QUEUED_COUNT, queued_count);
+}
+
+pub fn get_lock() -> bool {
+    get_key(LOCK).unwrap_or_default()
+}
+
+pub fn set_lock(lock: bool) {
+    set_key(LOCK, lock);
+}
+
+pub fn get_contract_hash() -> ContractHash {
+    get_key(SELF_CONTRACT_HASH).unwrap_or_default()
+}
+
+pub fn set_contract_hash(contract_hash: ContractHash) {
+    set_key(SELF_CONTRACT_HASH, contract_hash);
+}
+
+pub fn get_package_hash() -> ContractPackageHash {
+    get_key(SELF_CONTRACT_PACKAGE_HASH).unwrap_or_default()
+}
+
+pub fn set_package_hash(package_hash: ContractPackageHash) {
+    set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
+}
@@ -0,0 +1,103 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
+
+pub enum TimelockEvent {
+    QueueOperation {
+        id: U256,
+        target: Key,
+        entry_point: String,
+        eta: U256,
+    },
+    CancelOperation {
+        id: U256,
+    },
+    ExecuteOperation {
+        id: U256,
+    },
+    NewDelay {
+        delay: U256,
+    },
+    NewGuardian {
+        guardian: Key,
+    },
+    CommitOwnership {
+        admin: Key,
+    },
+    ApplyOwnership {
+        admin: Key,
+    },
+}
+
+impl TimelockEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            TimelockEvent::QueueOperation {
+                id: _,
+                target: _,
+                entry_point: _,
+                eta: _,
+            } => "queueOperation",
+            TimelockEvent::CancelOperation { id: _ } => "cancelOperation",
+            TimelockEvent::ExecuteOperation { id: _ } => "executeOperation",
+            TimelockEvent::NewDelay { delay: _ } => "newDelay",
+            TimelockEvent::NewGuardian { guardian: _ } => "newGuardian",
+            TimelockEvent::CommitOwnership { admin: _ } => "commitOwnership",
+            TimelockEvent::ApplyOwnership { admin: _ } => "applyOwnership",
+        }
+        .to_string()
+    }
+}
+
+impl CasperEvent for TimelockEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            TimelockEvent::QueueOperation {
+                id,
+                target,
+                entry_point,
+                eta,
+            } => Ok([
+                id.to_bytes()?,
+                target.to_bytes()?,
+                entry_point.to_bytes()?,
+                eta.to_bytes()?,
+            ]
+            .concat()),
+            TimelockEvent::CancelOperation { id } => id.to_bytes(),
+            TimelockEvent::ExecuteOperation { id } => id.to_bytes(),
+            TimelockEvent::NewDelay { delay } => delay.to_bytes(),
+            TimelockEvent::NewGuardian { guardian } => guardian.to_bytes(),
+            TimelockEvent::CommitOwnership { admin } => admin.to_bytes(),
+            TimelockEvent::ApplyOwnership { admin } => admin.to_bytes(),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "queueOperation",
+                Schema::new()
+                    .with_elem::<U256>("id")
+                    .with_elem::<Key>("target")
+                    .with_elem::<String>("entry_point")
+                    .with_elem::<U256>("eta"),
+            )
+            .with("cancelOperation", Schema::new().with_elem::<U256>("id"))
+            .with("executeOperation", Schema::new().with_elem::<U256>("id"))
+            .with("newDelay", Schema::new().with_elem::<U256>("delay"))
+            .with("newGuardian", Schema::new().with_elem::<Key>("guardian"))
+            .with("commitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("applyOwnership", Schema::new().with_elem::<Key>("admin"))
+    }
+}
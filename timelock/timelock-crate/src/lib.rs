@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod data;
+pub mod event;
+mod timelock;
+
+pub use timelock::TIMELOCK;
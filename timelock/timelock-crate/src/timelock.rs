@@ -0,0 +1,241 @@
+use crate::{data::*, event::TimelockEvent};
+use alloc::string::String;
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, Bytes},
+    ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use common::{errors::*, events, utils::*};
+
+/// @notice Delays admin calls to the DAO contracts, so that they can be reviewed before they land
+/// @dev Deploy it as the `admin` of a contract and queue its admin calls here. A queued
+/// operation becomes executable `delay` after being queued and stays executable for
+/// `GRACE_PERIOD`. The guardian can cancel queued operations, while changing the delay, the
+/// guardian or the admin has to go through the timelock itself.
+pub trait TIMELOCK<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        admin: Key,
+        guardian: Key,
+        delay: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        if delay < MINIMUM_DELAY || delay > MAXIMUM_DELAY {
+            runtime::revert(ApiError::from(Error::TimelockInvalidDelay1));
+        }
+        Operations::init();
+        QueuedOperations::init();
+        QueuedIndex::init();
+        events::init::<TimelockEvent>();
+        set_admin(admin);
+        set_guardian(guardian);
+        set_delay(delay);
+        set_operations_count(0.into());
+        set_queued_count(0.into());
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    #[inline(always)]
+    fn only_admin(&self) {
+        if self.get_caller() != get_admin() {
+            runtime::revert(ApiError::from(Error::TimelockAdminOnly));
+        }
+    }
+
+    /// @notice Settings can only be changed by an operation executed by this contract
+    #[inline(always)]
+    fn only_self(&self) {
+        if self.get_caller() != Key::from(get_package_hash()) {
+            runtime::revert(ApiError::from(Error::TimelockOnlySelf));
+        }
+    }
+
+    #[inline(always)]
+    fn _now(&self) -> U256 {
+        U256::from(u64::from(runtime::get_blocktime()))
+    }
+
+    #[inline(always)]
+    fn _queued_operation(&self, id: U256) -> Operation {
+        let operation: Operation = Operations::instance()
+            .get(&id)
+            .unwrap_or_revert_with(Error::TimelockNoSuchOperation);
+        if operation.state != QUEUED {
+            runtime::revert(ApiError::from(Error::TimelockNotQueued));
+        }
+        operation
+    }
+
+    /// @notice Remove operation `id` from the list of queued operations
+    /// @dev The last queued operation takes its place
+    #[inline(always)]
+    fn _remove_queued(&self, id: U256) {
+        let last_index: U256 = get_queued_count()
+            .checked_sub(1.into())
+            .unwrap_or_revert_with(Error::TimelockSubtractionError1);
+        let index: U256 = QueuedIndex::instance().get(&id);
+        if index != last_index {
+            let last_id: U256 = QueuedOperations::instance().get(&last_index);
+            QueuedOperations::instance().set(&index, last_id);
+            QueuedIndex::instance().set(&last_id, index);
+        }
+        set_queued_count(last_index);
+    }
+
+    /// @notice Queue a call of `entry_point` of contract package `target`
+    /// @param args `RuntimeArgs` of the call, serialized with `ToBytes`
+    /// @param eta Time from which on the call can be executed, at least `delay` from now
+    /// @return Id of the operation
+    #[inline(always)]
+    fn queue(&self, target: Key, entry_point: String, args: Bytes, eta: U256) -> U256 {
+        self.only_admin();
+        let _: RuntimeArgs = bytesrepr::deserialize(args.to_vec())
+            .unwrap_or_revert_with(Error::TimelockInvalidArgs1);
+        let earliest: U256 = self
+            ._now()
+            .checked_add(get_delay())
+            .unwrap_or_revert_with(Error::TimelockAdditionError1);
+        if eta < earliest {
+            runtime::revert(ApiError::from(Error::TimelockEtaTooEarly));
+        }
+        let id: U256 = get_operations_count();
+        Operations::instance().set(
+            &id,
+            Operation {
+                target,
+                entry_point: entry_point.clone(),
+                args,
+                eta,
+                state: QUEUED,
+            },
+        );
+        set_operations_count(
+            id.checked_add(1.into())
+                .unwrap_or_revert_with(Error::TimelockAdditionError2),
+        );
+        let index: U256 = get_queued_count();
+        QueuedOperations::instance().set(&index, id);
+        QueuedIndex::instance().set(&id, index);
+        set_queued_count(
+            index
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::TimelockAdditionError3),
+        );
+        self.emit(&TimelockEvent::QueueOperation {
+            id,
+            target,
+            entry_point,
+            eta,
+        });
+        id
+    }
+
+    /// @notice Cancel queued operation `id`
+    /// @dev Callable by the admin and the guardian
+    #[inline(always)]
+    fn cancel(&self, id: U256) {
+        if self.get_caller() != get_admin() && self.get_caller() != get_guardian() {
+            runtime::revert(ApiError::from(Error::TimelockAccessDenied));
+        }
+        let mut operation: Operation = self._queued_operation(id);
+        operation.state = CANCELLED;
+        Operations::instance().set(&id, operation);
+        self._remove_queued(id);
+        self.emit(&TimelockEvent::CancelOperation { id });
+    }
+
+    /// @notice Execute queued operation `id` once its eta passed
+    #[inline(always)]
+    fn execute(&self, id: U256) {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::TimelockIsLocked));
+        }
+        set_lock(true);
+        self.only_admin();
+        let mut operation: Operation = self._queued_operation(id);
+        if self._now() < operation.eta {
+            runtime::revert(ApiError::from(Error::TimelockNotReady));
+        }
+        if self._now()
+            > operation
+                .eta
+                .checked_add(GRACE_PERIOD)
+                .unwrap_or_revert_with(Error::TimelockAdditionError4)
+        {
+            runtime::revert(ApiError::from(Error::TimelockStale));
+        }
+        operation.state = EXECUTED;
+        Operations::instance().set(&id, operation.clone());
+        self._remove_queued(id);
+        let args: RuntimeArgs = bytesrepr::deserialize(operation.args.to_vec())
+            .unwrap_or_revert_with(Error::TimelockInvalidArgs2);
+        call_versioned_contract_ignoring_result(
+            operation.target.into_hash().unwrap_or_revert().into(),
+            &operation.entry_point,
+            args,
+        );
+        self.emit(&TimelockEvent::ExecuteOperation { id });
+        set_lock(false);
+    }
+
+    /// @notice Set the minimum delay of new operations
+    #[inline(always)]
+    fn set_delay(&self, delay: U256) {
+        self.only_self();
+        if delay < MINIMUM_DELAY || delay > MAXIMUM_DELAY {
+            runtime::revert(ApiError::from(Error::TimelockInvalidDelay2));
+        }
+        set_delay(delay);
+        self.emit(&TimelockEvent::NewDelay { delay });
+    }
+
+    /// @notice Set the address allowed to cancel queued operations
+    #[inline(always)]
+    fn set_guardian(&self, guardian: Key) {
+        self.only_self();
+        set_guardian(guardian);
+        self.emit(&TimelockEvent::NewGuardian { guardian });
+    }
+
+    /// @notice Transfer ownership of the timelock to `addr`, applied once `addr` accepts it
+    #[inline(always)]
+    fn commit_transfer_ownership(&self, addr: Key) {
+        self.only_self();
+        set_future_admin(addr);
+        self.emit(&TimelockEvent::CommitOwnership { admin: addr });
+    }
+
+    #[inline(always)]
+    fn accept_transfer_ownership(&self) {
+        let admin: Key = get_future_admin();
+        if admin == zero_address() || self.get_caller() != admin {
+            runtime::revert(ApiError::from(Error::TimelockFutureAdminOnly));
+        }
+        set_admin(admin);
+        self.emit(&TimelockEvent::ApplyOwnership { admin });
+    }
+
+    /// @notice Get operation `id`
+    #[inline(always)]
+    fn get_operation(&self, id: U256) -> Operation {
+        Operations::instance()
+            .get(&id)
+            .unwrap_or_revert_with(Error::TimelockNoSuchOperation)
+    }
+
+    /// @notice Get the id of the queued operation at `index`
+    #[inline(always)]
+    fn queued_operation_by_index(&self, index: U256) -> U256 {
+        if index >= get_queued_count() {
+            runtime::revert(ApiError::from(Error::TimelockInvalidIndex));
+        }
+        QueuedOperations::instance().get(&index)
+    }
+
+    fn emit(&self, timelock_event: &TimelockEvent) {
+        events::emit(timelock_event);
+    }
+}
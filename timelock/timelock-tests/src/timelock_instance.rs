@@ -0,0 +1,108 @@
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    runtime_args, CLTyped, Key, RuntimeArgs, U256,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+use timelock_crate::data::Operation;
+
+pub struct TIMELOCKInstance(TestContract);
+impl TIMELOCKInstance {
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        admin: Key,
+        guardian: Key,
+        delay: U256,
+        time: u64,
+    ) -> TIMELOCKInstance {
+        TIMELOCKInstance(TestContract::new(
+            env,
+            "timelock.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "admin" => admin,
+                "guardian" => guardian,
+                "delay" => delay,
+            },
+            time,
+        ))
+    }
+
+    pub fn queue(
+        &self,
+        owner: AccountHash,
+        target: Key,
+        entry_point: &str,
+        args: RuntimeArgs,
+        eta: U256,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            owner,
+            "queue",
+            runtime_args! {
+                "target" => target,
+                "entry_point" => entry_point.to_string(),
+                "args" => Bytes::from(args.to_bytes().unwrap()),
+                "eta" => eta
+            },
+            time,
+        );
+    }
+
+    pub fn cancel(&self, owner: AccountHash, id: U256, time: u64) {
+        self.0.call_contract(
+            owner,
+            "cancel",
+            runtime_args! {
+                "id" => id
+            },
+            time,
+        );
+    }
+
+    pub fn execute(&self, owner: AccountHash, id: U256, time: u64) {
+        self.0.call_contract(
+            owner,
+            "execute",
+            runtime_args! {
+                "id" => id
+            },
+            time,
+        );
+    }
+
+    pub fn set_delay(&self, owner: AccountHash, delay: U256, time: u64) {
+        self.0.call_contract(
+            owner,
+            "set_delay",
+            runtime_args! {
+                "delay" => delay
+            },
+            time,
+        );
+    }
+
+    pub fn get_operation(&self, id: U256) -> Option<Operation> {
+        self.0.query_dictionary(OPERATIONS_DICT, id.to_string())
+    }
+
+    pub fn queued_operation_by_index(&self, index: U256) -> U256 {
+        self.0
+            .query_dictionary(QUEUED_OPERATIONS_DICT, index.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+
+    // Get stored key values
+    pub fn key_value<T: CLTyped + FromBytes>(&self, key: String) -> T {
+        self.0.query_named_key(key)
+    }
+}
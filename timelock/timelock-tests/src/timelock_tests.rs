@@ -0,0 +1,210 @@
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+use timelock_crate::data::{Operation, CANCELLED, EXECUTED, GRACE_PERIOD, QUEUED};
+
+use crate::timelock_instance::TIMELOCKInstance;
+
+const NAME: &str = "TIMELOCK";
+const MILLI_SECONDS_IN_DAY: u64 = 86_400_000;
+const DELAY_MS: u64 = MILLI_SECONDS_IN_DAY * 2;
+
+fn deploy_smart_wallet_checker(env: &TestEnv, sender: AccountHash, admin: Key) -> TestContract {
+    TestContract::new(
+        env,
+        "smart-wallet-checker.wasm",
+        "smart-wallet-checker",
+        sender,
+        runtime_args! {
+            "admin" => admin,
+        },
+        0,
+    )
+}
+
+// Deploys the timelock and a smart wallet checker administrated by it
+fn deploy() -> (
+    TestEnv,
+    TIMELOCKInstance,
+    TestContract,
+    AccountHash,
+    AccountHash,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let guardian = env.next_user();
+    let timelock = TIMELOCKInstance::new_deploy(
+        &env,
+        NAME,
+        owner,
+        Key::from(owner),
+        Key::from(guardian),
+        U256::from(DELAY_MS),
+        0,
+    );
+    let checker = deploy_smart_wallet_checker(&env, owner, Key::Hash(timelock.package_hash()));
+    (env, timelock, checker, owner, guardian)
+}
+
+fn check(env: &TestEnv, owner: AccountHash, checker: &TestContract, addr: Key) -> bool {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(CHECK),
+            "package_hash" => Key::Hash(checker.package_hash()),
+            "addr" => addr
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[CHECK.into()])
+}
+
+fn queue_approve_wallet(
+    timelock: &TIMELOCKInstance,
+    checker: &TestContract,
+    owner: AccountHash,
+    wallet: Key,
+) {
+    timelock.queue(
+        owner,
+        Key::Hash(checker.package_hash()),
+        "approve_wallet",
+        runtime_args! {
+            "wallet" => wallet
+        },
+        U256::from(DELAY_MS),
+        0,
+    );
+}
+
+#[test]
+fn test_deploy() {
+    let (_env, timelock, _, owner, guardian) = deploy();
+    let ret: Key = timelock.key_value(ADMIN.to_string());
+    assert_eq!(ret, Key::from(owner));
+    let ret: Key = timelock.key_value(GUARDIAN.to_string());
+    assert_eq!(ret, Key::from(guardian));
+    let ret: U256 = timelock.key_value(DELAY.to_string());
+    assert_eq!(ret, U256::from(DELAY_MS));
+}
+
+#[test]
+fn test_queue() {
+    let (_env, timelock, checker, owner, _) = deploy();
+    queue_approve_wallet(&timelock, &checker, owner, Key::Hash([7u8; 32]));
+    let operation: Operation = timelock.get_operation(0.into()).unwrap();
+    assert_eq!(operation.target, Key::Hash(checker.package_hash()));
+    assert_eq!(operation.entry_point, "approve_wallet");
+    assert_eq!(operation.eta, U256::from(DELAY_MS));
+    assert_eq!(operation.state, QUEUED);
+    let ret: U256 = timelock.key_value(QUEUED_COUNT.to_string());
+    assert_eq!(ret, 1.into(), "Operation not listed");
+    assert_eq!(timelock.queued_operation_by_index(0.into()), 0.into());
+}
+
+#[test]
+#[should_panic]
+fn test_queue_before_delay() {
+    let (_env, timelock, checker, owner, _) = deploy();
+    timelock.queue(
+        owner,
+        Key::Hash(checker.package_hash()),
+        "approve_wallet",
+        runtime_args! {
+            "wallet" => Key::Hash([7u8; 32])
+        },
+        U256::from(DELAY_MS - 1),
+        0,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_queue_by_non_admin() {
+    let (_env, timelock, checker, _, guardian) = deploy();
+    queue_approve_wallet(&timelock, &checker, guardian, Key::Hash([7u8; 32]));
+}
+
+#[test]
+fn test_execute() {
+    let (env, timelock, checker, owner, _) = deploy();
+    let wallet: Key = Key::Hash([7u8; 32]);
+    queue_approve_wallet(&timelock, &checker, owner, wallet);
+    timelock.execute(owner, 0.into(), DELAY_MS);
+    assert!(check(&env, owner, &checker, wallet), "Call not executed");
+    let operation: Operation = timelock.get_operation(0.into()).unwrap();
+    assert_eq!(operation.state, EXECUTED);
+    let ret: U256 = timelock.key_value(QUEUED_COUNT.to_string());
+    assert_eq!(ret, 0.into(), "Executed operation still listed");
+}
+
+#[test]
+#[should_panic]
+fn test_execute_before_eta() {
+    let (_env, timelock, checker, owner, _) = deploy();
+    queue_approve_wallet(&timelock, &checker, owner, Key::Hash([7u8; 32]));
+    timelock.execute(owner, 0.into(), DELAY_MS - 1);
+}
+
+#[test]
+#[should_panic]
+fn test_execute_after_grace_period() {
+    let (_env, timelock, checker, owner, _) = deploy();
+    queue_approve_wallet(&timelock, &checker, owner, Key::Hash([7u8; 32]));
+    timelock.execute(owner, 0.into(), DELAY_MS + GRACE_PERIOD.as_u64() + 1);
+}
+
+#[test]
+fn test_cancel() {
+    let (_env, timelock, checker, owner, guardian) = deploy();
+    queue_approve_wallet(&timelock, &checker, owner, Key::Hash([7u8; 32]));
+    queue_approve_wallet(&timelock, &checker, owner, Key::Hash([8u8; 32]));
+    timelock.cancel(guardian, 0.into(), 0);
+    let operation: Operation = timelock.get_operation(0.into()).unwrap();
+    assert_eq!(operation.state, CANCELLED);
+    let ret: U256 = timelock.key_value(QUEUED_COUNT.to_string());
+    assert_eq!(ret, 1.into());
+    assert_eq!(
+        timelock.queued_operation_by_index(0.into()),
+        1.into(),
+        "Last queued operation not moved"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_execute_cancelled() {
+    let (_env, timelock, checker, owner, guardian) = deploy();
+    queue_approve_wallet(&timelock, &checker, owner, Key::Hash([7u8; 32]));
+    timelock.cancel(guardian, 0.into(), 0);
+    timelock.execute(owner, 0.into(), DELAY_MS);
+}
+
+#[test]
+fn test_set_delay_through_timelock() {
+    let (_env, timelock, _, owner, _) = deploy();
+    let delay: U256 = U256::from(MILLI_SECONDS_IN_DAY * 3);
+    timelock.queue(
+        owner,
+        Key::Hash(timelock.package_hash()),
+        "set_delay",
+        runtime_args! {
+            "delay" => delay
+        },
+        U256::from(DELAY_MS),
+        0,
+    );
+    timelock.execute(owner, 0.into(), DELAY_MS);
+    let ret: U256 = timelock.key_value(DELAY.to_string());
+    assert_eq!(ret, delay, "Delay not changed");
+}
+
+#[test]
+#[should_panic]
+fn test_set_delay_directly() {
+    let (_env, timelock, _, owner, _) = deploy();
+    timelock.set_delay(owner, U256::from(MILLI_SECONDS_IN_DAY * 3), 0);
+}
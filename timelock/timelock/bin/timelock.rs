@@ -0,0 +1,368 @@
+#![no_main]
+#![no_std]
+extern crate alloc;
+use alloc::{collections::BTreeSet, format, string::String, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs,
+    URef, U256,
+};
+use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
+use timelock_crate::{
+    self,
+    data::{self, Operation},
+    TIMELOCK,
+};
+
+#[derive(Default)]
+struct Timelock(OnChainContractStorage);
+impl ContractContext<OnChainContractStorage> for Timelock {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl TIMELOCK<OnChainContractStorage> for Timelock {}
+impl Timelock {
+    fn constructor(
+        &mut self,
+        admin: Key,
+        guardian: Key,
+        delay: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        TIMELOCK::init(self, admin, guardian, delay, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let admin: Key = runtime::get_named_arg("admin");
+    let guardian: Key = runtime::get_named_arg("guardian");
+    let delay: U256 = runtime::get_named_arg("delay");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Timelock::default().constructor(admin, guardian, delay, contract_hash, package_hash);
+}
+
+/// Queue a call of an entry point of a contract, only callable by the admin
+/// # Parameters
+/// * `target` - Contract package hash of the target
+/// * `entry_point` - Entry point of the target
+/// * `args` - `RuntimeArgs` of the call, serialized with `ToBytes`
+/// * `eta` - Time from which on the call can be executed, at least `delay` from now
+#[no_mangle]
+fn queue() {
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let args: Bytes = runtime::get_named_arg("args");
+    let eta: U256 = runtime::get_named_arg("eta");
+    let ret: U256 = Timelock::default().queue(target, entry_point, args, eta);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Cancel a queued operation, callable by the admin and the guardian
+#[no_mangle]
+fn cancel() {
+    let id: U256 = runtime::get_named_arg("id");
+    Timelock::default().cancel(id);
+}
+
+/// Execute a queued operation once its eta passed, only callable by the admin
+#[no_mangle]
+fn execute() {
+    let id: U256 = runtime::get_named_arg("id");
+    Timelock::default().execute(id);
+}
+
+/// Set the minimum delay of new operations, only callable through the timelock
+#[no_mangle]
+fn set_delay() {
+    let delay: U256 = runtime::get_named_arg("delay");
+    Timelock::default().set_delay(delay);
+}
+
+/// Set the address allowed to cancel queued operations, only callable through the timelock
+#[no_mangle]
+fn set_guardian() {
+    let guardian: Key = runtime::get_named_arg("guardian");
+    Timelock::default().set_guardian(guardian);
+}
+
+/// Transfer ownership of the timelock to `addr`, only callable through the timelock
+#[no_mangle]
+fn commit_transfer_ownership() {
+    let addr: Key = runtime::get_named_arg("addr");
+    Timelock::default().commit_transfer_ownership(addr);
+}
+
+/// Accept ownership of the timelock, only callable by the future admin
+#[no_mangle]
+fn accept_transfer_ownership() {
+    Timelock::default().accept_transfer_ownership();
+}
+
+#[no_mangle]
+fn get_operation() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: Operation = Timelock::default().get_operation(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Get the id of the queued operation at `index`
+#[no_mangle]
+fn queued_operation_by_index() {
+    let index: U256 = runtime::get_named_arg("index");
+    let ret: U256 = Timelock::default().queued_operation_by_index(index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn admin() {
+    runtime::ret(CLValue::from_t(data::get_admin()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn future_admin() {
+    runtime::ret(CLValue::from_t(data::get_future_admin()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn guardian() {
+    runtime::ret(CLValue::from_t(data::get_guardian()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn delay() {
+    runtime::ret(CLValue::from_t(data::get_delay()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn operations_count() {
+    runtime::ret(CLValue::from_t(data::get_operations_count()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn queued_count() {
+    runtime::ret(CLValue::from_t(data::get_queued_count()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("admin", Key::cl_type()),
+            Parameter::new("guardian", Key::cl_type()),
+            Parameter::new("delay", U256::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "queue",
+        vec![
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("args", Bytes::cl_type()),
+            Parameter::new("eta", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel",
+        vec![Parameter::new("id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute",
+        vec![Parameter::new("id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_delay",
+        vec![Parameter::new("delay", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_guardian",
+        vec![Parameter::new("guardian", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "commit_transfer_ownership",
+        vec![Parameter::new("addr", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "accept_transfer_ownership",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_operation",
+        vec![Parameter::new("id", U256::cl_type())],
+        Operation::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "queued_operation_by_index",
+        vec![Parameter::new("index", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_admin",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "guardian",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "delay",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "operations_count",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "queued_count",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        // add a first version to this package
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let admin: Key = runtime::get_named_arg("admin");
+        let guardian: Key = runtime::get_named_arg("guardian");
+        let delay: U256 = runtime::get_named_arg("delay");
+        let constructor_args = runtime_args! {
+            "admin" => admin,
+            "guardian" => guardian,
+            "delay" => delay,
+            "package_hash" => package_hash,
+            "contract_hash" => contract_hash,
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    }
+    // If contract package did already exist
+    else {
+        // get the package
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+        // create new version and install it
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::string::String;
+use casper_types::{ContractHash, ContractPackageHash, Key, U256};
+use casper_types_derive::{CLTyped, FromBytes, ToBytes};
+use casperlabs_contract_utils::*;
+use common::{keys::*, utils::*};
+
+pub const WEEK: U256 = U256([604800000, 0, 0, 0]); // all future times are rounded by week
+pub const MAX_DELEGATORS: U256 = U256([16, 0, 0, 0]); // active boosts a receiver can hold
+
+#[derive(Clone, Copy, CLTyped, ToBytes, FromBytes, Default)]
+pub struct BoostPoint {
+    pub bias: U256,
+    pub slope: U256, // - dbias / dt
+    pub ts: U256,
+}
+
+// A single delegator's boost to a receiver; it decays linearly to zero at `end`
+#[derive(Clone, Copy, CLTyped, ToBytes, FromBytes, Default)]
+pub struct ReceivedBoost {
+    pub bias: U256,
+    pub slope: U256,
+    pub ts: U256,
+    pub end: U256,
+}
+
+pub struct Delegated {
+    dict: Dict,
+}
+
+impl Delegated {
+    #[inline(always)]
+    pub fn instance() -> Delegated {
+        Delegated {
+            dict: Dict::instance(DELEGATED_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(DELEGATED_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, owner: &Key) -> BoostPoint {
+        self.dict.get_by_key(owner).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, owner: &Key, value: BoostPoint) {
+        self.dict.set_by_key(owner, value);
+    }
+}
+
+pub struct Received {
+    dict: Dict,
+}
+
+impl Received {
+    #[inline(always)]
+    pub fn instance() -> Received {
+        Received {
+            dict: Dict::instance(RECEIVED_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(RECEIVED_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, receiver: &Key, delegator: &Key) -> ReceivedBoost {
+        self.dict
+            .get(&keys_to_str(receiver, delegator))
+            .unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, receiver: &Key, delegator: &Key, value: ReceivedBoost) {
+        self.dict.set(&keys_to_str(receiver, delegator), value);
+    }
+}
+
+pub struct ReceivedFrom {
+    dict: Dict,
+}
+
+impl ReceivedFrom {
+    #[inline(always)]
+    pub fn instance() -> ReceivedFrom {
+        ReceivedFrom {
+            dict: Dict::instance(RECEIVED_FROM_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(RECEIVED_FROM_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, receiver: &Key, index: &U256) -> Key {
+        let key_: String = key_and_value_to_str(receiver, index);
+        self.dict.get(key_.as_str()).unwrap_or_else(zero_address)
+    }
+    #[inline(always)]
+    pub fn set(&self, receiver: &Key, index: &U256, delegator: Key) {
+        let key_: String = key_and_value_to_str(receiver, index);
+        self.dict.set(key_.as_str(), delegator);
+    }
+}
+
+pub struct ReceivedFromCount {
+    dict: Dict,
+}
+
+impl ReceivedFromCount {
+    #[inline(always)]
+    pub fn instance() -> ReceivedFromCount {
+        ReceivedFromCount {
+            dict: Dict::instance(RECEIVED_FROM_COUNT_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(RECEIVED_FROM_COUNT_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, receiver: &Key) -> U256 {
+        self.dict.get_by_key(receiver).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, receiver: &Key, value: U256) {
+        self.dict.set_by_key(receiver, value);
+    }
+}
+
+pub struct DelegatedSlopeChanges {
+    dict: Dict,
+}
+
+impl DelegatedSlopeChanges {
+    #[inline(always)]
+    pub fn instance() -> DelegatedSlopeChanges {
+        DelegatedSlopeChanges {
+            dict: Dict::instance(DELEGATED_SLOPE_CHANGES_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(DELEGATED_SLOPE_CHANGES_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, owner: &Key, time: &U256) -> U256 {
+        let key_: String = key_and_value_to_str(owner, time);
+        self.dict.get(key_.as_str()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, owner: &Key, time: &U256, value: U256) {
+        let key_: String = key_and_value_to_str(owner, time);
+        self.dict.set(key_.as_str(), value);
+    }
+}
+
+pub fn get_voting_escrow() -> Key {
+    get_key(VOTING_ESCROW).unwrap_or_else(zero_address)
+}
+
+pub fn set_voting_escrow(voting_escrow: Key) {
+    set_key(VOTING_ESCROW, voting_escrow);
+}
+
+pub fn get_lock() -> bool {
+    get_key(LOCK).unwrap_or_default()
+}
+
+pub fn set_lock(lock: bool) {
+    set_key(LOCK, lock);
+}
+
+pub fn get_contract_hash() -> ContractHash {
+    get_key(SELF_CONTRACT_HASH).unwrap_or_default()
+}
+
+pub fn set_contract_hash(contract_hash: ContractHash) {
+    set_key(SELF_CONTRACT_HASH, contract_hash);
+}
+
+pub fn get_package_hash() -> ContractPackageHash {
+    get_key(SELF_CONTRACT_PACKAGE_HASH).unwrap_or_default()
+}
+
+pub fn set_package_hash(package_hash: ContractPackageHash) {
+    set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
+}
@@ -0,0 +1,104 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
+
+pub enum VeBoostEvent {
+    Boost {
+        delegator: Key,
+        receiver: Key,
+        bias: U256,
+        slope: U256,
+        start: U256,
+    },
+    RejectBoost {
+        delegator: Key,
+        receiver: Key,
+        bias: U256,
+        slope: U256,
+    },
+}
+
+impl VeBoostEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            VeBoostEvent::Boost {
+                delegator: _,
+                receiver: _,
+                bias: _,
+                slope: _,
+                start: _,
+            } => "boost",
+            VeBoostEvent::RejectBoost {
+                delegator: _,
+                receiver: _,
+                bias: _,
+                slope: _,
+            } => "rejectBoost",
+        }
+        .to_string()
+    }
+}
+
+impl CasperEvent for VeBoostEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            VeBoostEvent::Boost {
+                delegator,
+                receiver,
+                bias,
+                slope,
+                start,
+            } => Ok([
+                delegator.to_bytes()?,
+                receiver.to_bytes()?,
+                bias.to_bytes()?,
+                slope.to_bytes()?,
+                start.to_bytes()?,
+            ]
+            .concat()),
+            VeBoostEvent::RejectBoost {
+                delegator,
+                receiver,
+                bias,
+                slope,
+            } => Ok([
+                delegator.to_bytes()?,
+                receiver.to_bytes()?,
+                bias.to_bytes()?,
+                slope.to_bytes()?,
+            ]
+            .concat()),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "boost",
+                Schema::new()
+                    .with_elem::<Key>("delegator")
+                    .with_elem::<Key>("receiver")
+                    .with_elem::<U256>("bias")
+                    .with_elem::<U256>("slope")
+                    .with_elem::<U256>("start"),
+            )
+            .with(
+                "rejectBoost",
+                Schema::new()
+                    .with_elem::<Key>("delegator")
+                    .with_elem::<Key>("receiver")
+                    .with_elem::<U256>("bias")
+                    .with_elem::<U256>("slope"),
+            )
+    }
+}
@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod data;
+pub mod event;
+mod ve_boost;
+
+pub use ve_boost::VEBOOST;
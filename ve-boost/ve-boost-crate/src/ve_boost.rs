@@ -0,0 +1,360 @@
+use crate::{data::*, event::VeBoostEvent};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use common::{errors::*, events, utils::zero_address};
+
+/// @notice Delegate a slice of veCRV boost to another address
+/// @dev A delegation is a (bias, slope) pair cut from the delegator's voting escrow balance.
+/// It decays linearly to zero at the chosen expiry, which cannot be later than the delegator's
+/// lock end. Gauges read `adjusted_balance_of` instead of the raw voting escrow balance.
+pub trait VEBOOST<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        voting_escrow: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        Delegated::init();
+        Received::init();
+        ReceivedFrom::init();
+        ReceivedFromCount::init();
+        DelegatedSlopeChanges::init();
+        events::init::<VeBoostEvent>();
+        set_voting_escrow(voting_escrow);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    /// @notice Decay `point` up to time `t`, applying the scheduled slope changes on week boundaries
+    #[inline(always)]
+    fn _decay(
+        &self,
+        point: BoostPoint,
+        t: U256,
+        slope_change: &dyn Fn(U256) -> U256,
+    ) -> BoostPoint {
+        let mut point: BoostPoint = point;
+        if point.ts >= t || point.ts == 0.into() {
+            point.ts = U256::max(point.ts, t);
+            return point;
+        }
+        let mut t_i: U256 = point
+            .ts
+            .checked_div(WEEK)
+            .unwrap_or_revert_with(Error::VeBoostDivisionError1)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::VeBoostMultiplicationError1);
+        for _ in 0..255 {
+            t_i = t_i
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::VeBoostAdditionError1);
+            let mut d_slope: U256 = 0.into();
+            if t_i > t {
+                t_i = t;
+            } else {
+                d_slope = slope_change(t_i);
+            }
+            let d_bias: U256 = point
+                .slope
+                .checked_mul(
+                    t_i.checked_sub(point.ts)
+                        .unwrap_or_revert_with(Error::VeBoostSubtractionError1),
+                )
+                .unwrap_or_revert_with(Error::VeBoostMultiplicationError2);
+            point.bias = point.bias.saturating_sub(d_bias);
+            point.slope = point.slope.saturating_sub(d_slope);
+            point.ts = t_i;
+            if t_i == t {
+                break;
+            }
+        }
+        point
+    }
+
+    #[inline(always)]
+    fn _delegated_at(&self, addr: Key, t: U256) -> BoostPoint {
+        self._decay(Delegated::instance().get(&addr), t, &|t_i: U256| {
+            DelegatedSlopeChanges::instance().get(&addr, &t_i)
+        })
+    }
+
+    /// @notice Boost of `delegator` to `receiver` at time `t`, zero once it has expired
+    #[inline(always)]
+    fn _received_at(&self, receiver: Key, delegator: Key, t: U256) -> ReceivedBoost {
+        let mut point: ReceivedBoost = Received::instance().get(&receiver, &delegator);
+        if t >= point.end {
+            return ReceivedBoost::default();
+        }
+        if t > point.ts {
+            point.bias = point.bias.saturating_sub(
+                point
+                    .slope
+                    .checked_mul(t - point.ts)
+                    .unwrap_or_revert_with(Error::VeBoostMultiplicationError4),
+            );
+            point.ts = t;
+        }
+        point
+    }
+
+    /// @notice Scale `bias` down when the delegator's voting escrow balance no longer
+    /// covers everything it delegated, e.g. after `withdraw_early` or a position transfer
+    #[inline(always)]
+    fn _capped(&self, delegator: Key, bias: U256, t: U256) -> U256 {
+        let delegated: U256 = self._delegated_at(delegator, t).bias;
+        let balance: U256 = self._ve_balance_of(delegator);
+        if delegated <= balance {
+            return bias;
+        }
+        bias.checked_mul(balance)
+            .unwrap_or_revert_with(Error::VeBoostMultiplicationError5)
+            .checked_div(delegated)
+            .unwrap_or_revert_with(Error::VeBoostDivisionError3)
+    }
+
+    /// @notice Drop the delegators whose boost to `receiver` has expired
+    #[inline(always)]
+    fn _prune_received_from(&self, receiver: Key, t: U256) {
+        let mut count: U256 = ReceivedFromCount::instance().get(&receiver);
+        let mut i: U256 = 0.into();
+        while i < count {
+            let delegator: Key = ReceivedFrom::instance().get(&receiver, &i);
+            if t >= Received::instance().get(&receiver, &delegator).end {
+                self._remove_received_from(receiver, i, count);
+                count = count - 1;
+            } else {
+                i = i + 1;
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn _remove_received_from(&self, receiver: Key, index: U256, count: U256) {
+        let last: U256 = count - 1;
+        let delegator: Key = ReceivedFrom::instance().get(&receiver, &index);
+        Received::instance().set(&receiver, &delegator, ReceivedBoost::default());
+        if index != last {
+            ReceivedFrom::instance().set(
+                &receiver,
+                &index,
+                ReceivedFrom::instance().get(&receiver, &last),
+            );
+        }
+        ReceivedFrom::instance().set(&receiver, &last, zero_address());
+        ReceivedFromCount::instance().set(&receiver, last);
+    }
+
+    #[inline(always)]
+    fn _ve_balance_of(&self, addr: Key) -> U256 {
+        runtime::call_versioned_contract(
+            get_voting_escrow().into_hash().unwrap_or_revert().into(),
+            None,
+            "balance_of",
+            runtime_args! {
+                "addr" => addr,
+                "t" => None::<U256>
+            },
+        )
+    }
+
+    /// @notice Delegate `amount` of the caller's voting power to `receiver` until `endtime`
+    /// @param receiver Address which receives the boost
+    /// @param amount Voting power delegated right now, decaying to zero at `endtime`
+    /// @param endtime Expiry of the delegation, rounded down to whole weeks
+    #[inline(always)]
+    fn boost(&self, receiver: Key, amount: U256, endtime: U256) {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::VeBoostIsLocked));
+        }
+        set_lock(true);
+        let delegator: Key = self.get_caller();
+        if amount == 0.into() {
+            runtime::revert(ApiError::from(Error::VeBoostZeroAmount));
+        }
+        if receiver == delegator {
+            runtime::revert(ApiError::from(Error::VeBoostSelfBoost));
+        }
+        let now: U256 = U256::from(u64::from(runtime::get_blocktime()));
+        let endtime: U256 = endtime
+            .checked_div(WEEK)
+            .unwrap_or_revert_with(Error::VeBoostDivisionError2)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::VeBoostMultiplicationError3);
+        if endtime <= now {
+            runtime::revert(ApiError::from(Error::VeBoostEndtimeInPast));
+        }
+        let locked_end: U256 = runtime::call_versioned_contract(
+            get_voting_escrow().into_hash().unwrap_or_revert().into(),
+            None,
+            "locked_end",
+            runtime_args! {
+                "addr" => delegator
+            },
+        );
+        if endtime > locked_end {
+            runtime::revert(ApiError::from(Error::VeBoostEndtimeAfterLockEnd));
+        }
+        let mut delegated: BoostPoint = self._delegated_at(delegator, now);
+        if amount
+            > self
+                ._ve_balance_of(delegator)
+                .saturating_sub(delegated.bias)
+        {
+            runtime::revert(ApiError::from(Error::VeBoostInsufficientDelegableBalance));
+        }
+        let duration: U256 = endtime
+            .checked_sub(now)
+            .unwrap_or_revert_with(Error::VeBoostSubtractionError2);
+        let slope: U256 = amount / duration;
+        if slope == 0.into() {
+            runtime::revert(ApiError::from(Error::VeBoostZeroSlope));
+        }
+        let bias: U256 = slope * duration;
+
+        delegated.bias = delegated
+            .bias
+            .checked_add(bias)
+            .unwrap_or_revert_with(Error::VeBoostAdditionError2);
+        delegated.slope = delegated
+            .slope
+            .checked_add(slope)
+            .unwrap_or_revert_with(Error::VeBoostAdditionError3);
+        Delegated::instance().set(&delegator, delegated);
+        DelegatedSlopeChanges::instance().set(
+            &delegator,
+            &endtime,
+            DelegatedSlopeChanges::instance()
+                .get(&delegator, &endtime)
+                .checked_add(slope)
+                .unwrap_or_revert_with(Error::VeBoostAdditionError4),
+        );
+
+        let mut received: ReceivedBoost = self._received_at(receiver, delegator, now);
+        if received.bias == 0.into() {
+            self._prune_received_from(receiver, now);
+            let count: U256 = ReceivedFromCount::instance().get(&receiver);
+            if count >= MAX_DELEGATORS {
+                runtime::revert(ApiError::from(Error::VeBoostTooManyDelegators));
+            }
+            ReceivedFrom::instance().set(&receiver, &count, delegator);
+            ReceivedFromCount::instance().set(
+                &receiver,
+                count
+                    .checked_add(1.into())
+                    .unwrap_or_revert_with(Error::VeBoostAdditionError9),
+            );
+            received.end = endtime;
+        } else if received.end != endtime {
+            runtime::revert(ApiError::from(Error::VeBoostEndtimeMismatch));
+        }
+        received.bias = received
+            .bias
+            .checked_add(bias)
+            .unwrap_or_revert_with(Error::VeBoostAdditionError5);
+        received.slope = received
+            .slope
+            .checked_add(slope)
+            .unwrap_or_revert_with(Error::VeBoostAdditionError6);
+        received.ts = now;
+        Received::instance().set(&receiver, &delegator, received);
+
+        self.emit(&VeBoostEvent::Boost {
+            delegator,
+            receiver,
+            bias,
+            slope,
+            start: now,
+        });
+        set_lock(false);
+    }
+
+    /// @notice Cancel the boost `delegator` gives to the caller
+    /// @dev Lets a receiver free a slot taken by an unwanted boost; the remaining
+    /// voting power returns to the delegator
+    /// @param delegator Address whose boost is rejected
+    #[inline(always)]
+    fn reject_boost(&self, delegator: Key) {
+        let receiver: Key = self.get_caller();
+        let now: U256 = U256::from(u64::from(runtime::get_blocktime()));
+        let received: ReceivedBoost = self._received_at(receiver, delegator, now);
+        if received.bias == 0.into() {
+            runtime::revert(ApiError::from(Error::VeBoostNoBoost));
+        }
+        let mut delegated: BoostPoint = self._delegated_at(delegator, now);
+        delegated.bias = delegated.bias.saturating_sub(received.bias);
+        delegated.slope = delegated.slope.saturating_sub(received.slope);
+        Delegated::instance().set(&delegator, delegated);
+        DelegatedSlopeChanges::instance().set(
+            &delegator,
+            &received.end,
+            DelegatedSlopeChanges::instance()
+                .get(&delegator, &received.end)
+                .saturating_sub(received.slope),
+        );
+        let count: U256 = ReceivedFromCount::instance().get(&receiver);
+        let mut i: U256 = 0.into();
+        while i < count {
+            if ReceivedFrom::instance().get(&receiver, &i) == delegator {
+                self._remove_received_from(receiver, i, count);
+                break;
+            }
+            i = i + 1;
+        }
+        self.emit(&VeBoostEvent::RejectBoost {
+            delegator,
+            receiver,
+            bias: received.bias,
+            slope: received.slope,
+        });
+    }
+
+    /// @notice Voting power `addr` currently delegates to others
+    #[inline(always)]
+    fn delegated_balance(&self, addr: Key) -> U256 {
+        self._delegated_at(addr, U256::from(u64::from(runtime::get_blocktime())))
+            .bias
+    }
+
+    /// @notice Voting power `addr` currently receives from others
+    /// @dev Each boost is capped by its delegator's current voting escrow balance
+    #[inline(always)]
+    fn received_balance(&self, addr: Key) -> U256 {
+        let now: U256 = U256::from(u64::from(runtime::get_blocktime()));
+        let mut total: U256 = 0.into();
+        let count: U256 = ReceivedFromCount::instance().get(&addr);
+        let mut i: U256 = 0.into();
+        while i < count {
+            let delegator: Key = ReceivedFrom::instance().get(&addr, &i);
+            let received: ReceivedBoost = self._received_at(addr, delegator, now);
+            if received.bias > 0.into() {
+                total = total
+                    .checked_add(self._capped(delegator, received.bias, now))
+                    .unwrap_or_revert_with(Error::VeBoostAdditionError7);
+            }
+            i = i + 1;
+        }
+        total
+    }
+
+    /// @notice Voting power `addr` can still delegate
+    #[inline(always)]
+    fn delegable_balance(&self, addr: Key) -> U256 {
+        self._ve_balance_of(addr)
+            .saturating_sub(self.delegated_balance(addr))
+    }
+
+    /// @notice Voting escrow balance of `addr` net of outgoing and incoming delegations
+    #[inline(always)]
+    fn adjusted_balance_of(&self, addr: Key) -> U256 {
+        self.delegable_balance(addr)
+            .checked_add(self.received_balance(addr))
+            .unwrap_or_revert_with(Error::VeBoostAdditionError8)
+    }
+
+    fn emit(&self, ve_boost_event: &VeBoostEvent) {
+        events::emit(ve_boost_event);
+    }
+}
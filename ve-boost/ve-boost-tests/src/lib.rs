@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod ve_boost_tests;
+
+#[cfg(test)]
+pub mod ve_boost_instance;
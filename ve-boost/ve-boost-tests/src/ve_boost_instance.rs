@@ -0,0 +1,59 @@
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, runtime_args, CLTyped, Key, RuntimeArgs, U256,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+
+pub struct VEBOOSTInstance(TestContract);
+impl VEBOOSTInstance {
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        voting_escrow: Key,
+        time: u64,
+    ) -> VEBOOSTInstance {
+        VEBOOSTInstance(TestContract::new(
+            env,
+            "ve-boost.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "voting_escrow" => voting_escrow,
+            },
+            time,
+        ))
+    }
+
+    pub fn boost(&self, owner: AccountHash, receiver: Key, amount: U256, endtime: U256, time: u64) {
+        self.0.call_contract(
+            owner,
+            "boost",
+            runtime_args! {
+                "receiver" => receiver,
+                "amount" => amount,
+                "endtime" => endtime
+            },
+            time,
+        );
+    }
+
+    pub fn reject_boost(&self, owner: AccountHash, delegator: Key, time: u64) {
+        self.0.call_contract(
+            owner,
+            "reject_boost",
+            runtime_args! {
+                "delegator" => delegator
+            },
+            time,
+        );
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+
+    // Get stored key values
+    pub fn key_value<T: CLTyped + FromBytes>(&self, key: String) -> T {
+        self.0.query_named_key(key)
+    }
+}
@@ -0,0 +1,378 @@
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+use crv20::Address;
+use ve_boost_crate::data::WEEK;
+
+use crate::ve_boost_instance::VEBOOSTInstance;
+
+const NAME: &str = "VEBOOST";
+const TEN_E_NINE: u128 = 1000000000;
+const MILLI_SECONDS_IN_DAY: u64 = 86_400_000;
+
+fn deploy_erc20_crv(env: &TestEnv, sender: AccountHash, time_now: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "erc20-crv.wasm",
+        "erc20-crv",
+        sender,
+        runtime_args! {
+            "name" => "CRV",
+            "symbol" => "ERC20CRV",
+            "decimals" => 9_u8,
+        },
+        time_now,
+    )
+}
+
+fn deploy_voting_escrow(
+    env: &TestEnv,
+    sender: AccountHash,
+    token_addr: Key,
+    time_now: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "voting-escrow.wasm",
+        "voting-escrow",
+        sender,
+        runtime_args! {
+            "token_addr" => token_addr,
+            "name" => "Vote-escrowed CRV",
+            "symbol" => "veCRV",
+            "version" => "veCRV_1.0.0",
+        },
+        time_now,
+    )
+}
+
+// Deploys CRV, voting escrow and ve-boost, and locks 1000 CRV of the owner for ~4 years
+fn deploy() -> (
+    TestEnv,
+    AccountHash,
+    VEBOOSTInstance,
+    TestContract,
+    u64,
+    U256,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let time_now: u64 = WEEK.as_u64() * 2000;
+    let erc20_crv = deploy_erc20_crv(&env, owner, time_now);
+    let voting_escrow =
+        deploy_voting_escrow(&env, owner, Key::Hash(erc20_crv.package_hash()), time_now);
+    let instance = VEBOOSTInstance::new_deploy(
+        &env,
+        NAME,
+        owner,
+        Key::Hash(voting_escrow.package_hash()),
+        time_now,
+    );
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    let spender: Address = Address::Contract(voting_escrow.package_hash().into());
+    erc20_crv.call_contract(
+        owner,
+        "increase_allowance",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => amount
+        },
+        time_now,
+    );
+    voting_escrow.call_contract(
+        owner,
+        "create_lock",
+        runtime_args! {
+            "value" => amount,
+            "unlock_time" => unlock_time
+        },
+        time_now,
+    );
+    let lock_end = unlock_time / WEEK * WEEK;
+    (env, owner, instance, voting_escrow, time_now, lock_end)
+}
+
+fn query(
+    env: &TestEnv,
+    owner: AccountHash,
+    instance: &VEBOOSTInstance,
+    entrypoint: &str,
+    addr: Key,
+    time: u64,
+) -> U256 {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(entrypoint),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => addr
+        },
+        time,
+    );
+    env.query_account_named_key(owner, &[entrypoint.into()])
+}
+
+#[test]
+fn test_deploy() {
+    let (_env, _owner, instance, voting_escrow, _, _) = deploy();
+    let ret: Key = instance.key_value(VOTING_ESCROW.to_string());
+    assert_eq!(
+        ret,
+        Key::Hash(voting_escrow.package_hash()),
+        "Voting escrow not set"
+    );
+}
+
+#[test]
+fn test_adjusted_balance_of_without_boost() {
+    let (env, owner, instance, _, time_now, _) = deploy();
+    let adjusted = query(
+        &env,
+        owner,
+        &instance,
+        ADJUSTED_BALANCE_OF,
+        Key::from(owner),
+        time_now,
+    );
+    let delegable = query(
+        &env,
+        owner,
+        &instance,
+        DELEGABLE_BALANCE,
+        Key::from(owner),
+        time_now,
+    );
+    assert!(adjusted > 0.into(), "Lock not reflected");
+    assert_eq!(
+        adjusted, delegable,
+        "Adjusted balance differs from VE balance"
+    );
+}
+
+#[test]
+fn test_boost() {
+    let (env, owner, instance, _, time_now, lock_end) = deploy();
+    let receiver = env.next_user();
+    let before = query(
+        &env,
+        owner,
+        &instance,
+        ADJUSTED_BALANCE_OF,
+        Key::from(owner),
+        time_now,
+    );
+    let amount: U256 = before / 2;
+    instance.boost(owner, Key::from(receiver), amount, lock_end, time_now);
+    let delegated = query(
+        &env,
+        owner,
+        &instance,
+        DELEGATED_BALANCE,
+        Key::from(owner),
+        time_now,
+    );
+    let received = query(
+        &env,
+        owner,
+        &instance,
+        RECEIVED_BALANCE,
+        Key::from(receiver),
+        time_now,
+    );
+    assert!(delegated > 0.into(), "Nothing delegated");
+    assert!(delegated <= amount, "Delegated more than requested");
+    assert_eq!(
+        delegated, received,
+        "Delegated and received balances differ"
+    );
+    let owner_adjusted = query(
+        &env,
+        owner,
+        &instance,
+        ADJUSTED_BALANCE_OF,
+        Key::from(owner),
+        time_now,
+    );
+    let receiver_adjusted = query(
+        &env,
+        owner,
+        &instance,
+        ADJUSTED_BALANCE_OF,
+        Key::from(receiver),
+        time_now,
+    );
+    assert_eq!(
+        owner_adjusted,
+        before - delegated,
+        "Owner balance not reduced"
+    );
+    assert_eq!(receiver_adjusted, received, "Receiver balance not boosted");
+}
+
+#[test]
+fn test_boost_expires() {
+    let (env, owner, instance, _, time_now, _) = deploy();
+    let receiver = env.next_user();
+    let endtime: U256 = (U256::from(time_now) / WEEK + 4) * WEEK;
+    instance.boost(
+        owner,
+        Key::from(receiver),
+        U256::from(100 * TEN_E_NINE),
+        endtime,
+        time_now,
+    );
+    let received = query(
+        &env,
+        owner,
+        &instance,
+        RECEIVED_BALANCE,
+        Key::from(receiver),
+        endtime.as_u64(),
+    );
+    assert_eq!(received, 0.into(), "Boost did not expire");
+}
+
+#[test]
+#[should_panic]
+fn test_boost_self() {
+    let (_env, owner, instance, _, time_now, lock_end) = deploy();
+    instance.boost(
+        owner,
+        Key::from(owner),
+        U256::from(TEN_E_NINE),
+        lock_end,
+        time_now,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_boost_endtime_after_lock_end() {
+    let (env, owner, instance, _, time_now, lock_end) = deploy();
+    let receiver = env.next_user();
+    instance.boost(
+        owner,
+        Key::from(receiver),
+        U256::from(TEN_E_NINE),
+        lock_end + WEEK,
+        time_now,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_boost_more_than_delegable() {
+    let (env, owner, instance, _, time_now, lock_end) = deploy();
+    let receiver = env.next_user();
+    instance.boost(
+        owner,
+        Key::from(receiver),
+        U256::from(1001 * TEN_E_NINE),
+        lock_end,
+        time_now,
+    );
+}
+
+#[test]
+fn test_boost_capped_after_withdraw_early() {
+    let (env, owner, instance, voting_escrow, time_now, lock_end) = deploy();
+    let receiver = env.next_user();
+    instance.boost(
+        owner,
+        Key::from(receiver),
+        U256::from(100 * TEN_E_NINE),
+        lock_end,
+        time_now,
+    );
+    voting_escrow.call_contract(
+        owner,
+        "set_penalty_receiver",
+        runtime_args! {
+            "addr" => Key::from(env.next_user())
+        },
+        time_now,
+    );
+    voting_escrow.call_contract(owner, "withdraw_early", runtime_args! {}, time_now);
+    let received = query(
+        &env,
+        owner,
+        &instance,
+        RECEIVED_BALANCE,
+        Key::from(receiver),
+        time_now,
+    );
+    assert_eq!(received, 0.into(), "Boost outlived the delegator's lock");
+}
+
+#[test]
+fn test_reject_boost() {
+    let (env, owner, instance, _, time_now, lock_end) = deploy();
+    let receiver = env.next_user();
+    let before = query(
+        &env,
+        owner,
+        &instance,
+        ADJUSTED_BALANCE_OF,
+        Key::from(owner),
+        time_now,
+    );
+    instance.boost(
+        owner,
+        Key::from(receiver),
+        U256::from(100 * TEN_E_NINE),
+        lock_end,
+        time_now,
+    );
+    instance.reject_boost(receiver, Key::from(owner), time_now);
+    let received = query(
+        &env,
+        owner,
+        &instance,
+        RECEIVED_BALANCE,
+        Key::from(receiver),
+        time_now,
+    );
+    assert_eq!(received, 0.into(), "Boost not rejected");
+    let after = query(
+        &env,
+        owner,
+        &instance,
+        ADJUSTED_BALANCE_OF,
+        Key::from(owner),
+        time_now,
+    );
+    assert_eq!(after, before, "Delegated balance not returned");
+}
+
+#[test]
+#[should_panic]
+fn test_reject_boost_without_boost() {
+    let (env, owner, instance, _, time_now, _) = deploy();
+    let receiver = env.next_user();
+    instance.reject_boost(receiver, Key::from(owner), time_now);
+}
+
+#[test]
+#[should_panic]
+fn test_boost_with_other_endtime() {
+    let (env, owner, instance, _, time_now, lock_end) = deploy();
+    let receiver = env.next_user();
+    instance.boost(
+        owner,
+        Key::from(receiver),
+        U256::from(100 * TEN_E_NINE),
+        lock_end,
+        time_now,
+    );
+    instance.boost(
+        owner,
+        Key::from(receiver),
+        U256::from(100 * TEN_E_NINE),
+        lock_end - WEEK,
+        time_now,
+    );
+}
@@ -0,0 +1,253 @@
+#![no_main]
+#![no_std]
+extern crate alloc;
+use alloc::{collections::BTreeSet, format, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+};
+use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
+use ve_boost_crate::{self, data, VEBOOST};
+
+#[derive(Default)]
+struct VeBoost(OnChainContractStorage);
+impl ContractContext<OnChainContractStorage> for VeBoost {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl VEBOOST<OnChainContractStorage> for VeBoost {}
+impl VeBoost {
+    fn constructor(
+        &mut self,
+        voting_escrow: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        VEBOOST::init(self, voting_escrow, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let voting_escrow: Key = runtime::get_named_arg("voting_escrow");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    VeBoost::default().constructor(voting_escrow, contract_hash, package_hash);
+}
+
+/// Delegate `amount` of the caller's voting power to `receiver` until `endtime`
+/// # Parameters
+/// * `receiver` - Address which receives the boost
+/// * `amount` - Voting power delegated right now, decaying to zero at `endtime`
+/// * `endtime` - Expiry of the delegation, rounded down to whole weeks
+#[no_mangle]
+fn boost() {
+    let receiver: Key = runtime::get_named_arg("receiver");
+    let amount: U256 = runtime::get_named_arg("amount");
+    let endtime: U256 = runtime::get_named_arg("endtime");
+    VeBoost::default().boost(receiver, amount, endtime);
+}
+
+/// Cancel the boost `delegator` gives to the caller
+/// # Parameters
+/// * `delegator` - Address whose boost is rejected
+#[no_mangle]
+fn reject_boost() {
+    let delegator: Key = runtime::get_named_arg("delegator");
+    VeBoost::default().reject_boost(delegator);
+}
+
+/// Voting power `addr` currently delegates to others
+#[no_mangle]
+fn delegated_balance() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: U256 = VeBoost::default().delegated_balance(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Voting power `addr` currently receives from others
+#[no_mangle]
+fn received_balance() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: U256 = VeBoost::default().received_balance(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Voting power `addr` can still delegate
+#[no_mangle]
+fn delegable_balance() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: U256 = VeBoost::default().delegable_balance(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Voting escrow balance of `addr` net of outgoing and incoming delegations
+#[no_mangle]
+fn adjusted_balance_of() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: U256 = VeBoost::default().adjusted_balance_of(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn voting_escrow() {
+    runtime::ret(CLValue::from_t(data::get_voting_escrow()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("voting_escrow", Key::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "boost",
+        vec![
+            Parameter::new("receiver", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+            Parameter::new("endtime", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "reject_boost",
+        vec![Parameter::new("delegator", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "delegated_balance",
+        vec![Parameter::new("addr", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "received_balance",
+        vec![Parameter::new("addr", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "delegable_balance",
+        vec![Parameter::new("addr", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "adjusted_balance_of",
+        vec![Parameter::new("addr", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "voting_escrow",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        // add a first version to this package
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let voting_escrow: Key = runtime::get_named_arg("voting_escrow");
+        let constructor_args = runtime_args! {
+            "voting_escrow" => voting_escrow,
+            "package_hash" => package_hash,
+            "contract_hash" => contract_hash,
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    }
+    // If contract package did already exist
+    else {
+        // get the package
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+        // create new version and install it
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
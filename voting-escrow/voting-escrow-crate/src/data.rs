@@ -3,11 +3,13 @@ use alloc::{
   string::{String, ToString},
   vec::Vec,
 };
-use casper_contract::contract_api::runtime::get_blocktime;
-use casper_types::{ContractHash, ContractPackageHash, Key, U128, U256};
+use casper_contract::{contract_api::runtime::get_blocktime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ContractHash, ContractPackageHash, Key, U256};
 use casper_types_derive::{CLTyped, FromBytes, ToBytes};
 use casperlabs_contract_utils::{get_key, set_key, Dict};
-use common::{keys::*, utils::*};
+use common::{int128::I128, keys::*, utils::*};
+use core::convert::TryFrom;
+use renvm_sig::keccak256;
 
 pub const DEPOSIT_FOR_TYPE: i128 = 0;
 pub const CREATE_LOCK_TYPE: i128 = 1;
@@ -15,49 +17,54 @@ pub const INCREASE_LOCK_AMOUNT: i128 = 2;
 pub const INCREASE_UNLOCK_TIME: i128 = 3;
 pub const WEEK: U256 = U256([604800000, 0, 0, 0]); // all future times are rounded by week
 pub const MAXTIME: U256 = U256([126144000000, 0, 0, 0]); // 4 years
+pub const MAXTIME_I128: I128 = I128::new(126144000000); // `MAXTIME` for slope math
 pub const MULTIPLIER: U256 = U256([1000000000, 0, 0, 0]);
+// Lock end of auto max locks, which keep the full weight of a `MAXTIME` lock without decaying
+pub const AUTO_MAX_LOCK_END: U256 = U256::MAX;
+// Lock positions an address can own at once, bounding the loop in `delegate`
+pub const MAX_POSITIONS: U256 = U256([50, 0, 0, 0]);
 
 // We cannot really do block numbers per se b/c slope is per time, not per block
 // and per block could be fairly bad b/c Ethereum changes blocktimes.
 // What we can do is to extrapolate ***At functions
 #[derive(Clone, Copy, CLTyped, ToBytes, FromBytes, Default)]
 pub struct Point {
-  pub bias: (bool, U128),
-  pub slope: (bool, U128), // - dweight / dt
+  pub bias: I128,
+  pub slope: I128, // - dweight / dt
   pub ts: U256,
   pub blk: U256,
 }
 
 impl Point {
-  pub fn set_bias(&mut self, value: i128) {
-      self.bias = i128_to_tuple(value);
+  pub fn set_bias(&mut self, value: I128) {
+      self.bias = value;
   }
 
-  pub fn bias(&self) -> i128 {
-      tuple_to_i128(self.bias)
+  pub fn bias(&self) -> I128 {
+      self.bias
   }
 
-  pub fn set_slope(&mut self, value: i128) {
-      self.slope = i128_to_tuple(value);
+  pub fn set_slope(&mut self, value: I128) {
+      self.slope = value;
   }
 
-  pub fn slope(&self) -> i128 {
-      tuple_to_i128(self.slope)
+  pub fn slope(&self) -> I128 {
+      self.slope
   }
 }
 
 #[derive(Clone, Copy, CLTyped, ToBytes, FromBytes, Default)]
 pub struct LockedBalance {
-  pub amount: (bool, U128),
+  pub amount: I128,
   pub end: U256,
 }
 impl LockedBalance {
-  pub fn set_amount(&mut self, value: i128) {
-      self.amount = i128_to_tuple(value);
+  pub fn set_amount(&mut self, value: I128) {
+      self.amount = value;
   }
 
-  pub fn amount(&self) -> i128 {
-      tuple_to_i128(self.amount)
+  pub fn amount(&self) -> I128 {
+      self.amount
   }
 }
 
@@ -309,14 +316,12 @@ impl SlopeChanges {
       Dict::init(SLOPE_CHANGES)
   }
 
-  pub fn get(&self, time: &U256) -> i128 {
-      let ret: (bool, U128) = self.dict.get(time.to_string().as_str()).unwrap_or_default();
-      tuple_to_i128(ret)
+  pub fn get(&self, time: &U256) -> I128 {
+      self.dict.get(time.to_string().as_str()).unwrap_or_default()
   }
 
-  pub fn set(&self, time: &U256, value: i128) {
-      self.dict
-          .set(time.to_string().as_str(), i128_to_tuple(value));
+  pub fn set(&self, time: &U256, value: I128) {
+      self.dict.set(time.to_string().as_str(), value);
   }
 }
 
@@ -383,6 +388,319 @@ impl PointHistory {
   }
 }
 
+// Lock positions are checkpointed like users under a key derived from their token id,
+// so each position has its own `Locked` balance and user point history
+pub fn position_key(token_id: U256) -> Key {
+  Key::Hash(keccak256(format!("{}{}", "position_", token_id).as_bytes()))
+}
+
+// The total voting power of an owner, its own lock plus the lock positions it currently owns,
+// is checkpointed like a delegate under a key derived from its address
+pub fn owner_key(owner: Key) -> Key {
+  Key::Hash(keccak256(format!("{}{}", "owner_", owner).as_bytes()))
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct PositionOwners {
+  dict: Dict,
+}
+
+impl PositionOwners {
+  pub fn instance() -> PositionOwners {
+      PositionOwners {
+          dict: Dict::instance(POSITION_OWNERS_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(POSITION_OWNERS_DICT)
+  }
+
+  pub fn get(&self, token_id: &U256) -> Key {
+      self.dict
+          .get(token_id.to_string().as_str())
+          .unwrap_or_else(zero_address)
+  }
+
+  pub fn set(&self, token_id: &U256, owner: Key) {
+      self.dict.set(token_id.to_string().as_str(), owner);
+  }
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct OwnerPositions {
+  dict: Dict,
+}
+
+impl OwnerPositions {
+  pub fn instance() -> OwnerPositions {
+      OwnerPositions {
+          dict: Dict::instance(OWNER_POSITIONS_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(OWNER_POSITIONS_DICT)
+  }
+
+  pub fn get(&self, owner: &Key, index: &U256) -> U256 {
+      self.dict
+          .get(
+              hash(format!(
+                  "{}{}{}{}{}",
+                  OWNER_POSITIONS_DICT,
+                  "_",
+                  owner.to_formatted_string(),
+                  "_",
+                  index
+              ))
+              .as_str(),
+          )
+          .unwrap_or_default()
+  }
+
+  pub fn set(&self, owner: &Key, index: &U256, token_id: U256) {
+      self.dict.set(
+          hash(format!(
+              "{}{}{}{}{}",
+              OWNER_POSITIONS_DICT,
+              "_",
+              owner.to_formatted_string(),
+              "_",
+              index
+          ))
+          .as_str(),
+          token_id,
+      );
+  }
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct PositionIndex {
+  dict: Dict,
+}
+
+impl PositionIndex {
+  pub fn instance() -> PositionIndex {
+      PositionIndex {
+          dict: Dict::instance(POSITION_INDEX_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(POSITION_INDEX_DICT)
+  }
+
+  pub fn get(&self, token_id: &U256) -> U256 {
+      self.dict
+          .get(token_id.to_string().as_str())
+          .unwrap_or_default()
+  }
+
+  pub fn set(&self, token_id: &U256, index: U256) {
+      self.dict.set(token_id.to_string().as_str(), index);
+  }
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct PositionsCount {
+  dict: Dict,
+}
+
+impl PositionsCount {
+  pub fn instance() -> PositionsCount {
+      PositionsCount {
+          dict: Dict::instance(POSITIONS_COUNT_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(POSITIONS_COUNT_DICT)
+  }
+
+  pub fn get(&self, owner: &Key) -> U256 {
+      self.dict.get_by_key(owner).unwrap_or_default()
+  }
+
+  pub fn set(&self, owner: &Key, value: U256) {
+      self.dict.set_by_key(owner, value);
+  }
+}
+
+// Voting power is delegated per lock, `LockDelegates` remembers which delegate currently
+// holds the power of a lock so that it can be moved when the owner re-delegates
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct Delegates {
+  dict: Dict,
+}
+
+impl Delegates {
+  pub fn instance() -> Delegates {
+      Delegates {
+          dict: Dict::instance(DELEGATES_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(DELEGATES_DICT)
+  }
+
+  pub fn get(&self, owner: &Key) -> Key {
+      self.dict.get_by_key(owner).unwrap_or(*owner)
+  }
+
+  pub fn set(&self, owner: &Key, delegate: Key) {
+      self.dict.set_by_key(owner, delegate);
+  }
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct LockDelegates {
+  dict: Dict,
+}
+
+impl LockDelegates {
+  pub fn instance() -> LockDelegates {
+      LockDelegates {
+          dict: Dict::instance(LOCK_DELEGATES_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(LOCK_DELEGATES_DICT)
+  }
+
+  pub fn get(&self, lock: &Key) -> Option<Key> {
+      self.dict.get_by_key(lock)
+  }
+
+  pub fn set(&self, lock: &Key, delegate: Key) {
+      self.dict.set_by_key(lock, delegate);
+  }
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct DelegatePointHistory {
+  dict: Dict,
+}
+
+impl DelegatePointHistory {
+  pub fn instance() -> DelegatePointHistory {
+      DelegatePointHistory {
+          dict: Dict::instance(DELEGATE_POINT_HISTORY_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(DELEGATE_POINT_HISTORY_DICT)
+  }
+
+  pub fn get(&self, delegate: &Key, epoch: &U256) -> Point {
+      self.dict
+          .get(
+              hash(format!(
+                  "{}{}{}{}{}",
+                  DELEGATE_POINT_HISTORY_DICT,
+                  "_",
+                  delegate.to_formatted_string(),
+                  "_",
+                  epoch
+              ))
+              .as_str(),
+          )
+          .unwrap_or_default()
+  }
+
+  pub fn set(&self, delegate: &Key, epoch: &U256, value: Point) {
+      self.dict.set(
+          hash(format!(
+              "{}{}{}{}{}",
+              DELEGATE_POINT_HISTORY_DICT,
+              "_",
+              delegate.to_formatted_string(),
+              "_",
+              epoch
+          ))
+          .as_str(),
+          value,
+      );
+  }
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct DelegatePointEpoch {
+  dict: Dict,
+}
+
+impl DelegatePointEpoch {
+  pub fn instance() -> DelegatePointEpoch {
+      DelegatePointEpoch {
+          dict: Dict::instance(DELEGATE_POINT_EPOCH_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(DELEGATE_POINT_EPOCH_DICT)
+  }
+
+  pub fn get(&self, delegate: &Key) -> U256 {
+      self.dict.get_by_key(delegate).unwrap_or_default()
+  }
+
+  pub fn set(&self, delegate: &Key, value: U256) {
+      self.dict.set_by_key(delegate, value);
+  }
+}
+
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct DelegateSlopeChanges {
+  dict: Dict,
+}
+
+impl DelegateSlopeChanges {
+  pub fn instance() -> DelegateSlopeChanges {
+      DelegateSlopeChanges {
+          dict: Dict::instance(DELEGATE_SLOPE_CHANGES_DICT),
+      }
+  }
+
+  pub fn init() {
+      Dict::init(DELEGATE_SLOPE_CHANGES_DICT)
+  }
+
+  pub fn get(&self, delegate: &Key, time: &U256) -> I128 {
+      self
+          .dict
+          .get(
+              hash(format!(
+                  "{}{}{}{}{}",
+                  DELEGATE_SLOPE_CHANGES_DICT,
+                  "_",
+                  delegate.to_formatted_string(),
+                  "_",
+                  time
+              ))
+              .as_str(),
+          )
+          .unwrap_or_default()
+  }
+
+  pub fn set(&self, delegate: &Key, time: &U256, value: I128) {
+      self.dict.set(
+          hash(format!(
+              "{}{}{}{}{}",
+              DELEGATE_SLOPE_CHANGES_DICT,
+              "_",
+              delegate.to_formatted_string(),
+              "_",
+              time
+          ))
+          .as_str(),
+          value,
+      );
+  }
+}
+
 pub fn get_token() -> Key {
   get_key(TOKEN).unwrap_or_else(zero_address)
 }
@@ -415,6 +733,38 @@ pub fn set_future_admin(future_admin: Key) {
   set_key(FUTURE_ADMIN, future_admin);
 }
 
+pub fn get_smart_wallet_checker() -> Key {
+  get_key(SMART_WALLET_CHECKER).unwrap_or_else(zero_address)
+}
+
+pub fn set_smart_wallet_checker(smart_wallet_checker: Key) {
+  set_key(SMART_WALLET_CHECKER, smart_wallet_checker);
+}
+
+pub fn get_future_smart_wallet_checker() -> Key {
+  get_key(FUTURE_SMART_WALLET_CHECKER).unwrap_or_else(zero_address)
+}
+
+pub fn set_future_smart_wallet_checker(future_smart_wallet_checker: Key) {
+  set_key(FUTURE_SMART_WALLET_CHECKER, future_smart_wallet_checker);
+}
+
+pub fn get_penalty_receiver() -> Key {
+  get_key(PENALTY_RECEIVER).unwrap_or_else(zero_address)
+}
+
+pub fn set_penalty_receiver(penalty_receiver: Key) {
+  set_key(PENALTY_RECEIVER, penalty_receiver);
+}
+
+pub fn get_position_id() -> U256 {
+  get_key(POSITION_ID).unwrap_or_default()
+}
+
+pub fn set_position_id(position_id: U256) {
+  set_key(POSITION_ID, position_id);
+}
+
 pub fn get_controller() -> Key {
   get_key(CONTROLLER).unwrap_or_else(zero_address)
 }
@@ -495,15 +845,24 @@ pub fn set_package_hash(package_hash: ContractPackageHash) {
   set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
 }
 
-pub fn convert(a: U256, b: U256) -> i128 {
+pub fn convert(a: U256, b: U256) -> I128 {
   if a > b {
-      (a - b).to_string().as_str().parse::<i128>().unwrap()
+      I128::try_from(a - b).unwrap_or_revert()
   } else {
-      -(b - a).to_string().as_str().parse::<i128>().unwrap()
+      I128::try_from(b - a)
+          .unwrap_or_revert()
+          .checked_neg()
+          .unwrap_or_revert()
   }
 }
 
+const AVG_BLOCK_TIME_IN_MS: u64 = 45000;
+
 pub fn block_number() -> u64 {
-  const AVG_BLOCK_TIME_IN_MS: u64 = 45000;
   u64::from(get_blocktime()) / AVG_BLOCK_TIME_IN_MS
+}
+
+// Block number the chain had at timestamp `time`, estimated like `block_number`
+pub fn block_number_at(time: U256) -> u64 {
+  time.as_u64() / AVG_BLOCK_TIME_IN_MS
 }
\ No newline at end of file
@@ -1,5 +1,15 @@
-use alloc::string::{String, ToString};
-use casper_types::{Key, U256};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::{
+    events::{CasperEvent, Schema, Schemas},
+    int128::I128,
+};
 
 pub enum VotingEscrowEvent {
     CommitOwnership {
@@ -20,10 +30,41 @@ pub enum VotingEscrowEvent {
         value: U256,
         ts: U256,
     },
+    WithdrawEarly {
+        provider: Key,
+        value: U256,
+        penalty: U256,
+        ts: U256,
+    },
     Supply {
         prev_supply: U256,
         supply: U256,
     },
+    PositionTransfer {
+        from: Key,
+        to: Key,
+        token_id: U256,
+    },
+    Merge {
+        owner: Key,
+        from_id: U256,
+        to_id: U256,
+    },
+    Split {
+        owner: Key,
+        token_id: U256,
+        token_ids: Vec<U256>,
+    },
+    AutoMaxLock {
+        provider: Key,
+        enabled: bool,
+        ts: U256,
+    },
+    DelegateChanged {
+        delegator: Key,
+        from_delegate: Key,
+        to_delegate: Key,
+    },
 }
 
 impl VotingEscrowEvent {
@@ -43,11 +84,194 @@ impl VotingEscrowEvent {
                 value: _,
                 ts: _,
             } => "withdraw",
+            VotingEscrowEvent::WithdrawEarly {
+                provider: _,
+                value: _,
+                penalty: _,
+                ts: _,
+            } => "withdrawEarly",
             VotingEscrowEvent::Supply {
                 prev_supply: _,
                 supply: _,
             } => "supply",
+            VotingEscrowEvent::PositionTransfer {
+                from: _,
+                to: _,
+                token_id: _,
+            } => "positionTransfer",
+            VotingEscrowEvent::Merge {
+                owner: _,
+                from_id: _,
+                to_id: _,
+            } => "merge",
+            VotingEscrowEvent::Split {
+                owner: _,
+                token_id: _,
+                token_ids: _,
+            } => "split",
+            VotingEscrowEvent::AutoMaxLock {
+                provider: _,
+                enabled: _,
+                ts: _,
+            } => "autoMaxLock",
+            VotingEscrowEvent::DelegateChanged {
+                delegator: _,
+                from_delegate: _,
+                to_delegate: _,
+            } => "delegateChanged",
         }
         .to_string()
     }
 }
+
+impl CasperEvent for VotingEscrowEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            VotingEscrowEvent::CommitOwnership { admin } => admin.to_bytes(),
+            VotingEscrowEvent::ApplyOwnership { admin } => admin.to_bytes(),
+            VotingEscrowEvent::Deposit {
+                provider,
+                value,
+                locktime,
+                _type,
+                ts,
+            } => Ok([
+                provider.to_bytes()?,
+                value.to_bytes()?,
+                locktime.to_bytes()?,
+                I128::from(*_type).to_bytes()?,
+                ts.to_bytes()?,
+            ]
+            .concat()),
+            VotingEscrowEvent::Withdraw {
+                provider,
+                value,
+                ts,
+            } => Ok([provider.to_bytes()?, value.to_bytes()?, ts.to_bytes()?].concat()),
+            VotingEscrowEvent::WithdrawEarly {
+                provider,
+                value,
+                penalty,
+                ts,
+            } => Ok([
+                provider.to_bytes()?,
+                value.to_bytes()?,
+                penalty.to_bytes()?,
+                ts.to_bytes()?,
+            ]
+            .concat()),
+            VotingEscrowEvent::Supply {
+                prev_supply,
+                supply,
+            } => Ok([prev_supply.to_bytes()?, supply.to_bytes()?].concat()),
+            VotingEscrowEvent::PositionTransfer { from, to, token_id } => {
+                Ok([from.to_bytes()?, to.to_bytes()?, token_id.to_bytes()?].concat())
+            }
+            VotingEscrowEvent::Merge {
+                owner,
+                from_id,
+                to_id,
+            } => Ok([owner.to_bytes()?, from_id.to_bytes()?, to_id.to_bytes()?].concat()),
+            VotingEscrowEvent::Split {
+                owner,
+                token_id,
+                token_ids,
+            } => Ok([
+                owner.to_bytes()?,
+                token_id.to_bytes()?,
+                token_ids.to_bytes()?,
+            ]
+            .concat()),
+            VotingEscrowEvent::AutoMaxLock {
+                provider,
+                enabled,
+                ts,
+            } => Ok([provider.to_bytes()?, enabled.to_bytes()?, ts.to_bytes()?].concat()),
+            VotingEscrowEvent::DelegateChanged {
+                delegator,
+                from_delegate,
+                to_delegate,
+            } => Ok([
+                delegator.to_bytes()?,
+                from_delegate.to_bytes()?,
+                to_delegate.to_bytes()?,
+            ]
+            .concat()),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with("commitOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with("applyOwnership", Schema::new().with_elem::<Key>("admin"))
+            .with(
+                "deposit",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value")
+                    .with_elem::<U256>("locktime")
+                    .with_elem::<I128>("_type")
+                    .with_elem::<U256>("ts"),
+            )
+            .with(
+                "withdraw",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value")
+                    .with_elem::<U256>("ts"),
+            )
+            .with(
+                "withdrawEarly",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<U256>("value")
+                    .with_elem::<U256>("penalty")
+                    .with_elem::<U256>("ts"),
+            )
+            .with(
+                "supply",
+                Schema::new()
+                    .with_elem::<U256>("prev_supply")
+                    .with_elem::<U256>("supply"),
+            )
+            .with(
+                "positionTransfer",
+                Schema::new()
+                    .with_elem::<Key>("from")
+                    .with_elem::<Key>("to")
+                    .with_elem::<U256>("token_id"),
+            )
+            .with(
+                "merge",
+                Schema::new()
+                    .with_elem::<Key>("owner")
+                    .with_elem::<U256>("from_id")
+                    .with_elem::<U256>("to_id"),
+            )
+            .with(
+                "split",
+                Schema::new()
+                    .with_elem::<Key>("owner")
+                    .with_elem::<U256>("token_id")
+                    .with_elem::<Vec<U256>>("token_ids"),
+            )
+            .with(
+                "autoMaxLock",
+                Schema::new()
+                    .with_elem::<Key>("provider")
+                    .with_elem::<bool>("enabled")
+                    .with_elem::<U256>("ts"),
+            )
+            .with(
+                "delegateChanged",
+                Schema::new()
+                    .with_elem::<Key>("delegator")
+                    .with_elem::<Key>("from_delegate")
+                    .with_elem::<Key>("to_delegate"),
+            )
+    }
+}
@@ -6,5 +6,5 @@ pub mod data;
 pub mod event;
 mod voting_escrow;
 
-pub use common::utils;
+pub use common::{int128, utils};
 pub use voting_escrow::VOTINGESCROW;
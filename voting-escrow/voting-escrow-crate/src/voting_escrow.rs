@@ -1,22 +1,16 @@
-use core::convert::TryInto;
+use core::convert::TryFrom;
 
 use crate::{data::*, event::VotingEscrowEvent};
-use alloc::{
-    collections::BTreeMap,
-    string::{String, ToString},
-};
+use alloc::{string::String, vec::Vec};
 use casper_contract::{
-    contract_api::{
-        runtime::{self, get_blocktime},
-        storage,
-    },
+    contract_api::runtime::{self, get_blocktime},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
     runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 use casperlabs_contract_utils::{ContractContext, ContractStorage};
-use common::{errors::*, utils::*};
+use common::{errors::*, events, int128::I128, utils::*};
 use crv20::{self, Address};
 
 /// @notice Votes have a weight depending on time, so that users are committed to the future of (whatever they are voting for)
@@ -46,10 +40,20 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         package_hash: ContractPackageHash,
     ) {
         Locked::init();
+        PositionOwners::init();
+        OwnerPositions::init();
+        PositionIndex::init();
+        PositionsCount::init();
         UserPointHistory::init();
         UserPointEpoch::init();
         SlopeChanges::init();
         PointHistory::init();
+        Delegates::init();
+        LockDelegates::init();
+        DelegatePointHistory::init();
+        DelegatePointEpoch::init();
+        DelegateSlopeChanges::init();
+        events::init::<VotingEscrowEvent>();
 
         set_admin(self.get_caller());
         set_token(token_addr);
@@ -105,80 +109,215 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         VOTINGESCROW::emit(self, &VotingEscrowEvent::ApplyOwnership { admin });
     }
 
+    /// @notice Set an external contract to check for approved smart contract wallets
+    /// @param addr Address of Smart contract checker
+    #[inline(always)]
+    fn commit_smart_wallet_checker(&mut self, addr: Key) {
+        self.only_admin();
+        set_future_smart_wallet_checker(addr);
+    }
+
+    /// @notice Apply setting external contract to check approved smart contract wallets
+    #[inline(always)]
+    fn apply_smart_wallet_checker(&mut self) {
+        self.only_admin();
+        set_smart_wallet_checker(get_future_smart_wallet_checker());
+    }
+
+    /// @notice Check if the call is from a whitelisted smart contract, revert if not
+    /// @param addr Address to be checked
+    /// @param error Error to revert with if the contract is not whitelisted
+    #[inline(always)]
+    fn assert_not_contract(&self, addr: Key, error: Error) {
+        if let Key::Hash(_) = addr {
+            let checker: Key = get_smart_wallet_checker();
+            if checker != zero_address() && checker != account_zero_address() {
+                let approved: bool = runtime::call_versioned_contract(
+                    checker.into_hash().unwrap_or_revert().into(),
+                    None,
+                    "check",
+                    runtime_args! {
+                        "addr" => addr
+                    },
+                );
+                if approved {
+                    return;
+                }
+            }
+            runtime::revert(ApiError::from(error));
+        }
+    }
+
+    /// @notice Set the address receiving penalties of early withdrawals
+    /// @dev Early withdrawals are disabled as long as no receiver is set
+    /// @param addr Address of the penalty receiver, e.g. the fee distributor
     #[inline(always)]
-    fn get_last_user_slope(&self, addr: Key) -> i128 {
+    fn set_penalty_receiver(&mut self, addr: Key) {
+        self.only_admin();
+        set_penalty_receiver(addr);
+    }
+
+    /// @notice Get the current slope of the own lock of `addr`
+    /// @dev Lock positions have their own slope, see `position_balance_of`
+    #[inline(always)]
+    fn get_last_user_slope(&self, addr: Key) -> I128 {
+        let locked: LockedBalance = Locked::instance().get(&addr);
+        if locked.end == AUTO_MAX_LOCK_END {
+            // Auto max locks look like a lock just extended to `MAXTIME`, e.g. to gauge votes
+            return self._max_lock_point(locked).slope();
+        }
         let uepoch: U256 = UserPointEpoch::instance().get(&addr);
         UserPointHistory::instance().get(&addr, &uepoch).slope()
     }
 
+    /// @notice Get the number of checkpoints of the voting power of `addr`, lock positions included
+    #[inline(always)]
+    fn user_point_epoch(&self, addr: Key) -> U256 {
+        DelegatePointEpoch::instance().get(&owner_key(addr))
+    }
+
+    /// @notice Get checkpoint `idx` of the voting power of `addr`, lock positions included
+    /// @dev Lock positions ending at other times change the slope between checkpoints, so use
+    /// `balance_of_at_time` rather than extrapolating the point linearly
+    #[inline(always)]
+    fn user_point_history(&self, addr: Key, idx: U256) -> Point {
+        DelegatePointHistory::instance().get(&owner_key(addr), &idx)
+    }
+
     #[inline(always)]
     fn user_point_history_ts(&self, addr: Key, idx: U256) -> U256 {
-        UserPointHistory::instance().get(&addr, &idx).ts
+        self.user_point_history(addr, idx).ts
     }
 
+    /// @notice Get the end of the own lock of `addr`
+    /// @dev Lock positions have their own end, see `position_locked_end`
     #[inline(always)]
     fn locked_end(&self, addr: Key) -> U256 {
-        Locked::instance().get(&addr).end
+        self._effective_lock_end(Locked::instance().get(&addr).end)
+    }
+
+    /// @notice Get the own lock of `addr`
+    /// @dev Auto max locks report the end of a lock extended to `MAXTIME` now
+    #[inline(always)]
+    fn locked(&self, addr: Key) -> LockedBalance {
+        let mut locked: LockedBalance = Locked::instance().get(&addr);
+        locked.end = self._effective_lock_end(locked.end);
+        locked
+    }
+
+    /// @notice Get the lock end `end` stands for, auto max locks ending `MAXTIME` from now
+    #[inline(always)]
+    fn _effective_lock_end(&self, end: U256) -> U256 {
+        if end == AUTO_MAX_LOCK_END {
+            return self._max_lock_end();
+        }
+        end
+    }
+
+    /// @notice Get the end of a lock extended to `MAXTIME` now, rounded down to weeks
+    #[inline(always)]
+    fn _max_lock_end(&self) -> U256 {
+        U256::from(u64::from(get_blocktime()))
+            .checked_add(MAXTIME)
+            .unwrap_or_revert_with(Error::VotingEscrowAdditionError52)
+            .checked_div(WEEK)
+            .unwrap_or_revert_with(Error::VotingEscrowDivisionError20)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError23)
+    }
+
+    /// @notice Get the slope and the constant bias of auto max lock `locked`
+    /// @dev The slope is the one of a `MAXTIME` lock, the bias doesn't decay with it
+    #[inline(always)]
+    fn _max_lock_point(&self, locked: LockedBalance) -> Point {
+        let mut point: Point = Point::default();
+        if locked.amount() > I128::ZERO {
+            point.set_slope(
+                locked
+                    .amount()
+                    .checked_div(MAXTIME_I128)
+                    .unwrap_or_revert_with(Error::VotingEscrowDivisionError21),
+            );
+            point.set_bias(
+                point
+                    .slope()
+                    .checked_mul(MAXTIME_I128)
+                    .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError24),
+            );
+        }
+        point
     }
 
     /// @notice Record global and per-user data to checkpoint
     /// @param addr User's wallet address. No user checkpoint if 0x0
+    /// @param owner Address whose voting power includes the lock, `addr` unless it is a lock position
     /// @param old_locked Pevious locked amount / end lock time for the user
     /// @param new_locked New locked amount / end lock time for the user
     #[inline(always)]
-    fn _checkpoint(&self, addr: Key, old_locked: LockedBalance, new_locked: LockedBalance) {
+    fn _checkpoint(
+        &self,
+        addr: Key,
+        owner: Key,
+        old_locked: LockedBalance,
+        new_locked: LockedBalance,
+    ) {
         let mut u_old: Point = Point::default();
         let mut u_new: Point = Point::default();
-        let mut old_dslope: i128 = 0.into();
-        let mut new_dslope: i128 = 0.into();
+        let mut old_dslope: I128 = I128::ZERO;
+        let mut new_dslope: I128 = I128::ZERO;
         let mut epoch: U256 = get_epoch();
         if addr != zero_address() && addr != account_zero_address() {
             //  Calculate slopes and biases
             //  Kept at zero when they have to
-            if (old_locked.end > U256::from(u64::from(get_blocktime())))
-                && (old_locked.amount() > 0.into())
+            //  Auto max locks have a constant bias and no slope
+            if old_locked.end == AUTO_MAX_LOCK_END {
+                u_old.set_bias(self._max_lock_point(old_locked).bias());
+            } else if (old_locked.end > U256::from(u64::from(get_blocktime())))
+                && (old_locked.amount() > I128::ZERO)
             {
                 u_old.set_slope(
                     old_locked
                         .amount()
-                        .checked_div(MAXTIME.as_u128().try_into().unwrap())
+                        .checked_div(MAXTIME_I128)
                         .unwrap_or_revert_with(Error::VotingEscrowDivisionError1),
                 );
                 u_old.set_bias(
                     u_old
                         .slope()
                         .checked_mul(
-                            old_locked
-                                .end
-                                .checked_sub(U256::from(u64::from(get_blocktime())))
-                                .unwrap_or_revert_with(Error::VotingEscrowSubtractionError1)
-                                .as_u128()
-                                .try_into()
-                                .unwrap(),
+                            I128::try_from(
+                                old_locked
+                                    .end
+                                    .checked_sub(U256::from(u64::from(get_blocktime())))
+                                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError1),
+                            )
+                            .unwrap_or_revert(),
                         )
                         .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError1),
                 );
             }
-            if (new_locked.end > U256::from(u64::from(get_blocktime())))
-                && (new_locked.amount() > 0.into())
+            if new_locked.end == AUTO_MAX_LOCK_END {
+                u_new.set_bias(self._max_lock_point(new_locked).bias());
+            } else if (new_locked.end > U256::from(u64::from(get_blocktime())))
+                && (new_locked.amount() > I128::ZERO)
             {
                 u_new.set_slope(
                     new_locked
                         .amount()
-                        .checked_div(MAXTIME.as_u128().try_into().unwrap())
+                        .checked_div(MAXTIME_I128)
                         .unwrap_or_revert_with(Error::VotingEscrowDivisionError2),
                 );
                 u_new.set_bias(
                     u_new
                         .slope()
                         .checked_mul(
-                            new_locked
-                                .end
-                                .checked_sub(U256::from(u64::from(get_blocktime())))
-                                .unwrap_or_revert_with(Error::VotingEscrowSubtractionError2)
-                                .as_u128()
-                                .try_into()
-                                .unwrap(),
+                            I128::try_from(
+                                new_locked
+                                    .end
+                                    .checked_sub(U256::from(u64::from(get_blocktime())))
+                                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError2),
+                            )
+                            .unwrap_or_revert(),
                         )
                         .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError2),
                 )
@@ -240,7 +379,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             t_i = t_i
                 .checked_add(WEEK)
                 .unwrap_or_revert_with(Error::VotingEscrowAdditionError2);
-            let mut d_slope: i128 = 0.into();
+            let mut d_slope: I128 = I128::ZERO;
             if t_i > U256::from(u64::from(get_blocktime())) {
                 t_i = U256::from(u64::from(get_blocktime()));
             } else {
@@ -253,11 +392,12 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                         last_point
                             .slope()
                             .checked_mul(
-                                t_i.checked_sub(last_checkpoint)
-                                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError3)
-                                    .as_u128()
-                                    .try_into()
-                                    .unwrap(),
+                                I128::try_from(
+                                    t_i.checked_sub(last_checkpoint).unwrap_or_revert_with(
+                                        Error::VotingEscrowSubtractionError3,
+                                    ),
+                                )
+                                .unwrap_or_revert(),
                             )
                             .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError4),
                     )
@@ -269,13 +409,13 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                     .checked_add(d_slope)
                     .unwrap_or_revert_with(Error::VotingEscrowAdditionError3),
             );
-            if last_point.bias() < 0.into() {
+            if last_point.bias().is_negative() {
                 // This can happen
-                last_point.set_bias(0);
+                last_point.set_bias(I128::ZERO);
             }
-            if last_point.slope() < 0.into() {
+            if last_point.slope().is_negative() {
                 // This cannot happen - just in case
-                last_point.set_slope(0);
+                last_point.set_slope(I128::ZERO);
             }
             last_checkpoint = t_i;
             last_point.ts = t_i;
@@ -329,11 +469,11 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                     )
                     .unwrap_or_revert_with(Error::VotingEscrowAdditionError7),
             );
-            if last_point.slope() < 0.into() {
-                last_point.set_slope(0);
+            if last_point.slope().is_negative() {
+                last_point.set_slope(I128::ZERO);
             }
-            if last_point.bias() < 0.into() {
-                last_point.set_bias(0);
+            if last_point.bias().is_negative() {
+                last_point.set_bias(I128::ZERO);
             }
         }
         // Record the changed point into history
@@ -342,7 +482,10 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             // Schedule the slope changes (slope is going down)
             // We subtract new_user_slope from [new_locked.end]
             // and add old_user_slope to [old_locked.end]
-            if old_locked.end > U256::from(u64::from(get_blocktime())) {
+            // Nothing is scheduled for auto max locks, which don't decay
+            if old_locked.end > U256::from(u64::from(get_blocktime()))
+                && old_locked.end != AUTO_MAX_LOCK_END
+            {
                 // old_dslope was <something> - u_old.slope, so we cancel that
                 old_dslope = old_dslope
                     .checked_add(u_old.slope())
@@ -356,7 +499,8 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                 SlopeChanges::instance().set(&old_locked.end, old_dslope);
             }
             if new_locked.end > U256::from(u64::from(get_blocktime()))
-                && new_locked.end > old_locked.end
+                && new_locked.end != old_locked.end
+                && new_locked.end != AUTO_MAX_LOCK_END
             {
                 new_dslope = new_dslope
                     .checked_sub(u_new.slope())
@@ -374,6 +518,20 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             u_new.ts = U256::from(u64::from(get_blocktime()));
             u_new.blk = block_number().into();
             UserPointHistory::instance().set(&addr, &user_epoch, u_new);
+
+            // The delegate holding the lock's voting power follows the same change
+            let delegate: Key = LockDelegates::instance()
+                .get(&addr)
+                .unwrap_or_else(|| Delegates::instance().get(&addr));
+            self._update_delegate(delegate, u_old, old_locked.end, u_new, new_locked.end);
+            // And so does the voting power of its owner
+            self._update_delegate(
+                owner_key(owner),
+                u_old,
+                old_locked.end,
+                u_new,
+                new_locked.end,
+            );
         }
     }
 
@@ -403,7 +561,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         locked.set_amount(
             locked
                 .amount()
-                .checked_add(value.as_u128().try_into().unwrap())
+                .checked_add(I128::try_from(value).unwrap_or_revert())
                 .unwrap_or_revert_with(Error::VotingEscrowAdditionError11),
         );
         if unlock_time != 0.into() {
@@ -414,7 +572,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         // Both old_locked.end could be current or expired (>/< block.timestamp)
         // value == 0 (extend lock) or value > 0 (add to lock or extend lock)
         // _locked.end > block.timestamp (always)
-        self._checkpoint(addr, old_locked, locked);
+        self._checkpoint(addr, addr, old_locked, locked);
         if value != 0.into() {
             let _ret: () = runtime::call_versioned_contract(
                 get_token().into_hash().unwrap_or_revert().into(),
@@ -432,7 +590,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             &VotingEscrowEvent::Deposit {
                 provider: addr,
                 value,
-                locktime: locked.end,
+                locktime: self._effective_lock_end(locked.end),
                 _type,
                 ts: U256::from(u64::from(get_blocktime())),
             },
@@ -451,6 +609,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
     #[inline(always)]
     fn checkpoint(&self) {
         self._checkpoint(
+            zero_address(),
             zero_address(),
             LockedBalance::default(),
             LockedBalance::default(),
@@ -463,11 +622,15 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             runtime::revert(ApiError::from(Error::VotingEscrowIsLocked1));
         }
         set_lock(true);
+        self.assert_not_contract(
+            self.get_caller(),
+            Error::VotingEscrowSmartContractDepositorsNotAllowed1,
+        );
         let locked: LockedBalance = Locked::instance().get(&addr);
         if value <= 0.into() {
             runtime::revert(ApiError::from(Error::VotingEscrowNeedNonZeroValue1));
         }
-        if locked.amount() <= 0.into() {
+        if locked.amount() <= I128::ZERO {
             runtime::revert(ApiError::from(Error::VotingEscrowNoExistingLockFound1));
         }
         if locked.end <= U256::from(u64::from(get_blocktime())) {
@@ -491,6 +654,10 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             runtime::revert(ApiError::from(Error::VotingEscrowIsLocked2));
         }
         set_lock(true);
+        self.assert_not_contract(
+            self.get_caller(),
+            Error::VotingEscrowSmartContractDepositorsNotAllowed2,
+        );
         let unlock_time: U256 = unlock_time
             .checked_div(WEEK)
             .unwrap_or_revert_with(Error::VotingEscrowDivisionError4)
@@ -500,7 +667,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         if value <= 0.into() {
             runtime::revert(ApiError::from(Error::VotingEscrowNeedNonZeroValue2));
         }
-        if locked.amount() != 0.into() {
+        if locked.amount() != I128::ZERO {
             runtime::revert(ApiError::from(Error::VotingEscrowWithdrawOldTokensFirst));
         }
         if unlock_time <= U256::from(u64::from(get_blocktime())) {
@@ -531,11 +698,15 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             runtime::revert(ApiError::from(Error::VotingEscrowIsLocked3));
         }
         set_lock(true);
+        self.assert_not_contract(
+            self.get_caller(),
+            Error::VotingEscrowSmartContractDepositorsNotAllowed3,
+        );
         let locked: LockedBalance = Locked::instance().get(&self.get_caller());
         if value <= 0.into() {
             runtime::revert(ApiError::from(Error::VotingEscrowNeedNonZeroValue3));
         }
-        if locked.amount() <= 0.into() {
+        if locked.amount() <= I128::ZERO {
             runtime::revert(ApiError::from(Error::VotingEscrowNoExistingLockFound2));
         }
         if locked.end <= U256::from(u64::from(get_blocktime())) {
@@ -559,6 +730,10 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             runtime::revert(ApiError::from(Error::VotingEscrowIsLocked4));
         }
         set_lock(true);
+        self.assert_not_contract(
+            self.get_caller(),
+            Error::VotingEscrowSmartContractDepositorsNotAllowed4,
+        );
         let locked: LockedBalance = Locked::instance().get(&self.get_caller());
         let unlock_time: U256 = unlock_time
             .checked_div(WEEK)
@@ -568,7 +743,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         if locked.end <= U256::from(u64::from(get_blocktime())) {
             runtime::revert(ApiError::from(Error::VotingEscrowLockExpired));
         }
-        if locked.amount() <= 0.into() {
+        if locked.amount() <= I128::ZERO {
             runtime::revert(ApiError::from(Error::VotingEscrowNothingIsLocked));
         }
         if unlock_time <= locked.end {
@@ -599,10 +774,10 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         if U256::from(u64::from(get_blocktime())) < locked.end {
             runtime::revert(ApiError::from(Error::VotingEscrowTheLockDidntExpire));
         }
-        let value: U256 = locked.amount().try_into().unwrap();
+        let value: U256 = U256::try_from(locked.amount()).unwrap_or_revert();
         let old_locked: LockedBalance = locked;
         locked.end = 0.into();
-        locked.set_amount(0);
+        locked.set_amount(I128::ZERO);
         Locked::instance().set(&self.get_caller(), locked);
         let supply_before: U256 = get_supply();
         set_supply(
@@ -613,7 +788,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         // old_locked can have either expired <= timestamp or zero end
         // _locked has only 0 end
         // Both can have >= 0 amount
-        self._checkpoint(self.get_caller(), old_locked, locked);
+        self._checkpoint(self.get_caller(), self.get_caller(), old_locked, locked);
         let _ret: () = runtime::call_versioned_contract(
             get_token().into_hash().unwrap_or_revert().into(),
             None,
@@ -643,6 +818,541 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         );
     }
 
+    /// @notice Withdraw all tokens for `self.get_caller()` before the lock expires
+    /// @dev A penalty proportional to the remaining lock time (full amount at `MAXTIME`)
+    /// is sent to the penalty receiver, the rest is returned to the user
+    #[inline(always)]
+    fn withdraw_early(&mut self) {
+        let penalty_receiver: Key = get_penalty_receiver();
+        if penalty_receiver == zero_address() || penalty_receiver == account_zero_address() {
+            runtime::revert(ApiError::from(Error::VotingEscrowPenaltyReceiverNotSet));
+        }
+        let mut locked: LockedBalance = Locked::instance().get(&self.get_caller());
+        if locked.amount() <= I128::ZERO {
+            runtime::revert(ApiError::from(Error::VotingEscrowNoExistingLockFound3));
+        }
+        if locked.end == AUTO_MAX_LOCK_END {
+            runtime::revert(ApiError::from(Error::VotingEscrowAutoMaxLocked));
+        }
+        let now: U256 = U256::from(u64::from(get_blocktime()));
+        if now >= locked.end {
+            runtime::revert(ApiError::from(Error::VotingEscrowLockExpired2));
+        }
+        let value: U256 = U256::try_from(locked.amount()).unwrap_or_revert();
+        let time_left: U256 = U256::min(
+            locked
+                .end
+                .checked_sub(now)
+                .unwrap_or_revert_with(Error::VotingEscrowSubtractionError36),
+            MAXTIME,
+        );
+        let penalty: U256 = value
+            .checked_mul(time_left)
+            .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError17)
+            .checked_div(MAXTIME)
+            .unwrap_or_revert_with(Error::VotingEscrowDivisionError13);
+        let old_locked: LockedBalance = locked;
+        locked.end = 0.into();
+        locked.set_amount(I128::ZERO);
+        Locked::instance().set(&self.get_caller(), locked);
+        let supply_before: U256 = get_supply();
+        set_supply(
+            supply_before
+                .checked_sub(value)
+                .unwrap_or_revert_with(Error::VotingEscrowSubtractionError37),
+        );
+        // old_locked ends in the future, so its slope is removed from slope_changes
+        // _locked has only 0 end
+        self._checkpoint(self.get_caller(), self.get_caller(), old_locked, locked);
+        let _ret: () = runtime::call_versioned_contract(
+            get_token().into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer",
+            runtime_args! {
+                "recipient" => Address::from(self.get_caller()),
+                "amount" => value
+                    .checked_sub(penalty)
+                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError38)
+            },
+        );
+        if penalty > 0.into() {
+            let _ret: () = runtime::call_versioned_contract(
+                get_token().into_hash().unwrap_or_revert().into(),
+                None,
+                "transfer",
+                runtime_args! {
+                    "recipient" => Address::from(penalty_receiver),
+                    "amount" => penalty
+                },
+            );
+        }
+
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::WithdrawEarly {
+                provider: self.get_caller(),
+                value,
+                penalty,
+                ts: now,
+            },
+        );
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::Supply {
+                prev_supply: supply_before,
+                supply: get_supply(),
+            },
+        );
+    }
+
+    /// @notice Turn the auto max lock of `self.get_caller()` on or off
+    /// @dev While it is on, the lock keeps the full weight of a `MAXTIME` lock without decaying
+    /// and can't be withdrawn. Turning it off starts a normal `MAXTIME` lock from now on.
+    /// @param enabled Whether the lock should be an auto max lock
+    #[inline(always)]
+    fn set_auto_max_lock(&mut self, enabled: bool) {
+        let mut locked: LockedBalance = Locked::instance().get(&self.get_caller());
+        if locked.amount() <= I128::ZERO {
+            runtime::revert(ApiError::from(Error::VotingEscrowNoExistingLockFound4));
+        }
+        if enabled == (locked.end == AUTO_MAX_LOCK_END) {
+            runtime::revert(ApiError::from(Error::VotingEscrowAutoMaxLockUnchanged));
+        }
+        let now: U256 = U256::from(u64::from(get_blocktime()));
+        let old_locked: LockedBalance = locked;
+        if enabled {
+            if locked.end <= now {
+                runtime::revert(ApiError::from(Error::VotingEscrowLockExpired3));
+            }
+            locked.end = AUTO_MAX_LOCK_END;
+        } else {
+            locked.end = self._max_lock_end();
+        }
+        Locked::instance().set(&self.get_caller(), locked);
+        self._checkpoint(self.get_caller(), self.get_caller(), old_locked, locked);
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::AutoMaxLock {
+                provider: self.get_caller(),
+                enabled,
+                ts: now,
+            },
+        );
+    }
+
+    #[inline(always)]
+    fn auto_max_lock(&self, addr: Key) -> bool {
+        Locked::instance().get(&addr).end == AUTO_MAX_LOCK_END
+    }
+
+    /// @notice Assign lock position `token_id` to `owner`
+    /// @dev Reverts once `owner` holds `MAX_POSITIONS`, merging or withdrawing positions frees room
+    #[inline(always)]
+    fn _add_position(&self, owner: Key, token_id: U256) {
+        let count: U256 = PositionsCount::instance().get(&owner);
+        if count >= MAX_POSITIONS {
+            runtime::revert(ApiError::from(Error::VotingEscrowTooManyPositions));
+        }
+        OwnerPositions::instance().set(&owner, &count, token_id);
+        PositionIndex::instance().set(&token_id, count);
+        PositionsCount::instance().set(
+            &owner,
+            count
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError32),
+        );
+        PositionOwners::instance().set(&token_id, owner);
+    }
+
+    /// @notice Remove lock position `token_id` from `owner`
+    /// @dev The last position of the owner takes the place of the removed one
+    #[inline(always)]
+    fn _remove_position(&self, owner: Key, token_id: U256) {
+        let last_index: U256 = PositionsCount::instance()
+            .get(&owner)
+            .checked_sub(1.into())
+            .unwrap_or_revert_with(Error::VotingEscrowSubtractionError43);
+        let index: U256 = PositionIndex::instance().get(&token_id);
+        if index != last_index {
+            let last_token_id: U256 = OwnerPositions::instance().get(&owner, &last_index);
+            OwnerPositions::instance().set(&owner, &index, last_token_id);
+            PositionIndex::instance().set(&last_token_id, index);
+        }
+        OwnerPositions::instance().set(&owner, &last_index, 0.into());
+        PositionIndex::instance().set(&token_id, 0.into());
+        PositionsCount::instance().set(&owner, last_index);
+        PositionOwners::instance().set(&token_id, zero_address());
+    }
+
+    /// @notice Mint a new lock position id for `owner`
+    #[inline(always)]
+    fn _mint_position(&self, owner: Key) -> U256 {
+        let token_id: U256 = get_position_id()
+            .checked_add(1.into())
+            .unwrap_or_revert_with(Error::VotingEscrowAdditionError33);
+        set_position_id(token_id);
+        self._add_position(owner, token_id);
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::PositionTransfer {
+                from: zero_address(),
+                to: owner,
+                token_id,
+            },
+        );
+        token_id
+    }
+
+    /// @notice Burn lock position `token_id` of `owner`
+    #[inline(always)]
+    fn _burn_position(&self, owner: Key, token_id: U256) {
+        self._remove_position(owner, token_id);
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::PositionTransfer {
+                from: owner,
+                to: zero_address(),
+                token_id,
+            },
+        );
+    }
+
+    /// @notice Record `locked` as the new balance of position `token_id` and checkpoint it
+    #[inline(always)]
+    fn _set_position_locked(
+        &self,
+        token_id: U256,
+        old_locked: LockedBalance,
+        locked: LockedBalance,
+    ) {
+        if LockDelegates::instance()
+            .get(&position_key(token_id))
+            .is_none()
+        {
+            // New positions are delegated like the other locks of their owner
+            LockDelegates::instance().set(
+                &position_key(token_id),
+                Delegates::instance().get(&PositionOwners::instance().get(&token_id)),
+            );
+        }
+        Locked::instance().set(&position_key(token_id), locked);
+        self._checkpoint(
+            position_key(token_id),
+            PositionOwners::instance().get(&token_id),
+            old_locked,
+            locked,
+        );
+    }
+
+    /// @notice Deposit `value` tokens of `self.get_caller()` into a new lock position until `unlock_time`
+    /// @dev Every position has its own lock and point history, independent of the caller's own lock
+    /// @param value Amount to deposit
+    /// @param unlock_time Epoch time when tokens unlock, rounded down to whole weeks
+    /// @return Token id of the new position
+    #[inline(always)]
+    fn create_lock_position(&mut self, value: U256, unlock_time: U256) -> U256 {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::VotingEscrowIsLocked5));
+        }
+        set_lock(true);
+        let owner: Key = self.get_caller();
+        self.assert_not_contract(owner, Error::VotingEscrowSmartContractDepositorsNotAllowed5);
+        let unlock_time: U256 = unlock_time
+            .checked_div(WEEK)
+            .unwrap_or_revert_with(Error::VotingEscrowDivisionError16)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError19); // Locktime is rounded down to weeks
+        let now: U256 = U256::from(u64::from(get_blocktime()));
+        if value <= 0.into() {
+            runtime::revert(ApiError::from(Error::VotingEscrowNeedNonZeroValue4));
+        }
+        if unlock_time <= now {
+            runtime::revert(ApiError::from(
+                Error::VotingEscrowCanOnlyLockUntilTimeInTheFuture2,
+            ));
+        }
+        if unlock_time
+            > now
+                .checked_add(MAXTIME)
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError34)
+        {
+            runtime::revert(ApiError::from(Error::VotingEscrowVotingLockCanBe4YearsMax3));
+        }
+        let token_id: U256 = self._mint_position(owner);
+        let mut locked: LockedBalance = LockedBalance::default();
+        locked.set_amount(I128::try_from(value).unwrap_or_revert());
+        locked.end = unlock_time;
+        let supply_before: U256 = get_supply();
+        set_supply(
+            supply_before
+                .checked_add(value)
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError35),
+        );
+        self._set_position_locked(token_id, LockedBalance::default(), locked);
+        let _ret: () = runtime::call_versioned_contract(
+            get_token().into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer_from",
+            runtime_args! {
+                "owner" => Address::from(owner),
+                "recipient" => Address::from(Key::from(get_package_hash())),
+                "amount" => value
+            },
+        );
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::Deposit {
+                provider: owner,
+                value,
+                locktime: unlock_time,
+                _type: CREATE_LOCK_TYPE,
+                ts: now,
+            },
+        );
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::Supply {
+                prev_supply: supply_before,
+                supply: get_supply(),
+            },
+        );
+        set_lock(false);
+        token_id
+    }
+
+    /// @notice Withdraw all tokens of the expired lock position `token_id` and burn it
+    /// @param token_id Position to withdraw
+    #[inline(always)]
+    fn withdraw_position(&mut self, token_id: U256) {
+        if get_lock() {
+            runtime::revert(ApiError::from(Error::VotingEscrowIsLocked6));
+        }
+        set_lock(true);
+        let owner: Key = self.get_caller();
+        if PositionOwners::instance().get(&token_id) != owner {
+            runtime::revert(ApiError::from(Error::VotingEscrowNotPositionOwner1));
+        }
+        let locked: LockedBalance = Locked::instance().get(&position_key(token_id));
+        if U256::from(u64::from(get_blocktime())) < locked.end {
+            runtime::revert(ApiError::from(Error::VotingEscrowTheLockDidntExpire2));
+        }
+        let value: U256 = U256::try_from(locked.amount()).unwrap_or_revert();
+        let supply_before: U256 = get_supply();
+        set_supply(
+            supply_before
+                .checked_sub(value)
+                .unwrap_or_revert_with(Error::VotingEscrowSubtractionError44),
+        );
+        self._set_position_locked(token_id, locked, LockedBalance::default());
+        self._burn_position(owner, token_id);
+        let _ret: () = runtime::call_versioned_contract(
+            get_token().into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer",
+            runtime_args! {
+                "recipient" => Address::from(owner),
+                "amount" => value
+            },
+        );
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::Withdraw {
+                provider: owner,
+                value,
+                ts: U256::from(u64::from(get_blocktime())),
+            },
+        );
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::Supply {
+                prev_supply: supply_before,
+                supply: get_supply(),
+            },
+        );
+        set_lock(false);
+    }
+
+    /// @notice Merge lock position `from_id` into `to_id`
+    /// @dev `to_id` keeps the later of both lock ends, `from_id` is burnt
+    /// @param from_id Position to merge, both must belong to the caller and be unexpired
+    /// @param to_id Position receiving the locked tokens
+    #[inline(always)]
+    fn merge(&mut self, from_id: U256, to_id: U256) {
+        let owner: Key = self.get_caller();
+        if PositionOwners::instance().get(&from_id) != owner {
+            runtime::revert(ApiError::from(Error::VotingEscrowNotPositionOwner2));
+        }
+        if PositionOwners::instance().get(&to_id) != owner {
+            runtime::revert(ApiError::from(Error::VotingEscrowNotPositionOwner3));
+        }
+        if from_id == to_id {
+            runtime::revert(ApiError::from(Error::VotingEscrowSamePosition));
+        }
+        let now: U256 = U256::from(u64::from(get_blocktime()));
+        let locked_from: LockedBalance = Locked::instance().get(&position_key(from_id));
+        let locked_to: LockedBalance = Locked::instance().get(&position_key(to_id));
+        if locked_from.end <= now {
+            runtime::revert(ApiError::from(Error::VotingEscrowPositionExpired1));
+        }
+        if locked_to.end <= now {
+            runtime::revert(ApiError::from(Error::VotingEscrowPositionExpired2));
+        }
+        let mut new_locked: LockedBalance = locked_to;
+        new_locked.set_amount(
+            locked_to
+                .amount()
+                .checked_add(locked_from.amount())
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError36),
+        );
+        new_locked.end = U256::max(locked_from.end, locked_to.end);
+        // Supply is unchanged, only the slopes of both positions move
+        self._set_position_locked(from_id, locked_from, LockedBalance::default());
+        self._set_position_locked(to_id, locked_to, new_locked);
+        self._burn_position(owner, from_id);
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::Merge {
+                owner,
+                from_id,
+                to_id,
+            },
+        );
+    }
+
+    /// @notice Split lock position `token_id` into positions of `amounts`, all with the same lock end
+    /// @dev `token_id` keeps the first amount, new positions are minted for the others
+    /// @param token_id Unexpired position of the caller
+    /// @param amounts Amounts of the resulting positions, must add up to the locked amount
+    /// @return Token ids of the resulting positions
+    #[inline(always)]
+    fn split(&mut self, token_id: U256, amounts: Vec<U256>) -> Vec<U256> {
+        let owner: Key = self.get_caller();
+        if PositionOwners::instance().get(&token_id) != owner {
+            runtime::revert(ApiError::from(Error::VotingEscrowNotPositionOwner4));
+        }
+        let locked: LockedBalance = Locked::instance().get(&position_key(token_id));
+        if locked.end <= U256::from(u64::from(get_blocktime())) {
+            runtime::revert(ApiError::from(Error::VotingEscrowPositionExpired3));
+        }
+        let mut total: U256 = 0.into();
+        for amount in amounts.iter() {
+            if *amount == 0.into() {
+                runtime::revert(ApiError::from(Error::VotingEscrowZeroSplitAmount));
+            }
+            total = total
+                .checked_add(*amount)
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError37);
+        }
+        if amounts.is_empty() || total != U256::try_from(locked.amount()).unwrap_or_default() {
+            runtime::revert(ApiError::from(Error::VotingEscrowInvalidSplitAmounts));
+        }
+        let mut token_ids: Vec<U256> = Vec::new();
+        for (i, amount) in amounts.iter().enumerate() {
+            let mut new_locked: LockedBalance = LockedBalance::default();
+            new_locked.set_amount(I128::try_from(*amount).unwrap_or_revert());
+            new_locked.end = locked.end;
+            if i == 0 {
+                self._set_position_locked(token_id, locked, new_locked);
+                token_ids.push(token_id);
+            } else {
+                let new_id: U256 = self._mint_position(owner);
+                self._set_position_locked(new_id, LockedBalance::default(), new_locked);
+                token_ids.push(new_id);
+            }
+        }
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::Split {
+                owner,
+                token_id,
+                token_ids: token_ids.clone(),
+            },
+        );
+        token_ids
+    }
+
+    /// @notice Transfer lock position `token_id` of the caller to `recipient`
+    /// @dev The position keeps its lock and point history, its voting power is checkpointed
+    /// into the voting power of the recipient from now on
+    /// @param recipient New owner, contracts must be approved by the smart wallet checker
+    /// @param token_id Position to transfer
+    #[inline(always)]
+    fn transfer_position(&mut self, recipient: Key, token_id: U256) {
+        let owner: Key = self.get_caller();
+        if PositionOwners::instance().get(&token_id) != owner {
+            runtime::revert(ApiError::from(Error::VotingEscrowNotPositionOwner5));
+        }
+        if recipient == zero_address() || recipient == account_zero_address() {
+            runtime::revert(ApiError::from(Error::VotingEscrowZeroAddress2));
+        }
+        self.assert_not_contract(
+            recipient,
+            Error::VotingEscrowSmartContractDepositorsNotAllowed6,
+        );
+        self._remove_position(owner, token_id);
+        self._add_position(recipient, token_id);
+        // The voting power moves on to the recipient's delegate
+        let from: Key = LockDelegates::instance()
+            .get(&position_key(token_id))
+            .unwrap_or_else(|| Delegates::instance().get(&owner));
+        self._move_delegation(
+            position_key(token_id),
+            from,
+            Delegates::instance().get(&recipient),
+        );
+        // So does the voting power of the owners, the sender keeps its power of the past
+        let locked: LockedBalance = Locked::instance().get(&position_key(token_id));
+        let point: Point = self._locked_point(locked);
+        self._update_delegate(
+            owner_key(owner),
+            point,
+            locked.end,
+            Point::default(),
+            0.into(),
+        );
+        self._update_delegate(
+            owner_key(recipient),
+            Point::default(),
+            0.into(),
+            point,
+            locked.end,
+        );
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::PositionTransfer {
+                from: owner,
+                to: recipient,
+                token_id,
+            },
+        );
+    }
+
+    #[inline(always)]
+    fn owner_of(&self, token_id: U256) -> Key {
+        PositionOwners::instance().get(&token_id)
+    }
+
+    #[inline(always)]
+    fn positions_of(&self, owner: Key) -> U256 {
+        PositionsCount::instance().get(&owner)
+    }
+
+    #[inline(always)]
+    fn position_of_owner_by_index(&self, owner: Key, index: U256) -> U256 {
+        OwnerPositions::instance().get(&owner, &index)
+    }
+
+    #[inline(always)]
+    fn position_locked_end(&self, token_id: U256) -> U256 {
+        Locked::instance().get(&position_key(token_id)).end
+    }
+
+    /// @notice Get the voting power of lock position `token_id`
+    #[inline(always)]
+    fn position_balance_of(&self, token_id: U256, t: Option<U256>) -> U256 {
+        self._balance_of(position_key(token_id), t)
+    }
+
     /// The following ERC20/minime-compatible methods are not real balanceOf and supply!
     /// They measure the weights for the purpose of voting, so they don't represent real coins.
     /// @notice Binary search to estimate timestamp for block number
@@ -677,8 +1387,84 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         min
     }
 
+    /// @notice Binary search for the last global checkpoint recorded at or before `time`
+    /// @param time Timestamp to find
+    /// @param max_epoch Don't go beyond this epoch
+    /// @return Epoch of the checkpoint
+    #[inline(always)]
+    fn _find_timestamp_epoch(&self, time: U256, max_epoch: U256) -> U256 {
+        // Binary search
+        let mut min: U256 = 0.into();
+        let mut max: U256 = max_epoch;
+        for _ in 0..128 {
+            // Will be always enough for 128-bit numbers
+            if min >= max {
+                break;
+            }
+            let mid: U256 = min
+                .checked_add(max)
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError28)
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError29)
+                .checked_div(2.into())
+                .unwrap_or_revert_with(Error::VotingEscrowDivisionError14);
+            if PointHistory::instance().get(&mid).ts <= time {
+                min = mid;
+            } else {
+                max = mid
+                    .checked_sub(1.into())
+                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError39);
+            }
+        }
+        min
+    }
+
+    /// @notice Get the voting power of `addr` including all of its lock positions
     #[inline(always)]
     fn balance_of(&self, addr: Key, t: Option<U256>) -> U256 {
+        let t: U256 = t.unwrap_or_else(|| U256::from(u64::from(get_blocktime())));
+        self._owner_balance_at(addr, t)
+    }
+
+    /// @notice Get the voting power of `owner` at timestamp `time` from its own checkpoints
+    #[inline(always)]
+    fn _owner_balance_at(&self, owner: Key, time: U256) -> U256 {
+        let epoch: U256 = self._find_delegate_timestamp_epoch(owner_key(owner), time);
+        if epoch == 0.into() {
+            return 0.into();
+        }
+        self._owner_balance_from(owner, epoch, time)
+    }
+
+    /// @notice Get the voting power of `owner` at timestamp `time`, starting from its checkpoint `epoch`
+    /// @dev The locks an owner holds only change with a new checkpoint, so after its last one the
+    /// voting power is the sum of its locks. Earlier checkpoints are at most a week apart.
+    #[inline(always)]
+    fn _owner_balance_from(&self, owner: Key, epoch: U256, time: U256) -> U256 {
+        let key: Key = owner_key(owner);
+        let point: Point = DelegatePointHistory::instance().get(&key, &epoch);
+        if epoch != DelegatePointEpoch::instance().get(&key) || time < point.ts {
+            return U256::try_from(self._delegate_point_at(key, point, time).bias())
+                .unwrap_or_revert_with(Error::VotingEscrowInvalidBias1);
+        }
+        let mut balance: U256 = self._balance_of(owner, Some(time));
+        let count: U256 = PositionsCount::instance().get(&owner);
+        let mut i: U256 = 0.into();
+        while i < count {
+            let token_id: U256 = OwnerPositions::instance().get(&owner, &i);
+            balance = balance
+                .checked_add(self._balance_of(position_key(token_id), Some(time)))
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError54);
+            i = i
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError55);
+        }
+        balance
+    }
+
+    /// @notice Get the voting power of the single lock `addr`, e.g. a lock position
+    #[inline(always)]
+    fn _balance_of(&self, addr: Key, t: Option<U256>) -> U256 {
         let t: U256 = match t {
             Some(val) => val,
             None => {
@@ -699,32 +1485,41 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                         last_point
                             .slope()
                             .checked_mul(
-                                t.checked_sub(last_point.ts)
-                                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError12)
-                                    .as_u128()
-                                    .try_into()
-                                    .unwrap(),
+                                I128::try_from(
+                                    t.checked_sub(last_point.ts).unwrap_or_revert_with(
+                                        Error::VotingEscrowSubtractionError12,
+                                    ),
+                                )
+                                .unwrap_or_revert(),
                             )
                             .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError7),
                     )
                     .unwrap_or_revert_with(Error::VotingEscrowSubtractionError13),
             );
-            if last_point.bias() < 0.into() {
-                last_point.set_bias(0);
+            if last_point.bias().is_negative() {
+                last_point.set_bias(I128::ZERO);
             }
-            last_point.bias().try_into().unwrap()
+            U256::try_from(last_point.bias()).unwrap_or_revert()
         }
     }
 
-    #[allow(unused_assignments)]
+    /// @notice Measure voting power of `addr` at block height `block`, including the lock
+    /// positions it owned then
     #[inline(always)]
     fn balance_of_at(&self, addr: Key, block: U256) -> U256 {
+        self._balance_of_at(addr, block)
+    }
+
+    #[allow(unused_assignments)]
+    #[inline(always)]
+    fn _balance_of_at(&self, addr: Key, block: U256) -> U256 {
         if block > block_number().into() {
             runtime::revert(ApiError::from(Error::VotingEscrowInvalidBlockNumber1));
         }
+        let key: Key = owner_key(addr);
         // Binary search
         let mut min: U256 = 0.into();
-        let mut max: U256 = UserPointEpoch::instance().get(&addr);
+        let mut max: U256 = DelegatePointEpoch::instance().get(&key);
         for _ in 0..128 {
             // Will be always enough for 128-bit numbers
             if min >= max {
@@ -737,7 +1532,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                 .unwrap_or_revert_with(Error::VotingEscrowAdditionError19)
                 .checked_div(2.into())
                 .unwrap_or_revert_with(Error::VotingEscrowAdditionError20);
-            if UserPointHistory::instance().get(&addr, &mid).blk <= block {
+            if DelegatePointHistory::instance().get(&key, &mid).blk <= block {
                 min = mid;
             } else {
                 max = mid
@@ -745,7 +1540,9 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                     .unwrap_or_revert_with(Error::VotingEscrowSubtractionError14);
             }
         }
-        let mut upoint: Point = UserPointHistory::instance().get(&addr, &min);
+        if min == 0.into() {
+            return 0.into();
+        }
         let max_epoch: U256 = get_epoch();
         let epoch: U256 = self._find_block_epoch(block, max_epoch);
         let point_0: Point = PointHistory::instance().get(&epoch);
@@ -788,22 +1585,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
                 )
                 .unwrap_or_revert_with(Error::VotingEscrowAdditionError22);
         }
-        upoint.set_bias(
-            upoint
-                .bias()
-                .checked_sub(
-                    upoint
-                        .slope()
-                        .checked_mul(convert(block_time, upoint.ts))
-                        .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError9),
-                )
-                .unwrap_or_revert_with(Error::VotingEscrowSubtractionError18),
-        );
-        if upoint.bias() >= 0.into() {
-            upoint.bias().try_into().unwrap()
-        } else {
-            0.into()
-        }
+        self._owner_balance_from(addr, min, block_time)
     }
 
     /// @notice Calculate total voting power at some point in the past
@@ -823,7 +1605,7 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             t_i = t_i
                 .checked_add(WEEK)
                 .unwrap_or_revert_with(Error::VotingEscrowAdditionError23);
-            let mut d_slope: i128 = 0.into();
+            let mut d_slope: I128 = I128::ZERO;
             if t_i > t {
                 t_i = t;
             } else {
@@ -851,10 +1633,10 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
             );
             last_point.ts = t_i;
         }
-        if last_point.bias() < 0.into() {
-            last_point.set_bias(0);
+        if last_point.bias().is_negative() {
+            last_point.set_bias(I128::ZERO);
         }
-        last_point.bias().try_into().unwrap()
+        U256::try_from(last_point.bias()).unwrap_or_revert()
     }
 
     #[inline(always)]
@@ -933,6 +1715,378 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
         )
     }
 
+    /// @notice Measure voting power of `addr` at timestamp `time`
+    /// @dev Unlike `balance_of_at`, this is exact: it reads the user checkpoint in effect at
+    /// `time` instead of extrapolating a timestamp from an estimated block height
+    /// @param addr User's wallet address
+    /// @param time Timestamp to calculate the voting power at, can't be in the future
+    /// @return Voting power, including the lock positions `addr` owned at `time`
+    #[inline(always)]
+    fn balance_of_at_time(&self, addr: Key, time: U256) -> U256 {
+        if time > U256::from(u64::from(get_blocktime())) {
+            runtime::revert(ApiError::from(Error::VotingEscrowInvalidTimestamp1));
+        }
+        self._owner_balance_at(addr, time)
+    }
+
+    /// @notice Calculate total voting power at timestamp `time`
+    /// @dev Exact counterpart of `total_supply_at`, starting from the global checkpoint in effect at `time`
+    /// @param time Timestamp to calculate the total voting power at, can't be in the future
+    /// @return Total voting power at `time`
+    #[inline(always)]
+    fn total_supply_at_time(&self, time: U256) -> U256 {
+        if time > U256::from(u64::from(get_blocktime())) {
+            runtime::revert(ApiError::from(Error::VotingEscrowInvalidTimestamp2));
+        }
+        let target_epoch: U256 = self._find_timestamp_epoch(time, get_epoch());
+        let point: Point = PointHistory::instance().get(&target_epoch);
+        if point.ts > time {
+            // Nothing was locked before the first checkpoint
+            return 0.into();
+        }
+        self._supply_at(point, time)
+    }
+
+    /// @notice Measure voting power of each of `addrs` at timestamp `t`
+    /// @dev Batch version of `balance_of_at_time`, for snapshots of many holders at once
+    /// @param addrs Users' wallet addresses
+    /// @param t Timestamp to calculate the voting power at, can't be in the future
+    /// @return Voting power of each address, in the order of `addrs`
+    #[inline(always)]
+    fn balances_of_at(&self, addrs: Vec<Key>, t: U256) -> Vec<U256> {
+        addrs
+            .into_iter()
+            .map(|addr| self.balance_of_at_time(addr, t))
+            .collect()
+    }
+
+    /// @notice Calculate total voting power at every `step` from `from` up to `to`
+    /// @dev Batch version of `total_supply_at_time`
+    /// @param from Timestamp of the first value
+    /// @param to Timestamp not to go beyond, can't be in the future
+    /// @param step Time between two values
+    /// @return Total voting power at `from`, `from + step`, ... until `to`
+    #[inline(always)]
+    fn total_supply_series(&self, from: U256, to: U256, step: U256) -> Vec<U256> {
+        if step == 0.into() || from > to {
+            runtime::revert(ApiError::from(Error::VotingEscrowInvalidSeries));
+        }
+        let mut series: Vec<U256> = Vec::new();
+        let mut t: U256 = from;
+        while t <= to {
+            series.push(self.total_supply_at_time(t));
+            t = match t.checked_add(step) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        series
+    }
+
+    /// @notice Get the current bias/slope of `locked`
+    #[inline(always)]
+    fn _locked_point(&self, locked: LockedBalance) -> Point {
+        let now: U256 = U256::from(u64::from(get_blocktime()));
+        let mut point: Point = Point {
+            bias: Default::default(),
+            slope: Default::default(),
+            ts: now,
+            blk: block_number().into(),
+        };
+        if locked.end == AUTO_MAX_LOCK_END {
+            point.set_bias(self._max_lock_point(locked).bias());
+        } else if locked.end > now && locked.amount() > I128::ZERO {
+            point.set_slope(
+                locked
+                    .amount()
+                    .checked_div(MAXTIME_I128)
+                    .unwrap_or_revert_with(Error::VotingEscrowDivisionError17),
+            );
+            point.set_bias(
+                point
+                    .slope()
+                    .checked_mul(
+                        I128::try_from(
+                            locked
+                                .end
+                                .checked_sub(now)
+                                .unwrap_or_revert_with(Error::VotingEscrowSubtractionError45),
+                        )
+                        .unwrap_or_revert_with(Error::VotingEscrowInvalidLockDuration2),
+                    )
+                    .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError20),
+            );
+        }
+        point
+    }
+
+    /// @notice Extrapolate checkpoint `point` of `delegate` to time `t`
+    /// @dev Applies the scheduled slope changes of the delegate week by week, like `_supply_at`
+    #[inline(always)]
+    fn _delegate_point_at(&self, delegate: Key, point: Point, t: U256) -> Point {
+        let mut last_point: Point = point;
+        let mut t_i: U256 = last_point
+            .ts
+            .checked_div(WEEK)
+            .unwrap_or_revert_with(Error::VotingEscrowDivisionError18)
+            .checked_mul(WEEK)
+            .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError21);
+        for _ in 0..255 {
+            t_i = t_i
+                .checked_add(WEEK)
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError43);
+            if t_i > t {
+                t_i = t;
+            }
+            last_point.set_bias(
+                last_point
+                    .bias()
+                    .checked_sub(
+                        last_point
+                            .slope()
+                            .checked_mul(convert(t_i, last_point.ts))
+                            .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError22),
+                    )
+                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError46),
+            );
+            // A slope change at exactly `t` is applied too, so that `t` can be checkpointed
+            last_point.set_slope(
+                last_point
+                    .slope()
+                    .checked_add(DelegateSlopeChanges::instance().get(&delegate, &t_i))
+                    .unwrap_or_revert_with(Error::VotingEscrowAdditionError44),
+            );
+            last_point.ts = t_i;
+            if t_i == t {
+                break;
+            }
+        }
+        if last_point.bias().is_negative() {
+            last_point.set_bias(I128::ZERO);
+        }
+        if last_point.slope().is_negative() {
+            last_point.set_slope(I128::ZERO);
+        }
+        last_point
+    }
+
+    /// @notice Checkpoint `delegate`, replacing the delegated lock point `old_point` by `new_point`
+    /// @dev Also keeps the voting power of owners, checkpointed under `owner_key`
+    /// @param old_point Current bias/slope the lock delegated before, expiring at `old_end`
+    /// @param new_point Current bias/slope the lock delegates now, expiring at `new_end`
+    #[inline(always)]
+    fn _update_delegate(
+        &self,
+        delegate: Key,
+        old_point: Point,
+        old_end: U256,
+        new_point: Point,
+        new_end: U256,
+    ) {
+        let now: U256 = U256::from(u64::from(get_blocktime()));
+        let mut epoch: U256 = DelegatePointEpoch::instance().get(&delegate);
+        let mut point: Point = Point {
+            bias: Default::default(),
+            slope: Default::default(),
+            ts: now,
+            blk: block_number().into(),
+        };
+        if epoch > 0.into() {
+            // Fill the history up to now week by week, like `_checkpoint` does for the supply,
+            // so that reading the past never extrapolates across more than a week
+            let mut last_point: Point = DelegatePointHistory::instance().get(&delegate, &epoch);
+            let mut t_i: U256 = last_point
+                .ts
+                .checked_div(WEEK)
+                .unwrap_or_revert_with(Error::VotingEscrowDivisionError22)
+                .checked_mul(WEEK)
+                .unwrap_or_revert_with(Error::VotingEscrowMultiplicationError25);
+            for _ in 0..255 {
+                t_i = t_i
+                    .checked_add(WEEK)
+                    .unwrap_or_revert_with(Error::VotingEscrowAdditionError56);
+                if t_i >= now {
+                    break;
+                }
+                last_point = self._delegate_point_at(delegate, last_point, t_i);
+                last_point.blk = block_number_at(t_i).into();
+                epoch = epoch
+                    .checked_add(1.into())
+                    .unwrap_or_revert_with(Error::VotingEscrowAdditionError57);
+                DelegatePointHistory::instance().set(&delegate, &epoch, last_point);
+            }
+            point = self._delegate_point_at(delegate, last_point, now);
+        }
+        point.set_slope(
+            point
+                .slope()
+                .checked_add(
+                    new_point
+                        .slope()
+                        .checked_sub(old_point.slope())
+                        .unwrap_or_revert_with(Error::VotingEscrowSubtractionError47),
+                )
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError45),
+        );
+        point.set_bias(
+            point
+                .bias()
+                .checked_add(
+                    new_point
+                        .bias()
+                        .checked_sub(old_point.bias())
+                        .unwrap_or_revert_with(Error::VotingEscrowSubtractionError48),
+                )
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError46),
+        );
+        if point.slope().is_negative() {
+            point.set_slope(I128::ZERO);
+        }
+        if point.bias().is_negative() {
+            point.set_bias(I128::ZERO);
+        }
+        point.blk = block_number().into();
+        // Cancel the scheduled expiry of the old point and schedule the new one
+        if old_end > now && old_end != AUTO_MAX_LOCK_END {
+            DelegateSlopeChanges::instance().set(
+                &delegate,
+                &old_end,
+                DelegateSlopeChanges::instance()
+                    .get(&delegate, &old_end)
+                    .checked_add(old_point.slope())
+                    .unwrap_or_revert_with(Error::VotingEscrowAdditionError47),
+            );
+        }
+        if new_end > now && new_end != AUTO_MAX_LOCK_END {
+            DelegateSlopeChanges::instance().set(
+                &delegate,
+                &new_end,
+                DelegateSlopeChanges::instance()
+                    .get(&delegate, &new_end)
+                    .checked_sub(new_point.slope())
+                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError49),
+            );
+        }
+        let epoch: U256 = epoch
+            .checked_add(1.into())
+            .unwrap_or_revert_with(Error::VotingEscrowAdditionError48);
+        DelegatePointEpoch::instance().set(&delegate, epoch);
+        DelegatePointHistory::instance().set(&delegate, &epoch, point);
+    }
+
+    /// @notice Move the current voting power of `lock` from delegate `from` to `to`
+    #[inline(always)]
+    fn _move_delegation(&self, lock: Key, from: Key, to: Key) {
+        if from != to {
+            let locked: LockedBalance = Locked::instance().get(&lock);
+            let point: Point = self._locked_point(locked);
+            self._update_delegate(from, point, locked.end, Point::default(), 0.into());
+            self._update_delegate(to, Point::default(), 0.into(), point, locked.end);
+        }
+        LockDelegates::instance().set(&lock, to);
+    }
+
+    /// @notice Delegate the voting power of the caller's lock and lock positions to `delegatee`
+    /// @dev Delegated power keeps decaying like `balance_of`. Locks and positions created later
+    /// are delegated to `delegatee` as well, delegating to the caller itself undelegates.
+    /// @param delegatee Address receiving the voting power
+    #[inline(always)]
+    fn delegate(&mut self, delegatee: Key) {
+        if delegatee == zero_address() || delegatee == account_zero_address() {
+            runtime::revert(ApiError::from(Error::VotingEscrowZeroAddress3));
+        }
+        let owner: Key = self.get_caller();
+        let from: Key = Delegates::instance().get(&owner);
+        Delegates::instance().set(&owner, delegatee);
+        self._move_delegation(
+            owner,
+            LockDelegates::instance().get(&owner).unwrap_or(from),
+            delegatee,
+        );
+        let count: U256 = PositionsCount::instance().get(&owner);
+        let mut i: U256 = 0.into();
+        while i < count {
+            let lock: Key = position_key(OwnerPositions::instance().get(&owner, &i));
+            self._move_delegation(
+                lock,
+                LockDelegates::instance().get(&lock).unwrap_or(from),
+                delegatee,
+            );
+            i = i
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError49);
+        }
+        VOTINGESCROW::emit(
+            self,
+            &VotingEscrowEvent::DelegateChanged {
+                delegator: owner,
+                from_delegate: from,
+                to_delegate: delegatee,
+            },
+        );
+    }
+
+    /// @notice Take back the voting power delegated by the caller
+    #[inline(always)]
+    fn undelegate(&mut self) {
+        self.delegate(self.get_caller());
+    }
+
+    /// @notice Get the delegate of `owner`, `owner` itself if it didn't delegate
+    #[inline(always)]
+    fn delegates(&self, owner: Key) -> Key {
+        Delegates::instance().get(&owner)
+    }
+
+    /// @notice Binary search for the last checkpoint of `delegate` recorded at or before `time`
+    /// @return Delegate epoch of the checkpoint, 0 if there is none
+    #[inline(always)]
+    fn _find_delegate_timestamp_epoch(&self, delegate: Key, time: U256) -> U256 {
+        // Binary search
+        let mut min: U256 = 0.into();
+        let mut max: U256 = DelegatePointEpoch::instance().get(&delegate);
+        for _ in 0..128 {
+            // Will be always enough for 128-bit numbers
+            if min >= max {
+                break;
+            }
+            let mid: U256 = min
+                .checked_add(max)
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError50)
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::VotingEscrowAdditionError51)
+                .checked_div(2.into())
+                .unwrap_or_revert_with(Error::VotingEscrowDivisionError19);
+            if DelegatePointHistory::instance().get(&delegate, &mid).ts <= time {
+                min = mid;
+            } else {
+                max = mid
+                    .checked_sub(1.into())
+                    .unwrap_or_revert_with(Error::VotingEscrowSubtractionError50);
+            }
+        }
+        min
+    }
+
+    /// @notice Measure the voting power delegated to `delegate` at timestamp `time`
+    /// @dev Includes the power of `delegate`'s own locks unless it delegated them away
+    /// @param delegate Address to get the votes of
+    /// @param time Timestamp to calculate the votes at, can't be in the future
+    /// @return Delegated voting power
+    #[inline(always)]
+    fn get_votes(&self, delegate: Key, time: U256) -> U256 {
+        if time > U256::from(u64::from(get_blocktime())) {
+            runtime::revert(ApiError::from(Error::VotingEscrowInvalidTimestamp3));
+        }
+        let epoch: U256 = self._find_delegate_timestamp_epoch(delegate, time);
+        if epoch == 0.into() {
+            return 0.into();
+        }
+        let point: Point = DelegatePointHistory::instance().get(&delegate, &epoch);
+        U256::try_from(self._delegate_point_at(delegate, point, time).bias())
+            .unwrap_or_revert_with(Error::VotingEscrowInvalidBias3)
+    }
+
     #[inline(always)]
     fn change_controller(&self, new_controller: Key) {
         if self.get_caller() != get_controller() {
@@ -942,62 +2096,6 @@ pub trait VOTINGESCROW<Storage: ContractStorage>: ContractContext<Storage> {
     }
 
     fn emit(&self, voting_escrow_event: &VotingEscrowEvent) {
-        match voting_escrow_event {
-            VotingEscrowEvent::CommitOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", voting_escrow_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            VotingEscrowEvent::ApplyOwnership { admin } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", voting_escrow_event.type_name());
-                event.insert("admin", admin.to_string());
-                storage::new_uref(event);
-            }
-            VotingEscrowEvent::Deposit {
-                provider,
-                value,
-                locktime,
-                _type,
-                ts,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", voting_escrow_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                event.insert("locktime", locktime.to_string());
-                event.insert("_type", _type.to_string());
-                event.insert("ts", ts.to_string());
-                storage::new_uref(event);
-            }
-            VotingEscrowEvent::Withdraw {
-                provider,
-                value,
-                ts,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", voting_escrow_event.type_name());
-                event.insert("provider", provider.to_string());
-                event.insert("value", value.to_string());
-                event.insert("ts", ts.to_string());
-                storage::new_uref(event);
-            }
-            VotingEscrowEvent::Supply {
-                prev_supply,
-                supply,
-            } => {
-                let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", get_package_hash().to_string());
-                event.insert("event_type", voting_escrow_event.type_name());
-                event.insert("prev_supply", prev_supply.to_string());
-                event.insert("supply", supply.to_string());
-                storage::new_uref(event);
-            }
-        };
+        events::emit(voting_escrow_event);
     }
 }
@@ -4,11 +4,11 @@ use blake2::{
 };
 use casper_types::{
   account::AccountHash,
-  bytesrepr::{FromBytes, ToBytes},
+  bytesrepr::{Bytes, FromBytes, ToBytes},
   runtime_args, CLTyped, Key, RuntimeArgs, U256,
 };
 use casperlabs_test_env::{TestContract, TestEnv};
-use common::utils::hash;
+use common::{keys::*, utils::hash};
 use hex::encode;
 use std::time::SystemTime;
 use voting_escrow_crate::data::{LockedBalance, Point, LOCKED, USER_POINT_HISTORY};
@@ -66,6 +66,21 @@ impl VOTINGESCROWInstance {
       self.0
           .call_contract(owner, "apply_transfer_ownership", runtime_args! {}, time);
   }
+  pub fn commit_smart_wallet_checker(&self, owner: AccountHash, addr: Key, time: u64) {
+      self.0.call_contract(
+          owner,
+          "commit_smart_wallet_checker",
+          runtime_args! {
+              "addr" => addr
+          },
+          time,
+      );
+  }
+
+  pub fn apply_smart_wallet_checker(&self, owner: AccountHash, time: u64) {
+      self.0
+          .call_contract(owner, "apply_smart_wallet_checker", runtime_args! {}, time);
+  }
   pub fn checkpoint(&self, owner: AccountHash, time: u64) {
       self.0
           .call_contract(owner, "checkpoint", runtime_args! {}, time);
@@ -121,6 +136,108 @@ impl VOTINGESCROWInstance {
       self.0
           .call_contract(owner, "withdraw", runtime_args! {}, time);
   }
+  pub fn withdraw_early(&self, owner: AccountHash, time: u64) {
+      self.0
+          .call_contract(owner, "withdraw_early", runtime_args! {}, time);
+  }
+
+  pub fn set_auto_max_lock(&self, owner: AccountHash, enabled: bool, time: u64) {
+      self.0.call_contract(
+          owner,
+          "set_auto_max_lock",
+          runtime_args! {
+              "enabled" => enabled
+          },
+          time,
+      );
+  }
+
+  pub fn set_penalty_receiver(&self, owner: AccountHash, addr: Key, time: u64) {
+      self.0.call_contract(
+          owner,
+          "set_penalty_receiver",
+          runtime_args! {
+              "addr" => addr
+          },
+          time,
+      );
+  }
+
+  pub fn create_lock_position(&self, owner: AccountHash, value: U256, unlock_time: U256, time: u64) {
+      self.0.call_contract(
+          owner,
+          "create_lock_position",
+          runtime_args! {
+              "value" => value,
+              "unlock_time" => unlock_time
+          },
+          time,
+      );
+  }
+
+  pub fn withdraw_position(&self, owner: AccountHash, token_id: U256, time: u64) {
+      self.0.call_contract(
+          owner,
+          "withdraw_position",
+          runtime_args! {
+              "token_id" => token_id
+          },
+          time,
+      );
+  }
+
+  pub fn merge(&self, owner: AccountHash, from_id: U256, to_id: U256, time: u64) {
+      self.0.call_contract(
+          owner,
+          "merge",
+          runtime_args! {
+              "from_id" => from_id,
+              "to_id" => to_id
+          },
+          time,
+      );
+  }
+
+  pub fn split(&self, owner: AccountHash, token_id: U256, amounts: Vec<U256>, time: u64) {
+      self.0.call_contract(
+          owner,
+          "split",
+          runtime_args! {
+              "token_id" => token_id,
+              "amounts" => amounts
+          },
+          time,
+      );
+  }
+
+  pub fn transfer_position(&self, owner: AccountHash, recipient: Key, token_id: U256, time: u64) {
+      self.0.call_contract(
+          owner,
+          "transfer_position",
+          runtime_args! {
+              "recipient" => recipient,
+              "token_id" => token_id
+          },
+          time,
+      );
+  }
+
+  pub fn delegate(&self, owner: AccountHash, delegatee: Key, time: u64) {
+      self.0.call_contract(
+          owner,
+          "delegate",
+          runtime_args! {
+              "delegatee" => delegatee
+          },
+          time,
+      );
+  }
+
+  pub fn undelegate(&self, owner: AccountHash, time: u64) {
+      self.0
+          .call_contract(owner, "undelegate", runtime_args! {}, time);
+  }
+
   pub fn total_supply(&self, owner: AccountHash, t: Option<U256>, time: u64) {
       self.0.call_contract(
           owner,
@@ -156,6 +273,30 @@ impl VOTINGESCROWInstance {
       self.0.query_named_key(key)
   }
 
+  pub fn event(&self, index: u32) -> Bytes {
+      self.0
+          .query_dictionary(EVENTS_DICT, index.to_string())
+          .unwrap_or_default()
+  }
+
+  pub fn owner_of(&self, token_id: U256) -> Key {
+      self.0
+          .query_dictionary(POSITION_OWNERS_DICT, token_id.to_string())
+          .unwrap_or_else(|| Key::Hash([0u8; 32]))
+  }
+
+  pub fn delegates(&self, owner: &Key) -> Key {
+      self.0
+          .query_dictionary(DELEGATES_DICT, key_to_str(owner))
+          .unwrap_or(*owner)
+  }
+
+  pub fn positions_of(&self, owner: &Key) -> U256 {
+      self.0
+          .query_dictionary(POSITIONS_COUNT_DICT, key_to_str(owner))
+          .unwrap_or_default()
+  }
+
   pub fn query_locked(&self, owner: &Key) -> LockedBalance {
       LockedBalance {
           amount: self
@@ -1,9 +1,13 @@
 use crate::voting_escrow_instance::{now, VOTINGESCROWInstance, MILLI_SECONDS_IN_DAY};
-use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U128, U256};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    runtime_args, Key, RuntimeArgs, U128, U256,
+};
 use casperlabs_test_env::{TestContract, TestEnv};
-use common::keys::*;
+use common::{events::Schemas, int128::I128, keys::*};
 use crv20::Address;
-use voting_escrow_crate::data::{LockedBalance, Point, WEEK};
+use voting_escrow_crate::data::{position_key, LockedBalance, Point, MAXTIME, WEEK};
 pub const TEN_E_NINE: u128 = 1000000000;
 // CRV
 fn deploy_erc20_crv(env: &TestEnv, sender: AccountHash, time_now: u64) -> TestContract {
@@ -644,6 +648,187 @@ fn test_total_supply_at() {
     );
 }
 
+fn create_and_increase_lock_within_block(
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    erc20_crv: &TestContract,
+    env: &TestEnv,
+    time_now: u64,
+) -> (u64, u64, U256, U256) {
+    // Both actions land in the same estimated block of 45 seconds
+    let t0: u64 = time_now / 45000 * 45000;
+    let t1: u64 = t0 + 10000;
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(t0 + MILLI_SECONDS_IN_DAY * 365 * 4);
+    let spender: Address = Address::Contract(instance.package_hash().into());
+    erc20_crv.call_contract(
+        owner,
+        "increase_allowance",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => amount * 2
+        },
+        t0,
+    );
+    instance.create_lock(owner, amount, unlock_time, t0);
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(VE_BALANCE_OF),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner),
+            "t" => U256::from(t0)
+        },
+        t0,
+    );
+    let balance_at_t0: U256 = env.query_account_named_key(owner, &[BALANCE_OF.into()]);
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(VE_TOTAL_SUPPLY),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "t" => U256::from(t0),
+        },
+        t0,
+    );
+    let supply_at_t0: U256 = env.query_account_named_key(owner, &[TOTAL_SUPPLY.into()]);
+    instance.increase_amount(owner, amount, t1);
+    (t0, t1, balance_at_t0, supply_at_t0)
+}
+
+#[test]
+fn test_balance_of_at_time() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let (t0, _, balance_at_t0, _) =
+        create_and_increase_lock_within_block(owner, &instance, &erc20_crv, &env, time_now);
+    let query_time: u64 = t0 + MILLI_SECONDS_IN_DAY;
+    // The block estimate can't tell both checkpoints apart and reports the increased lock
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF_AT),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner),
+            "block" => U256::from(t0 / 45000)
+        },
+        query_time,
+    );
+    let approximated: U256 = env.query_account_named_key(owner, &[BALANCE_OF_AT.into()]);
+    assert_ne!(approximated, balance_at_t0, "Block estimate should diverge");
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF_AT_TIME),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner),
+            "time" => U256::from(t0)
+        },
+        query_time,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[BALANCE_OF_AT_TIME.into()]);
+    assert_eq!(ret, balance_at_t0, "Invalid balance of at time");
+    // Nothing was locked before the first checkpoint
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF_AT_TIME),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner),
+            "time" => U256::from(t0 - 1)
+        },
+        query_time,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[BALANCE_OF_AT_TIME.into()]);
+    assert_eq!(ret, 0.into(), "Balance before lock should be zero");
+}
+
+#[test]
+fn test_total_supply_at_time() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let (t0, t1, _, supply_at_t0) =
+        create_and_increase_lock_within_block(owner, &instance, &erc20_crv, &env, time_now);
+    let query_time: u64 = t0 + MILLI_SECONDS_IN_DAY;
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(TOTAL_SUPPLY_AT),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "block" => U256::from(t0 / 45000),
+        },
+        query_time,
+    );
+    let approximated: U256 = env.query_account_named_key(owner, &[TOTAL_SUPPLY_AT.into()]);
+    assert_ne!(approximated, supply_at_t0, "Block estimate should diverge");
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(TOTAL_SUPPLY_AT_TIME),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "time" => U256::from(t0),
+        },
+        query_time,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[TOTAL_SUPPLY_AT_TIME.into()]);
+    assert_eq!(ret, supply_at_t0, "Invalid total supply at time");
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(TOTAL_SUPPLY_AT_TIME),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "time" => U256::from(t1),
+        },
+        query_time,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[TOTAL_SUPPLY_AT_TIME.into()]);
+    assert!(
+        ret > supply_at_t0,
+        "Increase not reflected in total supply at time"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_balance_of_at_time_in_future() {
+    let (env, owner, instance, _, time_now) = deploy();
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF_AT_TIME),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner),
+            "time" => U256::from(time_now + 1)
+        },
+        time_now,
+    );
+}
+
 #[test]
 fn test_change_controller() {
     let (env, owner, instance, _, time_now) = deploy();
@@ -652,3 +837,1133 @@ fn test_change_controller() {
     let ret: Key = instance.key_value(CONTROLLER.to_string());
     assert_eq!(ret, new_controller, "Controller not changed");
 }
+
+fn deploy_smart_wallet_checker(env: &TestEnv, sender: AccountHash, time_now: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "smart-wallet-checker.wasm",
+        "smart-wallet-checker",
+        sender,
+        runtime_args! {
+            "admin" => Key::from(sender)
+        },
+        time_now,
+    )
+}
+
+#[test]
+fn test_commit_smart_wallet_checker() {
+    let (env, owner, instance, _, time_now) = deploy();
+    let checker = deploy_smart_wallet_checker(&env, owner, time_now);
+    instance.commit_smart_wallet_checker(owner, Key::Hash(checker.package_hash()), time_now);
+    let ret: Key = instance.key_value(FUTURE_SMART_WALLET_CHECKER.to_string());
+    assert_eq!(
+        ret,
+        Key::Hash(checker.package_hash()),
+        "Checker not committed"
+    );
+    let ret: Key = instance.key_value(SMART_WALLET_CHECKER.to_string());
+    assert_ne!(
+        ret,
+        Key::Hash(checker.package_hash()),
+        "Checker applied on commit"
+    );
+}
+
+#[test]
+fn test_apply_smart_wallet_checker() {
+    let (env, owner, instance, _, time_now) = deploy();
+    let checker = deploy_smart_wallet_checker(&env, owner, time_now);
+    instance.commit_smart_wallet_checker(owner, Key::Hash(checker.package_hash()), time_now);
+    instance.apply_smart_wallet_checker(owner, time_now);
+    let ret: Key = instance.key_value(SMART_WALLET_CHECKER.to_string());
+    assert_eq!(
+        ret,
+        Key::Hash(checker.package_hash()),
+        "Checker not applied"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_commit_smart_wallet_checker_by_non_admin() {
+    let (env, owner, instance, _, time_now) = deploy();
+    let checker = deploy_smart_wallet_checker(&env, owner, time_now);
+    let user = env.next_user();
+    instance.commit_smart_wallet_checker(user, Key::Hash(checker.package_hash()), time_now);
+}
+
+#[test]
+fn test_create_lock_with_smart_wallet_checker() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let checker = deploy_smart_wallet_checker(&env, owner, time_now);
+    instance.commit_smart_wallet_checker(owner, Key::Hash(checker.package_hash()), time_now);
+    instance.apply_smart_wallet_checker(owner, time_now);
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    let spender: Address = Address::Contract(instance.package_hash().into());
+    erc20_crv.call_contract(
+        owner,
+        "increase_allowance",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => amount
+        },
+        time_now,
+    );
+    // Account callers are never checked against the allowlist
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    let locked: LockedBalance = instance.query_locked(&Key::from(owner));
+    assert_eq!(
+        locked.amount().value(),
+        1000 * TEN_E_NINE as i128,
+        "Lock not created"
+    );
+}
+
+// The agent forwards arbitrary calls as its own package, so it acts as a contract wallet
+fn deploy_agent(env: &TestEnv, sender: AccountHash, time_now: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "agent.wasm",
+        "agent",
+        sender,
+        runtime_args! {
+            "ownership_agent" => Key::from(sender),
+            "parameter_agent" => Key::from(sender),
+            "emergency_agent" => Key::from(sender),
+        },
+        time_now,
+    )
+}
+
+fn agent_execute(
+    agent: &TestContract,
+    sender: AccountHash,
+    target: Key,
+    entry_point: &str,
+    args: RuntimeArgs,
+    time_now: u64,
+) {
+    agent.call_contract(
+        sender,
+        "execute",
+        runtime_args! {
+            "target" => target,
+            "entry_point" => entry_point.to_string(),
+            "args" => Bytes::from(args.to_bytes().unwrap())
+        },
+        time_now,
+    );
+}
+
+// Funds the agent with CRV and lets it approve the voting escrow
+fn deploy_contract_locker() -> (
+    TestEnv,
+    AccountHash,
+    VOTINGESCROWInstance,
+    TestContract,
+    TestContract,
+    u64,
+) {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let checker = deploy_smart_wallet_checker(&env, owner, time_now);
+    instance.commit_smart_wallet_checker(owner, Key::Hash(checker.package_hash()), time_now);
+    instance.apply_smart_wallet_checker(owner, time_now);
+    let agent = deploy_agent(&env, owner, time_now);
+    let amount: U256 = U256::from(2000 * TEN_E_NINE);
+    erc20_crv.call_contract(
+        owner,
+        "transfer",
+        runtime_args! {
+            "recipient" => Address::Contract(agent.package_hash().into()),
+            "amount" => amount
+        },
+        time_now,
+    );
+    agent_execute(
+        &agent,
+        owner,
+        Key::Hash(erc20_crv.package_hash()),
+        "approve",
+        runtime_args! {
+            "spender" => Address::Contract(instance.package_hash().into()),
+            "amount" => amount
+        },
+        time_now,
+    );
+    (env, owner, instance, checker, agent, time_now)
+}
+
+#[test]
+#[should_panic]
+fn test_create_lock_by_unapproved_contract() {
+    let (_, owner, instance, _, agent, time_now) = deploy_contract_locker();
+    agent_execute(
+        &agent,
+        owner,
+        Key::Hash(instance.package_hash()),
+        "create_lock",
+        runtime_args! {
+            "value" => U256::from(1000 * TEN_E_NINE),
+            "unlock_time" => U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4)
+        },
+        time_now,
+    );
+}
+
+#[test]
+fn test_create_lock_and_increase_amount_by_approved_contract() {
+    let (_, owner, instance, checker, agent, time_now) = deploy_contract_locker();
+    checker.call_contract(
+        owner,
+        "approve_wallet",
+        runtime_args! {
+            "wallet" => Key::Hash(agent.package_hash())
+        },
+        time_now,
+    );
+    agent_execute(
+        &agent,
+        owner,
+        Key::Hash(instance.package_hash()),
+        "create_lock",
+        runtime_args! {
+            "value" => U256::from(1000 * TEN_E_NINE),
+            "unlock_time" => U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4)
+        },
+        time_now,
+    );
+    agent_execute(
+        &agent,
+        owner,
+        Key::Hash(instance.package_hash()),
+        "increase_amount",
+        runtime_args! {
+            "value" => U256::from(1000 * TEN_E_NINE)
+        },
+        time_now,
+    );
+    let locked: LockedBalance = instance.query_locked(&Key::Hash(agent.package_hash()));
+    assert_eq!(
+        locked.amount().value(),
+        2000 * TEN_E_NINE as i128,
+        "Contract lock not created"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_increase_amount_by_revoked_contract() {
+    let (_, owner, instance, checker, agent, time_now) = deploy_contract_locker();
+    let wallet = runtime_args! {
+        "wallet" => Key::Hash(agent.package_hash())
+    };
+    checker.call_contract(owner, "approve_wallet", wallet.clone(), time_now);
+    agent_execute(
+        &agent,
+        owner,
+        Key::Hash(instance.package_hash()),
+        "create_lock",
+        runtime_args! {
+            "value" => U256::from(1000 * TEN_E_NINE),
+            "unlock_time" => U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4)
+        },
+        time_now,
+    );
+    checker.call_contract(owner, "revoke_wallet", wallet, time_now);
+    agent_execute(
+        &agent,
+        owner,
+        Key::Hash(instance.package_hash()),
+        "increase_amount",
+        runtime_args! {
+            "value" => U256::from(1000 * TEN_E_NINE)
+        },
+        time_now,
+    );
+}
+
+fn crv_balance_of(env: &TestEnv, erc20_crv: &TestContract, owner: AccountHash, addr: Key) -> U256 {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF),
+            "package_hash" => Key::Hash(erc20_crv.package_hash()),
+            "address" => addr
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[BALANCE_OF.into()])
+}
+
+fn create_lock_for_early_withdrawal(
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    erc20_crv: &TestContract,
+    time_now: u64,
+) -> (U256, U256) {
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 2);
+    let spender: Address = Address::Contract(instance.package_hash().into());
+    erc20_crv.call_contract(
+        owner,
+        "increase_allowance",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => amount
+        },
+        time_now,
+    );
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    (amount, unlock_time / WEEK * WEEK)
+}
+
+#[test]
+fn test_set_penalty_receiver() {
+    let (env, owner, instance, _, time_now) = deploy();
+    let receiver: Key = Key::Account(env.next_user());
+    instance.set_penalty_receiver(owner, receiver, time_now);
+    let ret: Key = instance.key_value(PENALTY_RECEIVER.to_string());
+    assert_eq!(ret, receiver, "Penalty receiver not set");
+}
+
+#[test]
+#[should_panic]
+fn test_set_penalty_receiver_by_non_admin() {
+    let (env, _, instance, _, time_now) = deploy();
+    let user = env.next_user();
+    instance.set_penalty_receiver(user, Key::Account(user), time_now);
+}
+
+#[test]
+fn test_withdraw_early() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let receiver: Key = Key::Account(env.next_user());
+    instance.set_penalty_receiver(owner, receiver, time_now);
+    let (amount, end) = create_lock_for_early_withdrawal(owner, &instance, &erc20_crv, time_now);
+    let balance_before: U256 = crv_balance_of(&env, &erc20_crv, owner, Key::from(owner));
+    let withdraw_time = time_now + MILLI_SECONDS_IN_DAY * 30;
+    instance.withdraw_early(owner, withdraw_time);
+    let penalty: U256 = amount * (end - U256::from(withdraw_time)) / MAXTIME;
+    assert!(penalty > 0.into(), "No penalty charged");
+    let balance_after: U256 = crv_balance_of(&env, &erc20_crv, owner, Key::from(owner));
+    assert_eq!(
+        balance_after - balance_before,
+        amount - penalty,
+        "Wrong amount returned"
+    );
+    assert_eq!(
+        crv_balance_of(&env, &erc20_crv, owner, receiver),
+        penalty,
+        "Penalty not sent to receiver"
+    );
+    let locked: LockedBalance = instance.query_locked(&Key::from(owner));
+    assert_eq!(locked.amount().value(), 0, "Lock not cleared");
+    assert_eq!(locked.end, 0.into(), "Lock end not cleared");
+    let supply: U256 = instance.key_value(SUPPLY.to_string());
+    assert_eq!(supply, 0.into(), "Supply not reduced");
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(VE_BALANCE_OF),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner),
+            "t" => U256::from(withdraw_time)
+        },
+        withdraw_time,
+    );
+    let ve_balance: U256 = env.query_account_named_key(owner, &[BALANCE_OF.into()]);
+    assert_eq!(ve_balance, 0.into(), "Voting power left after withdrawal");
+    instance.total_supply(owner, None, withdraw_time);
+    // The removed slope must not be applied again when the lock would have expired
+    instance.checkpoint(owner, end.as_u64() + WEEK.as_u64());
+}
+
+#[test]
+#[should_panic]
+fn test_withdraw_early_without_penalty_receiver() {
+    let (_, owner, instance, erc20_crv, time_now) = deploy();
+    create_lock_for_early_withdrawal(owner, &instance, &erc20_crv, time_now);
+    instance.withdraw_early(owner, time_now + MILLI_SECONDS_IN_DAY);
+}
+
+#[test]
+#[should_panic]
+fn test_withdraw_early_after_lock_expired() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    instance.set_penalty_receiver(owner, Key::Account(env.next_user()), time_now);
+    let (_, end) = create_lock_for_early_withdrawal(owner, &instance, &erc20_crv, time_now);
+    instance.withdraw_early(owner, end.as_u64());
+}
+
+fn ve_balance_of(
+    env: &TestEnv,
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    addr: Key,
+    time: u64,
+) -> U256 {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(VE_BALANCE_OF),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => addr,
+            "t" => U256::from(time)
+        },
+        time,
+    );
+    env.query_account_named_key(owner, &[BALANCE_OF.into()])
+}
+
+fn position_balance_of(
+    env: &TestEnv,
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    token_id: U256,
+    time: u64,
+) -> U256 {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(POSITION_BALANCE_OF),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "token_id" => token_id,
+            "t" => U256::from(time)
+        },
+        time,
+    );
+    env.query_account_named_key(owner, &[POSITION_BALANCE_OF.into()])
+}
+
+fn approve_voting_escrow(
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    erc20_crv: &TestContract,
+    amount: U256,
+    time_now: u64,
+) {
+    let spender: Address = Address::Contract(instance.package_hash().into());
+    erc20_crv.call_contract(
+        owner,
+        "increase_allowance",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => amount
+        },
+        time_now,
+    );
+}
+
+#[test]
+fn test_create_lock_position() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    assert_eq!(
+        instance.owner_of(1.into()),
+        Key::from(owner),
+        "Position not minted"
+    );
+    assert_eq!(instance.positions_of(&Key::from(owner)), 1.into());
+    let locked: LockedBalance = instance.query_locked(&position_key(1.into()));
+    assert_eq!(
+        locked.amount().value(),
+        1000 * TEN_E_NINE as i128,
+        "Position not locked"
+    );
+    assert_eq!(locked.end, unlock_time / WEEK * WEEK);
+    // The position is not the owner's own lock, but counts towards its voting power
+    let own_locked: LockedBalance = instance.query_locked(&Key::from(owner));
+    assert_eq!(own_locked.amount().value(), 0);
+    let position_balance = position_balance_of(&env, owner, &instance, 1.into(), time_now);
+    assert!(position_balance > 0.into());
+    assert_eq!(
+        ve_balance_of(&env, owner, &instance, Key::from(owner), time_now),
+        position_balance,
+        "Position not included in balance of owner"
+    );
+}
+
+#[test]
+fn test_merge() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let short_unlock = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 2);
+    let long_unlock = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    approve_voting_escrow(
+        owner,
+        &instance,
+        &erc20_crv,
+        U256::from(1000 * TEN_E_NINE),
+        time_now,
+    );
+    instance.create_lock_position(owner, U256::from(400 * TEN_E_NINE), short_unlock, time_now);
+    instance.create_lock_position(owner, U256::from(600 * TEN_E_NINE), long_unlock, time_now);
+    let supply_before: U256 = instance.key_value(SUPPLY.to_string());
+    instance.merge(owner, 1.into(), 2.into(), time_now);
+    let locked: LockedBalance = instance.query_locked(&position_key(2.into()));
+    assert_eq!(
+        locked.amount().value(),
+        1000 * TEN_E_NINE as i128,
+        "Amounts not merged"
+    );
+    assert_eq!(
+        locked.end,
+        long_unlock / WEEK * WEEK,
+        "Later lock end not kept"
+    );
+    let burnt: LockedBalance = instance.query_locked(&position_key(1.into()));
+    assert_eq!(burnt.amount().value(), 0, "Merged position not cleared");
+    assert_eq!(instance.owner_of(1.into()), Key::Hash([0u8; 32]));
+    assert_eq!(instance.positions_of(&Key::from(owner)), 1.into());
+    let supply_after: U256 = instance.key_value(SUPPLY.to_string());
+    assert_eq!(supply_before, supply_after, "Supply changed by merge");
+    assert_eq!(
+        position_balance_of(&env, owner, &instance, 1.into(), time_now),
+        0.into()
+    );
+    assert_eq!(
+        ve_balance_of(&env, owner, &instance, Key::from(owner), time_now),
+        position_balance_of(&env, owner, &instance, 2.into(), time_now)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_merge_position_of_other_owner() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let user = env.next_user();
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(
+        owner,
+        &instance,
+        &erc20_crv,
+        U256::from(1000 * TEN_E_NINE),
+        time_now,
+    );
+    instance.create_lock_position(owner, U256::from(500 * TEN_E_NINE), unlock_time, time_now);
+    instance.create_lock_position(owner, U256::from(500 * TEN_E_NINE), unlock_time, time_now);
+    instance.transfer_position(owner, Key::from(user), 2.into(), time_now);
+    instance.merge(owner, 1.into(), 2.into(), time_now);
+}
+
+#[test]
+fn test_split() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    instance.split(
+        owner,
+        1.into(),
+        vec![U256::from(300 * TEN_E_NINE), U256::from(700 * TEN_E_NINE)],
+        time_now,
+    );
+    let first: LockedBalance = instance.query_locked(&position_key(1.into()));
+    let second: LockedBalance = instance.query_locked(&position_key(2.into()));
+    assert_eq!(
+        first.amount().value(),
+        300 * TEN_E_NINE as i128,
+        "Invalid first split"
+    );
+    assert_eq!(
+        second.amount().value(),
+        700 * TEN_E_NINE as i128,
+        "Invalid second split"
+    );
+    assert_eq!(first.end, second.end, "Lock ends differ after split");
+    assert_eq!(instance.owner_of(2.into()), Key::from(owner));
+    assert_eq!(instance.positions_of(&Key::from(owner)), 2.into());
+    let supply: U256 = instance.key_value(SUPPLY.to_string());
+    assert_eq!(supply, amount, "Supply changed by split");
+}
+
+#[test]
+#[should_panic]
+fn test_split_invalid_amounts() {
+    let (_, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    instance.split(
+        owner,
+        1.into(),
+        vec![U256::from(300 * TEN_E_NINE), U256::from(600 * TEN_E_NINE)],
+        time_now,
+    );
+}
+
+#[test]
+fn test_transfer_position() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let user = env.next_user();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    let balance: U256 = ve_balance_of(&env, owner, &instance, Key::from(owner), time_now);
+    instance.transfer_position(owner, Key::from(user), 1.into(), time_now);
+    assert_eq!(
+        instance.owner_of(1.into()),
+        Key::from(user),
+        "Position not transferred"
+    );
+    assert_eq!(instance.positions_of(&Key::from(owner)), 0.into());
+    assert_eq!(instance.positions_of(&Key::from(user)), 1.into());
+    assert_eq!(
+        ve_balance_of(&env, owner, &instance, Key::from(owner), time_now),
+        0.into()
+    );
+    assert_eq!(
+        ve_balance_of(&env, owner, &instance, Key::from(user), time_now),
+        balance,
+        "Voting power did not move with the position"
+    );
+}
+
+fn ve_balance_of_at_time(
+    env: &TestEnv,
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    addr: Key,
+    time: u64,
+    now: u64,
+) -> U256 {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF_AT_TIME),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => addr,
+            "time" => U256::from(time)
+        },
+        now,
+    );
+    env.query_account_named_key(owner, &[BALANCE_OF_AT_TIME.into()])
+}
+
+#[test]
+fn test_transfer_position_keeps_past_balance() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let user = env.next_user();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    let balance: U256 = position_balance_of(&env, owner, &instance, 1.into(), time_now);
+    let transfer_time: u64 = time_now + MILLI_SECONDS_IN_DAY;
+    instance.transfer_position(owner, Key::from(user), 1.into(), transfer_time);
+    let query_time: u64 = transfer_time + MILLI_SECONDS_IN_DAY;
+    assert_eq!(
+        ve_balance_of_at_time(
+            &env,
+            owner,
+            &instance,
+            Key::from(owner),
+            time_now,
+            query_time
+        ),
+        balance,
+        "Past voting power moved with the position"
+    );
+    assert_eq!(
+        ve_balance_of_at_time(
+            &env,
+            owner,
+            &instance,
+            Key::from(user),
+            time_now,
+            query_time
+        ),
+        0.into(),
+        "Recipient got voting power before the transfer"
+    );
+    assert_eq!(
+        ve_balance_of_at_time(
+            &env,
+            owner,
+            &instance,
+            Key::from(owner),
+            transfer_time,
+            query_time
+        ),
+        0.into()
+    );
+    assert_eq!(
+        ve_balance_of_at_time(
+            &env,
+            owner,
+            &instance,
+            Key::from(user),
+            transfer_time,
+            query_time
+        ),
+        position_balance_of(&env, owner, &instance, 1.into(), transfer_time),
+        "Recipient did not get the voting power from the transfer on"
+    );
+}
+
+#[test]
+fn test_get_last_user_slope_and_locked_end_ignore_positions() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(2500 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(GET_LAST_USER_SLOPE),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner)
+        },
+        time_now,
+    );
+    let ret: (bool, U128) = env.query_account_named_key(owner, &[GET_LAST_USER_SLOPE.into()]);
+    assert_eq!(ret, (false, 0.into()), "Position included in own slope");
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(LOCKED_END),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner)
+        },
+        time_now,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[LOCKED_END.into()]);
+    assert_eq!(ret, 0.into(), "Position included in own lock end");
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(USER_POINT_HISTORY_TS),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner),
+            "idx" => U256::from(1)
+        },
+        time_now,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[USER_POINT_HISTORY_TS.into()]);
+    assert_eq!(
+        ret,
+        U256::from(time_now),
+        "Position not checkpointed for owner"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_too_many_positions() {
+    let (_, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount * 51, time_now);
+    for _ in 0..51 {
+        instance.create_lock_position(owner, amount, unlock_time, time_now);
+    }
+}
+
+#[test]
+fn test_withdraw_position() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    let balance_before: U256 = crv_balance_of(&env, &erc20_crv, owner, Key::from(owner));
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    let after_unlock_time = time_now + MILLI_SECONDS_IN_DAY * 365;
+    instance.withdraw_position(owner, 1.into(), after_unlock_time);
+    assert_eq!(
+        crv_balance_of(&env, &erc20_crv, owner, Key::from(owner)),
+        balance_before,
+        "Tokens not returned"
+    );
+    assert_eq!(instance.positions_of(&Key::from(owner)), 0.into());
+    let supply: U256 = instance.key_value(SUPPLY.to_string());
+    assert_eq!(supply, 0.into());
+}
+
+fn get_votes(
+    env: &TestEnv,
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    delegate: Key,
+    time: u64,
+) -> U256 {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(GET_VOTES),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "delegate" => delegate,
+            "time" => U256::from(time)
+        },
+        time,
+    );
+    env.query_account_named_key(owner, &[GET_VOTES.into()])
+}
+
+#[test]
+fn test_delegate() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let delegate: Key = Key::Account(env.next_user());
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 2);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    let balance: U256 = ve_balance_of(&env, owner, &instance, Key::from(owner), time_now);
+    assert_eq!(
+        get_votes(&env, owner, &instance, Key::from(owner), time_now),
+        balance,
+        "Own voting power not counted without delegation"
+    );
+    instance.delegate(owner, delegate, time_now);
+    assert_eq!(instance.delegates(&Key::from(owner)), delegate);
+    assert_eq!(
+        get_votes(&env, owner, &instance, delegate, time_now),
+        balance
+    );
+    assert_eq!(
+        get_votes(&env, owner, &instance, Key::from(owner), time_now),
+        0.into()
+    );
+    // Delegated power decays exactly like the balance, across weeks and until the lock expires
+    for days in [30, 365, 365 * 2].iter() {
+        let time: u64 = time_now + MILLI_SECONDS_IN_DAY * days;
+        assert_eq!(
+            get_votes(&env, owner, &instance, delegate, time),
+            ve_balance_of(&env, owner, &instance, Key::from(owner), time),
+            "Delegated votes don't decay like the balance"
+        );
+    }
+}
+
+#[test]
+fn test_delegate_covers_later_locks() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let delegate: Key = Key::Account(env.next_user());
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(
+        owner,
+        &instance,
+        &erc20_crv,
+        U256::from(1000 * TEN_E_NINE),
+        time_now,
+    );
+    instance.delegate(owner, delegate, time_now);
+    instance.create_lock(owner, U256::from(400 * TEN_E_NINE), unlock_time, time_now);
+    instance.create_lock_position(owner, U256::from(600 * TEN_E_NINE), unlock_time, time_now);
+    instance.increase_amount(owner, U256::from(1), time_now);
+    let time: u64 = time_now + MILLI_SECONDS_IN_DAY * 100;
+    let balance: U256 = ve_balance_of(&env, owner, &instance, Key::from(owner), time);
+    assert!(balance > 0.into());
+    assert_eq!(
+        get_votes(&env, owner, &instance, delegate, time),
+        balance,
+        "Locks created after delegating not delegated"
+    );
+}
+
+#[test]
+fn test_undelegate() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let delegate: Key = Key::Account(env.next_user());
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    instance.delegate(owner, delegate, time_now);
+    let time: u64 = time_now + MILLI_SECONDS_IN_DAY * 10;
+    instance.undelegate(owner, time);
+    assert_eq!(instance.delegates(&Key::from(owner)), Key::from(owner));
+    assert_eq!(get_votes(&env, owner, &instance, delegate, time), 0.into());
+    assert_eq!(
+        get_votes(&env, owner, &instance, Key::from(owner), time),
+        ve_balance_of(&env, owner, &instance, Key::from(owner), time)
+    );
+    // The delegate keeps its votes in the past
+    assert!(get_votes(&env, owner, &instance, delegate, time_now) > 0.into());
+}
+
+#[test]
+fn test_transfer_position_moves_votes() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let delegate: Key = Key::Account(env.next_user());
+    let user = env.next_user();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock_position(owner, amount, unlock_time, time_now);
+    instance.delegate(owner, delegate, time_now);
+    let balance: U256 = ve_balance_of(&env, owner, &instance, Key::from(owner), time_now);
+    instance.transfer_position(owner, Key::from(user), 1.into(), time_now);
+    assert_eq!(
+        get_votes(&env, owner, &instance, delegate, time_now),
+        0.into()
+    );
+    assert_eq!(
+        get_votes(&env, owner, &instance, Key::from(user), time_now),
+        balance,
+        "Votes did not move to the recipient"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_get_votes_in_future() {
+    let (env, owner, instance, _, time_now) = deploy();
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(GET_VOTES),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "delegate" => Key::from(owner),
+            "time" => U256::from(time_now + 1)
+        },
+        time_now,
+    );
+}
+
+fn ve_total_supply(
+    env: &TestEnv,
+    owner: AccountHash,
+    instance: &VOTINGESCROWInstance,
+    time: u64,
+) -> U256 {
+    TestContract::new(
+        env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(VE_TOTAL_SUPPLY),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "t" => U256::from(time),
+        },
+        time,
+    );
+    env.query_account_named_key(owner, &[TOTAL_SUPPLY.into()])
+}
+
+#[test]
+fn test_set_auto_max_lock() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    instance.set_auto_max_lock(owner, true, time_now);
+    let locked: LockedBalance = instance.query_locked(&Key::from(owner));
+    assert_eq!(locked.end, U256::MAX, "Auto max lock not set");
+    // Views report the end of a lock extended to `MAXTIME` now, not the stored marker
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(LOCKED_END),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addr" => Key::from(owner)
+        },
+        time_now,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[LOCKED_END.into()]);
+    assert_eq!(ret, (U256::from(time_now) + MAXTIME) / WEEK * WEEK);
+    let full_weight: U256 = amount / MAXTIME * MAXTIME;
+    // The weight doesn't decay, not even past the original lock end
+    for days in [0, 100, 365 * 2].iter() {
+        let time: u64 = time_now + MILLI_SECONDS_IN_DAY * days;
+        assert_eq!(
+            ve_balance_of(&env, owner, &instance, Key::from(owner), time),
+            full_weight,
+            "Auto max lock decayed"
+        );
+        assert_eq!(ve_total_supply(&env, owner, &instance, time), full_weight);
+    }
+}
+
+#[test]
+fn test_unset_auto_max_lock() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    instance.set_auto_max_lock(owner, true, time_now);
+    let time_off: u64 = time_now + MILLI_SECONDS_IN_DAY * 100;
+    instance.set_auto_max_lock(owner, false, time_off);
+    let locked: LockedBalance = instance.query_locked(&Key::from(owner));
+    assert_eq!(
+        locked.end,
+        (U256::from(time_off) + MAXTIME) / WEEK * WEEK,
+        "Normal 4 year lock not started"
+    );
+    // Balance and supply decay together again and reach zero at the new lock end
+    let later: u64 = time_off + MILLI_SECONDS_IN_DAY * 365;
+    let balance: U256 = ve_balance_of(&env, owner, &instance, Key::from(owner), later);
+    assert!(balance < ve_balance_of(&env, owner, &instance, Key::from(owner), time_off));
+    assert_eq!(ve_total_supply(&env, owner, &instance, later), balance);
+    assert_eq!(
+        ve_total_supply(&env, owner, &instance, locked.end.as_u64()),
+        0.into(),
+        "Lock not expiring at the new lock end"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_withdraw_auto_max_lock() {
+    let (_, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    instance.set_auto_max_lock(owner, true, time_now);
+    instance.withdraw(owner, time_now + MILLI_SECONDS_IN_DAY * 365);
+}
+
+#[test]
+fn test_balances_of_at() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    let without_lock: Key = Key::Account(env.next_user());
+    let time: u64 = time_now + MILLI_SECONDS_IN_DAY * 10;
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCES_OF_AT),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "addrs" => vec![Key::from(owner), without_lock],
+            "t" => U256::from(time)
+        },
+        time + MILLI_SECONDS_IN_DAY * 20,
+    );
+    let ret: Vec<U256> = env.query_account_named_key(owner, &[BALANCES_OF_AT.into()]);
+    assert_eq!(
+        ret,
+        vec![
+            ve_balance_of(&env, owner, &instance, Key::from(owner), time),
+            0.into()
+        ],
+        "Invalid balances of at"
+    );
+}
+
+#[test]
+fn test_total_supply_series() {
+    let (env, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    let step: u64 = MILLI_SECONDS_IN_DAY * 7;
+    // `to` isn't reached exactly, the series stops at the last step before it
+    let to: u64 = time_now + step * 3 + MILLI_SECONDS_IN_DAY;
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(TOTAL_SUPPLY_SERIES),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "from" => U256::from(time_now),
+            "to" => U256::from(to),
+            "step" => U256::from(step)
+        },
+        to,
+    );
+    let ret: Vec<U256> = env.query_account_named_key(owner, &[TOTAL_SUPPLY_SERIES.into()]);
+    let expected: Vec<U256> = (0..4)
+        .map(|i| ve_total_supply(&env, owner, &instance, time_now + step * i))
+        .collect();
+    assert_eq!(ret, expected, "Invalid total supply series");
+}
+
+#[test]
+#[should_panic]
+fn test_total_supply_series_zero_step() {
+    let (env, owner, instance, _, time_now) = deploy();
+    TestContract::new(
+        &env,
+        TEST_SESSION_CODE_WASM,
+        TEST_SESSION_CODE_NAME,
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(TOTAL_SUPPLY_SERIES),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "from" => U256::from(time_now),
+            "to" => U256::from(time_now),
+            "step" => U256::zero()
+        },
+        time_now,
+    );
+}
+
+#[test]
+fn test_create_lock_events() {
+    let (_, owner, instance, erc20_crv, time_now) = deploy();
+    let amount: U256 = U256::from(1000 * TEN_E_NINE);
+    let unlock_time = U256::from(time_now + MILLI_SECONDS_IN_DAY * 365);
+    approve_voting_escrow(owner, &instance, &erc20_crv, amount, time_now);
+    instance.create_lock(owner, amount, unlock_time, time_now);
+    let length: u32 = instance.key_value(EVENTS_LENGTH.to_string());
+    assert_eq!(length, 2, "Deposit and supply events not emitted");
+    let schemas: Schemas = instance.key_value(EVENTS_SCHEMA.to_string());
+    assert_eq!(schemas.get("deposit").unwrap().elems().len(), 5);
+    let event: Bytes = instance.event(0);
+    let (name, fields) = String::from_bytes(&event).unwrap();
+    assert_eq!(name, "event_deposit");
+    let (provider, fields) = Key::from_bytes(fields).unwrap();
+    let (value, fields) = U256::from_bytes(fields).unwrap();
+    let (locktime, fields) = U256::from_bytes(fields).unwrap();
+    let (_type, fields) = I128::from_bytes(fields).unwrap();
+    let (ts, fields) = U256::from_bytes(fields).unwrap();
+    assert_eq!(provider, Key::from(owner));
+    assert_eq!(value, amount);
+    assert_eq!(locktime, unlock_time / WEEK * WEEK);
+    assert_eq!(_type, I128::new(1));
+    assert_eq!(ts, U256::from(time_now));
+    assert!(fields.is_empty());
+}
@@ -1,7 +1,7 @@
 #![no_main]
 #![no_std]
 extern crate alloc;
-use alloc::{boxed::Box, collections::BTreeSet, format, string::String, vec};
+use alloc::{boxed::Box, collections::BTreeSet, format, string::String, vec, vec::Vec};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
@@ -11,7 +11,7 @@ use casper_types::{
     EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
 };
 use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
-use voting_escrow_crate::{self, data, utils::*, VOTINGESCROW};
+use voting_escrow_crate::{self, data, int128::I128, VOTINGESCROW};
 
 #[derive(Default)]
 struct VotingEscrow(OnChainContractStorage);
@@ -81,14 +81,36 @@ fn apply_transfer_ownership() {
     VotingEscrow::default().apply_transfer_ownership();
 }
 
+/// @notice Set an external contract to check for approved smart contract wallets
+/// @param addr Address of Smart contract checker
+#[no_mangle]
+fn commit_smart_wallet_checker() {
+    let addr: Key = runtime::get_named_arg("addr");
+    VotingEscrow::default().commit_smart_wallet_checker(addr);
+}
+
+/// @notice Apply setting external contract to check approved smart contract wallets
+#[no_mangle]
+fn apply_smart_wallet_checker() {
+    VotingEscrow::default().apply_smart_wallet_checker();
+}
+
+/// @notice Set the address receiving penalties of early withdrawals
+/// @param addr Address of the penalty receiver
+#[no_mangle]
+fn set_penalty_receiver() {
+    let addr: Key = runtime::get_named_arg("addr");
+    VotingEscrow::default().set_penalty_receiver(addr);
+}
+
 /// @notice Get the most recently recorded rate of voting power decrease for `addr`
 /// @param addr Address of the user wallet
 /// @return Value of the slope
 #[no_mangle]
 fn get_last_user_slope() {
     let addr: Key = runtime::get_named_arg("addr");
-    let ret: i128 = VotingEscrow::default().get_last_user_slope(addr);
-    runtime::ret(CLValue::from_t(i128_to_tuple(ret)).unwrap_or_revert());
+    let ret: I128 = VotingEscrow::default().get_last_user_slope(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 /// @notice Get the timestamp for checkpoint `_idx` for `_addr`
@@ -163,6 +185,126 @@ fn withdraw() {
     VotingEscrow::default().withdraw();
 }
 
+/// @notice Withdraw all tokens for `self.get_caller()` before the lock expires
+/// @dev A penalty proportional to the remaining lock time goes to the penalty receiver
+#[no_mangle]
+fn withdraw_early() {
+    VotingEscrow::default().withdraw_early();
+}
+
+/// @notice Turn the auto max lock of `self.get_caller()` on or off
+/// @dev While it is on, the lock keeps the full weight of a 4 year lock without decaying
+/// @param enabled Whether the lock should be an auto max lock
+#[no_mangle]
+fn set_auto_max_lock() {
+    let enabled: bool = runtime::get_named_arg("enabled");
+    VotingEscrow::default().set_auto_max_lock(enabled);
+}
+
+/// @notice Check whether the lock of `addr` is an auto max lock
+#[no_mangle]
+fn auto_max_lock() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let ret: bool = VotingEscrow::default().auto_max_lock(addr);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Deposit `value` tokens for `self.get_caller()` into a new transferable lock position
+/// @param value Amount to deposit
+/// @param unlock_time Epoch time when tokens unlock, rounded down to whole weeks
+/// @return Token id of the new position
+#[no_mangle]
+fn create_lock_position() {
+    let value: U256 = runtime::get_named_arg("value");
+    let unlock_time: U256 = runtime::get_named_arg("unlock_time");
+    let ret: U256 = VotingEscrow::default().create_lock_position(value, unlock_time);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Withdraw all tokens of the expired lock position `token_id`
+/// @param token_id Position to withdraw
+#[no_mangle]
+fn withdraw_position() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    VotingEscrow::default().withdraw_position(token_id);
+}
+
+/// @notice Merge lock position `from_id` into `to_id`
+/// @param from_id Position to merge, burnt afterwards
+/// @param to_id Position receiving the locked tokens
+#[no_mangle]
+fn merge() {
+    let from_id: U256 = runtime::get_named_arg("from_id");
+    let to_id: U256 = runtime::get_named_arg("to_id");
+    VotingEscrow::default().merge(from_id, to_id);
+}
+
+/// @notice Split lock position `token_id` into positions of `amounts`
+/// @param token_id Position to split
+/// @param amounts Amounts of the resulting positions
+/// @return Token ids of the resulting positions
+#[no_mangle]
+fn split() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let amounts: Vec<U256> = runtime::get_named_arg("amounts");
+    let ret: Vec<U256> = VotingEscrow::default().split(token_id, amounts);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Transfer lock position `token_id` to `recipient`
+/// @param recipient New owner of the position
+/// @param token_id Position to transfer
+#[no_mangle]
+fn transfer_position() {
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    VotingEscrow::default().transfer_position(recipient, token_id);
+}
+
+/// @notice Get the owner of lock position `token_id`
+#[no_mangle]
+fn owner_of() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let ret: Key = VotingEscrow::default().owner_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Get the number of lock positions owned by `owner`
+#[no_mangle]
+fn positions_of() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let ret: U256 = VotingEscrow::default().positions_of(owner);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Get the token id of the `index`th lock position owned by `owner`
+#[no_mangle]
+fn position_of_owner_by_index() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let index: U256 = runtime::get_named_arg("index");
+    let ret: U256 = VotingEscrow::default().position_of_owner_by_index(owner, index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Get timestamp when the lock position `token_id` expires
+#[no_mangle]
+fn position_locked_end() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let ret: U256 = VotingEscrow::default().position_locked_end(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Get the voting power of lock position `token_id`
+/// @param token_id Position to query
+/// @param t Epoch time to return voting power at
+#[no_mangle]
+fn position_balance_of() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let t: Option<U256> = runtime::get_named_arg("t");
+    let ret: U256 = VotingEscrow::default().position_balance_of(token_id, t);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 /// @notice Get the current voting power for `self.get_caller()`
 /// @dev Adheres to the ERC20 `balanceOf` interface for Aragon compatibility
 /// @param addr User wallet address
@@ -178,6 +320,7 @@ fn balance_of() {
 
 /// @notice Measure voting power of `addr` at block height `_block`
 /// @dev Adheres to MiniMe `balanceOfAt` interface: https://github.com/Giveth/minime
+/// Block heights are estimated from the blocktime, use `balance_of_at_time` for exact snapshots
 /// @param addr User's wallet address
 /// @param _block Block to calculate the voting power at
 /// @return Voting power
@@ -200,6 +343,7 @@ fn total_supply() {
 }
 
 /// @notice Calculate total voting power at some point in the past
+/// @dev Block heights are estimated from the blocktime, use `total_supply_at_time` for exact snapshots
 /// @param _block Block to calculate the total voting power at
 /// @return Total voting power at `_block`
 #[no_mangle]
@@ -209,6 +353,87 @@ fn total_supply_at() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+/// @notice Measure voting power of `addr` at timestamp `time`
+/// @dev Exact replacement for `balance_of_at`, whose block heights are only estimated from the blocktime
+/// @param addr User's wallet address
+/// @param time Timestamp to calculate the voting power at
+/// @return Voting power
+#[no_mangle]
+fn balance_of_at_time() {
+    let addr: Key = runtime::get_named_arg("addr");
+    let time: U256 = runtime::get_named_arg("time");
+    let ret: U256 = VotingEscrow::default().balance_of_at_time(addr, time);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Calculate total voting power at timestamp `time`
+/// @dev Exact replacement for `total_supply_at`, whose block heights are only estimated from the blocktime
+/// @param time Timestamp to calculate the total voting power at
+/// @return Total voting power at `time`
+#[no_mangle]
+fn total_supply_at_time() {
+    let time: U256 = runtime::get_named_arg("time");
+    let ret: U256 = VotingEscrow::default().total_supply_at_time(time);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Measure voting power of each of `addrs` at timestamp `t`
+/// @param addrs Users' wallet addresses
+/// @param t Timestamp to calculate the voting power at
+/// @return Voting power of each address
+#[no_mangle]
+fn balances_of_at() {
+    let addrs: Vec<Key> = runtime::get_named_arg("addrs");
+    let t: U256 = runtime::get_named_arg("t");
+    let ret: Vec<U256> = VotingEscrow::default().balances_of_at(addrs, t);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Calculate total voting power at every `step` from `from` up to `to`
+/// @return Total voting power at `from`, `from + step`, ... until `to`
+#[no_mangle]
+fn total_supply_series() {
+    let from: U256 = runtime::get_named_arg("from");
+    let to: U256 = runtime::get_named_arg("to");
+    let step: U256 = runtime::get_named_arg("step");
+    let ret: Vec<U256> = VotingEscrow::default().total_supply_series(from, to, step);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Delegate the voting power of the caller's lock and lock positions to `delegatee`
+/// @param delegatee Address receiving the voting power
+#[no_mangle]
+fn delegate() {
+    let delegatee: Key = runtime::get_named_arg("delegatee");
+    VotingEscrow::default().delegate(delegatee);
+}
+
+/// @notice Take back the voting power delegated by the caller
+#[no_mangle]
+fn undelegate() {
+    VotingEscrow::default().undelegate();
+}
+
+/// @notice Get the delegate of `owner`
+#[no_mangle]
+fn delegates() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let ret: Key = VotingEscrow::default().delegates(owner);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// @notice Measure the voting power delegated to `delegate` at timestamp `time`
+/// @param delegate Address to get the votes of
+/// @param time Timestamp to calculate the votes at
+/// @return Delegated voting power
+#[no_mangle]
+fn get_votes() {
+    let delegate: Key = runtime::get_named_arg("delegate");
+    let time: U256 = runtime::get_named_arg("time");
+    let ret: U256 = VotingEscrow::default().get_votes(delegate, time);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 /// Dummy methods for compatibility with Aragon
 /// @dev Dummy method required for Aragon compatibility
 #[no_mangle]
@@ -232,7 +457,7 @@ fn supply() {
 #[no_mangle]
 fn locked() {
     let addr: Key = runtime::get_named_arg("addr");
-    runtime::ret(CLValue::from_t(data::Locked::instance().get(&addr)).unwrap_or_revert())
+    runtime::ret(CLValue::from_t(VotingEscrow::default().locked(addr)).unwrap_or_revert())
 }
 
 #[no_mangle]
@@ -251,7 +476,7 @@ fn user_point_history() {
     let user: Key = runtime::get_named_arg("user");
     let user_epoch: U256 = runtime::get_named_arg("user_epoch");
     runtime::ret(
-        CLValue::from_t(data::UserPointHistory::instance().get(&user, &user_epoch))
+        CLValue::from_t(VotingEscrow::default().user_point_history(user, user_epoch))
             .unwrap_or_revert(),
     )
 }
@@ -259,16 +484,13 @@ fn user_point_history() {
 #[no_mangle]
 fn user_point_epoch() {
     let user: Key = runtime::get_named_arg("user");
-    runtime::ret(CLValue::from_t(data::UserPointEpoch::instance().get(&user)).unwrap_or_revert())
+    runtime::ret(CLValue::from_t(VotingEscrow::default().user_point_epoch(user)).unwrap_or_revert())
 }
 
 #[no_mangle]
 fn slope_changes() {
     let time: U256 = runtime::get_named_arg("time");
-    runtime::ret(
-        CLValue::from_t(i128_to_tuple(data::SlopeChanges::instance().get(&time)))
-            .unwrap_or_revert(),
-    )
+    runtime::ret(CLValue::from_t(data::SlopeChanges::instance().get(&time)).unwrap_or_revert())
 }
 
 #[no_mangle]
@@ -311,6 +533,21 @@ fn future_admin() {
     runtime::ret(CLValue::from_t(data::get_future_admin()).unwrap_or_revert())
 }
 
+#[no_mangle]
+fn smart_wallet_checker() {
+    runtime::ret(CLValue::from_t(data::get_smart_wallet_checker()).unwrap_or_revert())
+}
+
+#[no_mangle]
+fn future_smart_wallet_checker() {
+    runtime::ret(CLValue::from_t(data::get_future_smart_wallet_checker()).unwrap_or_revert())
+}
+
+#[no_mangle]
+fn penalty_receiver() {
+    runtime::ret(CLValue::from_t(data::get_penalty_receiver()).unwrap_or_revert())
+}
+
 fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(EntryPoint::new(
@@ -341,10 +578,31 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "commit_smart_wallet_checker",
+        vec![Parameter::new("addr", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "apply_smart_wallet_checker",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_penalty_receiver",
+        vec![Parameter::new("addr", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "get_last_user_slope",
         vec![Parameter::new("addr", Key::cl_type())],
-        CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
+        I128::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
@@ -413,6 +671,115 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw_early",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_auto_max_lock",
+        vec![Parameter::new("enabled", bool::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "auto_max_lock",
+        vec![Parameter::new("addr", Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_lock_position",
+        vec![
+            Parameter::new("value", U256::cl_type()),
+            Parameter::new("unlock_time", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw_position",
+        vec![Parameter::new("token_id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "merge",
+        vec![
+            Parameter::new("from_id", U256::cl_type()),
+            Parameter::new("to_id", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "split",
+        vec![
+            Parameter::new("token_id", U256::cl_type()),
+            Parameter::new("amounts", CLType::List(Box::new(U256::cl_type()))),
+        ],
+        CLType::List(Box::new(U256::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_position",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_of",
+        vec![Parameter::new("token_id", U256::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "positions_of",
+        vec![Parameter::new("owner", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "position_of_owner_by_index",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("index", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "position_locked_end",
+        vec![Parameter::new("token_id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "position_balance_of",
+        vec![
+            Parameter::new("token_id", U256::cl_type()),
+            Parameter::new("t", CLType::Option(Box::new(CLType::U256))),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "balance_of",
         vec![
@@ -447,6 +814,75 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of_at_time",
+        vec![
+            Parameter::new("addr", Key::cl_type()),
+            Parameter::new("time", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply_at_time",
+        vec![Parameter::new("time", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balances_of_at",
+        vec![
+            Parameter::new("addrs", CLType::List(Box::new(Key::cl_type()))),
+            Parameter::new("t", U256::cl_type()),
+        ],
+        CLType::List(Box::new(U256::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply_series",
+        vec![
+            Parameter::new("from", U256::cl_type()),
+            Parameter::new("to", U256::cl_type()),
+            Parameter::new("step", U256::cl_type()),
+        ],
+        CLType::List(Box::new(U256::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "delegate",
+        vec![Parameter::new("delegatee", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "undelegate",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "delegates",
+        vec![Parameter::new("owner", Key::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_votes",
+        vec![
+            Parameter::new("delegate", Key::cl_type()),
+            Parameter::new("time", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "change_controller",
         vec![Parameter::new("new_controller", Key::cl_type())],
@@ -510,7 +946,7 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "slope_changes",
         vec![Parameter::new("time", U256::cl_type())],
-        CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U128)]),
+        I128::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
@@ -570,6 +1006,27 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "smart_wallet_checker",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "future_smart_wallet_checker",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "penalty_receiver",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points
 }
 
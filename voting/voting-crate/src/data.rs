@@ -0,0 +1,170 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{bytesrepr::Bytes, ContractHash, ContractPackageHash, Key, U256};
+use casper_types_derive::{CLTyped, FromBytes, ToBytes};
+use casperlabs_contract_utils::*;
+use common::{keys::*, utils::*};
+
+pub const PCT_BASE: U256 = U256([1000000000000000000, 0, 0, 0]); // 0% = 0; 1% = 10^16; 100% = 10^18
+
+pub const VOTER_STATE_ABSENT: u8 = 0;
+pub const VOTER_STATE_YEA: u8 = 1;
+pub const VOTER_STATE_NAY: u8 = 2;
+
+/// (target package hash, entry point, serialized `RuntimeArgs`)
+pub type Action = (Key, String, Bytes);
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+pub struct Vote {
+    pub executed: bool,
+    pub start_date: U256,
+    pub snapshot_ts: U256,
+    pub support_required: U256,
+    pub min_accept_quorum: U256,
+    pub yea: U256,
+    pub nay: U256,
+    pub voting_power: U256,
+}
+
+pub struct Votes {
+    dict: Dict,
+}
+
+impl Votes {
+    #[inline(always)]
+    pub fn instance() -> Votes {
+        Votes {
+            dict: Dict::instance(VOTES_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(VOTES_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, vote_id: &U256) -> Vote {
+        self.dict.get(&vote_id.to_string()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, vote_id: &U256, value: Vote) {
+        self.dict.set(&vote_id.to_string(), value);
+    }
+}
+
+pub struct VoteActions {
+    dict: Dict,
+}
+
+impl VoteActions {
+    #[inline(always)]
+    pub fn instance() -> VoteActions {
+        VoteActions {
+            dict: Dict::instance(VOTE_ACTIONS_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(VOTE_ACTIONS_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, vote_id: &U256) -> Vec<Action> {
+        self.dict.get(&vote_id.to_string()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, vote_id: &U256, value: Vec<Action>) {
+        self.dict.set(&vote_id.to_string(), value);
+    }
+}
+
+pub struct VoterStates {
+    dict: Dict,
+}
+
+impl VoterStates {
+    #[inline(always)]
+    pub fn instance() -> VoterStates {
+        VoterStates {
+            dict: Dict::instance(VOTER_STATES_DICT),
+        }
+    }
+    #[inline(always)]
+    pub fn init() {
+        Dict::init(VOTER_STATES_DICT)
+    }
+    #[inline(always)]
+    pub fn get(&self, vote_id: &U256, voter: &Key) -> (u8, U256) {
+        let key_: String = key_and_value_to_str(voter, vote_id);
+        self.dict.get(key_.as_str()).unwrap_or_default()
+    }
+    #[inline(always)]
+    pub fn set(&self, vote_id: &U256, voter: &Key, value: (u8, U256)) {
+        let key_: String = key_and_value_to_str(voter, vote_id);
+        self.dict.set(key_.as_str(), value);
+    }
+}
+
+pub fn get_voting_escrow() -> Key {
+    get_key(VOTING_ESCROW).unwrap_or_else(zero_address)
+}
+
+pub fn set_voting_escrow(voting_escrow: Key) {
+    set_key(VOTING_ESCROW, voting_escrow);
+}
+
+pub fn get_support_required_pct() -> U256 {
+    get_key(SUPPORT_REQUIRED_PCT).unwrap_or_default()
+}
+
+pub fn set_support_required_pct(support_required_pct: U256) {
+    set_key(SUPPORT_REQUIRED_PCT, support_required_pct);
+}
+
+pub fn get_min_accept_quorum_pct() -> U256 {
+    get_key(MIN_ACCEPT_QUORUM_PCT).unwrap_or_default()
+}
+
+pub fn set_min_accept_quorum_pct(min_accept_quorum_pct: U256) {
+    set_key(MIN_ACCEPT_QUORUM_PCT, min_accept_quorum_pct);
+}
+
+pub fn get_vote_time() -> U256 {
+    get_key(VOTE_TIME).unwrap_or_default()
+}
+
+pub fn set_vote_time(vote_time: U256) {
+    set_key(VOTE_TIME, vote_time);
+}
+
+pub fn get_min_balance() -> U256 {
+    get_key(MIN_BALANCE).unwrap_or_default()
+}
+
+pub fn set_min_balance(min_balance: U256) {
+    set_key(MIN_BALANCE, min_balance);
+}
+
+pub fn get_votes_length() -> U256 {
+    get_key(VOTES_LENGTH).unwrap_or_default()
+}
+
+pub fn set_votes_length(votes_length: U256) {
+    set_key(VOTES_LENGTH, votes_length);
+}
+
+pub fn get_contract_hash() -> ContractHash {
+    get_key(SELF_CONTRACT_HASH).unwrap_or_default()
+}
+
+pub fn set_contract_hash(contract_hash: ContractHash) {
+    set_key(SELF_CONTRACT_HASH, contract_hash);
+}
+
+pub fn get_package_hash() -> ContractPackageHash {
+    get_key(SELF_CONTRACT_PACKAGE_HASH).unwrap_or_default()
+}
+
+pub fn set_package_hash(package_hash: ContractPackageHash) {
+    set_key(SELF_CONTRACT_PACKAGE_HASH, package_hash);
+}
@@ -0,0 +1,126 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, U256,
+};
+use common::events::{CasperEvent, Schema, Schemas};
+
+pub enum VotingEvent {
+    StartVote {
+        vote_id: U256,
+        creator: Key,
+        metadata: String,
+    },
+    CastVote {
+        vote_id: U256,
+        voter: Key,
+        supports: bool,
+        stake: U256,
+    },
+    ExecuteVote {
+        vote_id: U256,
+    },
+    ChangeSupportRequired {
+        support_required_pct: U256,
+    },
+    ChangeMinQuorum {
+        min_accept_quorum_pct: U256,
+    },
+}
+
+impl VotingEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            VotingEvent::StartVote {
+                vote_id: _,
+                creator: _,
+                metadata: _,
+            } => "startVote",
+            VotingEvent::CastVote {
+                vote_id: _,
+                voter: _,
+                supports: _,
+                stake: _,
+            } => "castVote",
+            VotingEvent::ExecuteVote { vote_id: _ } => "executeVote",
+            VotingEvent::ChangeSupportRequired {
+                support_required_pct: _,
+            } => "changeSupportRequired",
+            VotingEvent::ChangeMinQuorum {
+                min_accept_quorum_pct: _,
+            } => "changeMinQuorum",
+        }
+        .to_string()
+    }
+}
+
+impl CasperEvent for VotingEvent {
+    fn name(&self) -> String {
+        self.type_name()
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            VotingEvent::StartVote {
+                vote_id,
+                creator,
+                metadata,
+            } => Ok([
+                vote_id.to_bytes()?,
+                creator.to_bytes()?,
+                metadata.to_bytes()?,
+            ]
+            .concat()),
+            VotingEvent::CastVote {
+                vote_id,
+                voter,
+                supports,
+                stake,
+            } => Ok([
+                vote_id.to_bytes()?,
+                voter.to_bytes()?,
+                supports.to_bytes()?,
+                stake.to_bytes()?,
+            ]
+            .concat()),
+            VotingEvent::ExecuteVote { vote_id } => vote_id.to_bytes(),
+            VotingEvent::ChangeSupportRequired {
+                support_required_pct,
+            } => support_required_pct.to_bytes(),
+            VotingEvent::ChangeMinQuorum {
+                min_accept_quorum_pct,
+            } => min_accept_quorum_pct.to_bytes(),
+        }
+    }
+
+    fn schemas() -> Schemas {
+        Schemas::new()
+            .with(
+                "startVote",
+                Schema::new()
+                    .with_elem::<U256>("vote_id")
+                    .with_elem::<Key>("creator")
+                    .with_elem::<String>("metadata"),
+            )
+            .with(
+                "castVote",
+                Schema::new()
+                    .with_elem::<U256>("vote_id")
+                    .with_elem::<Key>("voter")
+                    .with_elem::<bool>("supports")
+                    .with_elem::<U256>("stake"),
+            )
+            .with("executeVote", Schema::new().with_elem::<U256>("vote_id"))
+            .with(
+                "changeSupportRequired",
+                Schema::new().with_elem::<U256>("support_required_pct"),
+            )
+            .with(
+                "changeMinQuorum",
+                Schema::new().with_elem::<U256>("min_accept_quorum_pct"),
+            )
+    }
+}
@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod data;
+pub mod event;
+mod voting;
+
+pub use voting::VOTING;
@@ -0,0 +1,313 @@
+use crate::{data::*, event::VotingEvent};
+use alloc::{string::String, vec::Vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr, runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use common::{errors::*, events, utils::call_versioned_contract_ignoring_result};
+
+/// @notice Aragon style voting on arbitrary contract calls, weighted by veCRV
+/// @dev Voting power is read from the voting escrow at a timestamp snapshot taken when the vote
+/// is created, so locking after the vote started does not add any weight to it. Passed votes
+/// run their actions through `call_versioned_contract`, which makes this contract usable as the
+/// admin of other DAO contracts.
+pub trait VOTING<Storage: ContractStorage>: ContractContext<Storage> {
+    #[allow(clippy::too_many_arguments)]
+    fn init(
+        &self,
+        voting_escrow: Key,
+        support_required_pct: U256,
+        min_accept_quorum_pct: U256,
+        vote_time: U256,
+        min_balance: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        if min_accept_quorum_pct > support_required_pct {
+            runtime::revert(ApiError::from(Error::VotingInvalidQuorum));
+        }
+        if support_required_pct >= PCT_BASE {
+            runtime::revert(ApiError::from(Error::VotingInvalidSupport));
+        }
+        Votes::init();
+        VoteActions::init();
+        VoterStates::init();
+        events::init::<VotingEvent>();
+        set_voting_escrow(voting_escrow);
+        set_support_required_pct(support_required_pct);
+        set_min_accept_quorum_pct(min_accept_quorum_pct);
+        set_vote_time(vote_time);
+        set_min_balance(min_balance);
+        set_votes_length(0.into());
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    /// @notice Settings can only be changed by a vote executed by this contract
+    #[inline(always)]
+    fn only_self(&self) {
+        if self.get_caller() != Key::from(get_package_hash()) {
+            runtime::revert(ApiError::from(Error::VotingOnlySelf));
+        }
+    }
+
+    #[inline(always)]
+    fn _ve_balance_of_at_time(&self, addr: Key, time: U256) -> U256 {
+        runtime::call_versioned_contract(
+            get_voting_escrow().into_hash().unwrap_or_revert().into(),
+            None,
+            "balance_of_at_time",
+            runtime_args! {
+                "addr" => addr,
+                "time" => time
+            },
+        )
+    }
+
+    #[inline(always)]
+    fn _ve_total_supply_at_time(&self, time: U256) -> U256 {
+        runtime::call_versioned_contract(
+            get_voting_escrow().into_hash().unwrap_or_revert().into(),
+            None,
+            "total_supply_at_time",
+            runtime_args! {
+                "time" => time
+            },
+        )
+    }
+
+    /// @notice Check that `value` is more than `pct` of `total`
+    #[inline(always)]
+    fn _is_value_pct(&self, value: U256, total: U256, pct: U256) -> bool {
+        if total == 0.into() {
+            return false;
+        }
+        let computed_pct: U256 = value
+            .checked_mul(PCT_BASE)
+            .unwrap_or_revert_with(Error::VotingMultiplicationError1)
+            .checked_div(total)
+            .unwrap_or_revert_with(Error::VotingDivisionError1);
+        computed_pct > pct
+    }
+
+    #[inline(always)]
+    fn _vote_exists(&self, vote_id: U256) -> bool {
+        vote_id < get_votes_length()
+    }
+
+    #[inline(always)]
+    fn _is_vote_open(&self, vote: &Vote) -> bool {
+        let now: U256 = U256::from(u64::from(runtime::get_blocktime()));
+        now < vote
+            .start_date
+            .checked_add(get_vote_time())
+            .unwrap_or_revert_with(Error::VotingAdditionError1)
+            && !vote.executed
+    }
+
+    /// @notice Create a new vote executing `actions` once passed
+    /// @param actions List of (package hash, entry point, serialized `RuntimeArgs`) calls
+    /// @param metadata Vote metadata, e.g. a link to the proposal description
+    /// @return Id of the new vote
+    #[inline(always)]
+    fn new_vote(&self, actions: Vec<Action>, metadata: String) -> U256 {
+        for (_, _, args) in actions.iter() {
+            let _: RuntimeArgs = bytesrepr::deserialize(args.to_vec())
+                .unwrap_or_revert_with(Error::VotingInvalidAction1);
+        }
+        let now: U256 = U256::from(u64::from(runtime::get_blocktime()));
+        // Snapshot right before the creation, so that locks made at the same time do not count
+        let snapshot_ts: U256 = now
+            .checked_sub(1.into())
+            .unwrap_or_revert_with(Error::VotingSubtractionError1);
+        let voting_power: U256 = self._ve_total_supply_at_time(snapshot_ts);
+        if voting_power == 0.into() {
+            runtime::revert(ApiError::from(Error::VotingNoVotingPower));
+        }
+        let creator: Key = self.get_caller();
+        if self._ve_balance_of_at_time(creator, snapshot_ts) < get_min_balance() {
+            runtime::revert(ApiError::from(Error::VotingInsufficientBalance));
+        }
+        let vote_id: U256 = get_votes_length();
+        Votes::instance().set(
+            &vote_id,
+            Vote {
+                executed: false,
+                start_date: now,
+                snapshot_ts,
+                support_required: get_support_required_pct(),
+                min_accept_quorum: get_min_accept_quorum_pct(),
+                yea: 0.into(),
+                nay: 0.into(),
+                voting_power,
+            },
+        );
+        VoteActions::instance().set(&vote_id, actions);
+        set_votes_length(
+            vote_id
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Error::VotingAdditionError2),
+        );
+        self.emit(&VotingEvent::StartVote {
+            vote_id,
+            creator,
+            metadata,
+        });
+        vote_id
+    }
+
+    /// @notice Check whether `voter` can vote on vote `vote_id`
+    #[inline(always)]
+    fn can_vote(&self, vote_id: U256, voter: Key) -> bool {
+        if !self._vote_exists(vote_id) {
+            runtime::revert(ApiError::from(Error::VotingNoSuchVote));
+        }
+        let vote: Vote = Votes::instance().get(&vote_id);
+        self._is_vote_open(&vote) && self._ve_balance_of_at_time(voter, vote.snapshot_ts) > 0.into()
+    }
+
+    /// @notice Vote for or against vote `vote_id` with the caller's voting power at the snapshot
+    /// @dev A previous vote of the caller on the same vote is replaced, removing the stake it
+    /// was cast with rather than the current one
+    /// @param vote_id Id of the vote
+    /// @param supports Whether the caller supports the vote
+    #[inline(always)]
+    fn vote(&self, vote_id: U256, supports: bool) {
+        if !self._vote_exists(vote_id) {
+            runtime::revert(ApiError::from(Error::VotingNoSuchVote));
+        }
+        let voter: Key = self.get_caller();
+        let mut vote: Vote = Votes::instance().get(&vote_id);
+        let stake: U256 = self._ve_balance_of_at_time(voter, vote.snapshot_ts);
+        if !self._is_vote_open(&vote) || stake == 0.into() {
+            runtime::revert(ApiError::from(Error::VotingCannotVote));
+        }
+        let (state, old_stake): (u8, U256) = VoterStates::instance().get(&vote_id, &voter);
+        // If voter had previously voted, decrease count
+        if state == VOTER_STATE_YEA {
+            vote.yea = vote
+                .yea
+                .checked_sub(old_stake)
+                .unwrap_or_revert_with(Error::VotingSubtractionError2);
+        } else if state == VOTER_STATE_NAY {
+            vote.nay = vote
+                .nay
+                .checked_sub(old_stake)
+                .unwrap_or_revert_with(Error::VotingSubtractionError3);
+        }
+        if supports {
+            vote.yea = vote
+                .yea
+                .checked_add(stake)
+                .unwrap_or_revert_with(Error::VotingAdditionError3);
+            VoterStates::instance().set(&vote_id, &voter, (VOTER_STATE_YEA, stake));
+        } else {
+            vote.nay = vote
+                .nay
+                .checked_add(stake)
+                .unwrap_or_revert_with(Error::VotingAdditionError4);
+            VoterStates::instance().set(&vote_id, &voter, (VOTER_STATE_NAY, stake));
+        }
+        Votes::instance().set(&vote_id, vote);
+        self.emit(&VotingEvent::CastVote {
+            vote_id,
+            voter,
+            supports,
+            stake,
+        });
+    }
+
+    /// @notice Check whether vote `vote_id` passed and can be executed
+    /// @dev A vote can be executed before it ends once its yea votes alone exceed the
+    /// required support of the whole voting power
+    #[inline(always)]
+    fn can_execute(&self, vote_id: U256) -> bool {
+        if !self._vote_exists(vote_id) {
+            runtime::revert(ApiError::from(Error::VotingNoSuchVote));
+        }
+        let vote: Vote = Votes::instance().get(&vote_id);
+        if vote.executed {
+            return false;
+        }
+        // Voting is already decided
+        if self._is_value_pct(vote.yea, vote.voting_power, vote.support_required) {
+            return true;
+        }
+        // Vote ended?
+        if self._is_vote_open(&vote) {
+            return false;
+        }
+        let total_votes: U256 = vote.yea.saturating_add(vote.nay);
+        // Has enough support?
+        if !self._is_value_pct(vote.yea, total_votes, vote.support_required) {
+            return false;
+        }
+        // Has min quorum?
+        self._is_value_pct(vote.yea, vote.voting_power, vote.min_accept_quorum)
+    }
+
+    /// @notice Execute the actions of passed vote `vote_id`
+    /// @dev Values returned by the called entry points are ignored
+    #[inline(always)]
+    fn execute(&self, vote_id: U256) {
+        if !self.can_execute(vote_id) {
+            runtime::revert(ApiError::from(Error::VotingCannotExecute));
+        }
+        let mut vote: Vote = Votes::instance().get(&vote_id);
+        vote.executed = true;
+        Votes::instance().set(&vote_id, vote);
+        for (target, entry_point, args) in VoteActions::instance().get(&vote_id) {
+            let args: RuntimeArgs = bytesrepr::deserialize(args.to_vec())
+                .unwrap_or_revert_with(Error::VotingInvalidAction2);
+            call_versioned_contract_ignoring_result(
+                target.into_hash().unwrap_or_revert().into(),
+                &entry_point,
+                args,
+            );
+        }
+        self.emit(&VotingEvent::ExecuteVote { vote_id });
+    }
+
+    /// @notice Change the support required for new votes to pass
+    /// @param support_required_pct New support, 10^16 being 1%
+    #[inline(always)]
+    fn change_support_required_pct(&self, support_required_pct: U256) {
+        self.only_self();
+        if get_min_accept_quorum_pct() > support_required_pct {
+            runtime::revert(ApiError::from(Error::VotingInvalidQuorum));
+        }
+        if support_required_pct >= PCT_BASE {
+            runtime::revert(ApiError::from(Error::VotingInvalidSupport));
+        }
+        set_support_required_pct(support_required_pct);
+        self.emit(&VotingEvent::ChangeSupportRequired {
+            support_required_pct,
+        });
+    }
+
+    /// @notice Change the minimum share of the voting power new votes need to pass
+    /// @param min_accept_quorum_pct New quorum, 10^16 being 1%
+    #[inline(always)]
+    fn change_min_accept_quorum_pct(&self, min_accept_quorum_pct: U256) {
+        self.only_self();
+        if min_accept_quorum_pct > get_support_required_pct() {
+            runtime::revert(ApiError::from(Error::VotingInvalidQuorum));
+        }
+        set_min_accept_quorum_pct(min_accept_quorum_pct);
+        self.emit(&VotingEvent::ChangeMinQuorum {
+            min_accept_quorum_pct,
+        });
+    }
+
+    /// @notice Get the vote of `voter` on vote `vote_id`
+    /// @return 0 if absent, 1 for yea, 2 for nay
+    #[inline(always)]
+    fn get_voter_state(&self, vote_id: U256, voter: Key) -> u8 {
+        VoterStates::instance().get(&vote_id, &voter).0
+    }
+
+    fn emit(&self, voting_event: &VotingEvent) {
+        events::emit(voting_event);
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod voting_tests;
+
+#[cfg(test)]
+pub mod voting_instance;
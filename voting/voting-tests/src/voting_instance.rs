@@ -0,0 +1,111 @@
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes},
+    runtime_args, CLTyped, Key, RuntimeArgs, U256,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::keys::*;
+use voting_crate::data::{Action, Vote};
+
+pub struct VOTINGInstance(TestContract);
+impl VOTINGInstance {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        voting_escrow: Key,
+        support_required_pct: U256,
+        min_accept_quorum_pct: U256,
+        vote_time: U256,
+        min_balance: U256,
+        time: u64,
+    ) -> VOTINGInstance {
+        VOTINGInstance(TestContract::new(
+            env,
+            "voting.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "voting_escrow" => voting_escrow,
+                "support_required_pct" => support_required_pct,
+                "min_accept_quorum_pct" => min_accept_quorum_pct,
+                "vote_time" => vote_time,
+                "min_balance" => min_balance,
+            },
+            time,
+        ))
+    }
+
+    pub fn new_vote(&self, owner: AccountHash, actions: Vec<Action>, metadata: &str, time: u64) {
+        self.0.call_contract(
+            owner,
+            "new_vote",
+            runtime_args! {
+                "actions" => actions,
+                "metadata" => metadata.to_string()
+            },
+            time,
+        );
+    }
+
+    pub fn vote(&self, owner: AccountHash, vote_id: U256, supports: bool, time: u64) {
+        self.0.call_contract(
+            owner,
+            "vote",
+            runtime_args! {
+                "vote_id" => vote_id,
+                "supports" => supports
+            },
+            time,
+        );
+    }
+
+    pub fn execute(&self, owner: AccountHash, vote_id: U256, time: u64) {
+        self.0.call_contract(
+            owner,
+            "execute",
+            runtime_args! {
+                "vote_id" => vote_id
+            },
+            time,
+        );
+    }
+
+    pub fn change_support_required_pct(
+        &self,
+        owner: AccountHash,
+        support_required_pct: U256,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            owner,
+            "change_support_required_pct",
+            runtime_args! {
+                "support_required_pct" => support_required_pct
+            },
+            time,
+        );
+    }
+
+    pub fn get_vote(&self, vote_id: U256) -> Vote {
+        self.0
+            .query_dictionary(VOTES_DICT, vote_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn event(&self, index: u32) -> Bytes {
+        self.0
+            .query_dictionary(EVENTS_DICT, index.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+
+    // Get stored key values
+    pub fn key_value<T: CLTyped + FromBytes>(&self, key: String) -> T {
+        self.0.query_named_key(key)
+    }
+}
@@ -0,0 +1,278 @@
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    runtime_args, Key, RuntimeArgs, U256,
+};
+use casperlabs_test_env::{TestContract, TestEnv};
+use common::{events::Schemas, keys::*};
+use crv20::Address;
+use voting_crate::data::{Action, Vote, PCT_BASE};
+
+use crate::voting_instance::VOTINGInstance;
+
+const NAME: &str = "VOTING";
+const TEN_E_NINE: u128 = 1000000000;
+const TEN_E_SIXTEEN: u128 = 10000000000000000;
+const MILLI_SECONDS_IN_DAY: u64 = 86_400_000;
+const WEEK: u64 = 604_800_000;
+
+fn deploy_erc20_crv(env: &TestEnv, sender: AccountHash, time_now: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "erc20-crv.wasm",
+        "erc20-crv",
+        sender,
+        runtime_args! {
+            "name" => "CRV",
+            "symbol" => "ERC20CRV",
+            "decimals" => 9_u8,
+        },
+        time_now,
+    )
+}
+
+fn deploy_voting_escrow(
+    env: &TestEnv,
+    sender: AccountHash,
+    token_addr: Key,
+    time_now: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "voting-escrow.wasm",
+        "voting-escrow",
+        sender,
+        runtime_args! {
+            "token_addr" => token_addr,
+            "name" => "Vote-escrowed CRV",
+            "symbol" => "veCRV",
+            "version" => "veCRV_1.0.0",
+        },
+        time_now,
+    )
+}
+
+fn create_lock(
+    owner: AccountHash,
+    erc20_crv: &TestContract,
+    voting_escrow: &TestContract,
+    amount: U256,
+    time_now: u64,
+) {
+    let spender: Address = Address::Contract(voting_escrow.package_hash().into());
+    erc20_crv.call_contract(
+        owner,
+        "increase_allowance",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => amount
+        },
+        time_now,
+    );
+    voting_escrow.call_contract(
+        owner,
+        "create_lock",
+        runtime_args! {
+            "value" => amount,
+            "unlock_time" => U256::from(time_now + MILLI_SECONDS_IN_DAY * 365 * 4)
+        },
+        time_now,
+    );
+}
+
+// Deploys CRV, voting escrow and voting with 51% support, 30% quorum and a week long votes,
+// and locks 1000 CRV of the owner for ~4 years
+fn deploy() -> (
+    TestEnv,
+    AccountHash,
+    VOTINGInstance,
+    TestContract,
+    TestContract,
+    u64,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let time_now: u64 = WEEK * 2000;
+    let erc20_crv = deploy_erc20_crv(&env, owner, time_now);
+    let voting_escrow =
+        deploy_voting_escrow(&env, owner, Key::Hash(erc20_crv.package_hash()), time_now);
+    let instance = VOTINGInstance::new_deploy(
+        &env,
+        NAME,
+        owner,
+        Key::Hash(voting_escrow.package_hash()),
+        U256::from(51 * TEN_E_SIXTEEN),
+        U256::from(30 * TEN_E_SIXTEEN),
+        U256::from(WEEK),
+        U256::from(TEN_E_NINE),
+        time_now,
+    );
+    create_lock(
+        owner,
+        &erc20_crv,
+        &voting_escrow,
+        U256::from(1000 * TEN_E_NINE),
+        time_now,
+    );
+    (env, owner, instance, erc20_crv, voting_escrow, time_now)
+}
+
+fn change_support_action(instance: &VOTINGInstance, support_required_pct: U256) -> Action {
+    let args: RuntimeArgs = runtime_args! {
+        "support_required_pct" => support_required_pct
+    };
+    (
+        Key::Hash(instance.package_hash()),
+        "change_support_required_pct".to_string(),
+        Bytes::from(args.to_bytes().unwrap()),
+    )
+}
+
+#[test]
+fn test_deploy() {
+    let (_, _, instance, _, voting_escrow, _) = deploy();
+    let ret: Key = instance.key_value(VOTING_ESCROW.to_string());
+    assert_eq!(ret, Key::Hash(voting_escrow.package_hash()));
+    let ret: U256 = instance.key_value(SUPPORT_REQUIRED_PCT.to_string());
+    assert_eq!(ret, U256::from(51 * TEN_E_SIXTEEN));
+    let ret: U256 = instance.key_value(MIN_ACCEPT_QUORUM_PCT.to_string());
+    assert_eq!(ret, U256::from(30 * TEN_E_SIXTEEN));
+    let ret: U256 = instance.key_value(VOTE_TIME.to_string());
+    assert_eq!(ret, U256::from(WEEK));
+    let ret: U256 = instance.key_value(VOTES_LENGTH.to_string());
+    assert_eq!(ret, 0.into());
+}
+
+#[test]
+fn test_new_vote() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    let action = change_support_action(&instance, U256::from(60 * TEN_E_SIXTEEN));
+    instance.new_vote(owner, vec![action], "Raise support", time_now + 1000);
+    let ret: U256 = instance.key_value(VOTES_LENGTH.to_string());
+    assert_eq!(ret, 1.into(), "Vote not created");
+    let vote: Vote = instance.get_vote(0.into());
+    assert!(!vote.executed);
+    assert_eq!(vote.start_date, U256::from(time_now + 1000));
+    assert_eq!(vote.snapshot_ts, U256::from(time_now + 999));
+    assert_eq!(vote.support_required, U256::from(51 * TEN_E_SIXTEEN));
+    assert!(vote.voting_power > 0.into(), "No voting power at snapshot");
+}
+
+#[test]
+fn test_vote_and_execute() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    let action = change_support_action(&instance, U256::from(60 * TEN_E_SIXTEEN));
+    instance.new_vote(owner, vec![action], "Raise support", time_now + 1000);
+    instance.vote(owner, 0.into(), true, time_now + 2000);
+    let vote: Vote = instance.get_vote(0.into());
+    assert_eq!(vote.yea, vote.voting_power, "Stake not counted");
+    // The only locker supports the vote, so it is decided before it ends
+    instance.execute(owner, 0.into(), time_now + 3000);
+    let vote: Vote = instance.get_vote(0.into());
+    assert!(vote.executed, "Vote not executed");
+    let ret: U256 = instance.key_value(SUPPORT_REQUIRED_PCT.to_string());
+    assert_eq!(ret, U256::from(60 * TEN_E_SIXTEEN), "Action not executed");
+}
+
+#[test]
+fn test_execute_action_returning_value() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    let args: RuntimeArgs = runtime_args! {
+        "vote_id" => U256::from(0),
+        "voter" => Key::Account(owner)
+    };
+    let action: Action = (
+        Key::Hash(instance.package_hash()),
+        "get_voter_state".to_string(),
+        Bytes::from(args.to_bytes().unwrap()),
+    );
+    instance.new_vote(owner, vec![action], "Read voter state", time_now + 1000);
+    instance.vote(owner, 0.into(), true, time_now + 2000);
+    instance.execute(owner, 0.into(), time_now + 3000);
+    let vote: Vote = instance.get_vote(0.into());
+    assert!(vote.executed, "Vote not executed");
+}
+
+#[test]
+fn test_vote_events() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    instance.new_vote(owner, vec![], "Signal", time_now + 1000);
+    instance.vote(owner, 0.into(), true, time_now + 2000);
+    let length: u32 = instance.key_value(EVENTS_LENGTH.to_string());
+    assert_eq!(length, 2, "Start and cast vote events not emitted");
+    let schemas: Schemas = instance.key_value(EVENTS_SCHEMA.to_string());
+    assert_eq!(schemas.get("castVote").unwrap().elems().len(), 4);
+    let event: Bytes = instance.event(1);
+    let (name, fields) = String::from_bytes(&event).unwrap();
+    assert_eq!(name, "event_castVote");
+    let (vote_id, fields) = U256::from_bytes(fields).unwrap();
+    let (voter, fields) = Key::from_bytes(fields).unwrap();
+    let (supports, fields) = bool::from_bytes(fields).unwrap();
+    let (stake, fields) = U256::from_bytes(fields).unwrap();
+    assert_eq!(vote_id, 0.into());
+    assert_eq!(voter, Key::from(owner));
+    assert!(supports);
+    assert_eq!(stake, instance.get_vote(0.into()).yea);
+    assert!(fields.is_empty());
+}
+
+#[test]
+fn test_vote_change() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    instance.new_vote(owner, vec![], "Signal", time_now + 1000);
+    instance.vote(owner, 0.into(), true, time_now + 2000);
+    instance.vote(owner, 0.into(), false, time_now + 3000);
+    let vote: Vote = instance.get_vote(0.into());
+    assert_eq!(vote.yea, 0.into(), "Previous vote not removed");
+    assert_eq!(vote.nay, vote.voting_power);
+}
+
+#[test]
+#[should_panic]
+fn test_execute_rejected_vote() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    let action = change_support_action(&instance, U256::from(60 * TEN_E_SIXTEEN));
+    instance.new_vote(owner, vec![action], "Raise support", time_now + 1000);
+    instance.vote(owner, 0.into(), false, time_now + 2000);
+    instance.execute(owner, 0.into(), time_now + 1000 + WEEK);
+}
+
+#[test]
+#[should_panic]
+fn test_vote_with_lock_after_snapshot() {
+    let (env, owner, instance, erc20_crv, voting_escrow, time_now) = deploy();
+    let user = env.next_user();
+    instance.new_vote(owner, vec![], "Signal", time_now + 1000);
+    erc20_crv.call_contract(
+        owner,
+        "transfer",
+        runtime_args! {
+            "recipient" => Address::Account(user),
+            "amount" => U256::from(1000 * TEN_E_NINE)
+        },
+        time_now + 2000,
+    );
+    create_lock(
+        user,
+        &erc20_crv,
+        &voting_escrow,
+        U256::from(1000 * TEN_E_NINE),
+        time_now + 2000,
+    );
+    instance.vote(user, 0.into(), true, time_now + 3000);
+}
+
+#[test]
+#[should_panic]
+fn test_vote_after_end() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    instance.new_vote(owner, vec![], "Signal", time_now + 1000);
+    instance.vote(owner, 0.into(), true, time_now + 1000 + WEEK);
+}
+
+#[test]
+#[should_panic]
+fn test_change_support_required_pct_directly() {
+    let (_, owner, instance, _, _, time_now) = deploy();
+    instance.change_support_required_pct(owner, PCT_BASE / 2, time_now);
+}
@@ -0,0 +1,407 @@
+#![no_main]
+#![no_std]
+extern crate alloc;
+use alloc::{collections::BTreeSet, format, string::String, vec, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+};
+use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
+use voting_crate::{
+    self,
+    data::{self, Action, Vote},
+    VOTING,
+};
+
+#[derive(Default)]
+struct Voting(OnChainContractStorage);
+impl ContractContext<OnChainContractStorage> for Voting {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl VOTING<OnChainContractStorage> for Voting {}
+impl Voting {
+    #[allow(clippy::too_many_arguments)]
+    fn constructor(
+        &mut self,
+        voting_escrow: Key,
+        support_required_pct: U256,
+        min_accept_quorum_pct: U256,
+        vote_time: U256,
+        min_balance: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        VOTING::init(
+            self,
+            voting_escrow,
+            support_required_pct,
+            min_accept_quorum_pct,
+            vote_time,
+            min_balance,
+            contract_hash,
+            package_hash,
+        );
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let voting_escrow: Key = runtime::get_named_arg("voting_escrow");
+    let support_required_pct: U256 = runtime::get_named_arg("support_required_pct");
+    let min_accept_quorum_pct: U256 = runtime::get_named_arg("min_accept_quorum_pct");
+    let vote_time: U256 = runtime::get_named_arg("vote_time");
+    let min_balance: U256 = runtime::get_named_arg("min_balance");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Voting::default().constructor(
+        voting_escrow,
+        support_required_pct,
+        min_accept_quorum_pct,
+        vote_time,
+        min_balance,
+        contract_hash,
+        package_hash,
+    );
+}
+
+/// Create a new vote executing `actions` once passed
+/// # Parameters
+/// * `actions` - List of (package hash, entry point, serialized `RuntimeArgs`) calls
+/// * `metadata` - Vote metadata, e.g. a link to the proposal description
+#[no_mangle]
+fn new_vote() {
+    let actions: Vec<Action> = runtime::get_named_arg("actions");
+    let metadata: String = runtime::get_named_arg("metadata");
+    let ret: U256 = Voting::default().new_vote(actions, metadata);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Vote for or against vote `vote_id` with the caller's voting power at the snapshot
+/// # Parameters
+/// * `vote_id` - Id of the vote
+/// * `supports` - Whether the caller supports the vote
+#[no_mangle]
+fn vote() {
+    let vote_id: U256 = runtime::get_named_arg("vote_id");
+    let supports: bool = runtime::get_named_arg("supports");
+    Voting::default().vote(vote_id, supports);
+}
+
+/// Execute the actions of passed vote `vote_id`
+#[no_mangle]
+fn execute() {
+    let vote_id: U256 = runtime::get_named_arg("vote_id");
+    Voting::default().execute(vote_id);
+}
+
+/// Check whether `voter` can vote on vote `vote_id`
+#[no_mangle]
+fn can_vote() {
+    let vote_id: U256 = runtime::get_named_arg("vote_id");
+    let voter: Key = runtime::get_named_arg("voter");
+    let ret: bool = Voting::default().can_vote(vote_id, voter);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Check whether vote `vote_id` passed and can be executed
+#[no_mangle]
+fn can_execute() {
+    let vote_id: U256 = runtime::get_named_arg("vote_id");
+    let ret: bool = Voting::default().can_execute(vote_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn get_vote() {
+    let vote_id: U256 = runtime::get_named_arg("vote_id");
+    let ret: Vote = data::Votes::instance().get(&vote_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Get the vote of `voter` on vote `vote_id`, 0 if absent, 1 for yea, 2 for nay
+#[no_mangle]
+fn get_voter_state() {
+    let vote_id: U256 = runtime::get_named_arg("vote_id");
+    let voter: Key = runtime::get_named_arg("voter");
+    let ret: u8 = Voting::default().get_voter_state(vote_id, voter);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// Change the support required for new votes to pass, only callable through a vote
+#[no_mangle]
+fn change_support_required_pct() {
+    let support_required_pct: U256 = runtime::get_named_arg("support_required_pct");
+    Voting::default().change_support_required_pct(support_required_pct);
+}
+
+/// Change the minimum share of the voting power new votes need to pass, only callable through a vote
+#[no_mangle]
+fn change_min_accept_quorum_pct() {
+    let min_accept_quorum_pct: U256 = runtime::get_named_arg("min_accept_quorum_pct");
+    Voting::default().change_min_accept_quorum_pct(min_accept_quorum_pct);
+}
+
+#[no_mangle]
+fn voting_escrow() {
+    runtime::ret(CLValue::from_t(data::get_voting_escrow()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn support_required_pct() {
+    runtime::ret(CLValue::from_t(data::get_support_required_pct()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn min_accept_quorum_pct() {
+    runtime::ret(CLValue::from_t(data::get_min_accept_quorum_pct()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn vote_time() {
+    runtime::ret(CLValue::from_t(data::get_vote_time()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn min_balance() {
+    runtime::ret(CLValue::from_t(data::get_min_balance()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn votes_length() {
+    runtime::ret(CLValue::from_t(data::get_votes_length()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("voting_escrow", Key::cl_type()),
+            Parameter::new("support_required_pct", U256::cl_type()),
+            Parameter::new("min_accept_quorum_pct", U256::cl_type()),
+            Parameter::new("vote_time", U256::cl_type()),
+            Parameter::new("min_balance", U256::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "new_vote",
+        vec![
+            Parameter::new("actions", Vec::<Action>::cl_type()),
+            Parameter::new("metadata", String::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "vote",
+        vec![
+            Parameter::new("vote_id", U256::cl_type()),
+            Parameter::new("supports", bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute",
+        vec![Parameter::new("vote_id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "can_vote",
+        vec![
+            Parameter::new("vote_id", U256::cl_type()),
+            Parameter::new("voter", Key::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "can_execute",
+        vec![Parameter::new("vote_id", U256::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_vote",
+        vec![Parameter::new("vote_id", U256::cl_type())],
+        Vote::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_voter_state",
+        vec![
+            Parameter::new("vote_id", U256::cl_type()),
+            Parameter::new("voter", Key::cl_type()),
+        ],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "change_support_required_pct",
+        vec![Parameter::new("support_required_pct", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "change_min_accept_quorum_pct",
+        vec![Parameter::new("min_accept_quorum_pct", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "voting_escrow",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "support_required_pct",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "min_accept_quorum_pct",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "vote_time",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "min_balance",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "votes_length",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Store contract in the account's named keys. Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        // add a first version to this package
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let voting_escrow: Key = runtime::get_named_arg("voting_escrow");
+        let support_required_pct: U256 = runtime::get_named_arg("support_required_pct");
+        let min_accept_quorum_pct: U256 = runtime::get_named_arg("min_accept_quorum_pct");
+        let vote_time: U256 = runtime::get_named_arg("vote_time");
+        let min_balance: U256 = runtime::get_named_arg("min_balance");
+        let constructor_args = runtime_args! {
+            "voting_escrow" => voting_escrow,
+            "support_required_pct" => support_required_pct,
+            "min_accept_quorum_pct" => min_accept_quorum_pct,
+            "vote_time" => vote_time,
+            "min_balance" => min_balance,
+            "package_hash" => package_hash,
+            "contract_hash" => contract_hash,
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    }
+    // If contract package did already exist
+    else {
+        // get the package
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+        // create new version and install it
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}